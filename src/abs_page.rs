@@ -0,0 +1,260 @@
+//! A scraper of the abstract pages of arXiv.org, such as `https://arxiv.org/abs/1706.03762`,
+//! as a fallback of the export API.
+
+use crate::listing::{field, parse_subjects, selector, text};
+use crate::{
+	ArticleVersion, ArxivArticle, ArxivArticleLink, ArxivIdentifier, ScrapeError, VersionHistory,
+};
+use ::scraper::Html;
+use std::str::FromStr;
+use time::macros::format_description;
+use time::PrimitiveDateTime;
+
+impl ArxivArticle {
+	/// Scrapes the abstract page of an article, such as `https://arxiv.org/abs/1706.03762`,
+	/// including the history of its versions.
+	///
+	/// The article describes the version of the page, which is the latest version unless the
+	/// URL of the page has a version.
+	///
+	/// # Errors
+	/// An error is returned if the page is missing the identifier, title, abstract or
+	/// submission history of the article, or has no valid category.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivArticle;
+	///
+	/// let html = r#"<div id="abs">
+	///   <h1 class="title mathjax"><span class="descriptor">Title:</span>Attention Is All You Need</h1>
+	///   <div class="authors"><span class="descriptor">Authors:</span>
+	///     <a href="https://arxiv.org/a/vaswani_a_1">Ashish Vaswani</a>,
+	///     <a href="https://arxiv.org/a/shazeer_n_1">Noam Shazeer</a>
+	///   </div>
+	///   <blockquote class="abstract mathjax">
+	///     <span class="descriptor">Abstract:</span>The dominant sequence transduction models...
+	///   </blockquote>
+	///   <table summary="Additional metadata">
+	///     <tr><td class="tablecell label">Subjects:</td><td class="tablecell subjects">
+	///       <span class="primary-subject">Computation and Language (cs.CL)</span>;
+	///       Machine Learning (cs.LG)</td></tr>
+	///     <tr><td class="tablecell label">Cite as:</td><td class="tablecell arxivid">
+	///       <span class="arxivid"><a href="https://arxiv.org/abs/1706.03762">arXiv:1706.03762</a></span>
+	///     </td></tr>
+	///   </table>
+	/// </div>
+	/// <div class="submission-history">
+	///   <h2>Submission history</h2> From: Llion Jones<br/>
+	///   <strong><a href="/abs/1706.03762v1">[v1]</a></strong> Mon, 12 Jun 2017 17:57:34 UTC (1,102 KB)<br/>
+	///   <strong>[v2]</strong> Mon, 19 Jun 2017 16:49:45 UTC (1,125 KB)<br/>
+	/// </div>"#;
+	///
+	/// let article = ArxivArticle::from_abs_html(html).unwrap();
+	/// assert_eq!(article.title, "Attention Is All You Need");
+	/// assert_eq!(article.authors, ["Ashish Vaswani", "Noam Shazeer"]);
+	/// assert_eq!(article.versions.len(), 2);
+	/// assert_eq!(article.versions.latest().and_then(|version| version.size_kb), Some(1125));
+	/// ```
+	pub fn from_abs_html(html: &str) -> Result<Self, ScrapeError> {
+		let document = Html::parse_document(html);
+		let root = document.root_element();
+
+		let ids = root
+			.select(&selector(".arxivid a"))
+			.map(|a| ArxivIdentifier::from_str(&text(a)))
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(ScrapeError::InvalidId)?;
+		let id = ids
+			.iter()
+			.find(|id| id.version().is_some())
+			.or_else(|| ids.first())
+			.cloned()
+			.ok_or(ScrapeError::MissingElement("identifier"))?;
+
+		let history = root
+			.select(&selector(".submission-history"))
+			.next()
+			.map(text)
+			.unwrap_or_default();
+		let versions = parse_history(&history)?;
+		let first = versions
+			.first()
+			.ok_or(ScrapeError::MissingElement("submission history"))?;
+		let current = id
+			.version()
+			.and_then(|version| versions.get(version))
+			.or_else(|| versions.latest())
+			.unwrap_or(first);
+		let (published, updated) = (first.submitted, current.submitted);
+
+		let doi = root
+			.select(&selector("td.doi a"))
+			.map(text)
+			.next()
+			.map(|doi| String::from(doi.trim_start_matches("https://doi.org/")));
+		let mut links = vec![
+			ArxivArticleLink {
+				href: id.abs_url(),
+				rel: Some(String::from("alternate")),
+				title: None,
+				media_type: Some(String::from("text/html")),
+			},
+			ArxivArticleLink {
+				href: id.abs_url().replacen("/abs/", "/pdf/", 1),
+				rel: Some(String::from("related")),
+				title: Some(String::from("pdf")),
+				media_type: Some(String::from("application/pdf")),
+			},
+		];
+		if let Some(doi) = &doi {
+			links.push(ArxivArticleLink {
+				href: format!("https://doi.org/{}", doi),
+				rel: Some(String::from("related")),
+				title: Some(String::from("doi")),
+				media_type: None,
+			});
+		}
+
+		let subjects = field(root, "td.subjects", "").unwrap_or_default();
+		Ok(Self {
+			id,
+			title: field(root, "h1.title", "Title:").ok_or(ScrapeError::MissingElement("title"))?,
+			authors: root
+				.select(&selector(".authors a"))
+				.map(text)
+				.filter(|name| !name.is_empty())
+				.collect(),
+			summary: field(root, "blockquote.abstract", "Abstract:")
+				.ok_or(ScrapeError::MissingElement("abstract"))?,
+			categories: parse_subjects(&subjects)?,
+			links,
+			doi,
+			journal_ref: field(root, "td.jref", ""),
+			comment: field(root, "td.comments", ""),
+			published,
+			updated,
+			versions,
+		})
+	}
+}
+
+/// Parses the versions of a submission history, such as
+/// `[v1] Mon, 12 Jun 2017 17:57:34 UTC (1,102 KB) [v2] ...`
+fn parse_history(history: &str) -> Result<VersionHistory, ScrapeError> {
+	let format = format_description!(
+		"[weekday repr:short], [day padding:none] [month repr:short] [year] [hour]:[minute]:[second] UTC"
+	);
+	let mut versions = Vec::new();
+	for entry in history.split("[v").skip(1) {
+		let (version, rest) = match entry.split_once(']') {
+			Some((version, rest)) => match version.parse::<u8>() {
+				Ok(version) => (version, rest),
+				Err(_) => continue,
+			},
+			None => continue,
+		};
+		let (date, size) = match rest.split_once('(') {
+			Some((date, size)) => (date.trim(), Some(size)),
+			None => (rest.trim(), None),
+		};
+		let submitted = PrimitiveDateTime::parse(date, &format)
+			.map_err(|_| ScrapeError::InvalidDate(String::from(date)))?
+			.assume_utc();
+		let mut version = ArticleVersion::new(version, submitted);
+		version.size_kb = size.and_then(parse_size);
+		versions.push(version);
+	}
+	Ok(VersionHistory::new(versions))
+}
+
+/// Parses a size in kilobytes, such as `1,102 KB)`
+fn parse_size(size: &str) -> Option<u32> {
+	let (size, _) = size.split_once(['K', 'k'])?;
+	size.trim().replace(',', "").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ArxivArticleMetadata;
+	use time::macros::datetime;
+
+	const ABS: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head><title>[hep-th/0601001v2] An old article</title></head>
+<body>
+<div id="abs">
+  <div class="dateline">[Submitted on 1 Jan 2006 (<a href="/abs/hep-th/0601001v1">v1</a>), last revised 2 Feb 2006 (this version, v2)]</div>
+  <h1 class="title mathjax"><span class="descriptor">Title:</span>An old
+    article</h1>
+  <div class="authors"><span class="descriptor">Authors:</span><a href="/a/doe_j_1">Jane Doe</a>, <a href="/a/smith_j_1">John Smith</a></div>
+  <blockquote class="abstract mathjax">
+    <span class="descriptor">Abstract:</span>We study
+    holography.
+  </blockquote>
+  <div class="metatable">
+    <table summary="Additional metadata">
+      <tr><td class="tablecell label">Comments:</td><td class="tablecell comments mathjax">12 pages</td></tr>
+      <tr><td class="tablecell label">Subjects:</td><td class="tablecell subjects"><span class="primary-subject">High Energy Physics - Theory (hep-th)</span>; General Relativity and Quantum Cosmology (gr-qc)</td></tr>
+      <tr><td class="tablecell label">Journal&nbsp;reference:</td><td class="tablecell jref">Phys. Rev. D 73 (2006) 1</td></tr>
+      <tr><td class="tablecell label">Related DOI:</td><td class="tablecell doi"><a href="https://doi.org/10.1103/PhysRevD.73.1">https://doi.org/10.1103/PhysRevD.73.1</a></td></tr>
+      <tr><td class="tablecell label">Cite as:</td><td class="tablecell arxivid"><span class="arxivid"><a href="https://arxiv.org/abs/hep-th/0601001">arXiv:hep-th/0601001</a> [hep-th]</span></td></tr>
+      <tr><td class="tablecell label">&nbsp;</td><td class="tablecell arxividv">(or <span class="arxivid"><a href="https://arxiv.org/abs/hep-th/0601001v2">arXiv:hep-th/0601001v2</a> [hep-th]</span> for this version)</td></tr>
+    </table>
+  </div>
+</div>
+<div class="submission-history">
+  <h2>Submission history</h2> From: Jane Doe [<a href="/show-email/1/hep-th/0601001">view email</a>]
+  <br/><strong><a href="/abs/hep-th/0601001v1">[v1]</a></strong> Sun, 1 Jan 2006 10:00:00 UTC (24 KB)<br/>
+  <strong>[v2]</strong> Thu, 2 Feb 2006 11:30:00 UTC (1,025 KB)<br/>
+</div>
+</body>
+</html>"#;
+
+	#[test]
+	fn scrape_abs_page() {
+		let article = ArxivArticle::from_abs_html(ABS).unwrap();
+		assert_eq!(article.id.to_string(), "hep-th/0601001v2");
+		assert_eq!(article.title, "An old article");
+		assert_eq!(article.authors, ["Jane Doe", "John Smith"]);
+		assert_eq!(article.summary, "We study holography.");
+		assert_eq!(article.categories.to_string(), "hep-th gr-qc");
+		assert_eq!(article.comment.as_deref(), Some("12 pages"));
+		assert_eq!(article.journal_ref.as_deref(), Some("Phys. Rev. D 73 (2006) 1"));
+		assert_eq!(article.doi.as_deref(), Some("10.1103/PhysRevD.73.1"));
+		assert_eq!(article.pdf_url(), Some("https://arxiv.org/pdf/hep-th/0601001v2"));
+		assert_eq!(article.published, datetime!(2006-01-01 10:00:00 UTC));
+		assert_eq!(article.updated, datetime!(2006-02-02 11:30:00 UTC));
+		assert_eq!(article.versions.len(), 2);
+		assert_eq!(article.versions.first().and_then(|version| version.size_kb), Some(24));
+		assert_eq!(
+			article
+				.versions
+				.latest()
+				.and_then(|version| version.size_kb),
+			Some(1025)
+		);
+
+		let metadata = ArxivArticleMetadata::from(article);
+		assert_eq!(metadata.id.to_string(), "hep-th/0601001");
+		assert_eq!(metadata.versions.len(), 2);
+	}
+
+	#[test]
+	fn scrape_abs_page_errors() {
+		assert_eq!(
+			ArxivArticle::from_abs_html("<html></html>"),
+			Err(ScrapeError::MissingElement("identifier"))
+		);
+		let without_history = ABS.replace("[v", "");
+		assert_eq!(
+			ArxivArticle::from_abs_html(&without_history),
+			Err(ScrapeError::MissingElement("submission history"))
+		);
+		let invalid_date = ABS.replace("Sun, 1 Jan 2006", "1 January 2006");
+		assert_eq!(
+			ArxivArticle::from_abs_html(&invalid_date),
+			Err(ScrapeError::InvalidDate(String::from("1 January 2006 10:00:00 UTC")))
+		);
+	}
+}
@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// How an article was announced in the daily listings of arXiv.org
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::AnnouncementType;
+///
+/// let kind = AnnouncementType::from_str("replace-cross").unwrap();
+/// assert_eq!(kind, AnnouncementType::ReplaceCross);
+/// assert_eq!(kind.to_string(), "replace-cross");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnnouncementType {
+	/// A new submission to the category
+	New,
+	/// A new submission cross-listed from another category
+	Cross,
+	/// A new version of an article of the category
+	Replace,
+	/// A new version of an article cross-listed from another category
+	ReplaceCross,
+}
+
+impl AnnouncementType {
+	/// The announcement type of a new article or a new version, announced in the category it
+	/// was submitted to or cross-listed from another category
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::AnnouncementType;
+	///
+	/// assert_eq!(AnnouncementType::from_flags(true, true), AnnouncementType::ReplaceCross);
+	/// assert_eq!(AnnouncementType::from_flags(false, true), AnnouncementType::Cross);
+	/// ```
+	#[must_use]
+	pub const fn from_flags(replacement: bool, cross_list: bool) -> Self {
+		match (replacement, cross_list) {
+			(false, false) => Self::New,
+			(false, true) => Self::Cross,
+			(true, false) => Self::Replace,
+			(true, true) => Self::ReplaceCross,
+		}
+	}
+
+	/// Whether a new version of an existing article is announced
+	#[must_use]
+	#[inline]
+	pub const fn is_replacement(self) -> bool {
+		matches!(self, Self::Replace | Self::ReplaceCross)
+	}
+
+	/// Whether the article is announced in a category other than its primary category
+	#[must_use]
+	#[inline]
+	pub const fn is_cross_list(self) -> bool {
+		matches!(self, Self::Cross | Self::ReplaceCross)
+	}
+
+	/// The name of the announcement type in the feeds of arXiv.org, such as `replace-cross`
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::New => "new",
+			Self::Cross => "cross",
+			Self::Replace => "replace",
+			Self::ReplaceCross => "replace-cross",
+		}
+	}
+}
+
+impl Display for AnnouncementType {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for AnnouncementType {
+	type Err = AnnouncementTypeError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim() {
+			"new" => Ok(Self::New),
+			"cross" => Ok(Self::Cross),
+			"replace" => Ok(Self::Replace),
+			"replace-cross" => Ok(Self::ReplaceCross),
+			s => Err(AnnouncementTypeError::Unknown(String::from(s))),
+		}
+	}
+}
+
+/// An error that can occur when parsing an [`AnnouncementType`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnouncementTypeError {
+	/// The string is not one of `new`, `cross`, `replace` or `replace-cross`
+	Unknown(String),
+}
+
+impl Error for AnnouncementTypeError {}
+
+impl Display for AnnouncementTypeError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Unknown(s) => write!(f, "\"{}\" is not an announcement type of arXiv.org.", s),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn announcement_type_flags() {
+		for kind in [
+			AnnouncementType::New,
+			AnnouncementType::Cross,
+			AnnouncementType::Replace,
+			AnnouncementType::ReplaceCross,
+		] {
+			let flags = AnnouncementType::from_flags(kind.is_replacement(), kind.is_cross_list());
+			assert_eq!(flags, kind);
+		}
+		assert!(!AnnouncementType::New.is_replacement());
+		assert!(!AnnouncementType::Replace.is_cross_list());
+	}
+
+	#[test]
+	fn parse_announcement_type() {
+		for kind in [
+			AnnouncementType::New,
+			AnnouncementType::Cross,
+			AnnouncementType::Replace,
+			AnnouncementType::ReplaceCross,
+		] {
+			assert_eq!(AnnouncementType::from_str(kind.as_str()), Ok(kind));
+		}
+		assert_eq!(AnnouncementType::from_str(" new\n"), Ok(AnnouncementType::New));
+		assert_eq!(
+			AnnouncementType::from_str("replaced"),
+			Err(AnnouncementTypeError::Unknown(String::from("replaced")))
+		);
+	}
+}
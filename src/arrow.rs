@@ -0,0 +1,105 @@
+//! Support for converting identifiers to and from [Apache Arrow](https://arrow.apache.org)
+//! arrays, so that they can be moved into Polars or DataFusion pipelines.
+
+use crate::{ArxivId, ArxivIdError};
+use ::arrow_array::builder::StringBuilder;
+use ::arrow_array::StringArray;
+use ::arrow_schema::{DataType, Field};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// The name of the Arrow extension type of identifiers, which are stored in their canonical
+/// string form (such as `arXiv:2101.00001v1`) in `Utf8` arrays
+pub const ARXIV_ID_EXTENSION_NAME: &str = "arxiv.id";
+
+/// The metadata key of the name of an Arrow extension type
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+impl ArxivId {
+	/// The Arrow field of a column of identifiers, annotated with the extension type name
+	/// [`ARXIV_ID_EXTENSION_NAME`]
+	#[must_use]
+	pub fn arrow_field(name: &str, nullable: bool) -> Field {
+		Field::new(name, DataType::Utf8, nullable).with_metadata(HashMap::from([(
+			String::from(EXTENSION_NAME_KEY),
+			String::from(ARXIV_ID_EXTENSION_NAME),
+		)]))
+	}
+
+	/// Converts identifiers into an Arrow array of their canonical string forms, where
+	/// [`None`] becomes a null value.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let ids = vec![
+	///     ArxivId::from_str("arXiv:2101.00001v1").unwrap(),
+	///     ArxivId::from_str("arXiv:0704.0001").unwrap(),
+	/// ];
+	/// let array = ArxivId::to_arrow(&ids);
+	/// assert_eq!(array.value(0), "arXiv:2101.00001v1");
+	/// assert_eq!(ArxivId::from_arrow(&array), Ok(vec![Some(ids[0].clone()), Some(ids[1].clone())]));
+	/// ```
+	pub fn to_arrow<'a, I>(ids: I) -> StringArray
+	where
+		I: IntoIterator,
+		I::Item: Into<Option<&'a ArxivId>>,
+	{
+		let ids = ids.into_iter();
+		let mut builder = StringBuilder::with_capacity(ids.size_hint().0, 0);
+		for id in ids {
+			match id.into() {
+				Some(id) => {
+					// writing into a builder never fails
+					let _ = write!(builder, "{}", id);
+					builder.append_value("");
+				}
+				None => builder.append_null(),
+			}
+		}
+
+		builder.finish()
+	}
+
+	/// Parses an Arrow array of identifiers in their canonical string forms, where a null
+	/// value becomes [`None`], returning the first [`ArxivIdError`] if any value is invalid.
+	pub fn from_arrow(array: &StringArray) -> Result<Vec<Option<Self>>, ArxivIdError> {
+		array
+			.iter()
+			.map(|value| value.map(Self::from_str).transpose())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::arrow_array::Array;
+
+	#[test]
+	fn arrow_nulls() {
+		let id = ArxivId::from_str("arXiv:1501.00001").unwrap();
+		let array = ArxivId::to_arrow([Some(&id), None]);
+		assert_eq!(array.null_count(), 1);
+		assert_eq!(ArxivId::from_arrow(&array), Ok(vec![Some(id), None]));
+	}
+
+	#[test]
+	fn arrow_invalid() {
+		let array = StringArray::from(vec!["arXiv:1501.00001", "arXiv:1513.00001"]);
+		assert_eq!(ArxivId::from_arrow(&array), Err(ArxivIdError::InvalidMonth));
+	}
+
+	#[test]
+	fn arrow_field() {
+		let field = ArxivId::arrow_field("id", false);
+		assert_eq!(field.data_type(), &DataType::Utf8);
+		assert_eq!(
+			field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str),
+			Some(ARXIV_ID_EXTENSION_NAME)
+		);
+	}
+}
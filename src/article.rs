@@ -0,0 +1,129 @@
+use crate::{
+	ArticleVersion, ArxivArticleMetadata, ArxivCategorySet, ArxivIdentifier, VersionHistory,
+};
+use time::OffsetDateTime;
+
+/// A link of an article, such as to its abstract page or its PDF
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivArticleLink {
+	/// The URL of the link
+	pub href: String,
+	/// The relation of the link to the article, such as `alternate` or `related`
+	pub rel: Option<String>,
+	/// The title of the link, such as `pdf` or `doi`
+	pub title: Option<String>,
+	/// The media type of the resource, such as `application/pdf`
+	pub media_type: Option<String>,
+}
+
+/// An article as described by the export API of arXiv.org
+///
+/// The title and summary are normalized by collapsing the line breaks and indentation of the
+/// API response into single spaces.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivArticle, ArxivCategoryId};
+///
+/// let feed = r#"<feed xmlns="http://www.w3.org/2005/Atom"
+///   xmlns:arxiv="http://arxiv.org/schemas/atom">
+///   <entry>
+///     <id>http://arxiv.org/abs/1706.03762v7</id>
+///     <updated>2023-08-02T00:41:18Z</updated>
+///     <published>2017-06-12T17:57:34Z</published>
+///     <title>Attention Is All You Need</title>
+///     <summary>The dominant sequence transduction models...</summary>
+///     <author><name>Ashish Vaswani</name></author>
+///     <author><name>Noam Shazeer</name></author>
+///     <arxiv:comment>15 pages, 5 figures</arxiv:comment>
+///     <link href="http://arxiv.org/pdf/1706.03762v7" rel="related" type="application/pdf" title="pdf"/>
+///     <arxiv:primary_category term="cs.CL" scheme="http://arxiv.org/schemas/atom"/>
+///     <category term="cs.CL" scheme="http://arxiv.org/schemas/atom"/>
+///     <category term="cs.LG" scheme="http://arxiv.org/schemas/atom"/>
+///   </entry>
+/// </feed>"#;
+///
+/// let articles = ArxivArticle::from_atom_feed(feed).unwrap();
+/// let article = &articles[0];
+/// assert_eq!(article.title, "Attention Is All You Need");
+/// assert_eq!(article.authors, ["Ashish Vaswani", "Noam Shazeer"]);
+/// assert_eq!(article.categories.primary(), ArxivCategoryId::from_str("cs.CL").unwrap());
+/// assert_eq!(article.pdf_url(), Some("http://arxiv.org/pdf/1706.03762v7"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivArticle {
+	/// The identifier of the article, including the version described
+	pub id: ArxivIdentifier,
+	/// The title of the article
+	pub title: String,
+	/// The names of the authors of the article, in order
+	pub authors: Vec<String>,
+	/// The abstract of the article
+	pub summary: String,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// The links of the article, such as to its abstract page or its PDF
+	pub links: Vec<ArxivArticleLink>,
+	/// The DOI of the published version of the article, if any
+	pub doi: Option<String>,
+	/// The journal reference of the published version of the article, if any
+	pub journal_ref: Option<String>,
+	/// The comments of the authors, if any, such as the number of pages
+	pub comment: Option<String>,
+	/// When the first version of the article was submitted
+	pub published: OffsetDateTime,
+	/// When the version of the article described was submitted
+	pub updated: OffsetDateTime,
+	/// The history of the versions of the article, which is empty if it is not described,
+	/// such as by the export API
+	pub versions: VersionHistory,
+}
+
+impl ArxivArticle {
+	/// The URL of the PDF of the article, if the entry links to it
+	#[must_use]
+	pub fn pdf_url(&self) -> Option<&str> {
+		self.links
+			.iter()
+			.find(|link| link.title.as_deref() == Some("pdf"))
+			.map(|link| link.href.as_str())
+	}
+}
+
+impl From<ArxivArticle> for ArxivArticleMetadata {
+	/// Converts an article into its metadata, with the history of its versions if it is
+	/// described, or otherwise with its first version and the version described by the article
+	fn from(article: ArxivArticle) -> Self {
+		let mut id = article.id;
+		let versions = match article.versions.is_empty() {
+			true => {
+				let described = id
+					.version()
+					.filter(|&version| version > 1)
+					.map(|version| ArticleVersion::new(version, article.updated));
+				let first = ArticleVersion::new(1, article.published);
+				std::iter::once(first).chain(described).collect()
+			}
+			false => article.versions,
+		};
+		id.set_latest();
+		Self {
+			id,
+			submitter: None,
+			versions,
+			title: article.title,
+			abstract_text: article.summary,
+			authors: article.authors,
+			categories: article.categories,
+			license: None,
+			journal_ref: article.journal_ref,
+			doi: article.doi,
+			comments: article.comment,
+			report_no: None,
+			updated: None,
+		}
+	}
+}
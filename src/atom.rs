@@ -0,0 +1,344 @@
+use crate::metadata::collapse_whitespace;
+use crate::{
+	ArxivArticle, ArxivArticleLink, ArxivCategoryId, ArxivCategoryIdError, ArxivCategorySet,
+	ArxivIdError, ArxivIdentifier, VersionHistory,
+};
+use ::quick_xml::events::{BytesStart, Event};
+use ::quick_xml::Reader;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+/// The prefix of the identifiers of the entries the export API uses to report errors
+const API_ERROR_PREFIX: &str = "http://arxiv.org/api/errors";
+
+/// An error that can occur when parsing an Atom feed of the export API of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AtomError {
+	/// The feed is not well-formed XML
+	Xml(String),
+	/// An entry is missing a required element
+	MissingElement(&'static str),
+	/// An entry has an invalid identifier
+	InvalidId(ArxivIdError),
+	/// An entry has an invalid primary category
+	InvalidCategory(ArxivCategoryIdError),
+	/// An entry has an invalid date
+	InvalidDate(String),
+	/// The export API reported an error instead of results, such as a malformed query
+	Api(String),
+}
+
+impl Error for AtomError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidId(e) => Some(e),
+			Self::InvalidCategory(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for AtomError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Xml(e) => write!(f, "The feed is not well-formed XML: {}", e),
+			Self::MissingElement(name) => write!(f, "An entry is missing the <{}> element.", name),
+			Self::InvalidId(e) => write!(f, "An entry has an invalid arXiv identifier: {}", e),
+			Self::InvalidCategory(e) => write!(f, "An entry has an invalid category: {}", e),
+			Self::InvalidDate(s) => write!(f, "An entry has an invalid date \"{}\".", s),
+			Self::Api(message) => write!(f, "The arXiv API returned an error: {}", message),
+		}
+	}
+}
+
+impl ArxivArticle {
+	/// Parses the entries of an Atom feed of the export API of arXiv.org.
+	///
+	/// Identifiers and categories are validated with the parsers of the crate. Besides the
+	/// categories of arXiv.org, entries may list classification codes (such as MSC codes) as
+	/// categories, which are skipped. If the feed reports an error of the API instead of
+	/// results, an [`AtomError::Api`] error is returned.
+	///
+	/// See also: [Structure of the API response][response]
+	///
+	/// [response]: https://info.arxiv.org/help/api/user-manual.html#_details_of_atom_results_returned
+	pub fn from_atom_feed(xml: &str) -> Result<Vec<Self>, AtomError> {
+		parse_feed(xml).map(|feed| feed.articles)
+	}
+}
+
+/// A page of results of the export API of arXiv.org
+///
+/// See also: [`ArxivArticle::from_atom_feed`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AtomFeed {
+	/// The total number of results of the query across all pages, if the feed reports it
+	pub total_results: Option<usize>,
+	/// The articles of the page
+	pub articles: Vec<ArxivArticle>,
+}
+
+impl FromStr for AtomFeed {
+	type Err = AtomError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		parse_feed(s)
+	}
+}
+
+/// The elements of an entry, collected before they are validated
+#[derive(Default)]
+struct EntryFields {
+	id: Option<String>,
+	title: Option<String>,
+	authors: Vec<String>,
+	summary: Option<String>,
+	primary_category: Option<String>,
+	categories: Vec<String>,
+	links: Vec<ArxivArticleLink>,
+	doi: Option<String>,
+	journal_ref: Option<String>,
+	comment: Option<String>,
+	published: Option<String>,
+	updated: Option<String>,
+}
+
+impl EntryFields {
+	fn on_element(&mut self, e: &BytesStart<'_>) -> Result<(), AtomError> {
+		match e.local_name().as_ref() {
+			b"link" => {
+				if let Some(href) = attribute(e, "href")? {
+					self.links.push(ArxivArticleLink {
+						href,
+						rel: attribute(e, "rel")?,
+						title: attribute(e, "title")?,
+						media_type: attribute(e, "type")?,
+					});
+				}
+			}
+			b"primary_category" => self.primary_category = attribute(e, "term")?,
+			b"category" => self.categories.extend(attribute(e, "term")?),
+			_ => {}
+		}
+		Ok(())
+	}
+
+	fn on_text(&mut self, name: &[u8], text: String) {
+		match name {
+			b"id" => self.id = Some(text),
+			b"title" => self.title = Some(text),
+			b"name" => self.authors.push(text),
+			b"summary" => self.summary = Some(text),
+			b"doi" => self.doi = Some(text),
+			b"journal_ref" => self.journal_ref = Some(text),
+			b"comment" => self.comment = Some(text),
+			b"published" => self.published = Some(text),
+			b"updated" => self.updated = Some(text),
+			_ => {}
+		}
+	}
+
+	fn into_article(self) -> Result<ArxivArticle, AtomError> {
+		let id = self.id.ok_or(AtomError::MissingElement("id"))?;
+		if id.starts_with(API_ERROR_PREFIX) {
+			return Err(AtomError::Api(self.summary.unwrap_or(id)));
+		}
+
+		let primary = self
+			.primary_category
+			.ok_or(AtomError::MissingElement("arxiv:primary_category"))?;
+		let primary = ArxivCategoryId::from_str(&primary).map_err(AtomError::InvalidCategory)?;
+		let cross = self
+			.categories
+			.iter()
+			.filter_map(|term| ArxivCategoryId::from_str(term).ok());
+
+		Ok(ArxivArticle {
+			id: ArxivIdentifier::from_abs_url(&id).map_err(AtomError::InvalidId)?,
+			title: self.title.ok_or(AtomError::MissingElement("title"))?,
+			authors: self.authors,
+			summary: self.summary.ok_or(AtomError::MissingElement("summary"))?,
+			categories: ArxivCategorySet::new(primary, cross),
+			links: self.links,
+			doi: self.doi,
+			journal_ref: self.journal_ref,
+			comment: self.comment,
+			published: parse_date(self.published, "published")?,
+			updated: parse_date(self.updated, "updated")?,
+			versions: VersionHistory::default(),
+		})
+	}
+}
+
+/// The unescaped value of an attribute of an element, if present
+fn attribute(e: &BytesStart<'_>, name: &str) -> Result<Option<String>, AtomError> {
+	match e.try_get_attribute(name) {
+		Ok(Some(attr)) => attr
+			.unescape_value()
+			.map(|value| Some(value.into_owned()))
+			.map_err(|e| AtomError::Xml(e.to_string())),
+		Ok(None) => Ok(None),
+		Err(e) => Err(AtomError::Xml(e.to_string())),
+	}
+}
+
+fn parse_date(s: Option<String>, name: &'static str) -> Result<OffsetDateTime, AtomError> {
+	let s = s.ok_or(AtomError::MissingElement(name))?;
+	OffsetDateTime::parse(&s, &Rfc3339).map_err(|_| AtomError::InvalidDate(s))
+}
+
+/// Parses the entries of an Atom feed of the export API
+pub(crate) fn parse_feed(xml: &str) -> Result<AtomFeed, AtomError> {
+	let mut reader = Reader::from_str(xml);
+	let mut total_results = None;
+	let mut articles = Vec::new();
+	let mut entry: Option<EntryFields> = None;
+	let mut text = String::new();
+
+	loop {
+		match reader.read_event() {
+			Ok(Event::Start(e)) => {
+				if e.local_name().as_ref() == b"entry" {
+					entry = Some(EntryFields::default());
+				} else if let Some(fields) = entry.as_mut() {
+					fields.on_element(&e)?;
+				}
+				text.clear();
+			}
+			Ok(Event::Empty(e)) => {
+				if let Some(fields) = entry.as_mut() {
+					fields.on_element(&e)?;
+				}
+			}
+			Ok(Event::Text(e)) => {
+				text.push_str(&e.unescape().map_err(|e| AtomError::Xml(e.to_string()))?);
+			}
+			Ok(Event::CData(e)) => text.push_str(&String::from_utf8_lossy(&e)),
+			Ok(Event::End(e)) => {
+				if e.local_name().as_ref() == b"entry" {
+					if let Some(fields) = entry.take() {
+						articles.push(fields.into_article()?);
+					}
+				} else if let Some(fields) = entry.as_mut() {
+					fields.on_text(e.local_name().as_ref(), collapse_whitespace(&text));
+				} else if e.local_name().as_ref() == b"totalResults" {
+					total_results = text.trim().parse().ok();
+				}
+				text.clear();
+			}
+			Ok(Event::Eof) => break,
+			Ok(_) => {}
+			Err(e) => return Err(AtomError::Xml(e.to_string())),
+		}
+	}
+
+	Ok(AtomFeed {
+		total_results,
+		articles,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ArxivArticleMetadata;
+
+	const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="html">ArXiv Query: search_query=&amp;id_list=2101.00001</title>
+  <id>http://arxiv.org/api/cHxbiOdZaP56ODnBPIenZhzg5f8</id>
+  <updated>2021-01-05T00:00:00-05:00</updated>
+  <opensearch:totalResults xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">1</opensearch:totalResults>
+  <entry>
+    <id>http://arxiv.org/abs/2101.00001v1</id>
+    <updated>2021-01-02T10:00:00Z</updated>
+    <published>2020-12-31T19:00:00Z</published>
+    <title>Etat de l'art sur l'application des bandits
+  multi-bras</title>
+    <summary>  Un examen des bandits &amp; de leurs applications.
+</summary>
+    <author>
+      <name>Djallel Bouneffouf</name>
+    </author>
+    <arxiv:doi xmlns:arxiv="http://arxiv.org/schemas/atom">10.1000/182</arxiv:doi>
+    <link title="doi" href="http://dx.doi.org/10.1000/182" rel="related"/>
+    <arxiv:comment xmlns:arxiv="http://arxiv.org/schemas/atom">in French</arxiv:comment>
+    <link href="http://arxiv.org/abs/2101.00001v1" rel="alternate" type="text/html"/>
+    <arxiv:primary_category xmlns:arxiv="http://arxiv.org/schemas/atom" term="cs.LG" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="cs.LG" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="cs.AI" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="68T05" scheme="http://arxiv.org/schemas/atom"/>
+  </entry>
+</feed>"#;
+
+	#[test]
+	fn parse_atom_feed() {
+		let feed = parse_feed(FEED).unwrap();
+		assert_eq!(feed.total_results, Some(1));
+
+		let articles = feed.articles;
+		assert_eq!(articles.len(), 1);
+
+		let article = &articles[0];
+		assert_eq!(article.id, ArxivIdentifier::from_str("2101.00001v1").unwrap());
+		assert_eq!(article.title, "Etat de l'art sur l'application des bandits multi-bras");
+		assert_eq!(article.authors, ["Djallel Bouneffouf"]);
+		assert_eq!(article.summary, "Un examen des bandits & de leurs applications.");
+		assert_eq!(article.categories.to_string(), "cs.LG cs.AI");
+		assert_eq!(article.links.len(), 2);
+		assert_eq!(article.links[1].rel.as_deref(), Some("alternate"));
+		assert_eq!(article.doi.as_deref(), Some("10.1000/182"));
+		assert_eq!(article.journal_ref, None);
+		assert_eq!(article.comment.as_deref(), Some("in French"));
+		assert_eq!(article.published.unix_timestamp(), 1609441200);
+		assert_eq!(article.updated.year(), 2021);
+	}
+
+	#[test]
+	fn metadata_from_atom_feed() {
+		let feed = FEED.replace("2101.00001v1", "2101.00001v3");
+		let article = parse_feed(&feed).unwrap().articles.remove(0);
+		let published = article.published;
+		let metadata = ArxivArticleMetadata::from(article);
+		assert_eq!(metadata.id.to_string(), "arXiv:2101.00001");
+		assert_eq!(metadata.versions.len(), 2);
+		assert_eq!(metadata.versions.first().map(|version| version.submitted), Some(published));
+		assert_eq!(metadata.latest_version().map(|version| version.version), Some(3));
+		assert_eq!(metadata.comments.as_deref(), Some("in French"));
+		assert_eq!(metadata.license, None);
+	}
+
+	#[test]
+	fn parse_atom_feed_api_error() {
+		let feed = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <id>http://arxiv.org/api/errors#incorrect_id_format_for_1234</id>
+    <title>Error</title>
+    <summary>incorrect id format for 1234</summary>
+  </entry>
+</feed>"#;
+		assert_eq!(
+			ArxivArticle::from_atom_feed(feed),
+			Err(AtomError::Api(String::from("incorrect id format for 1234")))
+		);
+	}
+
+	#[test]
+	fn parse_atom_feed_invalid() {
+		let parse = ArxivArticle::from_atom_feed;
+		assert!(matches!(parse("<feed><entry></feed>"), Err(AtomError::Xml(_))));
+		assert_eq!(
+			parse("<feed><entry><id>http://arxiv.org/abs/2101.00001</id></entry></feed>"),
+			Err(AtomError::MissingElement("arxiv:primary_category"))
+		);
+		assert!(matches!(
+			parse(&FEED.replace(r#"term="cs.LG" scheme"#, r#"term="cs.XYZ" scheme"#)),
+			Err(AtomError::InvalidCategory(ArxivCategoryIdError::Unknown(_)))
+		));
+		assert_eq!(parse("<feed></feed>"), Ok(Vec::new()));
+	}
+}
@@ -0,0 +1,459 @@
+use crate::metadata::collapse_whitespace;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The particles of family names, which belong to the keyname of an author when lowercase,
+/// such as in `Ludwig van Beethoven`
+const PARTICLES: [&str; 17] = [
+	"da", "das", "de", "del", "della", "der", "des", "di", "do", "dos", "du", "la", "le", "ten",
+	"ter", "van", "von",
+];
+
+/// The suffixes of names, such as in `Martin Luther King Jr.`
+const SUFFIXES: [&str; 7] = ["Jr", "Jr.", "Sr", "Sr.", "II", "III", "IV"];
+
+/// The words naming a collaboration rather than a person, such as in `ATLAS Collaboration`
+const COLLABORATION_MARKERS: [&str; 6] = [
+	"collaboration",
+	"collaborations",
+	"consortium",
+	"group",
+	"team",
+	"project",
+];
+
+/// An error that can occur when parsing an author of an article
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArxivAuthorError {
+	/// The author has no name outside of the parentheses of its affiliations
+	EmptyName,
+	/// A parenthesis of the affiliations of the author is not closed, or not opened
+	UnbalancedParentheses,
+}
+
+impl Error for ArxivAuthorError {}
+
+impl Display for ArxivAuthorError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::EmptyName => f.write_str("An author must have a name."),
+			Self::UnbalancedParentheses => {
+				f.write_str("The affiliations of an author must be enclosed in parentheses.")
+			}
+		}
+	}
+}
+
+/// An author of an article, as listed by arXiv.org, such as `Jane Doe (MIT)`
+///
+/// The name is split into the keyname, forenames and suffix used by citations with
+/// heuristics: the keyname is the last word of the name along with its lowercase particles
+/// (such as `van`), unless the author is a collaboration, whose whole name is its keyname.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::ArxivAuthor;
+///
+/// let author = ArxivAuthor::from_str("Ludwig van Beethoven Jr. (Universität Bonn)").unwrap();
+/// assert_eq!(author.name, "Ludwig van Beethoven Jr.");
+/// assert_eq!(author.affiliations, ["Universität Bonn"]);
+/// assert_eq!(author.keyname(), "van Beethoven");
+/// assert_eq!(author.forenames(), Some("Ludwig"));
+/// assert_eq!(author.suffix(), Some("Jr."));
+/// assert_eq!(author.initials().as_deref(), Some("L."));
+///
+/// let author = ArxivAuthor::from_str("The ATLAS Collaboration").unwrap();
+/// assert!(author.is_collaboration());
+/// assert_eq!(author.keyname(), "The ATLAS Collaboration");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ArxivAuthor {
+	/// The full name of the author, from the forenames to the suffix
+	pub name: String,
+	/// The affiliations of the author, in order
+	pub affiliations: Vec<String>,
+}
+
+impl ArxivAuthor {
+	/// Creates an author without affiliations
+	#[must_use]
+	pub fn new(name: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			affiliations: Vec::new(),
+		}
+	}
+
+	/// Adds an affiliation to the author
+	#[must_use]
+	pub fn with_affiliation(mut self, affiliation: impl Into<String>) -> Self {
+		self.affiliations.push(affiliation.into());
+		self
+	}
+
+	/// Parses a list of authors, such as `A. Smith (MIT), B. Jones and C. Brown`, skipping the
+	/// authors without a name.
+	///
+	/// Authors are separated by commas and the last of them by `and`, except within
+	/// parentheses. The numbered affiliations of a trailing legend, such as in
+	/// `A. Smith (1), B. Jones (1 and 2) ((1) MIT, (2) Harvard)`, are resolved, and a
+	/// collaboration leading the list, such as in `ATLAS Collaboration: A. Smith, B. Jones`,
+	/// is listed as the first author.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivAuthor;
+	///
+	/// let authors = ArxivAuthor::from_list("A. Smith (1), B. Jones (1, 2) ((1) MIT, (2) CERN)");
+	/// assert_eq!(authors.len(), 2);
+	/// assert_eq!(authors[0].affiliations, ["MIT"]);
+	/// assert_eq!(authors[1].affiliations, ["MIT", "CERN"]);
+	/// ```
+	#[must_use]
+	pub fn from_list(authors: &str) -> Vec<Self> {
+		let mut list = split_authors(authors)
+			.iter()
+			.flat_map(|entry| match entry.split_once(": ") {
+				Some((collaboration, first)) if !collaboration.contains('(') => {
+					vec![collaboration, first]
+				}
+				_ => vec![entry.as_str()],
+			})
+			.filter_map(|entry| Self::from_str(entry).ok())
+			.collect::<Vec<_>>();
+
+		let legend = list
+			.last_mut()
+			.and_then(|last| match last.affiliations.last() {
+				Some(affiliation) if affiliation.starts_with('(') => last.affiliations.pop(),
+				_ => None,
+			})
+			.map(|legend| parse_legend(&legend));
+		if let Some(legend) = legend {
+			for author in &mut list {
+				author.affiliations = author
+					.affiliations
+					.iter()
+					.flat_map(|affiliation| resolve_affiliation(affiliation, &legend))
+					.collect();
+			}
+		}
+		list
+	}
+
+	/// Whether the author is a collaboration rather than a person, such as
+	/// `ATLAS Collaboration` or `LIGO Scientific Consortium`
+	#[must_use]
+	pub fn is_collaboration(&self) -> bool {
+		self.name.split_whitespace().any(|word| {
+			COLLABORATION_MARKERS
+				.iter()
+				.any(|marker| word.eq_ignore_ascii_case(marker))
+		})
+	}
+
+	/// The family name of the author along with its particles, such as `van Beethoven`, or
+	/// the whole name of a collaboration
+	#[must_use]
+	pub fn keyname(&self) -> &str {
+		let (_, keyname, _) = self.split_name();
+		keyname
+	}
+
+	/// The given names or initials of the author, if any
+	#[must_use]
+	pub fn forenames(&self) -> Option<&str> {
+		let (forenames, _, _) = self.split_name();
+		forenames
+	}
+
+	/// The suffix of the name of the author, such as `Jr.`, if any
+	#[must_use]
+	pub fn suffix(&self) -> Option<&str> {
+		let (_, _, suffix) = self.split_name();
+		suffix
+	}
+
+	/// The initials of the forenames of the author, such as `C.-P.` for `Chien-Peng`, if any
+	#[must_use]
+	pub fn initials(&self) -> Option<String> {
+		let initials = self
+			.forenames()?
+			.split_whitespace()
+			.map(|forename| {
+				forename
+					.split('-')
+					.filter_map(|part| part.chars().find(|c| c.is_alphabetic()))
+					.map(|c| format!("{}.", c.to_uppercase()))
+					.collect::<Vec<_>>()
+					.join("-")
+			})
+			.filter(|initial| !initial.is_empty())
+			.collect::<Vec<_>>();
+		match initials.is_empty() {
+			true => None,
+			false => Some(initials.join(" ")),
+		}
+	}
+
+	/// The name of the author in the inverted order of bibliographies, such as
+	/// `King, Martin Luther, Jr.` for `Martin Luther King Jr.`
+	#[must_use]
+	pub fn inverted_name(&self) -> String {
+		let (forenames, keyname, suffix) = self.split_name();
+		let mut name = String::from(keyname);
+		for part in [forenames, suffix].into_iter().flatten() {
+			name.push_str(", ");
+			name.push_str(part);
+		}
+		name
+	}
+
+	/// Splits the name into its forenames, keyname and suffix
+	fn split_name(&self) -> (Option<&str>, &str, Option<&str>) {
+		let name = self.name.trim();
+		if self.is_collaboration() {
+			return (None, name, None);
+		}
+
+		let (name, suffix) = match name.rsplit_once(' ') {
+			Some((rest, last)) if SUFFIXES.contains(&last) => {
+				(rest.trim_end().trim_end_matches(','), Some(last))
+			}
+			_ => (name, None),
+		};
+		let words = name.split_whitespace().collect::<Vec<_>>();
+		let mut start = words.len().saturating_sub(1);
+		while start > 1 && PARTICLES.contains(&words[start - 1]) {
+			start -= 1;
+		}
+		let keyname = match words.get(start) {
+			Some(word) => &name[offset(name, word)..],
+			None => name,
+		};
+		let forenames = match start {
+			0 => None,
+			_ => Some(name[..offset(name, words[start])].trim_end()),
+		};
+		(forenames, keyname, suffix)
+	}
+}
+
+impl FromStr for ArxivAuthor {
+	type Err = ArxivAuthorError;
+
+	/// Parses an author with its affiliations in parentheses, such as
+	/// `Jane Doe (MIT) (Harvard)`
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut name = String::new();
+		let mut affiliations = Vec::new();
+		let mut depth = 0_usize;
+		let mut start = 0;
+		for (i, c) in s.char_indices() {
+			match c {
+				'(' => {
+					if depth == 0 {
+						start = i + 1;
+					}
+					depth += 1;
+				}
+				')' => {
+					depth = depth
+						.checked_sub(1)
+						.ok_or(ArxivAuthorError::UnbalancedParentheses)?;
+					if depth == 0 {
+						affiliations.push(collapse_whitespace(&s[start..i]));
+						name.push(' ');
+					}
+				}
+				c if depth == 0 => name.push(c),
+				_ => {}
+			}
+		}
+		if depth > 0 {
+			return Err(ArxivAuthorError::UnbalancedParentheses);
+		}
+
+		let name = collapse_whitespace(&name);
+		if name.is_empty() {
+			return Err(ArxivAuthorError::EmptyName);
+		}
+		affiliations.retain(|affiliation| !affiliation.is_empty());
+		Ok(Self { name, affiliations })
+	}
+}
+
+impl Display for ArxivAuthor {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.name)?;
+		for affiliation in &self.affiliations {
+			write!(f, " ({})", affiliation)?;
+		}
+		Ok(())
+	}
+}
+
+/// Splits a list of authors, which are separated by commas and the last of them by `and`,
+/// except within the parentheses of their affiliations
+pub(crate) fn split_authors(authors: &str) -> Vec<String> {
+	let mut names = Vec::new();
+	let mut depth = 0_usize;
+	let mut start = 0;
+	for (i, c) in authors.char_indices() {
+		if i < start {
+			continue;
+		}
+		match c {
+			'(' => depth += 1,
+			')' => depth = depth.saturating_sub(1),
+			',' if depth == 0 => {
+				names.push(&authors[start..i]);
+				start = i + 1;
+			}
+			' ' if depth == 0 && authors[i..].starts_with(" and ") => {
+				names.push(&authors[start..i]);
+				start = i + " and ".len();
+			}
+			_ => {}
+		}
+	}
+	names.push(&authors[start..]);
+	names
+		.into_iter()
+		.map(collapse_whitespace)
+		.filter(|name| !name.is_empty())
+		.collect()
+}
+
+/// Parses a legend of numbered affiliations, such as `(1) MIT, (2) Harvard`
+fn parse_legend(legend: &str) -> Vec<(String, String)> {
+	legend
+		.split('(')
+		.filter_map(|entry| entry.split_once(')'))
+		.map(|(number, affiliation)| {
+			let affiliation = affiliation.trim().trim_end_matches([',', ';']).trim();
+			(String::from(number.trim()), String::from(affiliation))
+		})
+		.collect()
+}
+
+/// Resolves the numbers of the legend an affiliation refers to, such as `1, 2` or `1 and 2`,
+/// or keeps the affiliation if it is not a reference
+fn resolve_affiliation(affiliation: &str, legend: &[(String, String)]) -> Vec<String> {
+	let numbers = affiliation
+		.split([',', '&'])
+		.flat_map(|part| part.split(" and "))
+		.map(str::trim)
+		.filter(|number| !number.is_empty())
+		.collect::<Vec<_>>();
+	let resolved = numbers
+		.iter()
+		.map(|number| {
+			legend
+				.iter()
+				.find(|(n, _)| n == number)
+				.map(|(_, affiliation)| affiliation.clone())
+		})
+		.collect::<Option<Vec<_>>>();
+	match resolved {
+		Some(resolved) if !resolved.is_empty() => resolved,
+		_ => vec![String::from(affiliation)],
+	}
+}
+
+/// The byte offset of a word borrowed from the string
+fn offset(s: &str, word: &str) -> usize {
+	word.as_ptr() as usize - s.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn author(s: &str) -> ArxivAuthor {
+		ArxivAuthor::from_str(s).unwrap()
+	}
+
+	#[test]
+	fn parse_author() {
+		let jane = author(" Jane  Doe (University of California, Berkeley) (CERN) ");
+		assert_eq!(jane.name, "Jane Doe");
+		assert_eq!(jane.affiliations, ["University of California, Berkeley", "CERN"]);
+		assert_eq!(jane.to_string(), "Jane Doe (University of California, Berkeley) (CERN)");
+		assert_eq!(author(&jane.to_string()), jane);
+
+		assert_eq!(ArxivAuthor::from_str("(MIT)"), Err(ArxivAuthorError::EmptyName));
+		assert_eq!(ArxivAuthor::from_str("  "), Err(ArxivAuthorError::EmptyName));
+		assert_eq!(
+			ArxivAuthor::from_str("Jane Doe (MIT"),
+			Err(ArxivAuthorError::UnbalancedParentheses)
+		);
+		assert_eq!(
+			ArxivAuthor::from_str("Jane Doe MIT)"),
+			Err(ArxivAuthorError::UnbalancedParentheses)
+		);
+	}
+
+	#[test]
+	fn split_author_names() {
+		let author = author("C.-P. Yuan");
+		assert_eq!((author.forenames(), author.keyname()), (Some("C.-P."), "Yuan"));
+		assert_eq!(author.initials().as_deref(), Some("C.-P."));
+
+		let author = ArxivAuthor::new("Chien-Peng  Yuan");
+		assert_eq!(author.initials().as_deref(), Some("C.-P."));
+
+		let author = ArxivAuthor::new("Juan Martín de la Cruz");
+		assert_eq!(author.keyname(), "de la Cruz");
+		assert_eq!(author.forenames(), Some("Juan Martín"));
+		assert_eq!(author.initials().as_deref(), Some("J. M."));
+
+		let author = ArxivAuthor::new("John Smith, Jr");
+		assert_eq!(author.keyname(), "Smith");
+		assert_eq!(author.suffix(), Some("Jr"));
+		assert_eq!(author.inverted_name(), "Smith, John, Jr");
+
+		let author = ArxivAuthor::new("Plato");
+		assert_eq!((author.forenames(), author.keyname()), (None, "Plato"));
+		assert_eq!(author.initials(), None);
+		assert_eq!(author.inverted_name(), "Plato");
+
+		let author = ArxivAuthor::new("LIGO Scientific Collaboration");
+		assert!(author.is_collaboration());
+		assert_eq!((author.forenames(), author.suffix()), (None, None));
+	}
+
+	#[test]
+	fn parse_author_list() {
+		let authors = ArxivAuthor::from_list(
+			"The CMS Collaboration: A. Tumasyan, W. Adam (HEPHY),\n  E. Brown and F. Green",
+		);
+		let names = authors
+			.iter()
+			.map(|author| author.name.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			names,
+			[
+				"The CMS Collaboration",
+				"A. Tumasyan",
+				"W. Adam",
+				"E. Brown",
+				"F. Green"
+			]
+		);
+		assert!(authors[0].is_collaboration());
+		assert_eq!(authors[2].affiliations, ["HEPHY"]);
+
+		let authors = ArxivAuthor::from_list(
+			"A. Smith (1), B. Jones (1 and 2), C. Brown (Oxford) ((1) MIT, (2) CERN)",
+		);
+		assert_eq!(authors[0].affiliations, ["MIT"]);
+		assert_eq!(authors[1].affiliations, ["MIT", "CERN"]);
+		assert_eq!(authors[2].affiliations, ["Oxford"]);
+		assert!(ArxivAuthor::from_list(" , and ").is_empty());
+	}
+}
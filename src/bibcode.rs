@@ -0,0 +1,114 @@
+use crate::{ArxivId, ArxivIdError, ArxivIdResult};
+use std::str::FromStr;
+
+/// The journal abbreviation of arXiv.org in ADS bibcodes
+const ADS_JOURNAL: &str = "arXiv";
+
+/// The length of an ADS bibcode
+const ADS_BIBCODE_LEN: usize = 19;
+
+/// The length of the volume and page of an ADS bibcode of arXiv.org,
+/// which hold the identifier of the article
+const ADS_VOLUME_LEN: usize = 9;
+
+impl ArxivId {
+	/// The bibcode of the article in the Astrophysics Data System (ADS), such as
+	/// `2021arXiv210100001S`
+	///
+	/// This conversion is best-effort, with the following limitations:
+	///  - The last character of a bibcode is the initial of the last name of the first author,
+	///    which is not part of the identifier, so it must be given. It is uppercased, and
+	///    replaced by `.` if it is not an ASCII letter, which is what ADS uses when there are
+	///    no authors.
+	///  - The year of a bibcode is the year of publication, which is assumed to be the year
+	///    of submission.
+	///  - ADS does not assign bibcodes to versions, so the version (if any) is not included.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v1").unwrap();
+	/// assert_eq!(id.to_ads_bibcode('s'), "2021arXiv210100001S");
+	///
+	/// let id = ArxivId::from_str("arXiv:0704.0001").unwrap();
+	/// assert_eq!(id.to_ads_bibcode('B'), "2007arXiv0704.0001B");
+	/// ```
+	#[must_use]
+	pub fn to_ads_bibcode(&self, author_initial: char) -> String {
+		let initial = match author_initial.is_ascii_alphabetic() {
+			true => author_initial.to_ascii_uppercase(),
+			false => '.',
+		};
+		let mut article = self.clone();
+		article.set_latest();
+		let mut volume = article.to_bare_string();
+		if volume.len() > ADS_VOLUME_LEN {
+			volume.retain(|c| c != Self::TOKEN_DOT);
+		}
+
+		format!("{}{}{}{}", self.year(), ADS_JOURNAL, volume, initial)
+	}
+
+	/// Parses the identifier of an article from its bibcode in the Astrophysics Data System
+	/// (ADS), such as `2021arXiv210100001S`, returning an [`ArxivIdError::Syntax`] error if the
+	/// bibcode is not of an arXiv preprint.
+	///
+	/// Only the identifier is recovered, so the initial of the author is ignored, and the
+	/// identifier is always of the latest version. Bibcodes of articles using the old
+	/// identifier scheme (before April 2007) are not supported.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_ads_bibcode("2021arXiv210100001S");
+	/// assert_eq!(id, ArxivId::from_str("arXiv:2101.00001"));
+	/// assert!(ArxivId::from_ads_bibcode("2019ApJ...875L...1E").is_err());
+	/// ```
+	pub fn from_ads_bibcode(bibcode: &str) -> ArxivIdResult {
+		let bibcode = bibcode.trim();
+		if bibcode.len() != ADS_BIBCODE_LEN
+			|| !bibcode.is_ascii()
+			|| !bibcode[4..9].eq_ignore_ascii_case(ADS_JOURNAL)
+		{
+			return Err(ArxivIdError::Syntax);
+		}
+
+		let (yymm, number) = bibcode[9..9 + ADS_VOLUME_LEN].split_at(4);
+		let number = number.strip_prefix(Self::TOKEN_DOT).unwrap_or(number);
+
+		Self::from_str(&format!("{}{}{}{}", Self::PREFIX, yymm, Self::TOKEN_DOT, number))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ads_bibcode_round_trip() {
+		for s in ["arXiv:2101.00001", "arXiv:0704.0001", "arXiv:1412.9999"] {
+			let id = ArxivId::from_str(s).unwrap();
+			assert_eq!(ArxivId::from_ads_bibcode(&id.to_ads_bibcode('A')), Ok(id));
+		}
+	}
+
+	#[test]
+	fn ads_bibcode_without_author() {
+		let id = ArxivId::from_str("arXiv:2304.11188v2").unwrap();
+		assert_eq!(id.to_ads_bibcode('1'), "2023arXiv230411188.");
+	}
+
+	#[test]
+	fn parse_ads_bibcode_invalid() {
+		assert_eq!(ArxivId::from_ads_bibcode(""), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivId::from_ads_bibcode("2021ApJ..210100001S"), Err(ArxivIdError::Syntax));
+		assert_eq!(
+			ArxivId::from_ads_bibcode("2021arXiv211300001S"),
+			Err(ArxivIdError::InvalidMonth)
+		);
+	}
+}
@@ -0,0 +1,473 @@
+//! Generation of BibTeX entries of arXiv articles, in the format of the BibTeX citations
+//! exported by the abstract pages of arXiv.org, and parsing of their `eprint` fields.
+
+use crate::doi::strip_prefix_ignore_case;
+use crate::metadata::collapse_whitespace;
+use crate::{
+	ArxivArticleMetadata, ArxivAuthor, ArxivId, ArxivIdError, ArxivIdentifier, JournalRef,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::str::FromStr;
+
+/// The words skipped when choosing the word of the title used in citation keys
+const STOPWORDS: [&str; 12] = [
+	"a", "an", "and", "at", "for", "from", "in", "of", "on", "the", "to", "with",
+];
+
+impl ArxivArticleMetadata {
+	/// A BibTeX entry of the article, such as exported by the abstract pages of arXiv.org,
+	/// with its `eprint`, `archivePrefix` and `primaryClass` fields.
+	///
+	/// Articles with a journal reference or a DOI are `@article` entries, with the journal,
+	/// volume and pages found in their journal reference, while other articles are `@misc`
+	/// entries. The year is the year of the journal reference if it names one, and otherwise
+	/// the year of the latest version of the article. The citation key is made of the keyname
+	/// of the first author, the year, and the first significant word of the title, such as
+	/// `vaswani2017attention`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(feature = "oai")]
+	/// # {
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivArticleMetadata, ArxivRawMetadata};
+	///
+	/// let xml = r#"<arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+	///   <id>1706.03762</id>
+	///   <version version="v1"><date>Mon, 12 Jun 2017 17:57:34 GMT</date><size>1102kb</size></version>
+	///   <title>Attention Is All You Need</title>
+	///   <authors>Ashish Vaswani, Noam Shazeer</authors>
+	///   <categories>cs.CL cs.LG</categories>
+	///   <abstract>The dominant sequence transduction models...</abstract>
+	/// </arXivRaw>"#;
+	/// let metadata = ArxivArticleMetadata::from(ArxivRawMetadata::from_str(xml).unwrap());
+	///
+	/// assert_eq!(
+	///     metadata.to_bibtex(),
+	///     "@misc{vaswani2017attention,
+	///   title = {Attention Is All You Need},
+	///   author = {Ashish Vaswani and Noam Shazeer},
+	///   year = {2017},
+	///   eprint = {1706.03762},
+	///   archivePrefix = {arXiv},
+	///   primaryClass = {cs.CL},
+	///   url = {https://arxiv.org/abs/1706.03762},
+	/// }
+	/// "
+	/// );
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn to_bibtex(&self) -> String {
+		let journal_ref = self.journal_ref.as_deref().map(JournalRef::parse);
+		let kind = match journal_ref.is_some() || self.doi.is_some() {
+			true => "article",
+			false => "misc",
+		};
+		let year = self.published_year(journal_ref.as_ref());
+
+		let mut fields = vec![
+			("title", self.title.clone()),
+			("author", self.authors.join(" and ")),
+		];
+		if let Some(journal_ref) = journal_ref {
+			if let Some(journal) = journal_ref.journal {
+				fields.push(("journal", journal));
+			}
+			if let Some(volume) = journal_ref.volume {
+				fields.push(("volume", volume));
+			}
+			if let Some(pages) = journal_ref.pages {
+				fields.push(("pages", pages.replace('-', "--")));
+			}
+		}
+		fields.extend([
+			("year", year.to_string()),
+			("eprint", self.id.to_bare_string()),
+			("archivePrefix", String::from("arXiv")),
+			("primaryClass", self.categories.primary().to_string()),
+		]);
+		if let Some(doi) = &self.doi {
+			fields.push(("doi", doi.clone()));
+		}
+		fields.push(("url", self.id.abs_url()));
+
+		write_entry(kind, &self.citation_key(year), &fields)
+	}
+
+	/// The citation key of the article, such as `vaswani2017attention`
+	pub(crate) fn citation_key(&self, year: u16) -> String {
+		let keyname = self
+			.authors
+			.first()
+			.map(|name| ArxivAuthor::new(name.as_str()).keyname().to_lowercase())
+			.unwrap_or_default();
+		let word = self
+			.title
+			.split(|c: char| !c.is_alphanumeric())
+			.map(str::to_lowercase)
+			.find(|word| !word.is_empty() && !STOPWORDS.contains(&word.as_str()))
+			.unwrap_or_default();
+		let key = format!("{}{}{}", keyname, year, word);
+		key.chars().filter(char::is_ascii_alphanumeric).collect()
+	}
+}
+
+impl ArxivId {
+	/// A minimal BibTeX entry of the article, with its `eprint`, `archivePrefix`, DOI and URL
+	/// fields but without its title and authors, keyed by the identifier
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+	/// assert_eq!(
+	///     id.to_bibtex_stub(),
+	///     "@misc{arXiv:2101.00001v2,
+	///   eprint = {2101.00001v2},
+	///   archivePrefix = {arXiv},
+	///   doi = {10.48550/arXiv.2101.00001},
+	///   url = {https://arxiv.org/abs/2101.00001v2},
+	/// }
+	/// "
+	/// );
+	/// ```
+	#[must_use]
+	pub fn to_bibtex_stub(&self) -> String {
+		let fields = [
+			("eprint", self.to_bare_string()),
+			("archivePrefix", String::from("arXiv")),
+			("doi", self.to_doi()),
+			("url", self.abs_url()),
+		];
+		write_entry("misc", &self.to_string(), &fields)
+	}
+}
+
+/// An error that can occur when parsing the identifier of a BibTeX entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArxivBibtexError {
+	/// The text has no BibTeX entry, or the entry has no `eprint` field
+	MissingEprint,
+	/// The `archivePrefix` or `eprinttype` field of the entry is not `arXiv`
+	NotArxiv,
+	/// The identifier of the `eprint` field is invalid
+	InvalidArxivId(ArxivIdError),
+}
+
+impl Error for ArxivBibtexError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::MissingEprint | Self::NotArxiv => None,
+			Self::InvalidArxivId(e) => Some(e),
+		}
+	}
+}
+
+impl Display for ArxivBibtexError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::MissingEprint => f.write_str("A BibTeX entry must have an eprint field."),
+			Self::NotArxiv => f.write_str("The eprint of the BibTeX entry is not from arXiv."),
+			Self::InvalidArxivId(e) => write!(f, "The eprint is not a valid identifier: {}", e),
+		}
+	}
+}
+
+impl ArxivIdentifier {
+	/// Parses the identifier of either scheme in the `eprint` field of the first BibTeX
+	/// entry of the text, such as `eprint = {2101.00001}` or `eprint = "hep-th/9901001"`
+	///
+	/// The entry must not have an `archivePrefix` (or BibLaTeX `eprinttype`) field other than
+	/// `arXiv`, while an `arXiv:` prefix of the identifier is ignored.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArxivBibtexError, ArxivIdentifier};
+	///
+	/// let entry = "@article{witten1998,
+	///   title = {Anti De Sitter Space And Holography},
+	///   eprint = {hep-th/9802150},
+	///   archivePrefix = {arXiv},
+	/// }";
+	/// let id = ArxivIdentifier::from_bibtex_entry(entry).unwrap();
+	/// assert_eq!(id.to_bare_string(), "hep-th/9802150");
+	///
+	/// let entry = "@misc{doe2020, eprint = {hal-01234567}, archivePrefix = {HAL}}";
+	/// let error = ArxivIdentifier::from_bibtex_entry(entry);
+	/// assert_eq!(error, Err(ArxivBibtexError::NotArxiv));
+	/// ```
+	pub fn from_bibtex_entry(entry: &str) -> Result<Self, ArxivBibtexError> {
+		let (_, fields) = entries(entry)
+			.next()
+			.ok_or(ArxivBibtexError::MissingEprint)?;
+		eprint_of(&fields)
+	}
+}
+
+impl ArxivId {
+	/// Parses the identifier of the new scheme in the `eprint` field of the first BibTeX
+	/// entry of the text, such as `eprint = {2101.00001}`
+	///
+	/// See also: [`ArxivIdentifier::from_bibtex_entry`], which also parses identifiers of the
+	/// old scheme.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let entry = r#"@misc{arXiv:2101.00001, eprint = "arXiv:2101.00001v2", archivePrefix = "arXiv"}"#;
+	/// let id = ArxivId::from_bibtex_entry(entry).unwrap();
+	/// assert_eq!(id, ArxivId::from_str("arXiv:2101.00001v2").unwrap());
+	/// ```
+	pub fn from_bibtex_entry(entry: &str) -> Result<Self, ArxivBibtexError> {
+		match ArxivIdentifier::from_bibtex_entry(entry)? {
+			ArxivIdentifier::New(id) => Ok(id),
+			ArxivIdentifier::Old(_) => Err(ArxivBibtexError::InvalidArxivId(ArxivIdError::Syntax)),
+		}
+	}
+}
+
+/// Scans BibTeX text, such as a reference library, for the entries of arXiv articles,
+/// returning their citation keys and identifiers in order
+///
+/// Entries without an `eprint` field, with the `eprint` of another archive, or with an
+/// invalid identifier are skipped.
+///
+/// # Examples
+/// ```
+/// use arxiv::scan_bibtex_eprints;
+///
+/// let library = "
+/// @article{vaswani2017, eprint = {1706.03762}, archivePrefix = {arXiv}}
+/// @book{knuth1984, title = {The TeXbook}}
+/// @misc{maldacena1997, eprint = {hep-th/9711200}}
+/// ";
+/// let eprints = scan_bibtex_eprints(library);
+/// assert_eq!(eprints.len(), 2);
+/// assert_eq!(eprints[0].0, "vaswani2017");
+/// assert_eq!(eprints[1].1.to_bare_string(), "hep-th/9711200");
+/// ```
+#[must_use]
+pub fn scan_bibtex_eprints(text: &str) -> Vec<(String, ArxivIdentifier)> {
+	entries(text)
+		.filter_map(|(key, fields)| eprint_of(&fields).ok().map(|id| (key, id)))
+		.collect()
+}
+
+/// Parses the identifier of the `eprint` field among the fields of an entry
+fn eprint_of(fields: &[(String, String)]) -> Result<ArxivIdentifier, ArxivBibtexError> {
+	let field = |name: &str| {
+		fields
+			.iter()
+			.find(|(field, _)| field.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.trim())
+	};
+	let prefix = field("archivePrefix").or_else(|| field("eprinttype"));
+	if prefix.map_or(false, |prefix| !prefix.eq_ignore_ascii_case("arXiv")) {
+		return Err(ArxivBibtexError::NotArxiv);
+	}
+
+	let eprint = field("eprint").ok_or(ArxivBibtexError::MissingEprint)?;
+	let eprint = strip_prefix_ignore_case(eprint, "arXiv:").unwrap_or(eprint);
+	ArxivIdentifier::from_str(eprint).map_err(ArxivBibtexError::InvalidArxivId)
+}
+
+/// The citation keys and fields of the entries of BibTeX text, skipping the `@comment`,
+/// `@preamble` and `@string` entries which are not references
+fn entries(text: &str) -> impl Iterator<Item = (String, Vec<(String, String)>)> + '_ {
+	let mut rest = text;
+	std::iter::from_fn(move || loop {
+		let at = rest.find('@')?;
+		let after = &rest[at + 1..];
+		let kind_len = after
+			.find(|c: char| !c.is_ascii_alphanumeric())
+			.unwrap_or(after.len());
+		let kind = &after[..kind_len];
+		let body = after[kind_len..].trim_start();
+		let close = match body.chars().next() {
+			Some('{') => '}',
+			Some('(') => ')',
+			_ => {
+				rest = after;
+				continue;
+			}
+		};
+		let inner = &body[1..];
+		let (inner, after) = match find_closing(inner, close) {
+			Some(end) => (&inner[..end], &inner[end + 1..]),
+			None => (inner, ""),
+		};
+		rest = after;
+		let is_reference = !["comment", "preamble", "string"]
+			.iter()
+			.any(|other| kind.eq_ignore_ascii_case(other));
+		if is_reference && !kind.is_empty() {
+			return Some(parse_fields(inner));
+		}
+	})
+}
+
+/// Finds the byte offset of the closing delimiter of an entry, outside of braces
+fn find_closing(s: &str, close: char) -> Option<usize> {
+	let mut depth = 0_usize;
+	for (i, c) in s.char_indices() {
+		match c {
+			'{' => depth += 1,
+			'}' if depth > 0 => depth -= 1,
+			c if c == close && depth == 0 => return Some(i),
+			_ => {}
+		}
+	}
+	None
+}
+
+/// Parses the citation key and the fields of the body of an entry, with their values
+/// unwrapped from their braces or quotes
+fn parse_fields(body: &str) -> (String, Vec<(String, String)>) {
+	let (key, mut rest) = match body.find(',') {
+		Some(comma) => (body[..comma].trim(), &body[comma + 1..]),
+		None => (body.trim(), ""),
+	};
+	let mut fields = Vec::new();
+	while let Some(equals) = rest.find('=') {
+		let name = rest[..equals].trim().trim_start_matches(',').trim();
+		let value = rest[equals + 1..].trim_start();
+		let (value, after) = match value.chars().next() {
+			Some('{') => {
+				let end = find_closing(&value[1..], '}').map_or(value.len(), |end| end + 1);
+				(&value[1..end], value.get(end + 1..).unwrap_or(""))
+			}
+			Some('"') => {
+				let end = value[1..].find('"').map_or(value.len(), |end| end + 1);
+				(&value[1..end], value.get(end + 1..).unwrap_or(""))
+			}
+			_ => {
+				let end = find_closing(value, ',').unwrap_or(value.len());
+				(&value[..end], &value[end..])
+			}
+		};
+		fields.push((String::from(name), collapse_whitespace(value)));
+		rest = after;
+	}
+	(String::from(key), fields)
+}
+
+/// Writes a BibTeX entry with its fields, one per line
+fn write_entry(kind: &str, key: &str, fields: &[(&str, String)]) -> String {
+	let mut entry = format!("@{}{{{},\n", kind, key);
+	for (name, value) in fields {
+		let _ = writeln!(entry, "  {} = {{{}}},", name, value);
+	}
+	entry.push_str("}\n");
+	entry
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn metadata() -> ArxivArticleMetadata {
+		let mut metadata = ArxivArticleMetadata::fixture();
+		metadata.title =
+			String::from("The Large N Limit of Superconformal Field Theories and Supergravity");
+		metadata
+	}
+
+	#[test]
+	fn article_to_bibtex() {
+		assert_eq!(
+			metadata().to_bibtex(),
+			"@article{maldacena1998large,
+  title = {The Large N Limit of Superconformal Field Theories and Supergravity},
+  author = {Juan M. Maldacena},
+  journal = {Adv.Theor.Math.Phys.},
+  volume = {2},
+  pages = {231--252},
+  year = {1998},
+  eprint = {hep-th/9711200},
+  archivePrefix = {arXiv},
+  primaryClass = {hep-th},
+  doi = {10.4310/ATMP.1998.v2.n2.a1},
+  url = {https://arxiv.org/abs/hep-th/9711200},
+}
+"
+		);
+	}
+
+	#[test]
+	fn citation_keys() {
+		let mut metadata = metadata();
+		metadata.authors = vec![
+			String::from("Ludwig van Beethoven"),
+			String::from("Jane Doe"),
+		];
+		metadata.title = String::from("On the Müller-Lyer illusion");
+		assert_eq!(metadata.citation_key(2020), "vanbeethoven2020mller");
+
+		metadata.authors.clear();
+		metadata.title.clear();
+		assert_eq!(metadata.citation_key(2020), "2020");
+	}
+
+	#[test]
+	fn parse_eprint_of_generated_entries() {
+		let metadata = metadata();
+		assert_eq!(
+			ArxivIdentifier::from_bibtex_entry(&metadata.to_bibtex()),
+			Ok(metadata.id.clone())
+		);
+
+		let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+		assert_eq!(ArxivId::from_bibtex_entry(&id.to_bibtex_stub()), Ok(id));
+		assert_eq!(
+			ArxivId::from_bibtex_entry(&metadata.to_bibtex()),
+			Err(ArxivBibtexError::InvalidArxivId(ArxivIdError::Syntax))
+		);
+	}
+
+	#[test]
+	fn scan_bibtex_library() {
+		let library = r#"
+			@comment{eprint = {2101.00001}}
+			@string{arxiv = "arXiv"}
+			@Article(smith2020,
+				title = {On {Nested} Braces, and Commas},
+				year = 2020,
+				EPRINT = "ARXIV:2001.01234v3",
+				eprinttype = arxiv,
+			)
+			@misc{doe2021, eprint = {2101.0000x}}
+			@misc{roe2022, archivePrefix = {arXiv}}
+			@misc{poe2023, eprint = {hal-01234567}, eprinttype = {hal}}
+			@misc{unterminated, eprint = {math/0601001}
+		"#;
+		let eprints = scan_bibtex_eprints(library)
+			.into_iter()
+			.map(|(key, id)| (key, id.to_bare_string()))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			eprints,
+			[
+				(String::from("smith2020"), String::from("2001.01234v3")),
+				(String::from("unterminated"), String::from("math/0601001")),
+			]
+		);
+
+		let errors = [
+			("no entry", ArxivBibtexError::MissingEprint),
+			("@misc{roe2022, archivePrefix = {arXiv}}", ArxivBibtexError::MissingEprint),
+			(
+				"@misc{doe2021, eprint = {2101.0000x}}",
+				ArxivBibtexError::InvalidArxivId(ArxivIdError::InvalidId),
+			),
+		];
+		for (entry, error) in errors {
+			assert_eq!(ArxivIdentifier::from_bibtex_entry(entry), Err(error), "{}", entry);
+		}
+	}
+}
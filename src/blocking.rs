@@ -0,0 +1,292 @@
+//! A blocking client of the export API of arXiv.org, for scripts and build tools that do not
+//! run an asynchronous runtime.
+
+use crate::atom::AtomFeed;
+use crate::client::{
+	download_url, downloaded_size, parse_response, parse_rss_response, parse_versions_response,
+	retry_delay, write_download, Pages,
+};
+use crate::harvest::get_record_params;
+use crate::links::ARXIV_ORIGIN;
+use crate::query::encode_params;
+use crate::retry::RateLimiter;
+use crate::{
+	ArxivApiError, ArxivArticle, ArxivCategoryId, ArxivFeedEntry, ArxivId, ArxivIdentifier,
+	BlockingHttpTransport, HttpResponse, OaiMetadataFormat, Query, ReqwestBlockingTransport,
+	RetryPolicy, VersionHistory, ARXIV_API_DELAY, ARXIV_API_URL, ARXIV_OAI_URL, ARXIV_RSS_URL,
+};
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// A blocking client of the export API of arXiv.org, mirroring
+/// [`ArxivClient`](crate::ArxivClient), sending its requests with a [`BlockingHttpTransport`]
+/// which defaults to [`reqwest`](::reqwest)
+///
+/// The client must not be used within an asynchronous runtime, where blocking is not allowed.
+///
+/// # Examples
+/// ```no_run
+/// use std::str::FromStr;
+/// use arxiv::{ArxivBlockingClient, ArxivId};
+///
+/// let client = ArxivBlockingClient::new();
+/// let id = ArxivId::from_str("arXiv:1706.03762").unwrap();
+/// let article = client.fetch_metadata(&id).unwrap();
+/// println!("{}", article.title);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArxivBlockingClient<T = ReqwestBlockingTransport> {
+	transport: T,
+	api_url: String,
+	rss_url: String,
+	oai_url: String,
+	download_url: String,
+	limiter: Arc<RateLimiter>,
+	retry: RetryPolicy,
+}
+
+impl ArxivBlockingClient {
+	/// Creates a client of the export API with a default HTTP client
+	#[must_use]
+	pub fn new() -> Self {
+		Self::with_transport(ReqwestBlockingTransport::new())
+	}
+
+	/// Creates a client of the export API with an existing HTTP client, such as one
+	/// configured with a proxy or a custom user agent
+	#[must_use]
+	pub fn with_http_client(http: ::reqwest::blocking::Client) -> Self {
+		Self::with_transport(ReqwestBlockingTransport::from(http))
+	}
+}
+
+impl Default for ArxivBlockingClient {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: BlockingHttpTransport> ArxivBlockingClient<T> {
+	/// Creates a client of the export API sending its requests with the transport
+	#[must_use]
+	pub fn with_transport(transport: T) -> Self {
+		Self {
+			transport,
+			api_url: String::from(ARXIV_API_URL),
+			rss_url: String::from(ARXIV_RSS_URL),
+			oai_url: String::from(ARXIV_OAI_URL),
+			download_url: String::from(ARXIV_ORIGIN),
+			limiter: Arc::new(RateLimiter::new(ARXIV_API_DELAY)),
+			retry: RetryPolicy::default(),
+		}
+	}
+
+	/// Sets the endpoint of the export API, such as the one of a mirror
+	#[must_use]
+	pub fn with_api_url(mut self, url: impl Into<String>) -> Self {
+		self.api_url = url.into();
+		self
+	}
+
+	/// Sets the base URL of the RSS feeds of the categories, such as the one of a mirror
+	#[must_use]
+	pub fn with_rss_url(mut self, url: impl Into<String>) -> Self {
+		self.rss_url = url.into();
+		self
+	}
+
+	/// Sets the endpoint of the OAI-PMH interface, such as the one of a mirror
+	#[must_use]
+	pub fn with_oai_url(mut self, url: impl Into<String>) -> Self {
+		self.oai_url = url.into();
+		self
+	}
+
+	/// Sets the origin of the PDFs and sources of the articles, which defaults to
+	/// `https://arxiv.org`, such as `https://export.arxiv.org` for bulk downloads
+	#[must_use]
+	pub fn with_download_url(mut self, url: impl Into<String>) -> Self {
+		self.download_url = url.into();
+		self
+	}
+
+	/// Sets the minimum interval between requests, which defaults to [`ARXIV_API_DELAY`]
+	/// as recommended by arXiv.org, and is shared by the clones of the client
+	#[must_use]
+	pub fn with_rate_limit(mut self, interval: Duration) -> Self {
+		self.limiter = Arc::new(RateLimiter::new(interval));
+		self
+	}
+
+	/// Sets how requests rejected by the export API are retried
+	#[must_use]
+	pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+		self.retry = policy;
+		self
+	}
+
+	/// The endpoint of the export API
+	#[must_use]
+	#[inline]
+	pub fn api_url(&self) -> &str {
+		&self.api_url
+	}
+
+	/// The transport sending the requests of the client
+	#[must_use]
+	#[inline]
+	pub fn transport(&self) -> &T {
+		&self.transport
+	}
+
+	/// Fetches the metadata of an article, of the version of the identifier if it has one,
+	/// or of the latest version otherwise.
+	///
+	/// See also: [`ArxivClient::fetch_metadata`](crate::ArxivClient::fetch_metadata)
+	pub fn fetch_metadata(&self, id: &ArxivId) -> Result<ArxivArticle, ArxivApiError> {
+		let feed = self.fetch_feed(&[("id_list", id.to_bare_string())])?;
+		feed.articles
+			.into_iter()
+			.next()
+			.ok_or_else(|| ArxivApiError::NotFound(id.clone()))
+	}
+
+	/// Searches articles with a query of the export API, returning the page of results
+	/// selected by the query.
+	///
+	/// See also: [`ArxivClient::search`](crate::ArxivClient::search)
+	pub fn search(&self, query: &Query) -> Result<Vec<ArxivArticle>, ArxivApiError> {
+		Ok(self.fetch_feed(&query.to_params())?.articles)
+	}
+
+	/// Searches articles with a query of the export API, returning an iterator over all of the
+	/// results from the start index of the query, which blocks while requesting each page.
+	///
+	/// See also: [`ArxivClient::search_all`](crate::ArxivClient::search_all)
+	pub fn search_all<'a>(
+		&'a self,
+		query: &Query,
+	) -> impl Iterator<Item = Result<ArxivArticle, ArxivApiError>> + 'a {
+		let mut pages = Pages::new(query);
+		std::iter::from_fn(move || loop {
+			if let Some(article) = pages.buffer.pop_front() {
+				return Some(Ok(article));
+			}
+			let query = pages.next_page()?;
+			match self.fetch_feed(&query.to_params()) {
+				Ok(feed) => pages.push(feed),
+				Err(e) => {
+					pages.finish();
+					return Some(Err(e));
+				}
+			}
+		})
+	}
+
+	/// Fetches the daily RSS feed of a category, returning the articles of the last
+	/// announcement.
+	///
+	/// See also: [`ArxivClient::fetch_rss`](crate::ArxivClient::fetch_rss)
+	pub fn fetch_rss(
+		&self,
+		category: &ArxivCategoryId,
+	) -> Result<Vec<ArxivFeedEntry>, ArxivApiError> {
+		let url = format!("{}/{}", self.rss_url, category);
+		parse_rss_response(&self.send(&url)?)
+	}
+
+	/// Fetches the history of the versions of an article, with when each version was submitted
+	/// and its size, from its record in the `arXivRaw` format of the OAI-PMH interface.
+	///
+	/// See also: [`ArxivClient::versions`](crate::ArxivClient::versions)
+	pub fn versions(&self, id: &ArxivIdentifier) -> Result<VersionHistory, ArxivApiError> {
+		let params = get_record_params(id, OaiMetadataFormat::ArxivRaw);
+		let url = format!("{}?{}", self.oai_url, encode_params(&params));
+		parse_versions_response(&self.send(&url)?)
+	}
+
+	/// Downloads the PDF of an article to the path, resuming an interrupted download, and
+	/// returns the size of the file.
+	///
+	/// See also: [`ArxivClient::download_pdf`](crate::ArxivClient::download_pdf)
+	pub fn download_pdf(
+		&self,
+		id: &ArxivIdentifier,
+		path: impl AsRef<Path>,
+	) -> Result<u64, ArxivApiError> {
+		self.download(&download_url(&self.download_url, "pdf", id), path.as_ref())
+	}
+
+	/// Downloads the source of an article to the path, resuming an interrupted download, and
+	/// returns the size of the file.
+	///
+	/// See also: [`ArxivClient::download_source`](crate::ArxivClient::download_source)
+	pub fn download_source(
+		&self,
+		id: &ArxivIdentifier,
+		path: impl AsRef<Path>,
+	) -> Result<u64, ArxivApiError> {
+		self.download(&download_url(&self.download_url, "src", id), path.as_ref())
+	}
+
+	fn download(&self, url: &str, path: &Path) -> Result<u64, ArxivApiError> {
+		let offset = downloaded_size(path)?;
+		write_download(path, offset, &self.send_from(url, offset)?)
+	}
+
+	fn fetch_feed(&self, params: &[(&str, String)]) -> Result<AtomFeed, ArxivApiError> {
+		let url = format!("{}?{}", self.api_url, encode_params(params));
+		parse_response(&self.send(&url)?)
+	}
+
+	/// Sends a request once the rate limiter allows it, retrying it as long as it is rejected
+	/// and the retry policy allows it
+	fn send(&self, url: &str) -> Result<HttpResponse, ArxivApiError> {
+		self.send_from(url, 0)
+	}
+
+	fn send_from(&self, url: &str, offset: u64) -> Result<HttpResponse, ArxivApiError> {
+		let mut retries = 0;
+		loop {
+			thread::sleep(self.limiter.reserve());
+
+			let response = match offset {
+				0 => self.transport.get(url),
+				_ => self.transport.get_from(url, offset),
+			}
+			.map_err(ArxivApiError::Transport)?;
+			if RetryPolicy::is_retryable(response.status) && retries < self.retry.max_retries() {
+				thread::sleep(retry_delay(response.header("Retry-After"), &self.retry, retries));
+				retries += 1;
+				continue;
+			}
+
+			return Ok(response);
+		}
+	}
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+	use super::*;
+	use crate::fixtures::{ATOM_SEARCH, RSS_FEED};
+	use crate::MockTransport;
+	use std::str::FromStr;
+
+	#[test]
+	fn mock_transport() {
+		let transport = MockTransport::new()
+			.with_response(HttpResponse::new(200, ATOM_SEARCH))
+			.with_route("rss.arxiv.org", HttpResponse::new(200, RSS_FEED));
+		let client = ArxivBlockingClient::with_transport(transport).with_rate_limit(Duration::ZERO);
+
+		let articles = client.search(&Query::new().title("attention")).unwrap();
+		assert_eq!(articles[0].title, "Attention Is All You Need");
+		let category = ArxivCategoryId::from_str("hep-th").unwrap();
+		assert_eq!(client.fetch_rss(&category).unwrap().len(), 3);
+		assert_eq!(client.transport().requests()[1], "https://rss.arxiv.org/rss/hep-th");
+		assert!(matches!(client.search(&Query::new()), Err(ArxivApiError::Transport(_))));
+	}
+}
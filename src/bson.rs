@@ -0,0 +1,79 @@
+//! Support for storing identifiers and categories in [BSON](::bson) documents, such as
+//! MongoDB documents, as strings in their canonical forms.
+//!
+//! Since identifiers and categories serialize as strings with `serde`, they can be used
+//! directly as fields of typed document structs. The conversions to and from [`Bson`] values
+//! are useful for building queries and filters.
+//!
+//! # Examples
+//! ```
+//! use std::str::FromStr;
+//! use arxiv::ArxivId;
+//! use bson::{doc, Bson};
+//!
+//! let id = ArxivId::from_str("arXiv:2101.00001v1").unwrap();
+//! let filter = doc! { "id": &id };
+//! assert_eq!(filter.get("id"), Some(&Bson::String(String::from("arXiv:2101.00001v1"))));
+//! assert_eq!(ArxivId::try_from(&filter["id"]), Ok(id));
+//! ```
+
+use crate::{ArxivCategoryId, ArxivCategoryIdError, ArxivId, ArxivIdError};
+use ::bson::Bson;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+macro_rules! impl_bson_string {
+	($ty:ty, $err:expr) => {
+		impl From<$ty> for Bson {
+			fn from(value: $ty) -> Self {
+				Bson::String(value.to_string())
+			}
+		}
+
+		impl TryFrom<&Bson> for $ty {
+			type Error = <$ty as FromStr>::Err;
+
+			/// Parses a BSON string, returning a syntax error if the value is not a string.
+			fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+				match value {
+					Bson::String(s) => <$ty>::from_str(s),
+					_ => Err($err),
+				}
+			}
+		}
+	};
+}
+
+impl_bson_string!(ArxivId, ArxivIdError::Syntax);
+impl_bson_string!(ArxivCategoryId, ArxivCategoryIdError::Syntax);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::bson::{doc, from_document, to_document};
+	use ::serde::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	#[serde(crate = "::serde")]
+	struct Paper {
+		id: ArxivId,
+		category: ArxivCategoryId,
+	}
+
+	#[test]
+	fn bson_document_round_trip() {
+		let paper = Paper {
+			id: ArxivId::from_str("arXiv:2101.00001v1").unwrap(),
+			category: ArxivCategoryId::from_str("cs.LG").unwrap(),
+		};
+		let document = to_document(&paper).unwrap();
+		assert_eq!(document, doc! { "id": "arXiv:2101.00001v1", "category": "cs.LG" });
+		assert_eq!(from_document::<Paper>(document).unwrap(), paper);
+	}
+
+	#[test]
+	fn bson_not_a_string() {
+		assert_eq!(ArxivId::try_from(&Bson::Int32(1)), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivCategoryId::try_from(&Bson::Null), Err(ArxivCategoryIdError::Syntax));
+	}
+}
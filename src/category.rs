@@ -1,65 +1,165 @@
-use crate::subject_tables::*;
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use crate::{ArxivId, ArxivSubject, Taxonomy};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 /// An identifier for arXiv categories, which are composed of an archive and category
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Since the subject classes of the built-in taxonomy are static, and the subject classes
+/// added by a [`Taxonomy`] are kept inline, this type is [`Copy`] and never allocates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ArxivCategoryId {
 	group: ArxivGroup,
 	archive: ArxivArchive,
-	subject: String,
+	subject: SubjectClass,
+}
+
+/// The subject class of a category, either of the built-in taxonomy or added by a
+/// [`Taxonomy`]
+#[derive(Clone, Copy)]
+enum SubjectClass {
+	Builtin(&'static str),
+	Added {
+		len: u8,
+		bytes: [u8; SubjectClass::MAX_ADDED_LEN],
+	},
+}
+
+impl SubjectClass {
+	/// The maximum length in bytes of the subject classes added by a [`Taxonomy`]
+	const MAX_ADDED_LEN: usize = 15usize;
+
+	fn added(subject: &str) -> Option<Self> {
+		let mut bytes = [0u8; Self::MAX_ADDED_LEN];
+		bytes
+			.get_mut(..subject.len())?
+			.copy_from_slice(subject.as_bytes());
+		Some(Self::Added {
+			len: subject.len() as u8,
+			bytes,
+		})
+	}
+
+	fn as_str(&self) -> &str {
+		match self {
+			Self::Builtin(subject) => subject,
+			Self::Added { len, bytes } => {
+				std::str::from_utf8(&bytes[..usize::from(*len)]).unwrap_or_default()
+			}
+		}
+	}
+}
+
+impl PartialEq for SubjectClass {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_str() == other.as_str()
+	}
+}
+
+impl Eq for SubjectClass {}
+
+impl Hash for SubjectClass {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_str().hash(state);
+	}
+}
+
+impl Debug for SubjectClass {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		Debug::fmt(self.as_str(), f)
+	}
 }
 
 impl ArxivCategoryId {
 	pub(crate) const TOKEN_DELIM: char = '.';
+	const MAX_SUGGESTIONS: usize = 3usize;
+	const MAX_SUGGESTION_DISTANCE: usize = 2usize;
 
-	pub(super) const fn new(group: ArxivGroup, archive: ArxivArchive, subject: String) -> Self {
+	pub(super) const fn new(
+		group: ArxivGroup,
+		archive: ArxivArchive,
+		subject: &'static str,
+	) -> Self {
 		Self {
 			group,
 			archive,
-			subject,
+			subject: SubjectClass::Builtin(subject),
 		}
 	}
 
+	/// Creates a category of a subject class added by a [`Taxonomy`], returning [`None`] if
+	/// the subject class is too long to be kept inline
+	pub(crate) fn added(archive: ArxivArchive, subject: &str) -> Option<Self> {
+		Some(Self {
+			group: ArxivGroup::from(archive),
+			archive,
+			subject: SubjectClass::added(subject)?,
+		})
+	}
+
 	/// Checks if the string is a valid group identifier, based on the archive and category.
+	/// Archives without any subject classes (such as `hep-th`) are valid categories with an
+	/// empty subject class, and the subject classes added by the installed [`Taxonomy`] (see
+	/// [`Taxonomy::install`]) are valid as well.
 	///
 	/// Valid archive identifiers are listed under the official website's page for [category taxonomy][arxiv-cat].
 	///
 	/// [arxiv-cat]: <https://arxiv.org/category_taxonomy>
-	#[rustfmt::skip]
 	pub fn try_new(archive: ArxivArchive, subject: &str) -> Option<Self> {
-		let is_valid = match archive {
-			ArxivArchive::AstroPh => matches!(subject, "CO" | "EP" | "GA" | "HE" | "IM" | "SR"),
-			ArxivArchive::CondMat => matches!(subject,
-					| "dis-nn" | "mes-hall" | "mtrl-sci"
-					| "other" | "quant-gas" | "soft"
-					| "stat-mech" | "str-el" | "supr-con"
-			),
-			ArxivArchive::Cs => COMPSCI_TABLE.binary_search(&subject).is_ok(),
-			ArxivArchive::Econ => matches!(subject, "EM" | "GN" | "TH"),
-			ArxivArchive::Eess => matches!(subject, "AS" | "IV" | "SP" | "SY"),
-			ArxivArchive::GrQc => subject.is_empty(),
-			ArxivArchive::HepEx => subject.is_empty(),
-			ArxivArchive::HepLat => subject.is_empty(),
-			ArxivArchive::HepPh => subject.is_empty(),
-			ArxivArchive::HepTh => subject.is_empty(),
-			ArxivArchive::MathPh => subject.is_empty(),
-			ArxivArchive::Math => MATH_TABLE.binary_search(&subject).is_ok(),
-			ArxivArchive::Nlin => matches!(subject, "AO" | "CD" | "CG" | "PS" | "SI"),
-			ArxivArchive::NuclEx => subject.is_empty(),
-			ArxivArchive::NuclTh => subject.is_empty(),
-			ArxivArchive::Physics => PHYSICS_TABLE.binary_search(&subject).is_ok(),
-			ArxivArchive::QBio    => matches!(subject, "BM" | "CB" | "GN" | "MN" | "NC" | "OT" | "PE" | "QM" | "SC" | "TO"),
-			ArxivArchive::QFin => {
-				matches!(subject, "CP" | "EC" | "GN" | "MF" | "PM" | "PR" | "RM" | "ST" | "SR")
-			}
-			ArxivArchive::QuantPh => subject.is_empty(),
-			ArxivArchive::Stat => matches!(subject, "AP" | "CO" | "ME" | "ML" | "OT" | "TH"),
+		Self::try_builtin(archive, subject).or_else(|| Taxonomy::try_installed(archive, subject))
+	}
+
+	/// Checks if the subject class is valid for the archive in the built-in taxonomy
+	pub(crate) fn try_builtin(archive: ArxivArchive, subject: &str) -> Option<Self> {
+		let subject = match archive.has_subjects() {
+			true => ArxivSubject::parse(archive, subject)?.as_str(),
+			false if subject.is_empty() => "",
+			false => return None,
 		};
 
-		match is_valid {
-			true => Some(Self::new(ArxivGroup::from(archive), archive, String::from(subject))),
-			false => None,
+		Some(Self::new(ArxivGroup::from(archive), archive, subject))
+	}
+
+	/// Parses a category identifier leniently, ignoring surrounding whitespace and the case
+	/// of the archive and subject class, which are normalized to the official convention of a
+	/// lowercase archive and a canonical-case subject class.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let category = ArxivCategoryId::parse_lenient(" CS.lg ").unwrap();
+	/// assert_eq!(category, ArxivCategoryId::from_str("cs.LG").unwrap());
+	/// assert_eq!(ArxivCategoryId::parse_lenient("Cond-Mat.Quant-Gas").unwrap().to_string(), "cond-mat.quant-gas");
+	/// ```
+	pub fn parse_lenient(s: &str) -> Result<Self, ArxivCategoryIdError> {
+		let s = s.trim();
+		let (archive, subject) = Self::split(s)?;
+
+		ArxivArchive::from_str(&archive.trim().to_ascii_lowercase())
+			.ok()
+			.and_then(|archive| {
+				let subject = subject.trim();
+				let canonical = ArxivSubject::ids_of(archive)
+					.iter()
+					.find(|id| id.eq_ignore_ascii_case(subject))
+					.map_or(subject, |id| *id);
+				Self::try_new(archive, canonical)
+			})
+			.ok_or_else(|| ArxivCategoryIdError::Unknown(Self::suggest(s)))
+	}
+
+	/// Splits a category identifier into its archive and (possibly empty) subject class
+	pub(crate) fn split(s: &str) -> Result<(&str, &str), ArxivCategoryIdError> {
+		match s.split_once(Self::TOKEN_DELIM) {
+			Some((_, subject)) if subject.is_empty() || subject.contains(Self::TOKEN_DELIM) => {
+				Err(ArxivCategoryIdError::Syntax)
+			}
+			Some(parts) => Ok(parts),
+			None if s.is_empty() => Err(ArxivCategoryIdError::Syntax),
+			None => Ok((s, "")),
 		}
 	}
 
@@ -70,6 +170,23 @@ impl ArxivCategoryId {
 		self.group
 	}
 
+	/// Checks if the category belongs to the group
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ArxivGroup};
+	///
+	/// let category = ArxivCategoryId::from_str("hep-th").unwrap();
+	/// assert!(category.is_in_group(ArxivGroup::Physics));
+	/// assert!(!category.is_in_group(ArxivGroup::Math));
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn is_in_group(&self, group: ArxivGroup) -> bool {
+		self.group == group
+	}
+
 	/// The archive, representing a collection of publications
 	/// that relate to each other by a specific field of study
 	#[must_use]
@@ -81,34 +198,290 @@ impl ArxivCategoryId {
 	/// The subject class of the arXiv category
 	#[must_use]
 	#[inline]
-	pub fn subject(&self) -> String {
-		self.subject.to_owned()
+	pub fn subject(&self) -> &str {
+		self.subject.as_str()
+	}
+
+	/// The subject class of the arXiv category as a typed enum, or [`None`] if the archive
+	/// has no subject classes
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ArxivSubject, MathSubject};
+	///
+	/// let category = ArxivCategoryId::from_str("math.AG").unwrap();
+	/// assert_eq!(category.typed_subject(), Some(ArxivSubject::Math(MathSubject::AG)));
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn typed_subject(&self) -> Option<ArxivSubject> {
+		ArxivSubject::parse(self.archive, self.subject())
+	}
+
+	/// Whether or not the category is a deprecated alias of another category, which new
+	/// submissions are listed under instead
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// assert!(ArxivCategoryId::from_str("cs.NA").unwrap().is_deprecated());
+	/// assert!(!ArxivCategoryId::from_str("math.NA").unwrap().is_deprecated());
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn is_deprecated(&self) -> bool {
+		self.superseded_by().is_some()
+	}
+
+	/// The category that supersedes this category, if this category is a deprecated alias.
+	///
+	/// Harvesters processing decades of metadata can use this to normalize categories to
+	/// their current canonical form.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let category = ArxivCategoryId::from_str("math.MP").unwrap();
+	/// assert_eq!(category.superseded_by(), ArxivCategoryId::from_str("math-ph").ok());
+	/// ```
+	#[must_use]
+	pub fn superseded_by(&self) -> Option<Self> {
+		let (archive, subject) = match (self.archive, self.subject()) {
+			(ArxivArchive::Cs, "NA") => (ArxivArchive::Math, "NA"),
+			(ArxivArchive::Cs, "SY") => (ArxivArchive::Eess, "SY"),
+			(ArxivArchive::Math, "IT") => (ArxivArchive::Cs, "IT"),
+			(ArxivArchive::Math, "MP") => (ArxivArchive::MathPh, ""),
+			(ArxivArchive::QFin, "EC") => (ArxivArchive::Econ, "GN"),
+			(ArxivArchive::Stat, "TH") => (ArxivArchive::Math, "ST"),
+			_ => return None,
+		};
+
+		Some(Self::new(ArxivGroup::from(archive), archive, subject))
+	}
+
+	/// The canonical form of the category, which is the category that supersedes it if it
+	/// is deprecated, or the category itself otherwise
+	#[must_use]
+	#[inline]
+	pub fn canonical(&self) -> Self {
+		self.superseded_by().unwrap_or(*self)
+	}
+
+	/// The human-readable name of the arXiv category, which is the name of the subject class,
+	/// or the name of the archive if it has no subject classes or if the subject class was
+	/// added by a [`Taxonomy`] (see [`Taxonomy::name_of`])
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let category = ArxivCategoryId::from_str("astro-ph.HE").unwrap();
+	/// assert_eq!(category.name(), "High Energy Astrophysical Phenomena");
+	/// ```
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		match self.typed_subject() {
+			Some(subject) => subject.name(),
+			None => self.archive.name(),
+		}
+	}
+
+	/// Searches the category taxonomy for categories whose name or identifier matches the
+	/// query, ignoring case. Exact matches come first, followed by names containing the query,
+	/// followed by names where every word of the query is the start of a word in the name.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let categories = ArxivCategoryId::search("machine learning");
+	/// let ids: Vec<String> = categories.iter().map(|c| c.to_string()).collect();
+	/// assert_eq!(ids, vec!["cs.LG", "stat.ML"]);
+	/// ```
+	pub fn search(query: &str) -> Vec<Self> {
+		let query = query.trim().to_lowercase();
+		let words: Vec<&str> = query
+			.split(|c: char| !c.is_alphanumeric())
+			.filter(|w| !w.is_empty())
+			.collect();
+		if words.is_empty() {
+			return Vec::new();
+		}
+
+		let mut matches: Vec<(u8, Self)> = Vec::new();
+		for category in Self::all() {
+			let name = category.name().to_lowercase();
+			let rank = if name == query || category.to_string().to_lowercase() == query {
+				0
+			} else if name.contains(&query) {
+				1
+			} else if words.iter().all(|w| {
+				name.split(|c: char| !c.is_alphanumeric())
+					.any(|n| n.starts_with(w))
+			}) {
+				2
+			} else {
+				continue;
+			};
+			matches.push((rank, category));
+		}
+
+		matches.sort_by_key(|(rank, _)| *rank);
+		matches.into_iter().map(|(_, category)| category).collect()
+	}
+
+	/// Finds the valid categories that are nearest to a misspelled category, by their
+	/// case-insensitive edit distance, with the nearest category first.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let suggestions = ArxivCategoryId::suggest("astroph.HE");
+	/// assert_eq!(suggestions, vec![ArxivCategoryId::from_str("astro-ph.HE").unwrap()]);
+	/// ```
+	pub fn suggest(s: &str) -> Vec<Self> {
+		let s = s.trim().to_lowercase();
+		let max_distance = (s.chars().count() / 4).clamp(1, Self::MAX_SUGGESTION_DISTANCE);
+
+		let mut suggestions: Vec<(usize, Self)> = Self::all()
+			.map(|category| (edit_distance(&s, &category.to_string().to_lowercase()), category))
+			.filter(|(distance, _)| *distance <= max_distance)
+			.collect();
+
+		suggestions.sort_by_key(|(distance, _)| *distance);
+		suggestions.truncate(Self::MAX_SUGGESTIONS);
+		suggestions
+			.into_iter()
+			.map(|(_, category)| category)
+			.collect()
+	}
+
+	/// Every category in the taxonomy, in canonical order, including the archives without
+	/// any subject classes (such as `hep-th`).
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArxivArchive, ArxivCategoryId};
+	///
+	/// let mut categories = ArxivCategoryId::all();
+	/// assert_eq!(categories.next(), ArxivCategoryId::try_new(ArxivArchive::AstroPh, "CO"));
+	/// assert!(ArxivCategoryId::all().any(|c| c.archive() == ArxivArchive::HepTh));
+	/// ```
+	pub fn all() -> impl Iterator<Item = Self> {
+		ArxivArchive::ALL.into_iter().flat_map(|archive| {
+			let subjects = match ArxivSubject::ids_of(archive) {
+				[] => &[""],
+				subjects => subjects,
+			};
+
+			subjects
+				.iter()
+				.map(move |subject| Self::new(ArxivGroup::from(archive), archive, subject))
+		})
+	}
+}
+
+/// The Levenshtein distance between two strings, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut previous: Vec<usize> = (0..=b.len()).collect();
+	let mut current = vec![0; b.len() + 1];
+
+	for (i, ca) in a.chars().enumerate() {
+		current[0] = i + 1;
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(ca != *cb);
+			current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+		}
+		std::mem::swap(&mut previous, &mut current);
 	}
+
+	previous[b.len()]
 }
 
 impl Display for ArxivCategoryId {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		write!(f, "{}.{}", self.archive, self.subject)
+		match self.subject().is_empty() {
+			true => write!(f, "{}", self.archive),
+			false => write!(f, "{}.{}", self.archive, self.subject()),
+		}
 	}
 }
 
 impl FromStr for ArxivCategoryId {
-	type Err = ();
+	type Err = ArxivCategoryIdError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let parts: Vec<&str> = s.split(Self::TOKEN_DELIM).collect();
-		if parts.len() != 2 {
-			return Err(());
+		let (archive, subject) = Self::split(s)?;
+
+		ArxivArchive::from_str(archive)
+			.ok()
+			.and_then(|archive| Self::try_new(archive, subject))
+			.ok_or_else(|| ArxivCategoryIdError::Unknown(Self::suggest(s)))
+	}
+}
+
+/// An error that can occur when parsing and validating arXiv category identifiers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArxivCategoryIdError {
+	/// A generic parsing syntax error
+	Syntax,
+	/// The archive or subject class is not part of the category taxonomy, along with the
+	/// nearest valid categories (if any) as suggestions
+	Unknown(Vec<ArxivCategoryId>),
+}
+
+impl ArxivCategoryIdError {
+	/// The nearest valid categories to the category that failed to parse
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let error = ArxivCategoryId::from_str("cs.LGG").unwrap_err();
+	/// assert_eq!(error.suggestions()[0].to_string(), "cs.LG");
+	/// ```
+	#[must_use]
+	pub fn suggestions(&self) -> &[ArxivCategoryId] {
+		match self {
+			Self::Syntax => &[],
+			Self::Unknown(suggestions) => suggestions,
 		}
+	}
+}
 
-		let archive = ArxivArchive::from_str(parts[0])?;
-		let subject = parts[1];
+impl Error for ArxivCategoryIdError {}
 
-		Self::try_new(archive, subject).ok_or(())
+impl Display for ArxivCategoryIdError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Syntax => write!(f, "There was a syntax error; an arXiv category must conform to the schema of archive.subject."),
+			Self::Unknown(suggestions) if suggestions.is_empty() => write!(f, "The category is not part of the arXiv category taxonomy."),
+			Self::Unknown(suggestions) => {
+				write!(f, "The category is not part of the arXiv category taxonomy; did you mean ")?;
+				for (i, suggestion) in suggestions.iter().enumerate() {
+					match i {
+						0 => write!(f, "{}", suggestion)?,
+						_ if i + 1 == suggestions.len() => write!(f, " or {}", suggestion)?,
+						_ => write!(f, ", {}", suggestion)?,
+					}
+				}
+				write!(f, "?")
+			}
+		}
 	}
 }
 
 /// A type of classification for arXiv publications
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ArxivGroup {
 	/// Computer Science
 	Cs,
@@ -128,6 +501,42 @@ pub enum ArxivGroup {
 	Stat,
 }
 
+impl Display for ArxivGroup {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(
+			f,
+			"{}",
+			match self {
+				ArxivGroup::Cs => "cs",
+				ArxivGroup::Econ => "econ",
+				ArxivGroup::Eess => "eess",
+				ArxivGroup::Math => "math",
+				ArxivGroup::Physics => "physics",
+				ArxivGroup::QBio => "q-bio",
+				ArxivGroup::QFin => "q-fin",
+				ArxivGroup::Stat => "stat",
+			}
+		)
+	}
+}
+
+impl FromStr for ArxivGroup {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"cs" => Ok(Self::Cs),
+			"econ" => Ok(Self::Econ),
+			"eess" => Ok(Self::Eess),
+			"math" => Ok(Self::Math),
+			"physics" => Ok(Self::Physics),
+			"q-bio" => Ok(Self::QBio),
+			"q-fin" => Ok(Self::QFin),
+			"stat" => Ok(Self::Stat),
+			_ => Err(()),
+		}
+	}
+}
+
 impl From<ArxivArchive> for ArxivGroup {
 	fn from(archive: ArxivArchive) -> Self {
 		match archive {
@@ -160,7 +569,7 @@ impl From<ArxivArchive> for ArxivGroup {
 /// Valid archive identifiers are listed under the official website's page for [category taxonomy][arxiv-cat].
 ///
 /// [arxiv-cat]: <https://arxiv.org/category_taxonomy>
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ArxivArchive {
 	/// Astro physics
 	AstroPh,
@@ -204,6 +613,150 @@ pub enum ArxivArchive {
 	Stat,
 }
 
+impl ArxivArchive {
+	/// Every archive in the category taxonomy
+	pub const ALL: [Self; 20] = [
+		Self::AstroPh,
+		Self::CondMat,
+		Self::Cs,
+		Self::Econ,
+		Self::Eess,
+		Self::GrQc,
+		Self::HepEx,
+		Self::HepLat,
+		Self::HepPh,
+		Self::HepTh,
+		Self::MathPh,
+		Self::Math,
+		Self::Nlin,
+		Self::NuclEx,
+		Self::NuclTh,
+		Self::Physics,
+		Self::QBio,
+		Self::QFin,
+		Self::QuantPh,
+		Self::Stat,
+	];
+
+	/// The period during which the archive accepted submissions, which can be used to check
+	/// that an identifier and a category are temporally consistent with each other
+	///
+	/// Every archive of the taxonomy still accepts submissions, so the period has no last
+	/// month, unlike the periods of retired archives (see [`ArxivLegacyArchive::active_range`]).
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivArchive, ArxivId};
+	///
+	/// let range = ArxivArchive::QFin.active_range();
+	/// assert_eq!(range.first(), (2008, 12));
+	/// assert_eq!(range.last(), None);
+	/// assert!(!range.contains_id(&ArxivId::from_str("arXiv:0704.0001").unwrap()));
+	/// ```
+	#[must_use]
+	pub const fn active_range(self) -> ArxivActiveRange {
+		let first = match self {
+			Self::AstroPh => (1992, 4),
+			Self::CondMat => (1992, 4),
+			Self::Cs => (1993, 8),
+			Self::Econ => (2017, 9),
+			Self::Eess => (2017, 9),
+			Self::GrQc => (1992, 7),
+			Self::HepEx => (1994, 4),
+			Self::HepLat => (1992, 2),
+			Self::HepPh => (1992, 3),
+			Self::HepTh => (1991, 8),
+			Self::MathPh => (1998, 9),
+			Self::Math => (1992, 2),
+			Self::Nlin => (1993, 1),
+			Self::NuclEx => (1994, 12),
+			Self::NuclTh => (1992, 10),
+			Self::Physics => (1996, 10),
+			Self::QBio => (2003, 9),
+			Self::QFin => (2008, 12),
+			Self::QuantPh => (1994, 12),
+			Self::Stat => (2007, 4),
+		};
+
+		ArxivActiveRange { first, last: None }
+	}
+
+	/// Whether or not the archive is divided into subject classes, which is not the case
+	/// for archives such as `hep-th` or `quant-ph`
+	#[must_use]
+	#[inline]
+	pub const fn has_subjects(self) -> bool {
+		!ArxivSubject::ids_of(self).is_empty()
+	}
+
+	/// The human-readable name of the archive
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::AstroPh => "Astrophysics",
+			Self::CondMat => "Condensed Matter",
+			Self::Cs => "Computer Science",
+			Self::Econ => "Economics",
+			Self::Eess => "Electrical Engineering and Systems Science",
+			Self::GrQc => "General Relativity and Quantum Cosmology",
+			Self::HepEx => "High Energy Physics - Experiment",
+			Self::HepLat => "High Energy Physics - Lattice",
+			Self::HepPh => "High Energy Physics - Phenomenology",
+			Self::HepTh => "High Energy Physics - Theory",
+			Self::MathPh => "Mathematical Physics",
+			Self::Math => "Mathematics",
+			Self::Nlin => "Nonlinear Sciences",
+			Self::NuclEx => "Nuclear Experiment",
+			Self::NuclTh => "Nuclear Theory",
+			Self::Physics => "Physics",
+			Self::QBio => "Quantitative Biology",
+			Self::QFin => "Quantitative Finance",
+			Self::QuantPh => "Quantum Physics",
+			Self::Stat => "Statistics",
+		}
+	}
+}
+
+/// The inclusive range of months, as `(year, month)` pairs, during which an archive
+/// accepted submissions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArxivActiveRange {
+	first: (u16, u8),
+	last: Option<(u16, u8)>,
+}
+
+impl ArxivActiveRange {
+	/// The first month in which the archive accepted submissions
+	#[must_use]
+	#[inline]
+	pub const fn first(&self) -> (u16, u8) {
+		self.first
+	}
+
+	/// The last month in which the archive accepted submissions,
+	/// or [`None`] if the archive still accepts submissions
+	#[must_use]
+	#[inline]
+	pub const fn last(&self) -> Option<(u16, u8)> {
+		self.last
+	}
+
+	/// Whether or not the archive accepted submissions in the given year and month
+	#[must_use]
+	pub fn contains(&self, year: u16, month: u8) -> bool {
+		let period = (year, month);
+		period >= self.first && self.last.map_or(true, |last| period <= last)
+	}
+
+	/// Whether or not the archive accepted submissions in the period of the identifier
+	#[must_use]
+	#[inline]
+	pub fn contains_id(&self, id: &ArxivId) -> bool {
+		self.contains(id.year(), id.month())
+	}
+}
+
 impl Display for ArxivArchive {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
 		write!(
@@ -264,6 +817,143 @@ impl FromStr for ArxivArchive {
 	}
 }
 
+/// An archive which is no longer part of the category taxonomy, since it was merged into a
+/// category of another archive, such as `solv-int` into `nlin.SI`
+///
+/// The `categories` field of the metadata of older articles may still list these archives.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivCategoryId, ArxivLegacyArchive};
+///
+/// let archive = ArxivLegacyArchive::from_str("solv-int").unwrap();
+/// assert_eq!(archive.successor(), ArxivCategoryId::from_str("nlin.SI").unwrap());
+/// assert_eq!(archive.active_range().last(), Some((1999, 12)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArxivLegacyArchive {
+	/// Accelerator Physics, merged into `physics.acc-ph`
+	AccPhys,
+	/// Adaptation, Noise, and Self-Organizing Systems, merged into `nlin.AO`
+	AdapOrg,
+	/// Algebraic Geometry, merged into `math.AG`
+	AlgGeom,
+	/// Atmospheric-Oceanic Sciences, merged into `physics.ao-ph`
+	AoSci,
+	/// Atomic, Molecular and Optical Physics, merged into `physics.atom-ph`
+	AtomPh,
+	/// Bayesian Analysis, merged into `physics.data-an`
+	BayesAn,
+	/// Chaotic Dynamics, merged into `nlin.CD`
+	ChaoDyn,
+	/// Chemical Physics, merged into `physics.chem-ph`
+	ChemPh,
+	/// Computation and Language, merged into `cs.CL`
+	CmpLg,
+	/// Cellular Automata and Lattice Gases, merged into `nlin.CG`
+	CompGas,
+	/// Differential Geometry, merged into `math.DG`
+	DgGa,
+	/// Functional Analysis, merged into `math.FA`
+	FunctAn,
+	/// Materials Theory, merged into `cond-mat.mtrl-sci`
+	MtrlTh,
+	/// Pattern Formation and Solitons, merged into `nlin.PS`
+	PattSol,
+	/// Plasma Physics, merged into `physics.plasm-ph`
+	PlasmPh,
+	/// Quantum Algebra and Topology, merged into `math.QA`
+	QAlg,
+	/// Exactly Solvable and Integrable Systems, merged into `nlin.SI`
+	SolvInt,
+	/// Superconductivity, merged into `cond-mat.supr-con`
+	SuprCon,
+}
+
+impl ArxivLegacyArchive {
+	/// Every archive which is no longer part of the category taxonomy
+	pub const ALL: [Self; 18] = [
+		Self::AccPhys,
+		Self::AdapOrg,
+		Self::AlgGeom,
+		Self::AoSci,
+		Self::AtomPh,
+		Self::BayesAn,
+		Self::ChaoDyn,
+		Self::ChemPh,
+		Self::CmpLg,
+		Self::CompGas,
+		Self::DgGa,
+		Self::FunctAn,
+		Self::MtrlTh,
+		Self::PattSol,
+		Self::PlasmPh,
+		Self::QAlg,
+		Self::SolvInt,
+		Self::SuprCon,
+	];
+
+	/// The identifier of the archive, the archive and subject class of its successor, and
+	/// the first and last months in which it accepted submissions
+	const fn info(self) -> (&'static str, ArxivArchive, &'static str, (u16, u8), (u16, u8)) {
+		match self {
+			Self::AccPhys => ("acc-phys", ArxivArchive::Physics, "acc-ph", (1994, 11), (1996, 9)),
+			Self::AdapOrg => ("adap-org", ArxivArchive::Nlin, "AO", (1993, 3), (1999, 12)),
+			Self::AlgGeom => ("alg-geom", ArxivArchive::Math, "AG", (1992, 2), (1997, 12)),
+			Self::AoSci => ("ao-sci", ArxivArchive::Physics, "ao-ph", (1995, 2), (1996, 9)),
+			Self::AtomPh => ("atom-ph", ArxivArchive::Physics, "atom-ph", (1995, 9), (1996, 9)),
+			Self::BayesAn => ("bayes-an", ArxivArchive::Physics, "data-an", (1995, 6), (1996, 9)),
+			Self::ChaoDyn => ("chao-dyn", ArxivArchive::Nlin, "CD", (1993, 1), (1999, 12)),
+			Self::ChemPh => ("chem-ph", ArxivArchive::Physics, "chem-ph", (1994, 3), (1996, 9)),
+			Self::CmpLg => ("cmp-lg", ArxivArchive::Cs, "CL", (1994, 4), (1998, 9)),
+			Self::CompGas => ("comp-gas", ArxivArchive::Nlin, "CG", (1993, 2), (1999, 12)),
+			Self::DgGa => ("dg-ga", ArxivArchive::Math, "DG", (1994, 6), (1997, 12)),
+			Self::FunctAn => ("funct-an", ArxivArchive::Math, "FA", (1993, 11), (1997, 12)),
+			Self::MtrlTh => ("mtrl-th", ArxivArchive::CondMat, "mtrl-sci", (1994, 10), (1996, 9)),
+			Self::PattSol => ("patt-sol", ArxivArchive::Nlin, "PS", (1993, 2), (1999, 12)),
+			Self::PlasmPh => ("plasm-ph", ArxivArchive::Physics, "plasm-ph", (1995, 9), (1996, 9)),
+			Self::QAlg => ("q-alg", ArxivArchive::Math, "QA", (1994, 12), (1997, 12)),
+			Self::SolvInt => ("solv-int", ArxivArchive::Nlin, "SI", (1993, 4), (1999, 12)),
+			Self::SuprCon => ("supr-con", ArxivArchive::CondMat, "supr-con", (1994, 11), (1996, 9)),
+		}
+	}
+
+	/// The category which the archive was merged into
+	#[must_use]
+	pub fn successor(self) -> ArxivCategoryId {
+		let (_, archive, subject, _, _) = self.info();
+		ArxivCategoryId::new(ArxivGroup::from(archive), archive, subject)
+	}
+
+	/// The period during which the archive accepted submissions, which ended when it was
+	/// merged into its successor
+	#[must_use]
+	pub const fn active_range(self) -> ArxivActiveRange {
+		let (_, _, _, first, last) = self.info();
+		ArxivActiveRange {
+			first,
+			last: Some(last),
+		}
+	}
+}
+
+impl Display for ArxivLegacyArchive {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{}", self.info().0)
+	}
+}
+
+impl FromStr for ArxivLegacyArchive {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::ALL
+			.into_iter()
+			.find(|archive| archive.info().0 == s)
+			.ok_or(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -271,10 +961,26 @@ mod tests {
 	#[test]
 	fn parse_category_id() {
 		let cat_id = ArxivCategoryId::from_str("cs.LG");
+		assert_eq!(cat_id, Ok(ArxivCategoryId::new(ArxivGroup::Cs, ArxivArchive::Cs, "LG")));
+	}
+
+	#[test]
+	fn parse_bare_archive_category() {
+		let expected = ArxivCategoryId::try_new(ArxivArchive::HepTh, "").unwrap();
+		assert_eq!(ArxivCategoryId::from_str("hep-th"), Ok(expected));
 		assert_eq!(
-			cat_id,
-			Ok(ArxivCategoryId::new(ArxivGroup::Cs, ArxivArchive::Cs, String::from("LG")))
+			ArxivCategoryId::parse_lenient(" Quant-Ph "),
+			Ok(ArxivCategoryId::try_new(ArxivArchive::QuantPh, "").unwrap())
 		);
+		assert_eq!(ArxivCategoryId::from_str("hep-th."), Err(ArxivCategoryIdError::Syntax));
+		assert!(ArxivCategoryId::from_str("hep-th.LG").is_err());
+		assert!(ArxivCategoryId::from_str("cs").is_err());
+	}
+
+	#[test]
+	fn display_bare_archive_category() {
+		let category = ArxivCategoryId::try_new(ArxivArchive::GrQc, "").unwrap();
+		assert_eq!(category.to_string(), "gr-qc");
 	}
 
 	#[test]
@@ -287,11 +993,128 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn parse_category_lenient() {
+		let expected = ArxivCategoryId::try_new(ArxivArchive::Math, "AG").unwrap();
+		assert_eq!(ArxivCategoryId::parse_lenient("math.ag"), Ok(expected));
+		assert_eq!(ArxivCategoryId::parse_lenient("\tMATH . AG\n"), Ok(expected));
+		assert_eq!(ArxivCategoryId::parse_lenient("math."), Err(ArxivCategoryIdError::Syntax));
+		assert!(ArxivCategoryId::parse_lenient("math.XX").is_err());
+	}
+
+	#[test]
+	fn deprecated_categories() {
+		let deprecated: Vec<ArxivCategoryId> = ArxivCategoryId::all()
+			.filter(|c| c.is_deprecated())
+			.collect();
+		assert_eq!(deprecated.len(), 6);
+		for category in deprecated {
+			let canonical = category.canonical();
+			assert_ne!(canonical, category);
+			assert!(!canonical.is_deprecated());
+		}
+	}
+
+	#[test]
+	fn typed_subject() {
+		let category = ArxivCategoryId::from_str("q-fin.TR").unwrap();
+		assert_eq!(category.typed_subject(), Some(ArxivSubject::QFin(crate::QFinSubject::TR)));
+	}
+
+	#[test]
+	fn all_categories() {
+		let all: Vec<ArxivCategoryId> = ArxivCategoryId::all().collect();
+		assert_eq!(all.len(), 155);
+		for category in all {
+			assert_eq!(
+				ArxivCategoryId::try_new(category.archive(), category.subject()),
+				Some(category)
+			);
+		}
+	}
+
+	#[test]
+	fn search_by_name() {
+		let found = ArxivCategoryId::search("Quantum");
+		assert!(found.contains(&ArxivCategoryId::try_new(ArxivArchive::QuantPh, "").unwrap()));
+		assert!(
+			found.contains(&ArxivCategoryId::try_new(ArxivArchive::CondMat, "quant-gas").unwrap())
+		);
+		assert!(ArxivCategoryId::search("  ").is_empty());
+	}
+
+	#[test]
+	fn search_by_id() {
+		let found = ArxivCategoryId::search("CS.lg");
+		assert_eq!(found, vec![ArxivCategoryId::try_new(ArxivArchive::Cs, "LG").unwrap()]);
+	}
+
+	#[test]
+	fn parse_category_suggestions() {
+		let error = ArxivCategoryId::from_str("cs.LGG").unwrap_err();
+		assert_eq!(
+			error.suggestions().first(),
+			ArxivCategoryId::try_new(ArxivArchive::Cs, "LG").as_ref()
+		);
+		assert!(error.to_string().contains("did you mean cs.LG"));
+
+		let error = ArxivCategoryId::from_str("foo.bar").unwrap_err();
+		assert_eq!(error, ArxivCategoryIdError::Unknown(Vec::new()));
+	}
+
+	#[test]
+	fn parse_category_syntax() {
+		assert_eq!(ArxivCategoryId::from_str("cs.LG.AI"), Err(ArxivCategoryIdError::Syntax));
+	}
+
+	#[test]
+	fn test_edit_distance() {
+		assert_eq!(edit_distance("", "abc"), 3);
+		assert_eq!(edit_distance("kitten", "sitting"), 3);
+		assert_eq!(edit_distance("cs.lg", "cs.lg"), 0);
+	}
+
 	#[test]
 	fn group_from_archive() {
 		assert_eq!(ArxivGroup::from(ArxivArchive::AstroPh), ArxivGroup::Physics);
 	}
 
+	#[test]
+	fn group_round_trip() {
+		for archive in ArxivArchive::ALL {
+			let group = ArxivGroup::from(archive);
+			assert_eq!(ArxivGroup::from_str(&group.to_string()), Ok(group));
+		}
+	}
+
+	#[test]
+	fn archive_active_range() {
+		let range = ArxivArchive::Econ.active_range();
+		assert!(!range.contains(2017, 8));
+		assert!(range.contains(2017, 9));
+		assert!(range.contains(2099, 12));
+
+		let closed = ArxivLegacyArchive::ChaoDyn.active_range();
+		assert!(closed.contains(1999, 12));
+		assert!(!closed.contains(2000, 1));
+	}
+
+	#[test]
+	fn legacy_archives() {
+		for archive in ArxivLegacyArchive::ALL {
+			assert_eq!(ArxivLegacyArchive::from_str(&archive.to_string()), Ok(archive));
+			assert!(ArxivArchive::from_str(&archive.to_string()).is_err());
+			let successor = archive.successor();
+			assert!(successor.typed_subject().is_some(), "{}", archive);
+			assert!(successor.archive().active_range().contains(1999, 12), "{}", archive);
+		}
+		assert_eq!(
+			ArxivLegacyArchive::CmpLg.successor(),
+			ArxivCategoryId::from_str("cs.CL").unwrap()
+		);
+		assert_eq!(ArxivLegacyArchive::from_str("nlin"), Err(()));
+	}
+
 	#[test]
 	fn parse_archive() {
 		let archive = ArxivArchive::from_str("astro-ph");
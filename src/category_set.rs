@@ -0,0 +1,249 @@
+use crate::{ArxivCategoryId, ArxivCategoryIdError, ArxivGroup, ArxivLegacyArchive};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The categories of an article, composed of its primary category and the categories
+/// it is cross-listed in, as found in the space-separated `categories` field of arXiv metadata
+///
+/// Categories are kept as given, including deprecated aliases, and duplicate categories are
+/// only kept once. Archives which are no longer part of the taxonomy, as listed by older
+/// articles, are parsed as their successor (see [`ArxivLegacyArchive::successor`]). The
+/// [`ArxivCategorySet::canonical`] set replaces aliases by their canonical category.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivCategoryId, ArxivCategorySet};
+///
+/// let set = ArxivCategorySet::from_str("cs.LG stat.ML math.OC").unwrap();
+/// assert_eq!(set.primary(), ArxivCategoryId::from_str("cs.LG").unwrap());
+/// assert_eq!(set.cross().len(), 2);
+///
+/// // cs.NA is an alias of math.NA
+/// let set = ArxivCategorySet::from_str("cs.NA math.NA").unwrap();
+/// assert_eq!(set.to_string(), "cs.NA math.NA");
+/// assert_eq!(set.canonical().to_string(), "math.NA");
+///
+/// // solv-int was merged into nlin.SI
+/// let set = ArxivCategorySet::from_str("solv-int nlin.SI").unwrap();
+/// assert_eq!(set.to_string(), "nlin.SI");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArxivCategorySet {
+	primary: ArxivCategoryId,
+	cross: Vec<ArxivCategoryId>,
+}
+
+impl ArxivCategorySet {
+	/// Creates a set of categories, removing duplicates.
+	pub fn new<I>(primary: ArxivCategoryId, cross: I) -> Self
+	where
+		I: IntoIterator<Item = ArxivCategoryId>,
+	{
+		let mut set = Self {
+			primary,
+			cross: Vec::new(),
+		};
+		for category in cross {
+			set.insert_cross(category);
+		}
+		set
+	}
+
+	/// The primary category of the article
+	#[must_use]
+	#[inline]
+	pub const fn primary(&self) -> ArxivCategoryId {
+		self.primary
+	}
+
+	/// The categories the article is cross-listed in, in their original order
+	#[must_use]
+	#[inline]
+	pub fn cross(&self) -> &[ArxivCategoryId] {
+		&self.cross
+	}
+
+	/// The primary category followed by the cross-listed categories
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = ArxivCategoryId> + '_ {
+		std::iter::once(self.primary).chain(self.cross.iter().copied())
+	}
+
+	/// The number of categories, including the primary category
+	#[must_use]
+	#[inline]
+	pub fn len(&self) -> usize {
+		1 + self.cross.len()
+	}
+
+	/// Always `false`, since a set always contains its primary category
+	#[must_use]
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		false
+	}
+
+	/// Checks if the category is either the primary category or a cross-listed category,
+	/// where deprecated aliases match their canonical category
+	#[must_use]
+	pub fn contains(&self, category: &ArxivCategoryId) -> bool {
+		let category = category.canonical();
+		self.iter().any(|c| c.canonical() == category)
+	}
+
+	/// Checks if any category, either primary or cross-listed, belongs to the group
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategorySet, ArxivGroup};
+	///
+	/// let mut papers = vec![
+	///     ArxivCategorySet::from_str("cs.LG stat.ML").unwrap(),
+	///     ArxivCategorySet::from_str("math.AP math-ph").unwrap(),
+	/// ];
+	/// papers.retain(|categories| categories.is_in_group(ArxivGroup::Physics));
+	/// assert_eq!(papers.len(), 1);
+	/// ```
+	#[must_use]
+	pub fn is_in_group(&self, group: ArxivGroup) -> bool {
+		self.iter().any(|c| c.is_in_group(group))
+	}
+
+	/// The categories that belong to the group, starting with the primary category
+	pub fn in_group(&self, group: ArxivGroup) -> impl Iterator<Item = ArxivCategoryId> + '_ {
+		self.iter().filter(move |c| c.is_in_group(group))
+	}
+
+	/// Adds a cross-listed category, returning whether or not it was not already in the set.
+	pub fn insert_cross(&mut self, category: ArxivCategoryId) -> bool {
+		let is_new = !self.iter().any(|c| c == category);
+		if is_new {
+			self.cross.push(category);
+		}
+		is_new
+	}
+
+	/// The set with deprecated aliases replaced by their canonical category (see
+	/// [`ArxivCategoryId::canonical`]), keeping categories which become duplicates only once
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategorySet;
+	///
+	/// let set = ArxivCategorySet::from_str("cs.SY eess.SY cs.LG").unwrap();
+	/// assert_eq!(set.canonical().to_string(), "eess.SY cs.LG");
+	/// ```
+	#[must_use]
+	pub fn canonical(&self) -> Self {
+		Self::new(self.primary.canonical(), self.cross.iter().map(ArxivCategoryId::canonical))
+	}
+}
+
+impl Display for ArxivCategorySet {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{}", self.primary)?;
+		for category in &self.cross {
+			write!(f, " {}", category)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for ArxivCategorySet {
+	type Err = ArxivCategoryIdError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut categories = s.split_whitespace().map(parse_category);
+		let primary = categories.next().ok_or(ArxivCategoryIdError::Syntax)??;
+
+		let mut set = Self::new(primary, []);
+		for category in categories {
+			set.insert_cross(category?);
+		}
+
+		Ok(set)
+	}
+}
+
+/// Parses a category, or an archive which is no longer part of the taxonomy as its successor
+fn parse_category(s: &str) -> Result<ArxivCategoryId, ArxivCategoryIdError> {
+	ArxivCategoryId::from_str(s).or_else(|e| {
+		ArxivLegacyArchive::from_str(s)
+			.map(ArxivLegacyArchive::successor)
+			.map_err(|_| e)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn category(s: &str) -> ArxivCategoryId {
+		ArxivCategoryId::from_str(s).unwrap()
+	}
+
+	#[test]
+	fn parse_category_set() {
+		let set = ArxivCategorySet::from_str("  hep-th\tgr-qc  math-ph ").unwrap();
+		assert_eq!(set.primary(), category("hep-th"));
+		assert_eq!(set.cross(), &[category("gr-qc"), category("math-ph")]);
+		assert_eq!(set.to_string(), "hep-th gr-qc math-ph");
+	}
+
+	#[test]
+	fn parse_category_set_keeps_aliases() {
+		let set = ArxivCategorySet::from_str("cs.SY eess.SY cs.LG cs.LG math.MP").unwrap();
+		assert_eq!(set.to_string(), "cs.SY eess.SY cs.LG math.MP");
+		assert_eq!(set.primary(), category("cs.SY"));
+		assert!(set.contains(&category("math-ph")));
+		assert_eq!(set.len(), 4);
+		assert_eq!(ArxivCategorySet::from_str(&set.to_string()), Ok(set.clone()));
+
+		let canonical = set.canonical();
+		assert_eq!(canonical.to_string(), "eess.SY cs.LG math-ph");
+		assert_eq!(canonical.primary(), category("eess.SY"));
+		assert!(canonical.contains(&category("cs.SY")));
+		assert_eq!(canonical.len(), 3);
+	}
+
+	#[test]
+	fn parse_category_set_of_legacy_archives() {
+		for (categories, expected) in [
+			("chao-dyn", "nlin.CD"),
+			("supr-con cond-mat.supr-con", "cond-mat.supr-con"),
+			("alg-geom math.AG", "math.AG"),
+			("cmp-lg", "cs.CL"),
+			("hep-th solv-int", "hep-th nlin.SI"),
+		] {
+			assert_eq!(
+				ArxivCategorySet::from_str(categories).map(|set| set.to_string()),
+				Ok(String::from(expected))
+			);
+		}
+		assert!(ArxivCategorySet::from_str("solv-int.XX").is_err());
+	}
+
+	#[test]
+	fn category_set_in_group() {
+		let set = ArxivCategorySet::from_str("cs.LG stat.ML math.OC cs.AI").unwrap();
+		assert_eq!(
+			set.in_group(ArxivGroup::Cs).collect::<Vec<_>>(),
+			vec![category("cs.LG"), category("cs.AI")]
+		);
+		assert!(set.is_in_group(ArxivGroup::Math));
+		assert!(!set.is_in_group(ArxivGroup::Physics));
+	}
+
+	#[test]
+	fn parse_category_set_invalid() {
+		assert_eq!(ArxivCategorySet::from_str(" "), Err(ArxivCategoryIdError::Syntax));
+		assert!(matches!(
+			ArxivCategorySet::from_str("cs.LG cs.XYZ"),
+			Err(ArxivCategoryIdError::Unknown(_))
+		));
+	}
+}
@@ -0,0 +1,253 @@
+use crate::metadata::collapse_whitespace;
+use crate::{ArxivArticleMetadata, ArxivAuthor, JournalRef};
+
+/// The most authors listed in an APA citation before the list is truncated
+const APA_MAX_AUTHORS: usize = 20;
+
+/// The most authors listed in an IEEE citation before the list is shortened to `et al.`
+const IEEE_MAX_AUTHORS: usize = 6;
+
+/// A style of plain-text citations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CitationStyle {
+	/// The style of the American Psychological Association (7th edition), such as
+	/// `Vaswani, A., & Shazeer, N. (2017). Attention Is All You Need (arXiv:1706.03762). arXiv.`
+	Apa,
+	/// The style of the Institute of Electrical and Electronics Engineers, such as
+	/// `A. Vaswani and N. Shazeer, "Attention Is All You Need," arXiv:1706.03762, 2017.`
+	Ieee,
+}
+
+impl ArxivArticleMetadata {
+	/// A plain-text citation of the article in a citation style, with its identifier and DOI
+	///
+	/// This is a lightweight alternative to a full CSL processor: titles are kept as written,
+	/// and articles with a journal reference are cited with the journal, volume and pages
+	/// found in it. Articles without a DOI of their own are cited with the DOI registered by
+	/// arXiv.org, or with their URL for articles of the old scheme.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(feature = "oai")]
+	/// # {
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivArticleMetadata, ArxivRawMetadata, CitationStyle};
+	///
+	/// let xml = r#"<arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+	///   <id>1706.03762</id>
+	///   <version version="v1"><date>Mon, 12 Jun 2017 17:57:34 GMT</date><size>1102kb</size></version>
+	///   <title>Attention Is All You Need</title>
+	///   <authors>Ashish Vaswani, Noam Shazeer</authors>
+	///   <categories>cs.CL cs.LG</categories>
+	///   <abstract>The dominant sequence transduction models...</abstract>
+	/// </arXivRaw>"#;
+	/// let metadata = ArxivArticleMetadata::from(ArxivRawMetadata::from_str(xml).unwrap());
+	///
+	/// assert_eq!(
+	///     metadata.format_citation(CitationStyle::Apa),
+	///     "Vaswani, A., & Shazeer, N. (2017). Attention Is All You Need (arXiv:1706.03762). \
+	///      arXiv. https://doi.org/10.48550/arXiv.1706.03762"
+	/// );
+	/// assert_eq!(
+	///     metadata.format_citation(CitationStyle::Ieee),
+	///     "A. Vaswani and N. Shazeer, \"Attention Is All You Need,\" arXiv:1706.03762, 2017, \
+	///      doi: 10.48550/arXiv.1706.03762."
+	/// );
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn format_citation(&self, style: CitationStyle) -> String {
+		let authors = self
+			.authors
+			.iter()
+			.map(|name| ArxivAuthor::new(name.as_str()))
+			.collect::<Vec<_>>();
+		let journal_ref = self
+			.journal_ref
+			.as_deref()
+			.map(JournalRef::parse)
+			.filter(JournalRef::is_structured);
+		let year = self.published_year(journal_ref.as_ref());
+		let title = collapse_whitespace(&self.title);
+		let id = self.id.to_bare_string();
+
+		match style {
+			CitationStyle::Apa => {
+				let names = authors.iter().map(apa_name).collect::<Vec<_>>();
+				let venue = match &journal_ref {
+					Some(journal_ref) => journal_parts(journal_ref, "").join(", "),
+					None => String::from("arXiv"),
+				};
+				let link = match self.citation_doi() {
+					Some(doi) => format!("https://doi.org/{}", doi),
+					None => self.id.abs_url(),
+				};
+				format!(
+					"{} ({}). {} (arXiv:{}). {}. {}",
+					apa_list(&names),
+					year,
+					title,
+					id,
+					venue,
+					link
+				)
+			}
+			CitationStyle::Ieee => {
+				let names = authors.iter().map(ieee_name).collect::<Vec<_>>();
+				let mut parts = Vec::new();
+				if let Some(journal_ref) = &journal_ref {
+					parts.extend(journal_parts(journal_ref, "vol. "));
+				}
+				parts.push(format!("arXiv:{}", id));
+				parts.push(year.to_string());
+				let citation =
+					format!("{}, \"{},\" {}", ieee_list(&names), title, parts.join(", "));
+				match self.citation_doi() {
+					Some(doi) => format!("{}, doi: {}.", citation, doi),
+					None => format!("{}. [Online]. Available: {}", citation, self.id.abs_url()),
+				}
+			}
+		}
+	}
+}
+
+/// The journal, volume and pages of a journal reference, with the prefix of the volume
+fn journal_parts(journal_ref: &JournalRef, volume_prefix: &str) -> Vec<String> {
+	let mut parts = Vec::new();
+	parts.extend(journal_ref.journal.clone());
+	parts.extend(
+		journal_ref
+			.volume
+			.as_ref()
+			.map(|volume| format!("{}{}", volume_prefix, volume)),
+	);
+	parts.extend(journal_ref.pages.as_ref().map(|pages| {
+		let pages = pages.replace('-', "\u{2013}");
+		match (volume_prefix.is_empty(), pages.contains('\u{2013}')) {
+			(true, _) => pages,
+			(false, true) => format!("pp. {}", pages),
+			(false, false) => format!("p. {}", pages),
+		}
+	}));
+	parts
+}
+
+/// The name of an author in APA citations, such as `Vaswani, A.`
+fn apa_name(author: &ArxivAuthor) -> String {
+	let mut name = String::from(author.keyname());
+	if author.is_collaboration() {
+		return name;
+	}
+	for part in [author.initials().as_deref(), author.suffix()]
+		.into_iter()
+		.flatten()
+	{
+		name.push_str(", ");
+		name.push_str(part);
+	}
+	name
+}
+
+/// The name of an author in IEEE citations, such as `A. Vaswani`
+fn ieee_name(author: &ArxivAuthor) -> String {
+	let initials = match author.is_collaboration() {
+		true => None,
+		false => author.initials(),
+	};
+	let mut name = match initials {
+		Some(initials) => format!("{} {}", initials, author.keyname()),
+		None => String::from(author.keyname()),
+	};
+	if let Some(suffix) = author.suffix() {
+		name.push_str(", ");
+		name.push_str(suffix);
+	}
+	name
+}
+
+/// Lists the authors of an APA citation, such as `A, B, & C`, truncating lists of more than
+/// 20 authors to the first 19 and the last
+fn apa_list(names: &[String]) -> String {
+	match names {
+		[] => String::new(),
+		[name] => name.clone(),
+		[first, second] => format!("{}, & {}", first, second),
+		[rest @ .., last] if names.len() <= APA_MAX_AUTHORS => {
+			format!("{}, & {}", rest.join(", "), last)
+		}
+		[.., last] => format!("{}, . . . {}", names[..APA_MAX_AUTHORS - 1].join(", "), last),
+	}
+}
+
+/// Lists the authors of an IEEE citation, such as `A, B, and C`, shortening lists of more
+/// than 6 authors to the first and `et al.`
+fn ieee_list(names: &[String]) -> String {
+	match names {
+		[] => String::new(),
+		[name] => name.clone(),
+		[first, second] => format!("{} and {}", first, second),
+		[rest @ .., last] if names.len() <= IEEE_MAX_AUTHORS => {
+			format!("{}, and {}", rest.join(", "), last)
+		}
+		[first, ..] => format!("{} et al.", first),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn format_published_citations() {
+		let metadata = ArxivArticleMetadata::fixture();
+		assert_eq!(
+			metadata.format_citation(CitationStyle::Apa),
+			"Maldacena, J. M. (1998). The Large N Limit of Superconformal Field Theories \
+			 (arXiv:hep-th/9711200). Adv.Theor.Math.Phys., 2, 231\u{2013}252. \
+			 https://doi.org/10.4310/ATMP.1998.v2.n2.a1"
+		);
+		assert_eq!(
+			metadata.format_citation(CitationStyle::Ieee),
+			"J. M. Maldacena, \"The Large N Limit of Superconformal Field Theories,\" \
+			 Adv.Theor.Math.Phys., vol. 2, pp. 231\u{2013}252, arXiv:hep-th/9711200, 1998, \
+			 doi: 10.4310/ATMP.1998.v2.n2.a1."
+		);
+	}
+
+	#[test]
+	fn format_preprint_citations() {
+		let mut metadata = ArxivArticleMetadata::fixture();
+		metadata.journal_ref = None;
+		metadata.doi = None;
+		metadata.authors = vec![
+			String::from("Martin Luther King Jr."),
+			String::from("ATLAS Collaboration"),
+			String::from("Plato"),
+		];
+		assert_eq!(
+			metadata.format_citation(CitationStyle::Apa),
+			"King, M. L., Jr., ATLAS Collaboration, & Plato (1997). The Large N Limit of \
+			 Superconformal Field Theories (arXiv:hep-th/9711200). arXiv. \
+			 https://arxiv.org/abs/hep-th/9711200"
+		);
+		assert_eq!(
+			metadata.format_citation(CitationStyle::Ieee),
+			"M. L. King, Jr., ATLAS Collaboration, and Plato, \"The Large N Limit of \
+			 Superconformal Field Theories,\" arXiv:hep-th/9711200, 1997. [Online]. Available: \
+			 https://arxiv.org/abs/hep-th/9711200"
+		);
+	}
+
+	#[test]
+	fn list_authors() {
+		let names = (1..=25).map(|n| n.to_string()).collect::<Vec<_>>();
+		assert_eq!(apa_list(&names[..3]), "1, 2, & 3");
+		assert_eq!(
+			apa_list(&names),
+			"1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, . . . 25"
+		);
+		assert_eq!(ieee_list(&names[..6]), "1, 2, 3, 4, 5, and 6");
+		assert_eq!(ieee_list(&names[..7]), "1 et al.");
+		assert_eq!(ieee_list(&[]), "");
+	}
+}
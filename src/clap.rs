@@ -0,0 +1,128 @@
+//! Support for parsing and validating command-line arguments with [`clap`](::clap).
+//!
+//! Identifiers and categories implement [`ValueParserFactory`], so that they are picked
+//! automatically by `clap::value_parser!` and by arguments of the derive API.
+//!
+//! # Examples
+//! ```
+//! use arxiv::ArxivId;
+//! use clap::{value_parser, Arg, Command};
+//!
+//! let command = Command::new("fetch").arg(Arg::new("id").value_parser(value_parser!(ArxivId)));
+//! let matches = command.clone().try_get_matches_from(["fetch", "2101.00001v1"]).unwrap();
+//! assert_eq!(matches.get_one::<ArxivId>("id").unwrap().to_string(), "arXiv:2101.00001v1");
+//!
+//! let error = command.try_get_matches_from(["fetch", "2113.00001"]).unwrap_err();
+//! assert!(error.to_string().contains("A valid month must be between 1 and 12."));
+//! ```
+
+use crate::{ArxivCategoryId, ArxivId};
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::{Error, ErrorKind};
+use ::clap::{Arg, Command};
+use std::ffi::OsStr;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Creates an error for an invalid value of an argument, along with the reason.
+fn invalid_value(cmd: &Command, arg: Option<&Arg>, value: &OsStr, reason: impl Display) -> Error {
+	let arg = arg.map_or_else(|| String::from("..."), |arg| arg.to_string());
+	Error::raw(
+		ErrorKind::ValueValidation,
+		format!("invalid value '{}' for '{}': {}\n", value.to_string_lossy(), arg, reason),
+	)
+	.with_cmd(cmd)
+}
+
+/// The value parser of identifiers, which accepts identifiers with or without the `arXiv:`
+/// prefix, such as `arXiv:2101.00001v1` or `2101.00001v1`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArxivIdValueParser;
+
+impl TypedValueParser for ArxivIdValueParser {
+	type Value = ArxivId;
+
+	fn parse_ref(
+		&self,
+		cmd: &Command,
+		arg: Option<&Arg>,
+		value: &OsStr,
+	) -> Result<Self::Value, Error> {
+		let s = value
+			.to_str()
+			.ok_or_else(|| invalid_value(cmd, arg, value, "The value is not valid UTF-8."))?;
+		ArxivId::from_bare_str(s.trim()).map_err(|e| invalid_value(cmd, arg, value, e))
+	}
+}
+
+impl ValueParserFactory for ArxivId {
+	type Parser = ArxivIdValueParser;
+
+	fn value_parser() -> Self::Parser {
+		ArxivIdValueParser
+	}
+}
+
+/// The value parser of categories, which suggests similar categories for unknown categories
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArxivCategoryIdValueParser;
+
+impl TypedValueParser for ArxivCategoryIdValueParser {
+	type Value = ArxivCategoryId;
+
+	fn parse_ref(
+		&self,
+		cmd: &Command,
+		arg: Option<&Arg>,
+		value: &OsStr,
+	) -> Result<Self::Value, Error> {
+		let s = value
+			.to_str()
+			.ok_or_else(|| invalid_value(cmd, arg, value, "The value is not valid UTF-8."))?;
+		ArxivCategoryId::from_str(s.trim()).map_err(|e| invalid_value(cmd, arg, value, e))
+	}
+}
+
+impl ValueParserFactory for ArxivCategoryId {
+	type Parser = ArxivCategoryIdValueParser;
+
+	fn value_parser() -> Self::Parser {
+		ArxivCategoryIdValueParser
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::clap::value_parser;
+
+	fn command() -> Command {
+		Command::new("test")
+			.arg(Arg::new("id").value_parser(value_parser!(ArxivId)))
+			.arg(Arg::new("category").value_parser(value_parser!(ArxivCategoryId)))
+	}
+
+	#[test]
+	fn parse_arguments() {
+		let matches = command()
+			.try_get_matches_from(["test", "arXiv:2101.00001", "cs.LG"])
+			.unwrap();
+		assert_eq!(
+			matches.get_one::<ArxivId>("id"),
+			ArxivId::from_str("arXiv:2101.00001").ok().as_ref()
+		);
+		assert_eq!(
+			matches.get_one::<ArxivCategoryId>("category"),
+			ArxivCategoryId::from_str("cs.LG").ok().as_ref()
+		);
+	}
+
+	#[test]
+	fn parse_invalid_category() {
+		let error = command()
+			.try_get_matches_from(["test", "2101.00001", "cs.LGG"])
+			.unwrap_err();
+		assert_eq!(error.kind(), ErrorKind::ValueValidation);
+		assert!(error.to_string().contains("did you mean cs.LG"));
+	}
+}
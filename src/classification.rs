@@ -0,0 +1,399 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// An error that can occur when parsing classification codes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassificationError {
+	/// The string does not contain any classification codes
+	Empty,
+	/// The token is not a valid classification code
+	InvalidCode(String),
+}
+
+impl Error for ClassificationError {}
+
+impl Display for ClassificationError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Empty => write!(f, "There are no classification codes."),
+			Self::InvalidCode(c) => write!(f, "\"{}\" is not a valid classification code.", c),
+		}
+	}
+}
+
+/// A code of the [Mathematics Subject Classification][msc], such as `14J60` or `14-XX`
+///
+/// A code is composed of a 2-digit top-level class, a letter (or `-`) for the second level,
+/// and a 2-digit third level (or `xx` when the code is not fully specified).
+///
+/// [msc]: https://zbmath.org/classification/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MscCode(String);
+
+impl MscCode {
+	/// The code as a string, such as `14J60`
+	#[must_use]
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// The top-level class of the code, such as `14` (Algebraic geometry) for `14J60`
+	#[must_use]
+	pub fn top_level(&self) -> u8 {
+		// the first two characters are validated as ASCII digits
+		self.0[0..2].parse().unwrap_or_default()
+	}
+}
+
+impl Display for MscCode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.0)
+	}
+}
+
+impl FromStr for MscCode {
+	type Err = ClassificationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let bytes = s.as_bytes();
+		let is_valid = bytes.len() == 5
+			&& bytes[0..2].iter().all(u8::is_ascii_digit)
+			&& (bytes[2].is_ascii_alphabetic() || bytes[2] == b'-')
+			&& (bytes[3..5].iter().all(u8::is_ascii_digit)
+				|| bytes[3..5].eq_ignore_ascii_case(b"xx"));
+
+		match is_valid {
+			true => {
+				let mut code = s.to_ascii_uppercase();
+				if bytes[3..5].eq_ignore_ascii_case(b"xx") {
+					// the canonical form uses a lowercase "xx" below a letter, and "XX" below "-"
+					code.replace_range(3..5, if bytes[2] == b'-' { "XX" } else { "xx" });
+				}
+				Ok(Self(code))
+			}
+			false => Err(ClassificationError::InvalidCode(String::from(s))),
+		}
+	}
+}
+
+/// Whether a code of the Mathematics Subject Classification is primary or secondary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MscRole {
+	Primary,
+	Secondary,
+	/// The role of the code was not specified
+	Unspecified,
+}
+
+/// The codes of the Mathematics Subject Classification of an article, as found in the
+/// `msc-class` metadata field of arXiv, such as `14J60 (Primary), 32Q55 (Secondary)`
+///
+/// Roles are recognized both after the codes they apply to, in parentheses
+/// (`14J60, 14D20 (Primary)`), and before them (`Primary 14J60; Secondary 32Q55`).
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{MscClass, MscRole};
+///
+/// let msc = MscClass::from_str("14J60 (Primary), 32Q55, 14D20 (Secondary)").unwrap();
+/// assert_eq!(msc.primary().map(|c| c.as_str()).collect::<Vec<_>>(), vec!["14J60"]);
+/// assert_eq!(msc.secondary().count(), 2);
+/// assert_eq!(msc.to_string(), "14J60 (Primary), 32Q55, 14D20 (Secondary)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MscClass {
+	codes: Vec<(MscCode, MscRole)>,
+}
+
+impl MscClass {
+	/// Every code along with its role, in the original order
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&MscCode, MscRole)> {
+		self.codes.iter().map(|(code, role)| (code, *role))
+	}
+
+	/// The primary codes
+	#[inline]
+	pub fn primary(&self) -> impl Iterator<Item = &MscCode> {
+		self.with_role(MscRole::Primary)
+	}
+
+	/// The secondary codes
+	#[inline]
+	pub fn secondary(&self) -> impl Iterator<Item = &MscCode> {
+		self.with_role(MscRole::Secondary)
+	}
+
+	fn with_role(&self, role: MscRole) -> impl Iterator<Item = &MscCode> {
+		self.codes
+			.iter()
+			.filter(move |(_, r)| *r == role)
+			.map(|(code, _)| code)
+	}
+}
+
+impl Display for MscClass {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		for (i, (code, role)) in self.codes.iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "{}", code)?;
+
+			// the role is written once, after the last code of each run of the same role
+			let next_role = self.codes.get(i + 1).map(|(_, r)| *r);
+			match role {
+				MscRole::Primary if next_role != Some(*role) => f.write_str(" (Primary)")?,
+				MscRole::Secondary if next_role != Some(*role) => f.write_str(" (Secondary)")?,
+				_ => (),
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for MscClass {
+	type Err = ClassificationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut codes: Vec<(MscCode, MscRole)> = Vec::new();
+		let mut leading_role = MscRole::Unspecified;
+		let mut in_parens = false;
+
+		for token in tokenize(s) {
+			match token {
+				"(" => in_parens = true,
+				")" => in_parens = false,
+				"," | ";" => (),
+				_ if token.eq_ignore_ascii_case("and") => (),
+				_ => match parse_role(token) {
+					// a role in parentheses applies to the preceding codes without a role
+					Some(role) if in_parens => codes
+						.iter_mut()
+						.rev()
+						.take_while(|(_, r)| *r == MscRole::Unspecified)
+						.for_each(|(_, r)| *r = role),
+					Some(role) => leading_role = role,
+					None => codes.push((MscCode::from_str(token)?, leading_role)),
+				},
+			}
+		}
+
+		match codes.is_empty() {
+			true => Err(ClassificationError::Empty),
+			false => Ok(Self { codes }),
+		}
+	}
+}
+
+/// A code of the [1998 ACM Computing Classification System][acm], such as `F.2.2` or `I.2`
+///
+/// A code is composed of a top-level letter from `A` to `K`, followed by up to 2 levels, each
+/// of which is either a number or `m` (for the miscellaneous subtree).
+///
+/// [acm]: https://www.acm.org/publications/computing-classification-system/1998
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AcmCode(String);
+
+impl AcmCode {
+	/// The maximum number of levels below the top-level letter
+	pub const MAX_DEPTH: usize = 2;
+
+	/// The code as a string, such as `F.2.2`
+	#[must_use]
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// The top-level letter of the code, such as `F` (Theory of Computation) for `F.2.2`
+	#[must_use]
+	pub fn top_level(&self) -> char {
+		// the first character is validated as an ASCII letter
+		char::from(self.0.as_bytes()[0])
+	}
+
+	/// The number of levels below the top-level letter, such as 2 for `F.2.2`
+	#[must_use]
+	pub fn depth(&self) -> usize {
+		self.0.matches('.').count()
+	}
+}
+
+impl Display for AcmCode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.0)
+	}
+}
+
+impl FromStr for AcmCode {
+	type Err = ClassificationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		// some codes are written with a trailing dot, such as `F.2.2.`
+		let mut parts = s.strip_suffix('.').unwrap_or(s).split('.');
+		let top_level = parts.next().unwrap_or_default();
+		let levels: Vec<&str> = parts.collect();
+
+		let is_valid = matches!(top_level.as_bytes(), [b'A'..=b'K' | b'a'..=b'k'])
+			&& levels.len() <= Self::MAX_DEPTH
+			&& levels.iter().all(|level| {
+				level.eq_ignore_ascii_case("m")
+					|| (!level.is_empty() && level.bytes().all(|b| b.is_ascii_digit()))
+			});
+
+		match is_valid {
+			true => {
+				let mut code = top_level.to_ascii_uppercase();
+				for level in levels {
+					code.push('.');
+					code.push_str(&level.to_ascii_lowercase());
+				}
+				Ok(Self(code))
+			}
+			false => Err(ClassificationError::InvalidCode(String::from(s))),
+		}
+	}
+}
+
+/// The codes of the ACM Computing Classification System of an article, as found in the
+/// `acm-class` metadata field of arXiv, such as `F.2.2; I.2.7`
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::AcmClass;
+///
+/// let acm = AcmClass::from_str("F.2.2, i.2.7").unwrap();
+/// assert_eq!(acm.iter().map(|c| c.as_str()).collect::<Vec<_>>(), vec!["F.2.2", "I.2.7"]);
+/// assert_eq!(acm.to_string(), "F.2.2; I.2.7");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AcmClass {
+	codes: Vec<AcmCode>,
+}
+
+impl AcmClass {
+	/// Every code, in the original order
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = &AcmCode> {
+		self.codes.iter()
+	}
+}
+
+impl Display for AcmClass {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		for (i, code) in self.codes.iter().enumerate() {
+			if i > 0 {
+				f.write_str("; ")?;
+			}
+			write!(f, "{}", code)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for AcmClass {
+	type Err = ClassificationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let codes = tokenize(s)
+			.filter(|token| !matches!(*token, "," | ";"))
+			.map(AcmCode::from_str)
+			.collect::<Result<Vec<_>, _>>()?;
+
+		match codes.is_empty() {
+			true => Err(ClassificationError::Empty),
+			false => Ok(Self { codes }),
+		}
+	}
+}
+
+fn parse_role(token: &str) -> Option<MscRole> {
+	let token = token.trim_end_matches(':');
+	if token.eq_ignore_ascii_case("primary") {
+		Some(MscRole::Primary)
+	} else if token.eq_ignore_ascii_case("secondary") {
+		Some(MscRole::Secondary)
+	} else {
+		None
+	}
+}
+
+/// Splits a classification field into words and punctuation (parentheses,
+/// commas and semicolons), skipping whitespace
+fn tokenize(s: &str) -> impl Iterator<Item = &str> {
+	s.split_inclusive(|c: char| c.is_whitespace() || "(),;".contains(c))
+		.flat_map(|part| {
+			let (word, delim) = part.split_at(
+				part.char_indices()
+					.last()
+					.filter(|(_, c)| c.is_whitespace() || "(),;".contains(*c))
+					.map_or(part.len(), |(i, _)| i),
+			);
+			[word, delim.trim()]
+		})
+		.filter(|token| !token.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_msc_code() {
+		assert_eq!(MscCode::from_str("14J60").map(|c| c.top_level()), Ok(14));
+		assert_eq!(MscCode::from_str("14jXX").unwrap().as_str(), "14Jxx");
+		assert_eq!(MscCode::from_str("14-xx").unwrap().as_str(), "14-XX");
+		assert!(MscCode::from_str("1J60").is_err());
+		assert!(MscCode::from_str("14J6").is_err());
+	}
+
+	#[test]
+	fn parse_msc_class_leading_roles() {
+		let msc = MscClass::from_str("Primary: 14J60; Secondary: 32Q55 and 14D20").unwrap();
+		assert_eq!(msc.primary().count(), 1);
+		assert_eq!(msc.secondary().count(), 2);
+	}
+
+	#[test]
+	fn parse_msc_class_without_roles() {
+		let msc = MscClass::from_str("05C50,15A18").unwrap();
+		assert!(msc.iter().all(|(_, role)| role == MscRole::Unspecified));
+		assert_eq!(msc.to_string(), "05C50, 15A18");
+	}
+
+	#[test]
+	fn parse_msc_class_invalid() {
+		assert_eq!(MscClass::from_str(" "), Err(ClassificationError::Empty));
+		assert_eq!(
+			MscClass::from_str("14J60 (Primary), foo"),
+			Err(ClassificationError::InvalidCode(String::from("foo")))
+		);
+	}
+
+	#[test]
+	fn parse_acm_code() {
+		let code = AcmCode::from_str("f.2.M").unwrap();
+		assert_eq!(code.as_str(), "F.2.m");
+		assert_eq!((code.top_level(), code.depth()), ('F', 2));
+		assert_eq!(AcmCode::from_str("I.2.7.").unwrap().as_str(), "I.2.7");
+		assert_eq!(AcmCode::from_str("H").map(|c| c.depth()), Ok(0));
+		assert!(AcmCode::from_str("L.1").is_err());
+		assert!(AcmCode::from_str("F.2.2.1").is_err());
+		assert!(AcmCode::from_str("F..2").is_err());
+	}
+
+	#[test]
+	fn parse_acm_class() {
+		let acm = AcmClass::from_str("F.2.2; I.2.7 H.3.3").unwrap();
+		assert_eq!(acm.iter().count(), 3);
+		assert_eq!(acm.to_string(), "F.2.2; I.2.7; H.3.3");
+		assert_eq!(AcmClass::from_str(";"), Err(ClassificationError::Empty));
+	}
+}
@@ -0,0 +1,1093 @@
+//! An asynchronous client of the [export API][api] of arXiv.org.
+//!
+//! [api]: https://info.arxiv.org/help/api/user-manual.html
+
+use crate::atom::{parse_feed, AtomFeed};
+use crate::harvest::{get_record_params, parse_oai_http_response};
+use crate::links::ARXIV_ORIGIN;
+use crate::query::encode_params;
+use crate::retry::{parse_retry_after, RateLimiter};
+#[cfg(feature = "reqwest")]
+use crate::ReqwestTransport;
+use crate::{
+	ArticleVersion, ArxivArticle, ArxivArticleMetadata, ArxivCategoryId, ArxivCategoryIdError,
+	ArxivFeedEntry, ArxivId, ArxivIdError, ArxivIdentifier, AtomError, DataCiteError,
+	DataCiteMismatch, DataCiteRecord, HttpResponse, HttpTransport, OaiError, OaiMetadataFormat,
+	Query, RetryPolicy, RssError, TransportError, VersionHistory, ARXIV_OAI_URL, ARXIV_RSS_URL,
+	DATACITE_API_URL,
+};
+use ::futures_util::stream::{self, Stream, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// The endpoint of the export API of arXiv.org
+pub const ARXIV_API_URL: &str = "http://export.arxiv.org/api/query";
+
+/// The delay between consecutive requests to the export API recommended by arXiv.org
+///
+/// See also: [Terms of use of the API][terms]
+///
+/// [terms]: https://info.arxiv.org/help/api/tou.html
+pub const ARXIV_API_DELAY: Duration = Duration::from_secs(3);
+
+/// The number of results per request when paging through results, unless the query sets it
+const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// An error that can occur when requesting the export API or the other interfaces of arXiv.org
+///
+/// Failures to reach arXiv.org ([`Transport`](Self::Transport) and
+/// [`Status`](Self::Status)) are distinguished from malformed responses
+/// ([`Atom`](Self::Atom), [`Oai`](Self::Oai), [`Rss`](Self::Rss) and
+/// [`DataCite`](Self::DataCite)), and from identifiers
+/// and categories of the responses rejected by the parsers of the crate
+/// ([`InvalidId`](Self::InvalidId) and [`InvalidCategory`](Self::InvalidCategory)).
+#[derive(Debug)]
+pub enum ArxivApiError {
+	/// The request could not be sent, or its response could not be received
+	Transport(TransportError),
+	/// The API responded with an unsuccessful HTTP status code
+	Status(u16),
+	/// The response is not a valid Atom feed of the API, or reports an error
+	Atom(AtomError),
+	/// The response is not a valid response of the OAI-PMH interface, or reports an error
+	Oai(OaiError),
+	/// The response is not a valid RSS feed of arXiv.org
+	Rss(RssError),
+	/// The response is not a valid DataCite record
+	DataCite(DataCiteError),
+	/// The response has an invalid arXiv identifier
+	InvalidId(ArxivIdError),
+	/// The response has an invalid arXiv category
+	InvalidCategory(ArxivCategoryIdError),
+	/// The API does not know an article with the identifier
+	NotFound(ArxivId),
+	/// A downloaded file could not be written
+	Io(io::Error),
+}
+
+impl ArxivApiError {
+	/// Whether the request may succeed if it is sent again later, which is the case of
+	/// transport errors, and of the `429 Too Many Requests` and `5xx` status codes
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivApiError;
+	///
+	/// assert!(ArxivApiError::Status(503).is_retryable());
+	/// assert!(!ArxivApiError::Status(400).is_retryable());
+	/// ```
+	#[must_use]
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			Self::Transport(_) => true,
+			Self::Status(status) => *status == 429 || (500..600).contains(status),
+			_ => false,
+		}
+	}
+}
+
+impl Error for ArxivApiError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Transport(e) => Some(e.as_ref()),
+			Self::Atom(e) => Some(e),
+			Self::Oai(e) => Some(e),
+			Self::Rss(e) => Some(e),
+			Self::DataCite(e) => Some(e),
+			Self::InvalidId(e) => Some(e),
+			Self::InvalidCategory(e) => Some(e),
+			Self::Io(e) => Some(e),
+			Self::Status(_) | Self::NotFound(_) => None,
+		}
+	}
+}
+
+impl Display for ArxivApiError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Transport(e) => write!(f, "The request to the arXiv API failed: {}", e),
+			Self::Status(status) => {
+				write!(f, "The arXiv API responded with the HTTP status {}.", status)
+			}
+			Self::Atom(e) => e.fmt(f),
+			Self::Oai(e) => e.fmt(f),
+			Self::Rss(e) => e.fmt(f),
+			Self::DataCite(e) => e.fmt(f),
+			Self::InvalidId(e) => {
+				write!(f, "The arXiv API responded with an invalid identifier: {}", e)
+			}
+			Self::InvalidCategory(e) => {
+				write!(f, "The arXiv API responded with an invalid category: {}", e)
+			}
+			Self::NotFound(id) => {
+				write!(f, "The arXiv API has no article with the identifier {}.", id)
+			}
+			Self::Io(e) => write!(f, "The downloaded file could not be written: {}", e),
+		}
+	}
+}
+
+impl From<AtomError> for ArxivApiError {
+	fn from(e: AtomError) -> Self {
+		match e {
+			AtomError::InvalidId(e) => Self::InvalidId(e),
+			AtomError::InvalidCategory(e) => Self::InvalidCategory(e),
+			e => Self::Atom(e),
+		}
+	}
+}
+
+impl From<OaiError> for ArxivApiError {
+	fn from(e: OaiError) -> Self {
+		match e {
+			OaiError::InvalidId(e) => Self::InvalidId(e),
+			OaiError::InvalidCategory(e) => Self::InvalidCategory(e),
+			e => Self::Oai(e),
+		}
+	}
+}
+
+impl From<RssError> for ArxivApiError {
+	fn from(e: RssError) -> Self {
+		match e {
+			RssError::InvalidId(e) => Self::InvalidId(e),
+			e => Self::Rss(e),
+		}
+	}
+}
+
+impl From<DataCiteError> for ArxivApiError {
+	fn from(e: DataCiteError) -> Self {
+		Self::DataCite(e)
+	}
+}
+
+impl From<io::Error> for ArxivApiError {
+	fn from(e: io::Error) -> Self {
+		Self::Io(e)
+	}
+}
+
+/// An asynchronous client of the export API of arXiv.org, sending its requests with an
+/// [`HttpTransport`]
+///
+/// Requests are spaced by the rate limit of the client, and waited for with the timers of
+/// [`tokio`](::tokio), which must be enabled in the runtime.
+///
+/// # Examples
+/// ```no_run
+/// use std::str::FromStr;
+/// use arxiv::{ArxivClient, ArxivApiError, ArxivId, HttpTransport, Query};
+///
+/// async fn run<T: HttpTransport>(client: &ArxivClient<T>) -> Result<(), ArxivApiError> {
+///     let id = ArxivId::from_str("arXiv:1706.03762").unwrap();
+///     let article = client.fetch_metadata(&id).await?;
+///     println!("{}", article.title);
+///
+///     let query = Query::new().title("dropout").author("Hinton");
+///     for article in client.search(&query).await? {
+///         println!("{}: {}", article.id, article.title);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArxivClient<T> {
+	transport: T,
+	api_url: String,
+	rss_url: String,
+	oai_url: String,
+	download_url: String,
+	datacite_url: String,
+	limiter: Arc<RateLimiter>,
+	retry: RetryPolicy,
+	batch_size: usize,
+	concurrency: usize,
+}
+
+#[cfg(feature = "reqwest")]
+impl ArxivClient<ReqwestTransport> {
+	/// Creates a client of the export API with a default HTTP client of [`reqwest`](::reqwest)
+	#[must_use]
+	pub fn new() -> Self {
+		Self::with_transport(ReqwestTransport::new())
+	}
+
+	/// Creates a client of the export API with an existing HTTP client of
+	/// [`reqwest`](::reqwest), such as one configured with a proxy or a custom user agent
+	#[must_use]
+	pub fn with_http_client(http: ::reqwest::Client) -> Self {
+		Self::with_transport(ReqwestTransport::from(http))
+	}
+}
+
+#[cfg(feature = "reqwest")]
+impl Default for ArxivClient<ReqwestTransport> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: HttpTransport> ArxivClient<T> {
+	/// Creates a client of the export API sending its requests with the transport
+	#[must_use]
+	pub fn with_transport(transport: T) -> Self {
+		Self {
+			transport,
+			api_url: String::from(ARXIV_API_URL),
+			rss_url: String::from(ARXIV_RSS_URL),
+			oai_url: String::from(ARXIV_OAI_URL),
+			download_url: String::from(ARXIV_ORIGIN),
+			datacite_url: String::from(DATACITE_API_URL),
+			limiter: Arc::new(RateLimiter::new(ARXIV_API_DELAY)),
+			retry: RetryPolicy::default(),
+			batch_size: DEFAULT_PAGE_SIZE,
+			concurrency: 1,
+		}
+	}
+
+	/// Sets the endpoint of the export API, such as the one of a mirror
+	#[must_use]
+	pub fn with_api_url(mut self, url: impl Into<String>) -> Self {
+		self.api_url = url.into();
+		self
+	}
+
+	/// Sets the base URL of the RSS feeds of the categories, such as the one of a mirror
+	#[must_use]
+	pub fn with_rss_url(mut self, url: impl Into<String>) -> Self {
+		self.rss_url = url.into();
+		self
+	}
+
+	/// Sets the endpoint of the OAI-PMH interface, such as the one of a mirror
+	#[must_use]
+	pub fn with_oai_url(mut self, url: impl Into<String>) -> Self {
+		self.oai_url = url.into();
+		self
+	}
+
+	/// Sets the origin of the PDFs and sources of the articles, which defaults to
+	/// `https://arxiv.org`, such as `https://export.arxiv.org` for bulk downloads
+	#[must_use]
+	pub fn with_download_url(mut self, url: impl Into<String>) -> Self {
+		self.download_url = url.into();
+		self
+	}
+
+	/// Sets the endpoint of the DataCite records of DOIs, which defaults to
+	/// [`DATACITE_API_URL`], and to which DOIs are appended
+	#[must_use]
+	pub fn with_datacite_url(mut self, url: impl Into<String>) -> Self {
+		self.datacite_url = url.into();
+		self
+	}
+
+	/// Sets the minimum interval between requests, which defaults to [`ARXIV_API_DELAY`]
+	/// as recommended by arXiv.org, and is shared by the clones of the client
+	#[must_use]
+	pub fn with_rate_limit(mut self, interval: Duration) -> Self {
+		self.limiter = Arc::new(RateLimiter::new(interval));
+		self
+	}
+
+	/// Sets how requests rejected by the export API are retried
+	#[must_use]
+	pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+		self.retry = policy;
+		self
+	}
+
+	/// Sets the number of identifiers requested together by [`ArxivClient::fetch_many`], which
+	/// defaults to 100, and is at most [`Query::MAX_RESULTS_PER_REQUEST`]
+	#[must_use]
+	pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+		self.batch_size = batch_size.clamp(1, Query::MAX_RESULTS_PER_REQUEST);
+		self
+	}
+
+	/// Sets the number of requests of [`ArxivClient::fetch_many`] awaited concurrently, which
+	/// defaults to a single request at a time as recommended by arXiv.org
+	///
+	/// Concurrent requests are still spaced by the rate limit of the client, so that a slow
+	/// response only delays the requests following it.
+	#[must_use]
+	pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+		self.concurrency = concurrency.max(1);
+		self
+	}
+
+	/// The endpoint of the export API
+	#[must_use]
+	#[inline]
+	pub fn api_url(&self) -> &str {
+		&self.api_url
+	}
+
+	/// The base URL of the RSS feeds of the categories
+	#[must_use]
+	#[inline]
+	pub fn rss_url(&self) -> &str {
+		&self.rss_url
+	}
+
+	/// The endpoint of the OAI-PMH interface
+	#[must_use]
+	#[inline]
+	pub fn oai_url(&self) -> &str {
+		&self.oai_url
+	}
+
+	/// The origin of the PDFs and sources of the articles
+	#[must_use]
+	#[inline]
+	pub fn download_url(&self) -> &str {
+		&self.download_url
+	}
+
+	/// The endpoint of the DataCite records of DOIs
+	#[must_use]
+	#[inline]
+	pub fn datacite_url(&self) -> &str {
+		&self.datacite_url
+	}
+
+	/// The number of identifiers requested together by [`ArxivClient::fetch_many`]
+	#[must_use]
+	#[inline]
+	pub fn batch_size(&self) -> usize {
+		self.batch_size
+	}
+
+	/// The number of requests of [`ArxivClient::fetch_many`] awaited concurrently
+	#[must_use]
+	#[inline]
+	pub fn concurrency(&self) -> usize {
+		self.concurrency
+	}
+
+	/// The transport sending the requests of the client
+	#[must_use]
+	#[inline]
+	pub fn transport(&self) -> &T {
+		&self.transport
+	}
+
+	/// Fetches the metadata of an article, of the version of the identifier if it has one,
+	/// or of the latest version otherwise.
+	pub async fn fetch_metadata(&self, id: &ArxivId) -> Result<ArxivArticle, ArxivApiError> {
+		let feed = self.fetch_feed(&[("id_list", id.to_bare_string())]).await?;
+		feed.articles
+			.into_iter()
+			.next()
+			.ok_or_else(|| ArxivApiError::NotFound(id.clone()))
+	}
+
+	/// Fetches the metadata of many articles, returning them keyed by the identifiers they
+	/// were fetched with, of the version of each identifier if it has one, or of the latest
+	/// version otherwise.
+	///
+	/// The identifiers are requested in batches of the `id_list` parameter of the export API,
+	/// of the batch size of the client, with as many requests awaited at once as the
+	/// concurrency of the client, and all of them spaced by its rate limit. Identifiers
+	/// unknown to the API are missing from the results, and the first failed request fails
+	/// the whole fetch.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivClient, ArxivApiError, ArxivId, HttpTransport};
+	///
+	/// async fn run<T: HttpTransport>(client: &ArxivClient<T>) -> Result<(), ArxivApiError> {
+	///     let ids = ["arXiv:1706.03762", "arXiv:1207.0580v1"]
+	///         .into_iter()
+	///         .map(|id| ArxivId::from_str(id).unwrap())
+	///         .collect::<Vec<_>>();
+	///     let articles = client.fetch_many(&ids).await?;
+	///     for (id, article) in &articles {
+	///         println!("{}: {}", id, article.title);
+	///     }
+	///     Ok(())
+	/// }
+	/// ```
+	pub async fn fetch_many(
+		&self,
+		ids: &[ArxivId],
+	) -> Result<HashMap<ArxivId, ArxivArticle>, ArxivApiError> {
+		let mut unique = ids.to_vec();
+		unique.sort();
+		unique.dedup();
+		let requests = unique.chunks(self.batch_size).map(|batch| async move {
+			let id_list = batch
+				.iter()
+				.map(ArxivId::to_bare_string)
+				.collect::<Vec<_>>()
+				.join(",");
+			let params = [
+				("id_list", id_list),
+				("max_results", batch.len().to_string()),
+			];
+			let feed = self.fetch_feed(&params).await?;
+			Ok::<_, ArxivApiError>(match_batch(batch, &feed.articles))
+		});
+		let mut responses = stream::iter(requests).buffer_unordered(self.concurrency);
+		let mut articles = HashMap::with_capacity(unique.len());
+		while let Some(batch) = responses.next().await {
+			articles.extend(batch?);
+		}
+		Ok(articles)
+	}
+
+	/// Searches articles with a query of the export API, returning the page of results
+	/// selected by the query.
+	pub async fn search(&self, query: &Query) -> Result<Vec<ArxivArticle>, ArxivApiError> {
+		Ok(self.fetch_feed(&query.to_params()).await?.articles)
+	}
+
+	/// Searches articles with a query of the export API, returning a stream of all of the
+	/// results from the start index of the query.
+	///
+	/// The results are requested page by page, with as many results per page as the maximum
+	/// number of results of the query (or 100 if it is not set), and as spaced by the rate
+	/// limit of the client. The stream ends after the first error.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use futures_util::{pin_mut, StreamExt};
+	/// use arxiv::{ArxivClient, ArxivApiError, HttpTransport, Query};
+	///
+	/// async fn run<T: HttpTransport>(client: &ArxivClient<T>) -> Result<(), ArxivApiError> {
+	///     let results = client.search_all(&Query::new().title("dropout"));
+	///     pin_mut!(results);
+	///     while let Some(article) = results.next().await {
+	///         println!("{}", article?.title);
+	///     }
+	///     Ok(())
+	/// }
+	/// ```
+	pub fn search_all<'a>(
+		&'a self,
+		query: &Query,
+	) -> impl Stream<Item = Result<ArxivArticle, ArxivApiError>> + 'a {
+		stream::unfold(Pages::new(query), move |mut pages| async move {
+			loop {
+				if let Some(article) = pages.buffer.pop_front() {
+					return Some((Ok(article), pages));
+				}
+				let query = pages.next_page()?;
+				match self.fetch_feed(&query.to_params()).await {
+					Ok(feed) => pages.push(feed),
+					Err(e) => {
+						pages.finish();
+						return Some((Err(e), pages));
+					}
+				}
+			}
+		})
+	}
+
+	/// Fetches the daily RSS feed of a category, returning the articles of the last
+	/// announcement, including cross-listings and new versions.
+	pub async fn fetch_rss(
+		&self,
+		category: &ArxivCategoryId,
+	) -> Result<Vec<ArxivFeedEntry>, ArxivApiError> {
+		let url = format!("{}/{}", self.rss_url, category);
+		let response = send(&self.transport, &url, &self.limiter, &self.retry).await?;
+		parse_rss_response(&response)
+	}
+
+	/// Fetches the history of the versions of an article, with when each version was submitted
+	/// and its size, from its record in the `arXivRaw` format of the OAI-PMH interface.
+	pub async fn versions(&self, id: &ArxivIdentifier) -> Result<VersionHistory, ArxivApiError> {
+		let params = get_record_params(id, OaiMetadataFormat::ArxivRaw);
+		let url = format!("{}?{}", self.oai_url, encode_params(&params));
+		let response = send(&self.transport, &url, &self.limiter, &self.retry).await?;
+		parse_versions_response(&response)
+	}
+
+	/// Fetches the DataCite record of the DOI registered by arXiv.org for an article, such as
+	/// `10.48550/arXiv.1706.03762`
+	pub async fn fetch_datacite(&self, id: &ArxivId) -> Result<DataCiteRecord, ArxivApiError> {
+		let url = format!("{}/{}", self.datacite_url, id.to_doi());
+		let response = send(&self.transport, &url, &self.limiter, &self.retry).await?;
+		if !response.is_success() {
+			return Err(ArxivApiError::Status(response.status));
+		}
+		Ok(DataCiteRecord::from_xml(&String::from_utf8_lossy(&response.body))?)
+	}
+
+	/// Fetches the metadata of an article from the export API and the DataCite record of its
+	/// DOI, and reconciles their titles and authors, returning their mismatches, if any
+	///
+	/// See also: [`DataCiteRecord::reconcile`]
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivClient, ArxivApiError, ArxivId, HttpTransport};
+	///
+	/// async fn run<T: HttpTransport>(client: &ArxivClient<T>) -> Result<(), ArxivApiError> {
+	///     let id = ArxivId::from_str("arXiv:1706.03762").unwrap();
+	///     for mismatch in client.cross_check_datacite(&id).await? {
+	///         println!("{}: {}", id, mismatch);
+	///     }
+	///     Ok(())
+	/// }
+	/// ```
+	pub async fn cross_check_datacite(
+		&self,
+		id: &ArxivId,
+	) -> Result<Vec<DataCiteMismatch>, ArxivApiError> {
+		let article = self.fetch_metadata(id).await?;
+		let record = self.fetch_datacite(id).await?;
+		Ok(record.reconcile(&ArxivArticleMetadata::from(article)))
+	}
+
+	/// Downloads the PDF of an article to the path, of the version of the identifier if it has
+	/// one, or of the latest version otherwise, returning the size of the file.
+	///
+	/// If the file already exists, only its missing bytes are requested, so that interrupted
+	/// downloads are resumed, unless the transport does not support ranges or the server
+	/// sends the whole file again.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivClient, ArxivApiError, ArxivIdentifier, HttpTransport};
+	///
+	/// async fn run<T: HttpTransport>(client: &ArxivClient<T>) -> Result<(), ArxivApiError> {
+	///     let id = ArxivIdentifier::from_str("1706.03762v7").unwrap();
+	///     let size = client.download_pdf(&id, "1706.03762v7.pdf").await?;
+	///     println!("downloaded {} bytes", size);
+	///     Ok(())
+	/// }
+	/// ```
+	pub async fn download_pdf(
+		&self,
+		id: &ArxivIdentifier,
+		path: impl AsRef<Path>,
+	) -> Result<u64, ArxivApiError> {
+		let url = download_url(&self.download_url, "pdf", id);
+		self.download(&url, path.as_ref()).await
+	}
+
+	/// Downloads the source of an article to the path, usually a gzipped tarball, of the
+	/// version of the identifier if it has one, or of the latest version otherwise, returning
+	/// the size of the file.
+	///
+	/// Interrupted downloads are resumed as with [`ArxivClient::download_pdf`].
+	pub async fn download_source(
+		&self,
+		id: &ArxivIdentifier,
+		path: impl AsRef<Path>,
+	) -> Result<u64, ArxivApiError> {
+		let url = download_url(&self.download_url, "src", id);
+		self.download(&url, path.as_ref()).await
+	}
+
+	async fn download(&self, url: &str, path: &Path) -> Result<u64, ArxivApiError> {
+		let offset = downloaded_size(path)?;
+		let response = send_from(&self.transport, url, offset, &self.limiter, &self.retry).await?;
+		write_download(path, offset, &response)
+	}
+
+	async fn fetch_feed(&self, params: &[(&str, String)]) -> Result<AtomFeed, ArxivApiError> {
+		let url = format!("{}?{}", self.api_url, encode_params(params));
+		let response = send(&self.transport, &url, &self.limiter, &self.retry).await?;
+		parse_response(&response)
+	}
+}
+
+/// Pairs the identifiers of a batch of [`ArxivClient::fetch_many`] with the articles of its
+/// response, of the same version if the identifier has one
+fn match_batch(batch: &[ArxivId], articles: &[ArxivArticle]) -> Vec<(ArxivId, ArxivArticle)> {
+	batch
+		.iter()
+		.filter_map(|id| {
+			let article = articles.iter().find(|article| {
+				article.id.as_new().map_or(false, |found| {
+					found.year() == id.year()
+						&& found.month() == id.month()
+						&& found.number() == id.number()
+						&& id
+							.version()
+							.map_or(true, |version| found.version() == Some(version))
+				})
+			})?;
+			Some((id.clone(), article.clone()))
+		})
+		.collect()
+}
+
+/// Sends a request with the transport once the rate limiter allows it, retrying it as long as
+/// it is rejected and the policy allows it, and returning the last response
+pub(crate) async fn send<T: HttpTransport>(
+	transport: &T,
+	url: &str,
+	limiter: &RateLimiter,
+	policy: &RetryPolicy,
+) -> Result<HttpResponse, ArxivApiError> {
+	send_from(transport, url, 0, limiter, policy).await
+}
+
+/// Sends a request as [`send`] does, for the bytes of the resource from the offset unless it
+/// is zero
+pub(crate) async fn send_from<T: HttpTransport>(
+	transport: &T,
+	url: &str,
+	offset: u64,
+	limiter: &RateLimiter,
+	policy: &RetryPolicy,
+) -> Result<HttpResponse, ArxivApiError> {
+	let mut retries = 0;
+	loop {
+		let wait = limiter.reserve();
+		if !wait.is_zero() {
+			::tokio::time::sleep(wait).await;
+		}
+
+		let response = match offset {
+			0 => transport.get(url).await,
+			_ => transport.get_from(url, offset).await,
+		};
+		let response = response.map_err(ArxivApiError::Transport)?;
+		if RetryPolicy::is_retryable(response.status) && retries < policy.max_retries() {
+			let delay = retry_delay(response.header("Retry-After"), policy, retries);
+			::tokio::time::sleep(delay).await;
+			retries += 1;
+			continue;
+		}
+
+		return Ok(response);
+	}
+}
+
+/// Parses a response of the export API, checking its status code
+pub(crate) fn parse_response(response: &HttpResponse) -> Result<AtomFeed, ArxivApiError> {
+	if !response.is_success() {
+		return Err(ArxivApiError::Status(response.status));
+	}
+	Ok(parse_feed(&String::from_utf8_lossy(&response.body))?)
+}
+
+/// Parses a response with an RSS feed, checking its status code
+pub(crate) fn parse_rss_response(
+	response: &HttpResponse,
+) -> Result<Vec<ArxivFeedEntry>, ArxivApiError> {
+	if !response.is_success() {
+		return Err(ArxivApiError::Status(response.status));
+	}
+	Ok(ArxivFeedEntry::from_rss(&String::from_utf8_lossy(&response.body))?)
+}
+
+/// Parses a response with a record in the `arXivRaw` format into the versions of its article,
+/// checking its status code
+pub(crate) fn parse_versions_response(
+	response: &HttpResponse,
+) -> Result<VersionHistory, ArxivApiError> {
+	let record = parse_oai_http_response(response)?.into_record()?;
+	let metadata = record
+		.arxiv_raw()
+		.ok_or(OaiError::MissingElement("metadata"))??;
+	Ok(VersionHistory::new(metadata.versions.into_iter().map(ArticleVersion::from)))
+}
+
+/// The URL of the PDF or source of an article, which includes the version if present
+pub(crate) fn download_url(origin: &str, endpoint: &str, id: &ArxivIdentifier) -> String {
+	format!("{}/{}/{}", origin, endpoint, id.to_bare_string())
+}
+
+/// The size of the part of a file already downloaded, which is zero if it does not exist
+pub(crate) fn downloaded_size(path: &Path) -> Result<u64, ArxivApiError> {
+	match fs::metadata(path) {
+		Ok(metadata) => Ok(metadata.len()),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(0),
+		Err(e) => Err(e.into()),
+	}
+}
+
+/// Writes the body of a response to a download from the offset, appending it to the file
+/// if the response is partial, or replacing the file otherwise, and returns the size of the
+/// file
+pub(crate) fn write_download(
+	path: &Path,
+	offset: u64,
+	response: &HttpResponse,
+) -> Result<u64, ArxivApiError> {
+	let size = response.body.len() as u64;
+	match response.status {
+		// Partial content, from the end of the file
+		206 if offset > 0 => {
+			let mut file = OpenOptions::new().append(true).open(path)?;
+			file.write_all(&response.body)?;
+			Ok(offset + size)
+		}
+		// Range not satisfiable, since the file is already complete
+		416 if offset > 0 => Ok(offset),
+		_ if response.is_success() => {
+			fs::write(path, &response.body)?;
+			Ok(size)
+		}
+		status => Err(ArxivApiError::Status(status)),
+	}
+}
+
+/// The delay before retrying a rejected request, from the `Retry-After` header of the
+/// response if it has a valid one, or from the backoff of the policy otherwise
+pub(crate) fn retry_delay(
+	retry_after: Option<&str>,
+	policy: &RetryPolicy,
+	retries: u32,
+) -> Duration {
+	retry_after
+		.and_then(|value| parse_retry_after(value, OffsetDateTime::now_utc()))
+		.unwrap_or_else(|| policy.backoff(retries))
+}
+
+/// The progress of paging through the results of a query
+pub(crate) struct Pages {
+	query: Query,
+	next: Option<usize>,
+	pub(crate) buffer: VecDeque<ArxivArticle>,
+}
+
+impl Pages {
+	pub(crate) fn new(query: &Query) -> Self {
+		let page_size = query.max_results_per_page().unwrap_or(DEFAULT_PAGE_SIZE);
+		Self {
+			query: query.clone().max_results(page_size),
+			next: Some(query.start_index()),
+			buffer: VecDeque::new(),
+		}
+	}
+
+	/// The query of the next page, if any
+	pub(crate) fn next_page(&self) -> Option<Query> {
+		self.next.map(|start| self.query.clone().start(start))
+	}
+
+	/// Adds the results of the last page requested
+	pub(crate) fn push(&mut self, feed: AtomFeed) {
+		let start = self.next.unwrap_or_default();
+		let fetched = feed.articles.len();
+		let more = fetched > 0
+			&& match feed.total_results {
+				Some(total) => start + fetched < total,
+				None => Some(fetched) >= self.query.max_results_per_page(),
+			};
+		self.next = if more { Some(start + fetched) } else { None };
+		self.buffer.extend(feed.articles);
+	}
+
+	/// Stops paging, such as after an error
+	pub(crate) fn finish(&mut self) {
+		self.next = None;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::TransportFuture;
+	use std::str::FromStr;
+	use std::sync::Mutex;
+
+	const FEED: &str = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <id>http://arxiv.org/abs/1207.0580v1</id>
+    <updated>2012-07-03T15:04:18Z</updated>
+    <published>2012-07-03T15:04:18Z</published>
+    <title>Improving neural networks by preventing co-adaptation of feature detectors</title>
+    <summary>When a large feedforward neural network is trained on a small training set...</summary>
+    <arxiv:primary_category xmlns:arxiv="http://arxiv.org/schemas/atom" term="cs.NE"/>
+  </entry>
+</feed>"#;
+
+	fn feed(len: usize, total_results: Option<usize>) -> AtomFeed {
+		let article = ArxivArticle::from_atom_feed(FEED).unwrap().remove(0);
+		AtomFeed {
+			total_results,
+			articles: vec![article; len],
+		}
+	}
+
+	#[test]
+	fn pages_until_total_results() {
+		let mut pages = Pages::new(&Query::new().title("dropout").start(10).max_results(2));
+		assert_eq!(pages.next_page().map(|query| query.start_index()), Some(10));
+		pages.push(feed(2, Some(13)));
+
+		assert_eq!(pages.next_page().map(|query| query.start_index()), Some(12));
+		pages.push(feed(1, Some(13)));
+		assert!(pages.next_page().is_none());
+		assert_eq!(pages.buffer.len(), 3);
+	}
+
+	#[test]
+	fn pages_without_total_results() {
+		let mut pages = Pages::new(&Query::new().title("dropout"));
+		let query = pages.next_page().unwrap();
+		assert_eq!(query.max_results_per_page(), Some(DEFAULT_PAGE_SIZE));
+		pages.push(feed(DEFAULT_PAGE_SIZE, None));
+
+		assert!(pages.next_page().is_some());
+		pages.push(feed(0, None));
+		assert!(pages.next_page().is_none());
+	}
+
+	/// A transport replying with scripted responses, and recording the URLs requested
+	#[derive(Default)]
+	struct Scripted {
+		responses: Mutex<VecDeque<HttpResponse>>,
+		urls: Mutex<Vec<String>>,
+	}
+
+	impl Scripted {
+		fn new(responses: Vec<HttpResponse>) -> ArxivClient<Self> {
+			let transport = Self {
+				responses: Mutex::new(responses.into()),
+				urls: Mutex::default(),
+			};
+			ArxivClient::with_transport(transport)
+				.with_rate_limit(Duration::ZERO)
+				.with_retry_policy(RetryPolicy::default().with_initial_backoff(Duration::ZERO))
+		}
+
+		fn urls(&self) -> Vec<String> {
+			self.urls.lock().unwrap().clone()
+		}
+	}
+
+	impl HttpTransport for Scripted {
+		fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+			self.urls.lock().unwrap().push(String::from(url));
+			let response = self.responses.lock().unwrap().pop_front();
+			Box::pin(std::future::ready(response.ok_or_else(|| "no response left".into())))
+		}
+
+		fn get_from<'a>(&'a self, url: &'a str, offset: u64) -> TransportFuture<'a> {
+			let url = format!("{} (from {})", url, offset);
+			Box::pin(async move { self.get(&url).await })
+		}
+	}
+
+	fn page(total_results: usize) -> HttpResponse {
+		let feed = FEED
+			.replace("<entry>", &format!("<totalResults>{}</totalResults><entry>", total_results));
+		HttpResponse::new(200, feed)
+	}
+
+	#[tokio::test]
+	async fn client_fetch_metadata() {
+		let client = Scripted::new(vec![page(1), HttpResponse::new(200, "<feed/>")]);
+		let id = ArxivId::from_str("arXiv:1207.0580").unwrap();
+		let article = client.fetch_metadata(&id).await.unwrap();
+		assert_eq!(article.id.version(), Some(1));
+		assert!(matches!(client.fetch_metadata(&id).await, Err(ArxivApiError::NotFound(_))));
+		assert_eq!(
+			client.transport().urls()[0],
+			"http://export.arxiv.org/api/query?id_list=1207.0580"
+		);
+	}
+
+	#[tokio::test]
+	async fn client_retries_rejected_requests() {
+		let client = Scripted::new(vec![
+			HttpResponse::new(503, "").with_header("Retry-After", "0"),
+			HttpResponse::new(429, ""),
+			page(1),
+		]);
+		assert_eq!(
+			client
+				.search(&Query::new().title("dropout"))
+				.await
+				.unwrap()
+				.len(),
+			1
+		);
+		assert_eq!(client.transport().urls().len(), 3);
+
+		let client = Scripted::new(vec![HttpResponse::new(429, ""); 5]);
+		let client = client.with_retry_policy(RetryPolicy::NEVER);
+		assert!(matches!(client.search(&Query::new()).await, Err(ArxivApiError::Status(429))));
+		assert!(matches!(client.search(&Query::new()).await, Err(ArxivApiError::Status(429))));
+	}
+
+	#[tokio::test]
+	async fn client_fetch_many() {
+		let ids = [
+			"1207.0580v1",
+			"1207.0581",
+			"1207.0582",
+			"1207.0580v1",
+			"1207.0583",
+		]
+		.into_iter()
+		.map(|id| ArxivId::from_str(&format!("arXiv:{}", id)).unwrap())
+		.collect::<Vec<_>>();
+		let entries = ["1207.0580v1", "1207.0581v3", "1207.0582v2"]
+			.into_iter()
+			.map(|id| FEED.replace("1207.0580v1", id))
+			.map(|feed| feed.replace("<feed xmlns=\"http://www.w3.org/2005/Atom\">", ""))
+			.map(|feed| feed.replace("</feed>", ""))
+			.collect::<String>();
+		let feed = format!("<feed xmlns=\"http://www.w3.org/2005/Atom\">{}</feed>", entries);
+		let client = Scripted::new(vec![HttpResponse::new(200, feed); 2])
+			.with_batch_size(3)
+			.with_concurrency(2);
+
+		let articles = client.fetch_many(&ids).await.unwrap();
+		assert_eq!(articles.len(), 3);
+		assert_eq!(articles[&ids[1]].id.to_string(), "arXiv:1207.0581v3");
+		assert_eq!(articles[&ids[2]].id.version(), Some(2));
+		assert!(!articles.contains_key(&ids[4]));
+
+		let mut urls = client.transport().urls();
+		urls.sort();
+		assert_eq!(
+			urls,
+			[
+				"http://export.arxiv.org/api/query?id_list=1207.0580v1%2C1207.0581%2C1207.0582&max_results=3",
+				"http://export.arxiv.org/api/query?id_list=1207.0583&max_results=1",
+			]
+		);
+
+		let client =
+			Scripted::new(vec![HttpResponse::new(500, "")]).with_retry_policy(RetryPolicy::NEVER);
+		assert!(matches!(client.fetch_many(&ids).await, Err(ArxivApiError::Status(500))));
+		assert_eq!(client.with_batch_size(0).batch_size(), 1);
+	}
+
+	#[tokio::test]
+	async fn client_search_all() {
+		let client = Scripted::new(vec![page(3), page(3), page(3), page(3)]);
+		let query = Query::new().title("dropout").max_results(1);
+		let results = client.search_all(&query).collect::<Vec<_>>().await;
+		assert_eq!(results.len(), 3);
+		assert!(results.iter().all(Result::is_ok));
+
+		let urls = client.transport().urls();
+		assert_eq!(urls.len(), 3);
+		assert!(urls[2].ends_with("&start=2&max_results=1"));
+	}
+
+	#[tokio::test]
+	async fn client_fetch_rss() {
+		let rss = r#"<rss><channel><item>
+  <title>Improving neural networks</title>
+  <guid>oai:arXiv.org:1207.0580v1</guid>
+  <category>cs.NE</category>
+  <pubDate>Tue, 03 Jul 2012 00:00:00 -0400</pubDate>
+  <arxiv:announce_type>new</arxiv:announce_type>
+</item></channel></rss>"#;
+		let client = Scripted::new(vec![HttpResponse::new(200, rss)]);
+		let category = ArxivCategoryId::from_str("cs.NE").unwrap();
+		let entries = client.fetch_rss(&category).await.unwrap();
+		assert_eq!(entries[0].id.to_string(), "arXiv:1207.0580v1");
+		assert_eq!(client.transport().urls(), ["https://rss.arxiv.org/rss/cs.NE"]);
+	}
+
+	#[tokio::test]
+	async fn client_versions() {
+		let xml = r#"<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/"><GetRecord><record>
+  <header><identifier>oai:arXiv.org:0704.0001</identifier><datestamp>2008-11-13</datestamp></header>
+  <metadata><arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+    <id>0704.0001</id>
+    <version version="v2"><date>Tue, 24 Jul 2007 20:10:27 GMT</date><size>38kb</size></version>
+    <version version="v1"><date>Mon, 2 Apr 2007 19:18:42 GMT</date><size>37kb</size></version>
+    <title>Calculation of prompt diphoton production cross sections</title>
+    <authors>C. Bal\'azs, E. L. Berger</authors>
+    <categories>hep-ph</categories>
+    <abstract>A fully differential calculation in perturbative quantum chromodynamics.</abstract>
+  </arXivRaw></metadata>
+</record></GetRecord></OAI-PMH>"#;
+		let client = Scripted::new(vec![HttpResponse::new(200, xml)]);
+		let id = ArxivIdentifier::from_str("0704.0001").unwrap();
+		let history = client.versions(&id).await.unwrap();
+		assert_eq!(history.len(), 2);
+		assert_eq!(history.first().and_then(|version| version.size_kb), Some(37));
+		assert_eq!(history.latest().map(|version| version.version), Some(2));
+		assert_eq!(
+			client.transport().urls(),
+			["https://export.arxiv.org/oai2?verb=GetRecord&identifier=oai%3AarXiv.org%3A0704.0001&metadataPrefix=arXivRaw"]
+		);
+
+		let client = Scripted::new(vec![HttpResponse::new(404, "")]);
+		assert!(matches!(client.versions(&id).await, Err(ArxivApiError::Status(404))));
+	}
+
+	#[tokio::test]
+	async fn client_download() {
+		let path = std::env::temp_dir().join(format!("arxiv-download-{}.pdf", std::process::id()));
+		let _ = fs::remove_file(&path);
+		let id = ArxivIdentifier::from_str("hep-th/9901001v2").unwrap();
+
+		let client = Scripted::new(vec![
+			HttpResponse::new(200, "%PDF-"),
+			HttpResponse::new(206, "1.5"),
+			HttpResponse::new(416, ""),
+			HttpResponse::new(200, "%PDF-1.7"),
+		]);
+		assert_eq!(client.download_pdf(&id, &path).await.unwrap(), 5);
+		assert_eq!(client.download_pdf(&id, &path).await.unwrap(), 8);
+		assert_eq!(client.download_pdf(&id, &path).await.unwrap(), 8);
+		assert_eq!(fs::read(&path).unwrap(), b"%PDF-1.5");
+		// A server ignoring the range sends the whole file again
+		assert_eq!(client.download_pdf(&id, &path).await.unwrap(), 8);
+		assert_eq!(fs::read(&path).unwrap(), b"%PDF-1.7");
+		assert_eq!(
+			client.transport().urls(),
+			[
+				"https://arxiv.org/pdf/hep-th/9901001v2",
+				"https://arxiv.org/pdf/hep-th/9901001v2 (from 5)",
+				"https://arxiv.org/pdf/hep-th/9901001v2 (from 8)",
+				"https://arxiv.org/pdf/hep-th/9901001v2 (from 8)",
+			]
+		);
+
+		let client = Scripted::new(vec![HttpResponse::new(404, "")]);
+		let client = client.with_download_url("https://export.arxiv.org");
+		assert!(matches!(
+			client.download_source(&id, &path).await,
+			Err(ArxivApiError::Status(404))
+		));
+		assert_eq!(
+			client.transport().urls(),
+			["https://export.arxiv.org/src/hep-th/9901001v2 (from 8)"]
+		);
+		fs::remove_file(&path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn client_invalid_payload() {
+		let feed = FEED.replace("1207.0580v1", "1213.0580v1");
+		let client = Scripted::new(vec![HttpResponse::new(200, feed)]);
+		let error = client.search(&Query::new()).await.unwrap_err();
+		assert!(matches!(error, ArxivApiError::InvalidId(ArxivIdError::InvalidMonth)));
+		assert!(error.source().is_some());
+		assert!(!error.is_retryable());
+
+		let client = Scripted::new(vec![HttpResponse::new(200, "<feed><entry></feed>")]);
+		let error = client.search(&Query::new()).await.unwrap_err();
+		assert!(matches!(error, ArxivApiError::Atom(_)));
+		assert!(ArxivApiError::Transport("connection reset".into()).is_retryable());
+		assert!(ArxivApiError::Status(429).is_retryable());
+	}
+
+	#[tokio::test]
+	async fn client_search_all_stops_after_error() {
+		let client = Scripted::new(vec![page(3), HttpResponse::new(500, "")]);
+		let query = Query::new().max_results(1);
+		let results = client.search_all(&query).collect::<Vec<_>>().await;
+		assert_eq!(results.len(), 2);
+		assert!(matches!(results[1], Err(ArxivApiError::Status(500))));
+	}
+}
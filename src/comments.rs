@@ -0,0 +1,228 @@
+use crate::metadata::collapse_whitespace;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The units of the counts of the comments, by the words they are written with
+const UNITS: [(Unit, &[&str]); 3] = [
+	(Unit::Pages, &["page", "pages", "pp", "p"]),
+	(Unit::Figures, &["figure", "figures", "fig", "figs"]),
+	(Unit::Tables, &["table", "tables", "tab", "tabs"]),
+];
+
+/// The numbers commonly written as words in comments, such as in `two figures`
+const NUMBER_WORDS: [&str; 12] = [
+	"one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+	"twelve",
+];
+
+/// The words of the notes about where an article was accepted or published
+const VENUE_MARKERS: [&str; 9] = [
+	"accepted",
+	"to appear",
+	"published",
+	"proceedings",
+	"conference",
+	"workshop",
+	"symposium",
+	"submitted to",
+	"presented at",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+	Pages,
+	Figures,
+	Tables,
+}
+
+/// The comments of the authors of an article, such as
+/// `12 pages, 5 figures, accepted at NeurIPS 2024`, with the counts of pages, figures and
+/// tables found in them
+///
+/// Comments are split into parts at their commas and semicolons outside of parentheses.
+/// Parts starting with counts, such as `12 pages and 2 tables`, give the counts, while the
+/// other parts are kept as notes. The first count of each unit is kept, and counts added up
+/// like `10+3 pages` are summed.
+///
+/// # Examples
+/// ```
+/// use arxiv::ArticleComments;
+///
+/// let comments = ArticleComments::parse("12 pages, 5 figures, accepted at NeurIPS 2024");
+/// assert_eq!(comments.pages, Some(12));
+/// assert_eq!(comments.figures, Some(5));
+/// assert_eq!(comments.tables, None);
+/// assert_eq!(comments.notes, ["accepted at NeurIPS 2024"]);
+/// assert_eq!(comments.venue(), Some("accepted at NeurIPS 2024"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ArticleComments {
+	/// The comments as written, with their whitespace collapsed
+	pub raw: String,
+	/// The number of pages of the article, if given
+	pub pages: Option<u32>,
+	/// The number of figures of the article, if given
+	pub figures: Option<u32>,
+	/// The number of tables of the article, if given
+	pub tables: Option<u32>,
+	/// The parts of the comments other than the counts, in order
+	pub notes: Vec<String>,
+}
+
+impl ArticleComments {
+	/// Parses comments, finding the counts of pages, figures and tables in them
+	#[must_use]
+	pub fn parse(raw: &str) -> Self {
+		let mut comments = Self {
+			raw: collapse_whitespace(raw),
+			pages: None,
+			figures: None,
+			tables: None,
+			notes: Vec::new(),
+		};
+		for part in split_parts(raw) {
+			let note = comments.read_counts(&part);
+			if !note.is_empty() {
+				comments.notes.push(String::from(note));
+			}
+		}
+		comments
+	}
+
+	/// The first note about where the article was accepted, presented or published, such as
+	/// `accepted at NeurIPS 2024` or `to appear in Phys. Rev. D`, if any
+	#[must_use]
+	pub fn venue(&self) -> Option<&str> {
+		self.notes
+			.iter()
+			.find(|note| {
+				let note = note.to_lowercase();
+				VENUE_MARKERS.iter().any(|marker| note.contains(marker))
+			})
+			.map(String::as_str)
+	}
+
+	/// Reads the counts starting a part of the comments, returning the rest of the part
+	fn read_counts<'a>(&mut self, part: &'a str) -> &'a str {
+		let mut rest = part;
+		loop {
+			let (word, after) = split_word(rest);
+			let (word, after) = match word.to_lowercase().as_str() {
+				"and" | "with" | "+" | "&" => split_word(after),
+				_ => (word, after),
+			};
+			let (unit, after_unit) = split_word(after);
+			let count = parse_count(word);
+			let unit = find_unit(unit);
+			match (count, unit) {
+				(Some(count), Some(unit)) => {
+					let field = match unit {
+						Unit::Pages => &mut self.pages,
+						Unit::Figures => &mut self.figures,
+						Unit::Tables => &mut self.tables,
+					};
+					field.get_or_insert(count);
+					rest = after_unit;
+				}
+				_ => return rest.trim(),
+			}
+		}
+	}
+}
+
+impl From<&str> for ArticleComments {
+	fn from(raw: &str) -> Self {
+		Self::parse(raw)
+	}
+}
+
+impl Display for ArticleComments {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.raw)
+	}
+}
+
+/// Splits comments at their commas and semicolons outside of parentheses
+fn split_parts(s: &str) -> Vec<String> {
+	let mut parts = Vec::new();
+	let mut depth = 0_usize;
+	let mut start = 0;
+	for (i, c) in s.char_indices() {
+		match c {
+			'(' | '[' => depth += 1,
+			')' | ']' => depth = depth.saturating_sub(1),
+			',' | ';' if depth == 0 => {
+				parts.push(collapse_whitespace(&s[start..i]));
+				start = i + 1;
+			}
+			_ => {}
+		}
+	}
+	parts.push(collapse_whitespace(&s[start..]));
+	parts.retain(|part| !part.is_empty());
+	parts
+}
+
+/// Splits the first word of a string from the rest of it
+fn split_word(s: &str) -> (&str, &str) {
+	let s = s.trim_start();
+	match s.find(char::is_whitespace) {
+		Some(end) => (&s[..end], &s[end..]),
+		None => (s, ""),
+	}
+}
+
+/// Parses a count, such as `12`, `10+3` or `two`
+fn parse_count(word: &str) -> Option<u32> {
+	if let Some(i) = NUMBER_WORDS
+		.iter()
+		.position(|number| word.eq_ignore_ascii_case(number))
+	{
+		return u32::try_from(i + 1).ok();
+	}
+	word.split('+')
+		.map(|n| n.parse::<u32>().ok())
+		.sum::<Option<u32>>()
+}
+
+/// Finds the unit of a word, such as `pages` or `figs.`
+fn find_unit(word: &str) -> Option<Unit> {
+	let word = word
+		.trim_end_matches(|c: char| c.is_ascii_punctuation())
+		.to_lowercase();
+	UNITS
+		.iter()
+		.find(|(_, words)| words.contains(&word.as_str()))
+		.map(|&(unit, _)| unit)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_comments() {
+		let comments = ArticleComments::parse(
+			"10+3 pages and two figs.; 4 tables (one in the appendix, see below), v2: typos\n fixed; 20 pages",
+		);
+		assert_eq!(comments.pages, Some(13));
+		assert_eq!(comments.figures, Some(2));
+		assert_eq!(comments.tables, Some(4));
+		assert_eq!(comments.notes, ["(one in the appendix, see below)", "v2: typos fixed"]);
+		assert_eq!(comments.venue(), None);
+
+		let comments = ArticleComments::parse("To appear in Phys. Rev. D; 25 pp");
+		assert_eq!(comments.pages, Some(25));
+		assert_eq!(comments.venue(), Some("To appear in Phys. Rev. D"));
+		assert_eq!(comments.to_string(), "To appear in Phys. Rev. D; 25 pp");
+	}
+
+	#[test]
+	fn parse_comments_without_counts() {
+		let comments = ArticleComments::from("Withdrawn, pages of the proof are wrong");
+		assert_eq!((comments.pages, comments.figures, comments.tables), (None, None, None));
+		assert_eq!(comments.notes, ["Withdrawn", "pages of the proof are wrong"]);
+		assert_eq!(ArticleComments::parse(" ").notes.len(), 0);
+		assert_eq!(ArticleComments::parse("12 pages").notes.len(), 0);
+	}
+}
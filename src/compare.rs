@@ -0,0 +1,207 @@
+use crate::ArxivId;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A change in the version of an article between two lists of identifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArxivVersionChange {
+	before: Option<u8>,
+	after: Option<u8>,
+}
+
+impl ArxivVersionChange {
+	/// The version of the article in the first list, or [`None`] if it was unversioned
+	#[must_use]
+	#[inline]
+	pub const fn before(&self) -> Option<u8> {
+		self.before
+	}
+
+	/// The version of the article in the second list, or [`None`] if it was unversioned
+	#[must_use]
+	#[inline]
+	pub const fn after(&self) -> Option<u8> {
+		self.after
+	}
+}
+
+/// A structured report of the differences between two lists of arXiv identifiers,
+/// where identifiers are matched by article regardless of their version
+///
+/// See also: [`compare_lists`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArxivIdListDiff {
+	added: BTreeSet<ArxivId>,
+	removed: BTreeSet<ArxivId>,
+	version_changed: BTreeMap<ArxivId, ArxivVersionChange>,
+}
+
+impl ArxivIdListDiff {
+	/// The identifiers of articles that are only in the second list
+	#[must_use]
+	#[inline]
+	pub const fn added(&self) -> &BTreeSet<ArxivId> {
+		&self.added
+	}
+
+	/// The identifiers of articles that are only in the first list
+	#[must_use]
+	#[inline]
+	pub const fn removed(&self) -> &BTreeSet<ArxivId> {
+		&self.removed
+	}
+
+	/// The articles in both lists whose version changed, keyed by their unversioned identifier
+	#[must_use]
+	#[inline]
+	pub const fn version_changed(&self) -> &BTreeMap<ArxivId, ArxivVersionChange> {
+		&self.version_changed
+	}
+
+	/// Whether or not both lists contain the same articles with the same versions
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.version_changed.is_empty()
+	}
+}
+
+/// Compares two lists of arXiv identifiers by article, reporting which articles were added,
+/// removed, or changed versions between the first and second list.
+///
+/// If a list contains several versions of the same article, only the highest version is
+/// considered, where an unversioned identifier (which refers to the latest version) is
+/// considered higher than any explicit version.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{compare_lists, ArxivId};
+///
+/// let old = [
+///     ArxivId::from_str("arXiv:2101.00001v1").unwrap(),
+///     ArxivId::from_str("arXiv:2101.00002v1").unwrap(),
+/// ];
+/// let new = [
+///     ArxivId::from_str("arXiv:2101.00001v2").unwrap(),
+///     ArxivId::from_str("arXiv:2101.00003v1").unwrap(),
+/// ];
+///
+/// let diff = compare_lists(&old, &new);
+/// assert!(diff.added().contains(&new[1]));
+/// assert!(diff.removed().contains(&old[1]));
+/// assert_eq!(diff.version_changed().len(), 1);
+/// ```
+pub fn compare_lists<'a, A, B>(a: A, b: B) -> ArxivIdListDiff
+where
+	A: IntoIterator<Item = &'a ArxivId>,
+	B: IntoIterator<Item = &'a ArxivId>,
+{
+	let before = versions_by_article(a);
+	let after = versions_by_article(b);
+	let mut diff = ArxivIdListDiff::default();
+
+	for (article, version) in &before {
+		match after.get(article) {
+			None => {
+				diff.removed.insert(with_version(article, *version));
+			}
+			Some(v) if v != version => {
+				let change = ArxivVersionChange {
+					before: *version,
+					after: *v,
+				};
+				diff.version_changed.insert(article.clone(), change);
+			}
+			Some(_) => (),
+		}
+	}
+
+	for (article, version) in &after {
+		if !before.contains_key(article) {
+			diff.added.insert(with_version(article, *version));
+		}
+	}
+
+	diff
+}
+
+/// Groups identifiers by article, keeping the highest version of each article
+fn versions_by_article<'a, I>(ids: I) -> BTreeMap<ArxivId, Option<u8>>
+where
+	I: IntoIterator<Item = &'a ArxivId>,
+{
+	let mut articles: BTreeMap<ArxivId, Option<u8>> = BTreeMap::new();
+	for id in ids {
+		let mut article = id.clone();
+		article.set_latest();
+
+		let version = articles.entry(article).or_insert(id.version());
+		if version_rank(id.version()) > version_rank(*version) {
+			*version = id.version();
+		}
+	}
+
+	articles
+}
+
+const fn version_rank(version: Option<u8>) -> u16 {
+	match version {
+		Some(v) => v as u16,
+		None => u16::MAX,
+	}
+}
+
+fn with_version(article: &ArxivId, version: Option<u8>) -> ArxivId {
+	let mut id = article.clone();
+	if let Some(v) = version {
+		// the version was already validated when the identifier was created
+		let _ = id.set_version(v);
+	}
+	id
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	fn id(s: &str) -> ArxivId {
+		ArxivId::from_str(s).unwrap()
+	}
+
+	#[test]
+	fn compare_identical_lists() {
+		let ids = [id("arXiv:2101.00001v1"), id("arXiv:2101.00002")];
+		assert!(compare_lists(&ids, &ids).is_empty());
+	}
+
+	#[test]
+	fn compare_version_changes() {
+		let a = [id("arXiv:2101.00001v1"), id("arXiv:2101.00002v3")];
+		let b = [id("arXiv:2101.00001"), id("arXiv:2101.00002v2")];
+		let diff = compare_lists(&a, &b);
+
+		let changes: Vec<_> = diff.version_changed().values().copied().collect();
+		assert_eq!(
+			changes,
+			vec![
+				ArxivVersionChange {
+					before: Some(1),
+					after: None
+				},
+				ArxivVersionChange {
+					before: Some(3),
+					after: Some(2)
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn compare_keeps_highest_version() {
+		let a = [id("arXiv:2101.00001v2"), id("arXiv:2101.00001v1")];
+		let b = [id("arXiv:2101.00002v1")];
+		let diff = compare_lists(&a, &b);
+		assert_eq!(diff.removed().iter().collect::<Vec<_>>(), vec![&a[0]]);
+		assert_eq!(diff.added().iter().collect::<Vec<_>>(), vec![&b[0]]);
+	}
+}
@@ -0,0 +1,90 @@
+//! Transitional helpers for migrating from the 0.1 API, where the components of an
+//! [`ArxivId`] were accessed as public fields.
+//!
+//! The fields of [`ArxivId`] are now private, and are exposed through the accessor methods
+//! [`ArxivId::year`], [`ArxivId::month`], [`ArxivId::number`] and [`ArxivId::version`]. Code
+//! that still relies on field access (or struct literals and destructuring) can convert an
+//! identifier into [`ArxivIdFields`] in the meantime.
+//!
+//! This module will be removed in the next minor release.
+#![allow(deprecated)]
+
+use crate::{ArxivId, ArxivIdError};
+use std::convert::TryFrom;
+
+/// The components of an [`ArxivId`] as public fields, mirroring the 0.1 layout
+///
+/// # Examples
+/// ```
+/// # #![allow(deprecated)]
+/// use std::str::FromStr;
+/// use arxiv::ArxivId;
+/// use arxiv::compat::ArxivIdFields;
+///
+/// let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+/// let fields = ArxivIdFields::from(&id);
+/// assert_eq!(fields.year, 2023);
+/// assert_eq!(fields.month, 4);
+/// ```
+#[deprecated(
+	since = "0.2.0",
+	note = "use the accessor methods on `ArxivId` instead"
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArxivIdFields {
+	pub year: u16,
+	pub month: u8,
+	pub number: String,
+	pub version: Option<u8>,
+}
+
+impl From<&ArxivId> for ArxivIdFields {
+	fn from(id: &ArxivId) -> Self {
+		Self {
+			year: id.year(),
+			month: id.month(),
+			number: id.number(),
+			version: id.version(),
+		}
+	}
+}
+
+impl From<ArxivId> for ArxivIdFields {
+	fn from(id: ArxivId) -> Self {
+		Self::from(&id)
+	}
+}
+
+impl TryFrom<ArxivIdFields> for ArxivId {
+	type Error = ArxivIdError;
+
+	fn try_from(fields: ArxivIdFields) -> Result<Self, Self::Error> {
+		ArxivId::try_new(fields.year, fields.month, fields.number, fields.version)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	#[test]
+	fn round_trip_fields() {
+		let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+		let fields = ArxivIdFields::from(&id);
+		assert_eq!(fields.number, "11188");
+		assert_eq!(fields.version, Some(1));
+		assert_eq!(ArxivId::try_from(fields), Ok(id));
+	}
+
+	#[test]
+	fn invalid_fields() {
+		let fields = ArxivIdFields {
+			year: 2006,
+			month: 1,
+			number: String::from("0001"),
+			version: None,
+		};
+		assert_eq!(ArxivId::try_from(fields), Err(ArxivIdError::InvalidYear));
+	}
+}
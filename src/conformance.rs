@@ -0,0 +1,167 @@
+//! Grammar conformance cases for arXiv identifiers, categories and stamps.
+//!
+//! Each case pairs an input string with the outcome this crate produces when parsing it:
+//! either the canonical string form of the parsed value, or the kind of error. Alternative
+//! implementations and FFI bindings can run these cases to verify that they behave exactly
+//! like this crate.
+//!
+//! # Examples
+//! ```
+//! use std::str::FromStr;
+//! use arxiv::ArxivId;
+//! use arxiv::conformance::{Expected, IDENTIFIERS};
+//!
+//! for case in IDENTIFIERS {
+//!     match (ArxivId::from_str(case.input), case.expected) {
+//!         (Ok(id), Expected::Valid(canonical)) => assert_eq!(id.to_string(), canonical),
+//!         (Err(_), Expected::Invalid(_)) => (),
+//!         (result, expected) => panic!("{:?} should be {:?}", result, expected),
+//!     }
+//! }
+//! ```
+
+/// The expected outcome of parsing a conformance case
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+	/// The input is valid, and has the given canonical string form
+	Valid(&'static str),
+	/// The input is invalid, with the given kind of error, named after the error variant
+	Invalid(&'static str),
+}
+
+/// An input string along with its expected outcome when parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConformanceCase {
+	pub input: &'static str,
+	pub expected: Expected,
+}
+
+const fn valid(input: &'static str, canonical: &'static str) -> ConformanceCase {
+	ConformanceCase {
+		input,
+		expected: Expected::Valid(canonical),
+	}
+}
+
+const fn invalid(input: &'static str, error: &'static str) -> ConformanceCase {
+	ConformanceCase {
+		input,
+		expected: Expected::Invalid(error),
+	}
+}
+
+/// Conformance cases for parsing an [`ArxivId`](crate::ArxivId),
+/// where errors are named after the variants of [`ArxivIdError`](crate::ArxivIdError)
+pub const IDENTIFIERS: &[ConformanceCase] = &[
+	valid("arXiv:0704.0001", "arXiv:0704.0001"),
+	valid("arXiv:1412.7878", "arXiv:1412.7878"),
+	valid("arXiv:1501.00001", "arXiv:1501.00001"),
+	valid("arXiv:9912.12345v2", "arXiv:9912.12345v2"),
+	invalid("", "Syntax"),
+	invalid("2101.00001", "Syntax"),
+	invalid("arXiv:2101", "Syntax"),
+	invalid("arXiv:1.00001", "Syntax"),
+	invalid("arXiv:2101.00001.1", "Syntax"),
+	invalid("arXiv:0612.00001", "InvalidYear"),
+	invalid("arXiv:1513.00001", "InvalidMonth"),
+	invalid("arXiv:1500.00001", "InvalidMonth"),
+	invalid("arXiv:1501.001", "InvalidId"),
+	invalid("arXiv:1501.000001", "InvalidId"),
+	invalid("arXiv:1501.0000a", "InvalidId"),
+	invalid("arXiv:1501.00001v0", "InvalidVersion"),
+];
+
+/// Conformance cases for parsing an [`ArxivCategoryId`](crate::ArxivCategoryId),
+/// where errors are named after the variants of
+/// [`ArxivCategoryIdError`](crate::ArxivCategoryIdError)
+pub const CATEGORIES: &[ConformanceCase] = &[
+	valid("cs.LG", "cs.LG"),
+	valid("astro-ph.HE", "astro-ph.HE"),
+	valid("cond-mat.quant-gas", "cond-mat.quant-gas"),
+	valid("physics.ed-ph", "physics.ed-ph"),
+	valid("q-fin.TR", "q-fin.TR"),
+	valid("hep-th", "hep-th"),
+	valid("quant-ph", "quant-ph"),
+	invalid("", "Syntax"),
+	invalid("hep-th.", "Syntax"),
+	invalid("cs.LG.AI", "Syntax"),
+	invalid("cs", "Unknown"),
+	invalid("cs.lg", "Unknown"),
+	invalid("cs.LGG", "Unknown"),
+	invalid("astroph.HE", "Unknown"),
+	invalid("hep-th.LG", "Unknown"),
+];
+
+/// Conformance cases for parsing an [`ArxivStamp`](crate::ArxivStamp),
+/// where errors are named after the variants of [`ArxivStampError`](crate::ArxivStampError)
+pub const STAMPS: &[ConformanceCase] = &[
+	valid("arXiv:2001.00001 [cs.LG] 1 Jan 2000", "arXiv:2001.00001 [cs.LG] 1 Jan 2000"),
+	valid("arXiv:2001.00001 1 Jan 2000", "arXiv:2001.00001 1 Jan 2000"),
+	valid("arXiv:0712.0001 [hep-th] 4 Dec 2007", "arXiv:0712.0001 [hep-th] 4 Dec 2007"),
+	valid("arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007", "arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007"),
+	invalid("", "NotEnoughComponents"),
+	invalid("arXiv:2001.00001", "NotEnoughComponents"),
+	invalid("arXiv:2001.001 [cs.LG] 1 Jan 2000", "InvalidArxivId"),
+	invalid("arXiv:2001.00001 [cs.LG 1 Jan 2000", "InvalidCategory"),
+	invalid("arXiv:2001.00001 [cs.XX] 1 Jan 2000", "InvalidCategory"),
+	invalid("arXiv:2001.00001 [cs.LG] 32 Jan 2000", "InvalidDate"),
+	invalid("arXiv:2001.00001 [cs.LG] 1 Zan 2000", "InvalidDate"),
+];
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::*;
+	use std::fmt::Display;
+	use std::str::FromStr;
+
+	fn check<T: Display, E>(
+		cases: &[ConformanceCase],
+		parse: impl Fn(&str) -> Result<T, E>,
+		kind: impl Fn(&E) -> &'static str,
+	) {
+		for case in cases {
+			match (parse(case.input), case.expected) {
+				(Ok(value), Expected::Valid(canonical)) => {
+					assert_eq!(value.to_string(), canonical, "input: {:?}", case.input)
+				}
+				(Err(e), Expected::Invalid(error)) => {
+					assert_eq!(kind(&e), error, "input: {:?}", case.input)
+				}
+				(_, expected) => panic!("input {:?} should be {:?}", case.input, expected),
+			}
+		}
+	}
+
+	#[test]
+	fn identifiers_conform() {
+		check(IDENTIFIERS, ArxivId::from_str, |e| match e {
+			ArxivIdError::Syntax => "Syntax",
+			ArxivIdError::InvalidMonth => "InvalidMonth",
+			ArxivIdError::InvalidYear => "InvalidYear",
+			ArxivIdError::InvalidId => "InvalidId",
+			ArxivIdError::InvalidVersion => "InvalidVersion",
+		});
+	}
+
+	#[test]
+	fn categories_conform() {
+		check(CATEGORIES, ArxivCategoryId::from_str, |e| match e {
+			ArxivCategoryIdError::Syntax => "Syntax",
+			ArxivCategoryIdError::Unknown(_) => "Unknown",
+		});
+	}
+
+	#[test]
+	fn stamps_conform() {
+		check(STAMPS, ArxivStamp::from_str, |e| match e {
+			ArxivStampError::InvalidArxivId { .. } => "InvalidArxivId",
+			ArxivStampError::InvalidDate { .. } => "InvalidDate",
+			ArxivStampError::InvalidCategory { .. } => "InvalidCategory",
+			ArxivStampError::NotEnoughComponents => "NotEnoughComponents",
+			ArxivStampError::InconsistentCategory => "InconsistentCategory",
+			ArxivStampError::InconsistentDate => "InconsistentDate",
+			ArxivStampError::DateOutOfRange => "DateOutOfRange",
+		});
+	}
+}
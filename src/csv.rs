@@ -0,0 +1,118 @@
+//! Support for reading and writing identifiers as rows of CSV files with [`csv`](::csv).
+//!
+//! The canonical CSV form of an identifier has 2 columns, as given by
+//! [`ArxivId::CSV_HEADERS`]: the identifier without the `arXiv:` prefix and without its
+//! version, followed by its version, which is empty for the latest version.
+//!
+//! ```csv
+//! id,version
+//! 2101.00001,2
+//! 0704.0001,
+//! ```
+//!
+//! Identifiers and categories can also be used as fields of records deserialized with
+//! `serde`, either in their canonical string forms, or without the `arXiv:` prefix with
+//! [`crate::serde::bare`].
+
+use crate::{ArxivId, ArxivIdError, ArxivIdResult};
+use ::csv::StringRecord;
+
+impl ArxivId {
+	/// The headers of the canonical CSV form of identifiers
+	pub const CSV_HEADERS: [&'static str; 2] = ["id", "version"];
+
+	/// The canonical CSV record of the identifier, such as `2101.00001,2`
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+	/// assert_eq!(id.to_record(), vec!["2101.00001", "2"]);
+	/// ```
+	#[must_use]
+	pub fn to_record(&self) -> StringRecord {
+		let mut article = self.clone();
+		article.set_latest();
+		let version = self.version().map(|v| v.to_string()).unwrap_or_default();
+
+		StringRecord::from(vec![article.to_bare_string(), version])
+	}
+
+	/// Parses an identifier from a CSV record, where the first field is the identifier
+	/// with or without the `arXiv:` prefix, and the optional second field is its version.
+	///
+	/// The version may be either in the first field or in the second field, but if both are
+	/// given, they must be the same. Any other fields are ignored.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	/// use csv::StringRecord;
+	///
+	/// let expected = ArxivId::from_str("arXiv:2101.00001v2");
+	/// assert_eq!(ArxivId::from_record(&StringRecord::from(vec!["2101.00001", "2"])), expected);
+	/// assert_eq!(ArxivId::from_record(&StringRecord::from(vec!["arXiv:2101.00001v2"])), expected);
+	/// ```
+	pub fn from_record(record: &StringRecord) -> ArxivIdResult {
+		let mut id = Self::from_bare_str(record.get(0).ok_or(ArxivIdError::Syntax)?.trim())?;
+
+		match record.get(1).map(str::trim) {
+			None | Some("") => Ok(id),
+			Some(version) => {
+				let version = version
+					.parse::<u8>()
+					.map_err(|_| ArxivIdError::InvalidVersion)?;
+				if matches!(id.version(), Some(v) if v != version) {
+					return Err(ArxivIdError::InvalidVersion);
+				}
+
+				id.set_version(version)?;
+				Ok(id)
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	#[test]
+	fn csv_round_trip() {
+		let mut writer = ::csv::Writer::from_writer(Vec::new());
+		writer.write_record(ArxivId::CSV_HEADERS).unwrap();
+		for s in ["arXiv:2101.00001v2", "arXiv:0704.0001"] {
+			writer
+				.write_record(&ArxivId::from_str(s).unwrap().to_record())
+				.unwrap();
+		}
+		let data = writer.into_inner().unwrap();
+		assert_eq!(String::from_utf8_lossy(&data), "id,version\n2101.00001,2\n0704.0001,\n");
+
+		let mut reader = ::csv::Reader::from_reader(data.as_slice());
+		let ids = reader
+			.records()
+			.map(|record| ArxivId::from_record(&record.unwrap()))
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+		assert_eq!(ids[0], ArxivId::from_str("arXiv:2101.00001v2").unwrap());
+		assert!(ids[1].is_latest());
+	}
+
+	#[test]
+	fn csv_record_invalid() {
+		assert_eq!(ArxivId::from_record(&StringRecord::new()), Err(ArxivIdError::Syntax));
+		assert_eq!(
+			ArxivId::from_record(&StringRecord::from(vec!["2101.00001v1", "2"])),
+			Err(ArxivIdError::InvalidVersion)
+		);
+		assert_eq!(
+			ArxivId::from_record(&StringRecord::from(vec!["2101.00001", "0"])),
+			Err(ArxivIdError::InvalidVersion)
+		);
+	}
+}
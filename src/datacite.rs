@@ -0,0 +1,358 @@
+//! A parser of the [DataCite metadata][datacite] of the DOIs registered by arXiv.org, and
+//! their reconciliation with the metadata of arXiv.org.
+//!
+//! [datacite]: https://schema.datacite.org/
+
+use crate::metadata::collapse_whitespace;
+use crate::{ArxivArticleMetadata, ArxivAuthor};
+use ::quick_xml::events::Event;
+use ::quick_xml::Reader;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The endpoint of the REST API of DataCite returning the metadata of DOIs in the DataCite
+/// XML format, to which DOIs are appended
+pub const DATACITE_API_URL: &str =
+	"https://api.datacite.org/dois/application/vnd.datacite.datacite+xml";
+
+/// An error that can occur when parsing a DataCite record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataCiteError {
+	/// The record is not well-formed XML
+	Xml(String),
+	/// The record is missing a required element
+	MissingElement(&'static str),
+}
+
+impl Error for DataCiteError {}
+
+impl Display for DataCiteError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Xml(e) => write!(f, "The DataCite record is not well-formed XML: {}", e),
+			Self::MissingElement(name) => {
+				write!(f, "The DataCite record is missing the <{}> element.", name)
+			}
+		}
+	}
+}
+
+/// A creator of a work registered with DataCite, such as an author of an article
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DataCiteCreator {
+	/// The name of the creator, usually inverted such as `Vaswani, Ashish`
+	pub name: String,
+	/// The given name of the creator, if known
+	pub given_name: Option<String>,
+	/// The family name of the creator, if known
+	pub family_name: Option<String>,
+}
+
+impl DataCiteCreator {
+	/// The family name of the creator, or the part of its name before a comma, or its whole
+	/// name, such as for organizations
+	#[must_use]
+	pub fn keyname(&self) -> &str {
+		match &self.family_name {
+			Some(family_name) => family_name,
+			None => self.name.split(',').next().unwrap_or_default().trim(),
+		}
+	}
+}
+
+/// The DataCite metadata of a DOI, such as of the DOIs registered by arXiv.org for its
+/// articles
+///
+/// # Examples
+/// ```
+/// use arxiv::DataCiteRecord;
+///
+/// let xml = r#"<resource xmlns="http://datacite.org/schema/kernel-4">
+///   <identifier identifierType="DOI">10.48550/ARXIV.1706.03762</identifier>
+///   <creators>
+///     <creator>
+///       <creatorName nameType="Personal">Vaswani, Ashish</creatorName>
+///       <givenName>Ashish</givenName>
+///       <familyName>Vaswani</familyName>
+///     </creator>
+///   </creators>
+///   <titles><title>Attention Is All You Need</title></titles>
+///   <publisher>arXiv</publisher>
+///   <publicationYear>2017</publicationYear>
+/// </resource>"#;
+///
+/// let record = DataCiteRecord::from_xml(xml).unwrap();
+/// assert_eq!(record.doi, "10.48550/ARXIV.1706.03762");
+/// assert_eq!(record.creators[0].keyname(), "Vaswani");
+/// assert_eq!(record.publication_year, Some(2017));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataCiteRecord {
+	/// The DOI of the record, such as `10.48550/ARXIV.1706.03762`
+	pub doi: String,
+	/// The titles of the work, starting with its main title
+	pub titles: Vec<String>,
+	/// The creators of the work, in order
+	pub creators: Vec<DataCiteCreator>,
+	/// The publisher of the work, such as `arXiv`
+	pub publisher: Option<String>,
+	/// The year the work was published, if valid
+	pub publication_year: Option<u16>,
+}
+
+impl DataCiteRecord {
+	/// Parses a record in the DataCite XML format, such as returned by [`DATACITE_API_URL`].
+	/// The contributors of the record, which are not creators, are skipped.
+	pub fn from_xml(xml: &str) -> Result<Self, DataCiteError> {
+		let xml_error = |e: ::quick_xml::Error| DataCiteError::Xml(e.to_string());
+		let mut reader = Reader::from_str(xml);
+		let mut doi = None;
+		let mut titles = Vec::new();
+		let mut creators = Vec::new();
+		let mut publisher = None;
+		let mut publication_year = None;
+		let mut creator: Option<DataCiteCreator> = None;
+		let mut text = String::new();
+
+		loop {
+			match reader.read_event() {
+				Ok(Event::Start(e)) => {
+					if e.local_name().as_ref() == b"creator" {
+						creator = Some(DataCiteCreator {
+							name: String::new(),
+							given_name: None,
+							family_name: None,
+						});
+					}
+					text.clear();
+				}
+				Ok(Event::Text(e)) => text.push_str(&e.unescape().map_err(xml_error)?),
+				Ok(Event::CData(e)) => text.push_str(&String::from_utf8_lossy(&e)),
+				Ok(Event::End(e)) => {
+					let value = collapse_whitespace(&text);
+					match (e.local_name().as_ref(), creator.as_mut()) {
+						(b"creator", _) => creators.extend(creator.take()),
+						(b"creatorName", Some(creator)) => creator.name = value,
+						(b"givenName", Some(creator)) => creator.given_name = Some(value),
+						(b"familyName", Some(creator)) => creator.family_name = Some(value),
+						(b"identifier", _) => doi = Some(value),
+						(b"title", _) => titles.push(value),
+						(b"publisher", _) => publisher = Some(value),
+						(b"publicationYear", _) => publication_year = value.parse().ok(),
+						_ => {}
+					}
+					text.clear();
+				}
+				Ok(Event::Eof) => break,
+				Ok(_) => {}
+				Err(e) => return Err(xml_error(e)),
+			}
+		}
+
+		Ok(Self {
+			doi: doi.ok_or(DataCiteError::MissingElement("identifier"))?,
+			titles,
+			creators,
+			publisher,
+			publication_year,
+		})
+	}
+
+	/// Reconciles the title and authors of the record with the metadata of the article from
+	/// arXiv.org, returning their mismatches, if any
+	///
+	/// Titles match if any title of the record is the same as the title of the article,
+	/// ignoring case, whitespace and punctuation. Authors are matched in order by their
+	/// family names, or by their whole names such as for organizations, ignoring case and
+	/// punctuation, since their given names are often abbreviated.
+	#[must_use]
+	pub fn reconcile(&self, metadata: &ArxivArticleMetadata) -> Vec<DataCiteMismatch> {
+		let mut mismatches = Vec::new();
+		let title = normalize(&metadata.title);
+		if !self.titles.iter().any(|other| normalize(other) == title) {
+			mismatches.push(DataCiteMismatch::Title {
+				arxiv: collapse_whitespace(&metadata.title),
+				datacite: self.titles.first().cloned().unwrap_or_default(),
+			});
+		}
+
+		if metadata.authors.len() != self.creators.len() {
+			mismatches.push(DataCiteMismatch::AuthorCount {
+				arxiv: metadata.authors.len(),
+				datacite: self.creators.len(),
+			});
+		}
+		for (position, (author, creator)) in metadata.authors.iter().zip(&self.creators).enumerate()
+		{
+			let keyname = ArxivAuthor::new(author.as_str()).keyname().to_owned();
+			let same_name = normalize(author) == normalize(&creator.name);
+			if !same_name && normalize(&keyname) != normalize(creator.keyname()) {
+				mismatches.push(DataCiteMismatch::Author {
+					position,
+					arxiv: author.clone(),
+					datacite: creator.name.clone(),
+				});
+			}
+		}
+		mismatches
+	}
+}
+
+/// A difference between the metadata of an article on arXiv.org and the DataCite record of
+/// its DOI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataCiteMismatch {
+	/// No title of the record is the title of the article
+	Title {
+		/// The title of the article on arXiv.org
+		arxiv: String,
+		/// The main title of the record
+		datacite: String,
+	},
+	/// The numbers of authors and creators differ
+	AuthorCount {
+		/// The number of authors of the article on arXiv.org
+		arxiv: usize,
+		/// The number of creators of the record
+		datacite: usize,
+	},
+	/// The author and the creator at the same position have different family names
+	Author {
+		/// The position of the author, starting at 0
+		position: usize,
+		/// The name of the author on arXiv.org
+		arxiv: String,
+		/// The name of the creator of the record
+		datacite: String,
+	},
+}
+
+impl Display for DataCiteMismatch {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Title { arxiv, datacite } => write!(
+				f,
+				"The title \"{}\" differs from the DataCite title \"{}\".",
+				arxiv, datacite
+			),
+			Self::AuthorCount { arxiv, datacite } => {
+				write!(f, "The {} authors differ from the {} DataCite creators.", arxiv, datacite)
+			}
+			Self::Author {
+				position,
+				arxiv,
+				datacite,
+			} => write!(
+				f,
+				"The author {} \"{}\" differs from the DataCite creator \"{}\".",
+				position + 1,
+				arxiv,
+				datacite
+			),
+		}
+	}
+}
+
+/// Normalizes a title or name for comparisons, keeping only its lowercase letters and digits
+fn normalize(s: &str) -> String {
+	s.chars()
+		.filter(|c| c.is_alphanumeric())
+		.flat_map(char::to_lowercase)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const RECORD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<resource xmlns="http://datacite.org/schema/kernel-4">
+  <identifier identifierType="DOI">10.48550/ARXIV.1706.03762</identifier>
+  <creators>
+    <creator>
+      <creatorName nameType="Personal">Vaswani, Ashish</creatorName>
+      <givenName>Ashish</givenName>
+      <familyName>Vaswani</familyName>
+    </creator>
+    <creator>
+      <creatorName nameType="Personal">Shazeer, Noam</creatorName>
+    </creator>
+    <creator>
+      <creatorName nameType="Organizational">Google Brain</creatorName>
+    </creator>
+  </creators>
+  <titles>
+    <title>Attention Is All
+      You Need</title>
+  </titles>
+  <publisher>arXiv</publisher>
+  <publicationYear>2017</publicationYear>
+  <contributors>
+    <contributor contributorType="HostingInstitution">
+      <contributorName>arXiv</contributorName>
+      <familyName>arXiv</familyName>
+    </contributor>
+  </contributors>
+</resource>"#;
+
+	fn metadata(title: &str, authors: &[&str]) -> ArxivArticleMetadata {
+		let mut metadata = ArxivArticleMetadata::fixture();
+		metadata.title = String::from(title);
+		metadata.authors = authors.iter().map(|&author| String::from(author)).collect();
+		metadata
+	}
+
+	#[test]
+	fn parse_datacite_record() {
+		let record = DataCiteRecord::from_xml(RECORD).unwrap();
+		assert_eq!(record.titles, ["Attention Is All You Need"]);
+		assert_eq!(record.creators.len(), 3);
+		assert_eq!(record.creators[0].given_name.as_deref(), Some("Ashish"));
+		assert_eq!(record.creators[1].keyname(), "Shazeer");
+		assert_eq!(record.creators[2].keyname(), "Google Brain");
+		assert_eq!(record.publisher.as_deref(), Some("arXiv"));
+
+		assert_eq!(
+			DataCiteRecord::from_xml("<resource/>"),
+			Err(DataCiteError::MissingElement("identifier"))
+		);
+		assert!(matches!(
+			DataCiteRecord::from_xml("<resource><identifier></resource>"),
+			Err(DataCiteError::Xml(_))
+		));
+	}
+
+	#[test]
+	fn reconcile_datacite_record() {
+		let record = DataCiteRecord::from_xml(RECORD).unwrap();
+		let authors = ["Ashish Vaswani", "Noam Shazeer", "Google Brain"];
+		let matching = metadata("Attention is all you need.", &authors);
+		assert_eq!(record.reconcile(&matching), []);
+
+		let mismatching = metadata("Attention Is Not All You Need", &authors[..2]);
+		let mismatches = record.reconcile(&mismatching);
+		assert_eq!(
+			mismatches,
+			[
+				DataCiteMismatch::Title {
+					arxiv: String::from("Attention Is Not All You Need"),
+					datacite: String::from("Attention Is All You Need"),
+				},
+				DataCiteMismatch::AuthorCount {
+					arxiv: 2,
+					datacite: 3
+				},
+			]
+		);
+
+		let swapped = metadata("Attention Is All You Need", &["Noam Shazeer", "Ashish Vaswani"]);
+		let mismatches = record.reconcile(&swapped);
+		assert_eq!(mismatches.len(), 3);
+		assert_eq!(
+			mismatches[1].to_string(),
+			"The author 1 \"Noam Shazeer\" differs from the DataCite creator \"Vaswani, Ashish\"."
+		);
+	}
+}
@@ -0,0 +1,139 @@
+use crate::{ArxivCategoryId, ArxivId, ArxivStamp, ArxivStampError, ArxivStampResult};
+use time::{Date, Month};
+
+/// The range of years supported by the `time` crate by default, which is kept even if
+/// another dependency enables its `large-dates` feature
+const YEARS: std::ops::RangeInclusive<i32> = -9999..=9999;
+
+/// Creates a date from its calendar components, as an [`ArxivStampError::DateOutOfRange`]
+/// error if the date is out of the supported range, which is far beyond any valid stamp
+fn date_from_calendar(year: i32, month: u8, day: u8) -> Result<Date, ArxivStampError> {
+	if !YEARS.contains(&year) {
+		return Err(ArxivStampError::DateOutOfRange);
+	}
+
+	Month::try_from(month)
+		.and_then(|month| Date::from_calendar_date(year, month, day))
+		.map_err(|_| ArxivStampError::DateOutOfRange)
+}
+
+#[cfg(feature = "chrono")]
+impl ArxivStamp {
+	/// Creates a new [`ArxivStamp`] from a submitted date of the `chrono` crate, returning an
+	/// [`ArxivStampError::DateOutOfRange`] error if the date is out of the supported range.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivId, ArxivStamp};
+	/// use chrono::NaiveDate;
+	///
+	/// let submitted = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+	/// let stamp = ArxivStamp::from_chrono(ArxivId::from_str("arXiv:2001.00001").unwrap(), None, submitted).unwrap();
+	/// assert_eq!(stamp.submitted_chrono(), submitted);
+	/// ```
+	pub fn from_chrono(
+		id: ArxivId,
+		category: Option<ArxivCategoryId>,
+		submitted: chrono::NaiveDate,
+	) -> ArxivStampResult {
+		use chrono::Datelike;
+
+		let submitted =
+			date_from_calendar(submitted.year(), submitted.month() as u8, submitted.day() as u8)?;
+		Ok(Self::new(id, category, submitted))
+	}
+
+	/// The submitted date of the given publication for the stamp, as a date of the `chrono` crate
+	#[must_use]
+	pub fn submitted_chrono(&self) -> chrono::NaiveDate {
+		let submitted = self.submitted();
+		// every date of the `time` crate is within the range of `chrono`
+		chrono::NaiveDate::from_ymd_opt(
+			submitted.year(),
+			u32::from(u8::from(submitted.month())),
+			u32::from(submitted.day()),
+		)
+		.unwrap_or_default()
+	}
+}
+
+#[cfg(feature = "jiff")]
+impl ArxivStamp {
+	/// Creates a new [`ArxivStamp`] from a submitted date of the `jiff` crate.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivId, ArxivStamp};
+	/// use jiff::civil::date;
+	///
+	/// let submitted = date(2020, 1, 31);
+	/// let stamp = ArxivStamp::from_jiff(ArxivId::from_str("arXiv:2001.00001").unwrap(), None, submitted).unwrap();
+	/// assert_eq!(stamp.submitted_jiff(), submitted);
+	/// ```
+	pub fn from_jiff(
+		id: ArxivId,
+		category: Option<ArxivCategoryId>,
+		submitted: jiff::civil::Date,
+	) -> ArxivStampResult {
+		let submitted = date_from_calendar(
+			i32::from(submitted.year()),
+			submitted.month() as u8,
+			submitted.day() as u8,
+		)?;
+		Ok(Self::new(id, category, submitted))
+	}
+
+	/// The submitted date of the given publication for the stamp, as a date of the `jiff` crate
+	#[must_use]
+	pub fn submitted_jiff(&self) -> jiff::civil::Date {
+		let submitted = self.submitted();
+		// every date of the `time` crate is within the range of `jiff`
+		jiff::civil::Date::new(
+			submitted.year() as i16,
+			u8::from(submitted.month()) as i8,
+			submitted.day() as i8,
+		)
+		.unwrap_or_default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn chrono_round_trip() {
+		let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 29 Feb 2020").unwrap();
+		let converted = ArxivStamp::from_chrono(
+			stamp.id().clone(),
+			stamp.category().copied(),
+			stamp.submitted_chrono(),
+		);
+		assert_eq!(converted, Ok(stamp));
+	}
+
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn chrono_out_of_range() {
+		let id = ArxivId::from_str("arXiv:2001.00001").unwrap();
+		let error = ArxivStamp::from_chrono(id, None, chrono::NaiveDate::MAX).unwrap_err();
+		assert_eq!(error, ArxivStampError::DateOutOfRange);
+		assert_eq!(error.to_string(), "Submitted date is out of the supported range");
+	}
+
+	#[cfg(feature = "jiff")]
+	#[test]
+	fn jiff_round_trip() {
+		let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 29 Feb 2020").unwrap();
+		let converted = ArxivStamp::from_jiff(
+			stamp.id().clone(),
+			stamp.category().copied(),
+			stamp.submitted_jiff(),
+		);
+		assert_eq!(converted, Ok(stamp));
+	}
+}
@@ -0,0 +1,150 @@
+use crate::{ArxivId, ArxivIdError};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The DOI prefix of arXiv.org, registered with DataCite
+const DOI_PREFIX: &str = "10.48550";
+
+/// The resolver of DOIs, used for DOI URLs
+const DOI_RESOLVER: &str = "https://doi.org";
+
+/// An error that can occur when parsing the DOI of an arXiv article
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArxivDoiError {
+	/// The DOI was not registered by arXiv.org, as it does not start with `10.48550/arXiv.`
+	NotArxiv,
+	/// The identifier embedded in the DOI is invalid
+	InvalidArxivId(ArxivIdError),
+}
+
+impl Error for ArxivDoiError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::NotArxiv => None,
+			Self::InvalidArxivId(e) => Some(e),
+		}
+	}
+}
+
+impl Display for ArxivDoiError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::NotArxiv => write!(
+				f,
+				"The DOI must start with {}/arXiv. to be registered by arXiv.org.",
+				DOI_PREFIX
+			),
+			Self::InvalidArxivId(e) => {
+				write!(f, "The DOI contains an invalid arXiv identifier: {}", e)
+			}
+		}
+	}
+}
+
+impl ArxivId {
+	/// The DataCite DOI of the article, such as `10.48550/arXiv.2101.00001`
+	///
+	/// DOIs are registered for articles rather than for each of their versions,
+	/// so the version of the identifier (if any) is not included.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+	/// assert_eq!(id.to_doi(), "10.48550/arXiv.2101.00001");
+	/// ```
+	#[must_use]
+	pub fn to_doi(&self) -> String {
+		let mut article = self.clone();
+		article.set_latest();
+		format!("{}/arXiv.{}", DOI_PREFIX, article.to_bare_string())
+	}
+
+	/// The URL of the DataCite DOI of the article, such as
+	/// `https://doi.org/10.48550/arXiv.2101.00001`
+	///
+	/// See also: [`ArxivId::to_doi`]
+	#[must_use]
+	pub fn to_doi_url(&self) -> String {
+		format!("{}/{}", DOI_RESOLVER, self.to_doi())
+	}
+
+	/// Parses the identifier embedded in a DataCite DOI of arXiv.org, such as
+	/// `10.48550/arXiv.2101.00001`. DOIs are case-insensitive, and may be wrapped in a
+	/// resolver URL (such as `https://doi.org/`) or prefixed with `doi:`.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivDoiError, ArxivId};
+	///
+	/// let id = ArxivId::from_doi("10.48550/arXiv.2101.00001").unwrap();
+	/// assert_eq!(id, ArxivId::from_str("arXiv:2101.00001").unwrap());
+	/// assert!(ArxivId::from_doi("https://doi.org/10.48550/ARXIV.2101.00001").is_ok());
+	/// assert_eq!(ArxivId::from_doi("10.1000/182"), Err(ArxivDoiError::NotArxiv));
+	/// ```
+	pub fn from_doi(doi: &str) -> Result<Self, ArxivDoiError> {
+		let doi = doi.trim();
+		let doi = [
+			"https://doi.org/",
+			"http://doi.org/",
+			"https://dx.doi.org/",
+			"http://dx.doi.org/",
+			"doi:",
+		]
+		.iter()
+		.find_map(|prefix| strip_prefix_ignore_case(doi, prefix))
+		.unwrap_or(doi);
+
+		let id = strip_prefix_ignore_case(doi, DOI_PREFIX)
+			.and_then(|doi| strip_prefix_ignore_case(doi, "/arXiv."))
+			.ok_or(ArxivDoiError::NotArxiv)?;
+
+		Self::from_str(&format!("{}{}", Self::PREFIX, id)).map_err(ArxivDoiError::InvalidArxivId)
+	}
+}
+
+/// Strips a prefix from the string, ignoring ASCII case
+pub(crate) fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+	match s.get(..prefix.len()) {
+		Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	#[test]
+	fn doi_of_four_digit_identifier() {
+		let id = ArxivId::from_str("arXiv:0704.0001").unwrap();
+		assert_eq!(id.to_doi(), "10.48550/arXiv.0704.0001");
+		assert_eq!(id.to_doi_url(), "https://doi.org/10.48550/arXiv.0704.0001");
+	}
+
+	#[test]
+	fn parse_doi() {
+		let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+		assert_eq!(
+			ArxivId::from_doi(&id.to_doi()),
+			Ok(ArxivId::from_str("arXiv:2101.00001").unwrap())
+		);
+		assert!(ArxivId::from_doi("doi:10.48550/arxiv.2101.00001").is_ok());
+		assert!(ArxivId::from_doi("http://dx.doi.org/10.48550/arXiv.2101.00001").is_ok());
+	}
+
+	#[test]
+	fn parse_doi_invalid() {
+		assert_eq!(ArxivId::from_doi(""), Err(ArxivDoiError::NotArxiv));
+		assert_eq!(ArxivId::from_doi("10.48550/zenodo.123"), Err(ArxivDoiError::NotArxiv));
+		assert_eq!(
+			ArxivId::from_doi("10.48550/arXiv.2101.001"),
+			Err(ArxivDoiError::InvalidArxivId(ArxivIdError::InvalidId))
+		);
+	}
+}
@@ -0,0 +1,416 @@
+//! A parser of the plain-text digests of the [mailing lists][lists] of arXiv.org, which
+//! announce the new submissions, cross-listings and replacements of the subscribed categories.
+//!
+//! [lists]: https://info.arxiv.org/help/subscribe.html
+
+use crate::author::split_authors;
+use crate::metadata::collapse_whitespace;
+use crate::{
+	AnnouncementType, ArxivCategoryId, ArxivCategoryIdError, ArxivCategorySet, ArxivIdError,
+	ArxivIdentifier,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::iter::Peekable;
+use std::str::FromStr;
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+/// An error that can occur when parsing a digest of a mailing list of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailError {
+	/// An entry is missing a required field
+	MissingField(&'static str),
+	/// An entry has an invalid identifier
+	InvalidId(ArxivIdError),
+	/// An entry has an invalid category
+	InvalidCategory(ArxivCategoryIdError),
+	/// An entry has an invalid submission date
+	InvalidDate(String),
+}
+
+impl Error for EmailError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidId(e) => Some(e),
+			Self::InvalidCategory(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for EmailError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::MissingField(name) => write!(f, "An entry is missing the {} field.", name),
+			Self::InvalidId(e) => write!(f, "An entry has an invalid arXiv identifier: {}", e),
+			Self::InvalidCategory(e) => write!(f, "An entry has an invalid arXiv category: {}", e),
+			Self::InvalidDate(s) => write!(f, "An entry has an invalid date \"{}\".", s),
+		}
+	}
+}
+
+/// An entry of a digest of a mailing list of arXiv.org, announcing a new article or a new
+/// version of an article
+///
+/// The title and abstract are normalized by collapsing their line breaks and indentation into
+/// single spaces.
+///
+/// # Examples
+/// ```
+/// use arxiv::{AnnouncementType, ArxivEmailEntry};
+///
+/// let email = r"------------------------------------------------------------------------------
+/// \\
+/// arXiv:1706.03762
+/// Date: Mon, 12 Jun 2017 17:57:34 GMT   (1102kb,D)
+///
+/// Title: Attention Is All You Need
+/// Authors: Ashish Vaswani, Noam Shazeer and Niki Parmar
+/// Categories: cs.CL cs.LG
+/// Comments: 15 pages, 5 figures
+/// \\
+///   The dominant sequence transduction models are based on complex recurrent or
+/// convolutional neural networks.
+/// \\ ( https://arxiv.org/abs/1706.03762 ,  1102kb)
+/// ------------------------------------------------------------------------------
+/// ";
+///
+/// let entries = ArxivEmailEntry::from_email(email).unwrap();
+/// assert_eq!(entries[0].id.to_string(), "arXiv:1706.03762");
+/// assert_eq!(entries[0].announce_type, AnnouncementType::New);
+/// assert_eq!(entries[0].authors, ["Ashish Vaswani", "Noam Shazeer", "Niki Parmar"]);
+/// assert_eq!(entries[0].size_kb, Some(1102));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivEmailEntry {
+	/// The identifier of the article, without a version
+	pub id: ArxivIdentifier,
+	/// How the article was announced in the subscribed categories
+	pub announce_type: AnnouncementType,
+	/// When the announced version was submitted
+	pub submitted: OffsetDateTime,
+	/// The size of the submission of the announced version in kilobytes, if given
+	pub size_kb: Option<u32>,
+	/// The name and email address of the submitter, if given
+	pub submitter: Option<String>,
+	/// The title of the article
+	pub title: String,
+	/// The names of the authors of the article, in order
+	pub authors: Vec<String>,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// The comments of the authors, if any, such as the number of pages
+	pub comments: Option<String>,
+	/// The report number of the article, if any
+	pub report_no: Option<String>,
+	/// The journal reference of the published version of the article, if any
+	pub journal_ref: Option<String>,
+	/// The DOI of the published version of the article, if any
+	pub doi: Option<String>,
+	/// The URL of the license of the article, if any
+	pub license: Option<String>,
+	/// The abstract of the article, which is only given for new submissions and cross-listings
+	pub abstract_text: Option<String>,
+}
+
+impl ArxivEmailEntry {
+	/// The primary category of the article if it is cross-listed from it into the subscribed categories
+	#[must_use]
+	pub fn cross_listed_from(&self) -> Option<ArxivCategoryId> {
+		match self.announce_type.is_cross_list() {
+			true => Some(self.categories.primary()),
+			false => None,
+		}
+	}
+
+	/// Parses the entries of a digest of a mailing list of arXiv.org, in order.
+	///
+	/// The entries are delimited by lines of `\\`. The new submissions come first, followed
+	/// by the cross-listings after a line of `%%--%%`, and by the replacements after a line of
+	/// `%%%---%%%`, which are marked as cross-listings with `(*cross-listing*)`. The headers
+	/// and footers of the digest are ignored.
+	///
+	/// # Errors
+	/// An error is returned if an entry is missing its title, authors, categories or date,
+	/// or has an invalid identifier, category or date.
+	pub fn from_email(text: &str) -> Result<Vec<Self>, EmailError> {
+		let mut entries = Vec::new();
+		let mut cross_listings = false;
+		let mut lines = text.lines().map(str::trim_end).peekable();
+		while let Some(line) = lines.next() {
+			if line.starts_with("%%%---") {
+				cross_listings = false;
+			} else if line.starts_with("%%--%%") {
+				cross_listings = true;
+			} else if line == "\\\\"
+				&& lines
+					.peek()
+					.map_or(false, |next| next.starts_with("arXiv:"))
+			{
+				entries.push(parse_entry(&mut lines, cross_listings)?);
+			}
+		}
+		Ok(entries)
+	}
+}
+
+/// The fields of an entry, as they appear in the digest
+#[derive(Default)]
+struct EntryFields {
+	replaced: bool,
+	date: Option<String>,
+	submitter: Option<String>,
+	title: Option<String>,
+	authors: Option<String>,
+	categories: Option<String>,
+	comments: Option<String>,
+	report_no: Option<String>,
+	journal_ref: Option<String>,
+	doi: Option<String>,
+	license: Option<String>,
+}
+
+impl EntryFields {
+	fn field(&mut self, key: &str) -> Option<&mut Option<String>> {
+		match key {
+			"Date" => Some(&mut self.date),
+			"From" => Some(&mut self.submitter),
+			"Title" => Some(&mut self.title),
+			"Authors" => Some(&mut self.authors),
+			"Categories" => Some(&mut self.categories),
+			"Comments" => Some(&mut self.comments),
+			"Report-no" => Some(&mut self.report_no),
+			"Journal-ref" => Some(&mut self.journal_ref),
+			"DOI" => Some(&mut self.doi),
+			"License" => Some(&mut self.license),
+			_ => None,
+		}
+	}
+
+	fn on_line(&mut self, key: &mut String, line: &str) {
+		if line.starts_with(char::is_whitespace) {
+			if let Some(Some(value)) = self.field(key) {
+				value.push(' ');
+				value.push_str(line);
+			}
+		} else if let Some(date) = line.strip_prefix("replaced with revised version") {
+			self.replaced = true;
+			self.date = Some(String::from(date));
+			key.clear();
+		} else if let Some((name, value)) = line.split_once(": ") {
+			*key = String::from(name);
+			if let Some(field) = self.field(name) {
+				*field = Some(String::from(value));
+			}
+		}
+	}
+}
+
+fn parse_entry<'a, I>(
+	lines: &mut Peekable<I>,
+	cross_listings: bool,
+) -> Result<ArxivEmailEntry, EmailError>
+where
+	I: Iterator<Item = &'a str>,
+{
+	let id_line = lines.next().unwrap_or_default();
+	let id = id_line.split_whitespace().next().unwrap_or_default();
+	let id = ArxivIdentifier::from_str(id).map_err(EmailError::InvalidId)?;
+	let cross = cross_listings || id_line.contains("(*cross-listing*)");
+
+	let mut fields = EntryFields::default();
+	let mut key = String::new();
+	let mut has_abstract = false;
+	for line in lines.by_ref() {
+		if line.starts_with("\\\\") {
+			has_abstract = line == "\\\\";
+			break;
+		}
+		fields.on_line(&mut key, line);
+	}
+
+	let mut abstract_text = None;
+	if has_abstract {
+		let mut text = String::new();
+		for line in lines.by_ref().take_while(|line| !line.starts_with("\\\\")) {
+			text.push(' ');
+			text.push_str(line);
+		}
+		abstract_text = non_empty(Some(text));
+	}
+
+	let date = fields.date.ok_or(EmailError::MissingField("Date"))?;
+	let (submitted, size_kb) = parse_date(&date)?;
+	let categories = fields
+		.categories
+		.ok_or(EmailError::MissingField("Categories"))?;
+	Ok(ArxivEmailEntry {
+		id,
+		announce_type: AnnouncementType::from_flags(fields.replaced, cross),
+		submitted,
+		size_kb,
+		submitter: non_empty(fields.submitter),
+		title: non_empty(fields.title).ok_or(EmailError::MissingField("Title"))?,
+		authors: split_authors(&fields.authors.ok_or(EmailError::MissingField("Authors"))?),
+		categories: ArxivCategorySet::from_str(&categories).map_err(EmailError::InvalidCategory)?,
+		comments: non_empty(fields.comments),
+		report_no: non_empty(fields.report_no),
+		journal_ref: non_empty(fields.journal_ref),
+		doi: non_empty(fields.doi),
+		license: non_empty(fields.license),
+		abstract_text,
+	})
+}
+
+/// Parses a submission date followed by the size of the submission, such as
+/// `Mon, 12 Jun 2017 17:57:34 GMT   (1102kb,D)`
+fn parse_date(s: &str) -> Result<(OffsetDateTime, Option<u32>), EmailError> {
+	let (date, size) = match s.rsplit_once('(') {
+		Some((date, size)) => (date.trim(), size.split_once("kb")),
+		None => (s.trim(), None),
+	};
+	let date = OffsetDateTime::parse(date, &Rfc2822)
+		.map_err(|_| EmailError::InvalidDate(String::from(date)))?;
+	Ok((date, size.and_then(|(size, _)| size.trim().parse().ok())))
+}
+
+fn non_empty(s: Option<String>) -> Option<String> {
+	s.map(|s| collapse_whitespace(&s)).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use time::macros::datetime;
+
+	const DIGEST: &str = r"Submissions to:
+High Energy Physics - Theory
+received from  Thu 11 Apr 24 18:00:00 GMT  to  Fri 12 Apr 24 18:00:00 GMT
+------------------------------------------------------------------------------
+------------------------------------------------------------------------------
+\\
+arXiv:2404.08001
+Date: Fri, 12 Apr 2024 10:00:00 GMT   (24kb)
+
+Title: A holographic dual of
+  something
+Authors: Jane Doe (Univ. of A, Univ. of B), John Smith and ATLAS Collaboration
+Categories: hep-th gr-qc
+Comments: 12 pages,
+  3 figures
+License: http://creativecommons.org/licenses/by/4.0/
+\\
+  We study   holography.
+Second line.
+\\ ( https://arxiv.org/abs/2404.08001 ,  24kb)
+%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%--%%
+------------------------------------------------------------------------------
+\\
+arXiv:2404.07001 (*cross-listing*)
+Date: Thu, 11 Apr 2024 09:00:00 GMT   (100kb,D)
+
+Title: Gravitational waves
+Authors: A. Author
+Categories: gr-qc hep-th
+\\
+  Waves.
+\\ ( https://arxiv.org/abs/2404.07001 ,  100kb)
+%%%---%%%---%%%---%%%---%%%---%%%---%%%---%%%---%%%---%%%---%%%---%%%---%%%---
+------------------------------------------------------------------------------
+\\
+arXiv:hep-th/0601001
+replaced with revised version Fri, 12 Apr 2024 08:00:00 GMT   (30kb)
+
+Title: An old article
+Authors: B. Author
+Categories: hep-th
+Journal-ref: Phys. Rev. D 73 (2006) 1
+\\ ( https://arxiv.org/abs/hep-th/0601001 ,  30kb)
+------------------------------------------------------------------------------
+\\
+arXiv:2301.00001 (*cross-listing*)
+replaced with revised version Fri, 12 Apr 2024 07:00:00 GMT   (5kb)
+
+Title: A cross-listed replacement
+Authors: C. Author
+Categories: math-ph hep-th
+\\ ( https://arxiv.org/abs/2301.00001 ,  5kb)
+%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%
+---
+To unsubscribe, e-mail To: hep-th@arXiv.org, Subject: cancel
+";
+
+	#[test]
+	fn parse_digest() {
+		let entries = ArxivEmailEntry::from_email(DIGEST).unwrap();
+		let types = entries
+			.iter()
+			.map(|entry| entry.announce_type)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			types,
+			[
+				AnnouncementType::New,
+				AnnouncementType::Cross,
+				AnnouncementType::Replace,
+				AnnouncementType::ReplaceCross,
+			]
+		);
+
+		let entry = &entries[0];
+		assert_eq!(entry.id.to_string(), "arXiv:2404.08001");
+		assert_eq!(entry.submitted, datetime!(2024-04-12 10:00:00 UTC));
+		assert_eq!(entry.size_kb, Some(24));
+		assert_eq!(entry.title, "A holographic dual of something");
+		assert_eq!(
+			entry.authors,
+			[
+				"Jane Doe (Univ. of A, Univ. of B)",
+				"John Smith",
+				"ATLAS Collaboration"
+			]
+		);
+		assert_eq!(entry.categories.to_string(), "hep-th gr-qc");
+		assert_eq!(entry.comments.as_deref(), Some("12 pages, 3 figures"));
+		assert!(entry.license.is_some());
+		assert_eq!(entry.abstract_text.as_deref(), Some("We study holography. Second line."));
+
+		assert_eq!(entry.cross_listed_from(), None);
+		assert_eq!(entries[1].size_kb, Some(100));
+		assert_eq!(
+			entries[1]
+				.cross_listed_from()
+				.map(|c| c.to_string())
+				.as_deref(),
+			Some("gr-qc")
+		);
+		assert_eq!(entries[2].id.to_string(), "hep-th/0601001");
+		assert_eq!(entries[2].journal_ref.as_deref(), Some("Phys. Rev. D 73 (2006) 1"));
+		assert_eq!(entries[2].abstract_text, None);
+	}
+
+	#[test]
+	fn parse_digest_errors() {
+		let entry =
+			|fields: &str| format!("\\\\\narXiv:2404.08001\n{}\n\\\\ ( url , 1kb)\n", fields);
+		assert_eq!(ArxivEmailEntry::from_email("no entries"), Ok(Vec::new()));
+		assert_eq!(
+			ArxivEmailEntry::from_email(&entry("Title: T\nAuthors: A\nCategories: hep-th")),
+			Err(EmailError::MissingField("Date"))
+		);
+		assert_eq!(
+			ArxivEmailEntry::from_email(&entry(
+				"Date: yesterday (1kb)\nTitle: T\nAuthors: A\nCategories: hep-th"
+			)),
+			Err(EmailError::InvalidDate(String::from("yesterday")))
+		);
+		assert!(matches!(
+			ArxivEmailEntry::from_email(&entry(
+				"Date: Fri, 12 Apr 2024 10:00:00 GMT\nTitle: T\nAuthors: A\nCategories: nope"
+			)),
+			Err(EmailError::InvalidCategory(_))
+		));
+	}
+}
@@ -0,0 +1,205 @@
+use crate::{ArxivId, ArxivIdError, ArxivIdResult};
+
+/// The layout version of the compact encoding, stored in its 2 least significant bits
+const COMPACT_LAYOUT_VERSION: u64 = 1u64;
+
+/// The flag of the byte encoding for unique numbers with 5 digits (or 4)
+const BYTES_FIVE_DIGITS: u32 = 1u32 << 31;
+
+impl ArxivId {
+	/// Encodes the identifier into a stable, versioned integer of at most 39 significant bits.
+	///
+	/// Serializers with variable-length integers (such as `postcard`, or `bincode` with its
+	/// varint configuration) encode the integer in 5 to 6 bytes, compared to 16 or more bytes
+	/// for the string form, which makes it well-suited for caches and IPC messages carrying
+	/// large amounts of identifiers. The layout is versioned, so that values encoded by any
+	/// release of this crate can always be decoded by later releases.
+	///
+	/// # Layout
+	/// | Bits  | Component                                          |
+	/// |-------|----------------------------------------------------|
+	/// | 32-38 | year, as an offset from [`ArxivId::MIN_YEAR`]      |
+	/// | 28-31 | month                                              |
+	/// | 11-27 | unique number                                      |
+	/// | 10    | whether the unique number has 5 digits (or 4)      |
+	/// | 2-9   | version, where 0 is the latest version             |
+	/// | 0-1   | layout version, which is currently 1               |
+	///
+	/// The remaining bits are always zero, and the encoding sorts the same way as the
+	/// identifiers themselves.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+	/// let compact = id.to_compact();
+	/// assert!(compact < 1 << 39);
+	/// assert_eq!(ArxivId::from_compact(compact), Ok(id));
+	/// ```
+	#[must_use]
+	pub fn to_compact(&self) -> u64 {
+		// the unique number only contains ASCII digits, as validated by the constructors
+		let number = self.number();
+		let five_digits = u64::from(number.len() == Self::MAX_NUM_DIGITS);
+		let number: u64 = number.parse().unwrap_or_default();
+
+		(u64::from(self.year() - Self::MIN_YEAR) << 32)
+			| (u64::from(self.month()) << 28)
+			| (number << 11)
+			| (five_digits << 10)
+			| (u64::from(self.version().unwrap_or(0)) << 2)
+			| COMPACT_LAYOUT_VERSION
+	}
+
+	/// Decodes an identifier from its compact encoding, as created by [`ArxivId::to_compact`].
+	///
+	/// Returns an [`ArxivIdError::Syntax`] error if the layout version is unknown, or if any
+	/// unused bits are set, and otherwise validates each component as [`ArxivId::try_new`] does.
+	pub fn from_compact(compact: u64) -> ArxivIdResult {
+		if compact & 0b11 != COMPACT_LAYOUT_VERSION || compact >> 39 != 0 {
+			return Err(ArxivIdError::Syntax);
+		}
+
+		let year = ((compact >> 32) & 0x7F) as u16 + Self::MIN_YEAR;
+		let month = ((compact >> 28) & 0xF) as u8;
+		let number = (compact >> 11) & 0x1FFFF;
+		let five_digits = (compact >> 10) & 1 == 1;
+		let version = match ((compact >> 2) & 0xFF) as u8 {
+			0 => None,
+			v => Some(v),
+		};
+
+		let number = match five_digits {
+			true => format!("{:05}", number),
+			false => format!("{:04}", number),
+		};
+
+		Self::try_new(year, month, number, version)
+	}
+
+	/// Encodes the identifier into a fixed-width encoding of 8 bytes, which is well-suited
+	/// for dense on-disk indices, as every identifier takes the same amount of space.
+	///
+	/// # Layout
+	/// | Bytes | Component                                                   |
+	/// |-------|-------------------------------------------------------------|
+	/// | 0-1   | year, as a big-endian integer                               |
+	/// | 2     | month                                                       |
+	/// | 3-6   | unique number, as a big-endian integer                      |
+	/// | 3     | whether the unique number has 5 digits (or 4), as the MSB   |
+	/// | 7     | version, where 0 is the latest version                      |
+	///
+	/// Since every component is big-endian, comparing the bytes lexicographically sorts
+	/// them the same way as the identifiers themselves.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+	/// let bytes = id.to_bytes();
+	/// assert_eq!(bytes, [0x07, 0xE5, 0x01, 0x80, 0x00, 0x00, 0x01, 0x02]);
+	/// assert_eq!(ArxivId::from_bytes(bytes), Ok(id));
+	/// ```
+	#[must_use]
+	pub fn to_bytes(&self) -> [u8; 8] {
+		// the unique number only contains ASCII digits, as validated by the constructors
+		let number = self.number();
+		let five_digits = match number.len() == Self::MAX_NUM_DIGITS {
+			true => BYTES_FIVE_DIGITS,
+			false => 0,
+		};
+		let number = number.parse::<u32>().unwrap_or_default() | five_digits;
+
+		let mut bytes = [0u8; 8];
+		bytes[0..2].copy_from_slice(&self.year().to_be_bytes());
+		bytes[2] = self.month();
+		bytes[3..7].copy_from_slice(&number.to_be_bytes());
+		bytes[7] = self.version().unwrap_or(0);
+		bytes
+	}
+
+	/// Decodes an identifier from its fixed-width encoding, as created by [`ArxivId::to_bytes`].
+	///
+	/// Returns an [`ArxivIdError::InvalidId`] error if the unique number does not fit in its
+	/// number of digits, and otherwise validates each component as [`ArxivId::try_new`] does.
+	pub fn from_bytes(bytes: [u8; 8]) -> ArxivIdResult {
+		let year = u16::from_be_bytes([bytes[0], bytes[1]]);
+		let month = bytes[2];
+		let number = u32::from_be_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]);
+		let version = match bytes[7] {
+			0 => None,
+			v => Some(v),
+		};
+
+		let number = match number & BYTES_FIVE_DIGITS != 0 {
+			true => format!("{:05}", number & !BYTES_FIVE_DIGITS),
+			false => format!("{:04}", number),
+		};
+
+		Self::try_new(year, month, number, version)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	#[test]
+	fn compact_round_trip() {
+		for s in [
+			"arXiv:0704.0001",
+			"arXiv:1412.9999v12",
+			"arXiv:9912.99999v255",
+		] {
+			let id = ArxivId::from_str(s).unwrap();
+			assert_eq!(ArxivId::from_compact(id.to_compact()), Ok(id));
+		}
+	}
+
+	#[test]
+	fn compact_preserves_order() {
+		let a = ArxivId::from_str("arXiv:2012.99999").unwrap();
+		let b = ArxivId::from_str("arXiv:2101.00001").unwrap();
+		assert!(a.to_compact() < b.to_compact());
+	}
+
+	#[test]
+	fn compact_invalid() {
+		assert_eq!(ArxivId::from_compact(0), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivId::from_compact(u64::MAX), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivId::from_compact(1), Err(ArxivIdError::InvalidMonth));
+	}
+
+	#[test]
+	fn bytes_round_trip() {
+		for s in [
+			"arXiv:0704.0001",
+			"arXiv:1412.9999v12",
+			"arXiv:9912.99999v255",
+		] {
+			let id = ArxivId::from_str(s).unwrap();
+			assert_eq!(ArxivId::from_bytes(id.to_bytes()), Ok(id));
+		}
+	}
+
+	#[test]
+	fn bytes_preserve_order() {
+		let a = ArxivId::from_str("arXiv:2012.99999").unwrap();
+		let b = ArxivId::from_str("arXiv:2101.00001").unwrap();
+		assert!(a.to_bytes() < b.to_bytes());
+	}
+
+	#[test]
+	fn bytes_invalid() {
+		assert_eq!(ArxivId::from_bytes([0; 8]), Err(ArxivIdError::InvalidYear));
+		assert_eq!(
+			ArxivId::from_bytes([0x07, 0xE5, 0x01, 0x00, 0x01, 0x86, 0xA0, 0x00]),
+			Err(ArxivIdError::InvalidId)
+		);
+	}
+}
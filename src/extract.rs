@@ -0,0 +1,407 @@
+use crate::{ArxivIdOld, ArxivIdentifier};
+use std::io::{self, BufRead};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// The prefix of identifiers, matched regardless of its case
+const PREFIX: &str = "arXiv:";
+
+/// An extractor of the arXiv identifiers mentioned in free text, such as the references of
+/// an article or the links of a web page
+///
+/// Identifiers of both schemes are found with or without their `arXiv:` prefix, including
+/// inside the URLs of arXiv.org (such as `https://arxiv.org/abs/2101.00001v2`) and the DOIs
+/// registered by arXiv.org (such as `10.48550/arXiv.2101.00001`). Candidates which are not
+/// valid identifiers, such as `1312.00001` whose number has too many digits, are skipped.
+///
+/// Identifiers of the new scheme without any mention of arXiv around them, such as in
+/// `see 2101.00001`, are found unless [`ArxivIdExtractor::with_bare_ids`] disables them, as
+/// they may also be numbers of another kind.
+///
+/// # Examples
+/// ```
+/// use arxiv::ArxivIdExtractor;
+///
+/// let text = "Compare arXiv:2101.00001v2 with hep-th/9901001 and \
+///     https://arxiv.org/pdf/1706.03762, or 2312.12345 in version 1.2.";
+/// let ids = ArxivIdExtractor::new()
+///     .scan(text)
+///     .map(|(range, id)| (&text[range], id.to_string()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     ids,
+///     [
+///         ("arXiv:2101.00001v2", String::from("arXiv:2101.00001v2")),
+///         ("hep-th/9901001", String::from("hep-th/9901001")),
+///         ("1706.03762", String::from("arXiv:1706.03762")),
+///         ("2312.12345", String::from("arXiv:2312.12345")),
+///     ]
+/// );
+///
+/// let strict = ArxivIdExtractor::new().with_bare_ids(false);
+/// assert_eq!(strict.scan(text).count(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArxivIdExtractor {
+	bare_ids: bool,
+}
+
+impl ArxivIdExtractor {
+	/// Creates an extractor of the identifiers of both schemes, including bare identifiers of
+	/// the new scheme
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { bare_ids: true }
+	}
+
+	/// Sets whether identifiers of the new scheme are found without any mention of arXiv
+	/// around them, which is the default
+	#[must_use]
+	pub const fn with_bare_ids(mut self, bare_ids: bool) -> Self {
+		self.bare_ids = bare_ids;
+		self
+	}
+
+	/// Whether identifiers of the new scheme are found without any mention of arXiv around
+	/// them
+	#[must_use]
+	#[inline]
+	pub const fn bare_ids(&self) -> bool {
+		self.bare_ids
+	}
+
+	/// Scans text for identifiers, returning each identifier found in order along with its
+	/// byte range in the text, which includes its `arXiv:` prefix if it has one
+	pub fn scan<'a>(
+		&self,
+		text: &'a str,
+	) -> impl Iterator<Item = (Range<usize>, ArxivIdentifier)> + 'a {
+		let extractor = *self;
+		let mut from = 0;
+		std::iter::from_fn(move || {
+			let (range, id) = extractor.find(text, from)?;
+			from = range.end;
+			Some((range, id))
+		})
+	}
+
+	/// Reads text line by line from a reader, such as a large log or dump file, returning
+	/// each identifier found in order along with its line and column
+	///
+	/// Only a line is held in memory at a time, and the lines which are not valid UTF-8 are
+	/// read with their invalid bytes replaced. The iterator stops after the first error of
+	/// the reader.
+	///
+	/// # Examples
+	/// ```
+	/// use std::io::Cursor;
+	/// use arxiv::ArxivIdExtractor;
+	///
+	/// let log = Cursor::new("GET /abs/2101.00001 200\nGET /pdf/hep-th/9901001v2 404\n");
+	/// let ids = ArxivIdExtractor::new()
+	///     .extract_from_reader(log)
+	///     .collect::<Result<Vec<_>, _>>()
+	///     .unwrap();
+	/// assert_eq!((ids[1].line, ids[1].column), (2, 10));
+	/// assert_eq!(ids[1].id.to_string(), "hep-th/9901001v2");
+	/// ```
+	pub fn extract_from_reader<R: BufRead>(
+		&self,
+		reader: R,
+	) -> impl Iterator<Item = io::Result<ArxivIdMatch>> {
+		ReaderMatches {
+			extractor: *self,
+			reader,
+			buffer: Vec::new(),
+			line: 0,
+			pending: Vec::new().into_iter(),
+			done: false,
+		}
+	}
+
+	/// Finds the first identifier of the text starting after the byte offset
+	fn find(&self, text: &str, from: usize) -> Option<(Range<usize>, ArxivIdentifier)> {
+		let bytes = text.as_bytes();
+		(from..bytes.len()).find_map(|i| match bytes[i] {
+			b'0'..=b'9' => self.find_new(text, i),
+			b'/' => find_old(text, from, i),
+			_ => None,
+		})
+	}
+
+	/// Matches an identifier of the new scheme starting at the byte offset, such as
+	/// `2101.00001v2`
+	fn find_new(&self, text: &str, start: usize) -> Option<(Range<usize>, ArxivIdentifier)> {
+		let bytes = text.as_bytes();
+		let before = &text[..start];
+		let previous = before.bytes().last();
+		if previous.map_or(false, |b| b.is_ascii_alphanumeric())
+			|| digit_run(bytes, start) != 4
+			|| bytes.get(start + 4) != Some(&b'.')
+		{
+			return None;
+		}
+		// Identifiers have numbers of 4 digits until 1412, and of 5 digits since 1501
+		let number = match &text[start..start + 4] < "1501" {
+			true => 4,
+			false => 5,
+		};
+		if digit_run(bytes, start + 5) != number {
+			return None;
+		}
+		let end = version_end(bytes, start + 5 + number)?;
+
+		let prefixed = has_prefix(before);
+		let mentioned = prefixed || mentions_arxiv(before);
+		// Numbers following a dot, such as in `1.2101.00001`, are only identifiers of DOIs
+		if (previous == Some(b'.') || !self.bare_ids) && !mentioned {
+			return None;
+		}
+		let id = ArxivIdentifier::from_str(&text[start..end]).ok()?;
+		let start = if prefixed {
+			start - PREFIX.len()
+		} else {
+			start
+		};
+		Some((start..end, id))
+	}
+}
+
+impl Default for ArxivIdExtractor {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// An identifier found by [`ArxivIdExtractor::extract_from_reader`], with its position
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivIdMatch {
+	/// The identifier found
+	pub id: ArxivIdentifier,
+	/// The line of the identifier, starting at 1
+	pub line: usize,
+	/// The column of the identifier in characters, or of its `arXiv:` prefix if it has one,
+	/// starting at 1
+	pub column: usize,
+}
+
+/// The identifiers of the lines of a reader, read one line at a time
+struct ReaderMatches<R> {
+	extractor: ArxivIdExtractor,
+	reader: R,
+	buffer: Vec<u8>,
+	line: usize,
+	pending: std::vec::IntoIter<ArxivIdMatch>,
+	done: bool,
+}
+
+impl<R: BufRead> Iterator for ReaderMatches<R> {
+	type Item = io::Result<ArxivIdMatch>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(found) = self.pending.next() {
+				return Some(Ok(found));
+			}
+			if self.done {
+				return None;
+			}
+
+			self.buffer.clear();
+			match self.reader.read_until(b'\n', &mut self.buffer) {
+				Ok(0) => self.done = true,
+				Ok(_) => {
+					self.line += 1;
+					let text = String::from_utf8_lossy(&self.buffer);
+					let line = self.line;
+					self.pending = self
+						.extractor
+						.scan(&text)
+						.map(|(range, id)| ArxivIdMatch {
+							id,
+							line,
+							column: text[..range.start].chars().count() + 1,
+						})
+						.collect::<Vec<_>>()
+						.into_iter();
+				}
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+				Err(e) => {
+					self.done = true;
+					return Some(Err(e));
+				}
+			}
+		}
+	}
+}
+
+/// Matches an identifier of the old scheme around the slash at the byte offset, such as
+/// `hep-th/9901001v1` or `math.AG/0601001`, with its archive starting after the offset `from`
+fn find_old(text: &str, from: usize, slash: usize) -> Option<(Range<usize>, ArxivIdentifier)> {
+	let bytes = text.as_bytes();
+	let is_archive = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '.';
+	let archive = &text[from..slash];
+	let archive = archive
+		.rsplit(|c: char| !is_archive(c))
+		.next()
+		.unwrap_or(archive);
+	let archive = archive.trim_start_matches(['.', '-']);
+	if archive.is_empty() || digit_run(bytes, slash + 1) != 7 {
+		return None;
+	}
+	let start = slash - archive.len();
+	// the archive must start a word, rather than end a word of other letters such as `Müller`
+	if text[..start]
+		.chars()
+		.next_back()
+		.map_or(false, char::is_alphanumeric)
+	{
+		return None;
+	}
+	let end = version_end(bytes, slash + 8)?;
+
+	let id = ArxivIdOld::from_str(&text[start..end]).ok()?;
+	let start = if has_prefix(&text[..start]) {
+		start - PREFIX.len()
+	} else {
+		start
+	};
+	Some((start..end, ArxivIdentifier::Old(id)))
+}
+
+/// The end of an identifier ending at the byte offset, after its version if it has one, or
+/// [`None`] if the identifier is followed by other letters or digits
+fn version_end(bytes: &[u8], end: usize) -> Option<usize> {
+	let end = match (bytes.get(end), digit_run(bytes, end + 1)) {
+		(Some(b'v'), digits) if digits > 0 => end + 1 + digits,
+		_ => end,
+	};
+	match bytes.get(end) {
+		Some(b) if b.is_ascii_alphanumeric() => None,
+		_ => Some(end),
+	}
+}
+
+/// The number of ASCII digits starting at the byte offset
+fn digit_run(bytes: &[u8], start: usize) -> usize {
+	bytes
+		.get(start..)
+		.map_or(0, |rest| rest.iter().take_while(|b| b.is_ascii_digit()).count())
+}
+
+/// Whether the text ends with the `arXiv:` prefix, regardless of its case
+fn has_prefix(before: &str) -> bool {
+	before
+		.len()
+		.checked_sub(PREFIX.len())
+		.and_then(|start| before.get(start..))
+		.map_or(false, |end| end.eq_ignore_ascii_case(PREFIX))
+}
+
+/// Whether the text before an identifier mentions arXiv, either in the same word such as in
+/// URLs and DOIs, or as the previous word such as in `arXiv 2101.00001`
+fn mentions_arxiv(before: &str) -> bool {
+	let mut words = before.rsplit(char::is_whitespace);
+	match words.next().unwrap_or_default() {
+		"" => words.find(|word| !word.is_empty()).map_or(false, |word| {
+			word.trim_matches(|c: char| !c.is_alphanumeric())
+				.eq_ignore_ascii_case("arXiv")
+		}),
+		word => word.to_ascii_lowercase().contains("arxiv"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn scan(extractor: ArxivIdExtractor, text: &str) -> Vec<(&str, String)> {
+		extractor
+			.scan(text)
+			.map(|(range, id)| (&text[range], id.to_bare_string()))
+			.collect()
+	}
+
+	#[test]
+	fn extract_identifiers_in_links() {
+		let text = "[1] https://arxiv.org/abs/2101.00001v3; <http://export.arxiv.org/pdf/math.AG/0601001v2.pdf>,
+			doi:10.48550/arXiv.1706.03762 and ARXIV:cs/9901001 (arXiv 0704.0001).";
+		let extractor = ArxivIdExtractor::new().with_bare_ids(false);
+		assert_eq!(
+			scan(extractor, text),
+			[
+				("2101.00001v3", String::from("2101.00001v3")),
+				("math.AG/0601001v2", String::from("math.AG/0601001v2")),
+				("1706.03762", String::from("1706.03762")),
+				("ARXIV:cs/9901001", String::from("cs/9901001")),
+				("0704.0001", String::from("0704.0001")),
+			]
+		);
+	}
+
+	#[test]
+	fn skip_other_numbers() {
+		let text =
+			"In 1312.00001, 1501.0001, 12101.00001, 2101.000011, 1.2101.00001, 2101.00001vx, \
+			hep-th/99010012, hep-th/9901001 and 2101.00001v2 — 2101.00002.";
+		let ids = scan(ArxivIdExtractor::default(), text);
+		assert_eq!(
+			ids,
+			[
+				("hep-th/9901001", String::from("hep-th/9901001")),
+				("2101.00001v2", String::from("2101.00001v2")),
+				("2101.00002", String::from("2101.00002")),
+			]
+		);
+		assert_eq!(ArxivIdExtractor::new().scan("").count(), 0);
+		assert!(ArxivIdExtractor::new().bare_ids());
+	}
+
+	#[test]
+	fn skip_words_with_other_characters() {
+		let text = "see für/0601001, Müller/0601001, ü/9901001 and Ö-hep-th/9901001";
+		assert_eq!(
+			scan(ArxivIdExtractor::new(), text),
+			[("hep-th/9901001", String::from("hep-th/9901001"))]
+		);
+	}
+
+	#[test]
+	fn extract_from_lines_of_reader() {
+		let mut bytes =
+			b"caf\xc3\xa9 arXiv:2101.00001\r\n\xff 1706.03762 1706.03763\n\nhep-th/99".to_vec();
+		bytes.extend_from_slice(b"01001");
+		let ids = ArxivIdExtractor::new()
+			.extract_from_reader(io::Cursor::new(bytes))
+			.map(|found| {
+				let found = found.unwrap();
+				(found.line, found.column, found.id.to_bare_string())
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			ids,
+			[
+				(1, 6, String::from("2101.00001")),
+				(2, 3, String::from("1706.03762")),
+				(2, 14, String::from("1706.03763")),
+				(4, 1, String::from("hep-th/9901001")),
+			]
+		);
+	}
+
+	#[test]
+	fn stop_at_reader_error() {
+		struct Failing;
+
+		impl io::Read for Failing {
+			fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+				Err(io::Error::new(io::ErrorKind::Other, "disk failure"))
+			}
+		}
+
+		let mut found = ArxivIdExtractor::new().extract_from_reader(io::BufReader::new(Failing));
+		assert!(matches!(found.next(), Some(Err(_))));
+		assert!(found.next().is_none());
+	}
+}
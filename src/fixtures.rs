@@ -0,0 +1,303 @@
+//! Responses of the APIs of arXiv.org, for testing code built on [`ArxivClient`] and
+//! [`OaiHarvester`] with a [`MockTransport`] instead of requesting arXiv.org.
+//!
+//! The fixtures are trimmed down copies of real responses, and parse into the same typed
+//! articles and records as the responses they were taken from.
+//!
+//! # Examples
+//! ```
+//! use arxiv::fixtures::{OAI_LIST_RECORDS, OAI_LIST_RECORDS_LAST};
+//! use arxiv::{HttpResponse, MockTransport, OaiHarvester, OaiMetadataFormat, OaiRequest};
+//! use futures_util::TryStreamExt;
+//! use std::time::Duration;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let transport = MockTransport::new()
+//!     .with_response(HttpResponse::new(200, OAI_LIST_RECORDS))
+//!     .with_response(HttpResponse::new(200, OAI_LIST_RECORDS_LAST));
+//! let harvester = OaiHarvester::with_transport(transport).with_rate_limit(Duration::ZERO);
+//!
+//! let records = harvester
+//!     .list_records(&OaiRequest::new(OaiMetadataFormat::Arxiv))
+//!     .try_collect::<Vec<_>>()
+//!     .await
+//!     .unwrap();
+//! assert_eq!(records.len(), 3);
+//! assert_eq!(harvester.transport().requests().len(), 2);
+//! # }
+//! ```
+//!
+//! [`ArxivClient`]: crate::ArxivClient
+//! [`OaiHarvester`]: crate::OaiHarvester
+//! [`MockTransport`]: crate::MockTransport
+
+/// An Atom feed of the export API with the two articles found by a search, out of two
+pub const ATOM_SEARCH: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <link href="http://arxiv.org/api/query?search_query%3Dti%3Aattention%26id_list%3D%26start%3D0%26max_results%3D10" rel="self" type="application/atom+xml"/>
+  <title type="html">ArXiv Query: search_query=ti:attention&amp;id_list=&amp;start=0&amp;max_results=10</title>
+  <id>http://arxiv.org/api/6aFq3/GbrQ1jLmEZnU/eVvgNYgg</id>
+  <updated>2024-04-15T00:00:00-04:00</updated>
+  <opensearch:totalResults xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">2</opensearch:totalResults>
+  <opensearch:startIndex xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">0</opensearch:startIndex>
+  <opensearch:itemsPerPage xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">10</opensearch:itemsPerPage>
+  <entry>
+    <id>http://arxiv.org/abs/1706.03762v7</id>
+    <updated>2023-08-02T00:41:18Z</updated>
+    <published>2017-06-12T17:57:34Z</published>
+    <title>Attention Is All You Need</title>
+    <summary>  The dominant sequence transduction models are based on complex recurrent or
+convolutional neural networks in an encoder-decoder configuration.
+</summary>
+    <author>
+      <name>Ashish Vaswani</name>
+    </author>
+    <author>
+      <name>Noam Shazeer</name>
+    </author>
+    <arxiv:comment xmlns:arxiv="http://arxiv.org/schemas/atom">15 pages, 5 figures</arxiv:comment>
+    <link href="http://arxiv.org/abs/1706.03762v7" rel="alternate" type="text/html"/>
+    <link title="pdf" href="http://arxiv.org/pdf/1706.03762v7" rel="related" type="application/pdf"/>
+    <arxiv:primary_category xmlns:arxiv="http://arxiv.org/schemas/atom" term="cs.CL" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="cs.CL" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="cs.LG" scheme="http://arxiv.org/schemas/atom"/>
+  </entry>
+  <entry>
+    <id>http://arxiv.org/abs/hep-th/9901001v1</id>
+    <updated>1999-01-04T12:00:00Z</updated>
+    <published>1999-01-04T12:00:00Z</published>
+    <title>Attention on the lattice</title>
+    <summary>We study attention on the lattice.</summary>
+    <author>
+      <name>Juan Maldacena</name>
+    </author>
+    <arxiv:doi xmlns:arxiv="http://arxiv.org/schemas/atom">10.1103/PhysRevD.60.1</arxiv:doi>
+    <arxiv:journal_ref xmlns:arxiv="http://arxiv.org/schemas/atom">Phys. Rev. D 60 (1999) 1</arxiv:journal_ref>
+    <link href="http://arxiv.org/abs/hep-th/9901001v1" rel="alternate" type="text/html"/>
+    <link title="pdf" href="http://arxiv.org/pdf/hep-th/9901001v1" rel="related" type="application/pdf"/>
+    <arxiv:primary_category xmlns:arxiv="http://arxiv.org/schemas/atom" term="hep-th" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="hep-th" scheme="http://arxiv.org/schemas/atom"/>
+  </entry>
+</feed>"#;
+
+/// An Atom feed of the export API without any article, as returned for a search without
+/// results or a page past the last result
+pub const ATOM_EMPTY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="html">ArXiv Query: search_query=ti:nothing&amp;id_list=&amp;start=0&amp;max_results=10</title>
+  <id>http://arxiv.org/api/Xq4JmJ4XzPnSq5dEv5b5nuvBTlE</id>
+  <updated>2024-04-15T00:00:00-04:00</updated>
+  <opensearch:totalResults xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">0</opensearch:totalResults>
+  <opensearch:startIndex xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">0</opensearch:startIndex>
+  <opensearch:itemsPerPage xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">10</opensearch:itemsPerPage>
+</feed>"#;
+
+/// A DataCite record of the DOI registered by arXiv.org for the first article of
+/// [`ATOM_SEARCH`], which matches its title and authors
+pub const DATACITE_RECORD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<resource xmlns="http://datacite.org/schema/kernel-4" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://datacite.org/schema/kernel-4 http://schema.datacite.org/meta/kernel-4.4/metadata.xsd">
+  <identifier identifierType="DOI">10.48550/ARXIV.1706.03762</identifier>
+  <creators>
+    <creator>
+      <creatorName nameType="Personal">Vaswani, Ashish</creatorName>
+      <givenName>Ashish</givenName>
+      <familyName>Vaswani</familyName>
+    </creator>
+    <creator>
+      <creatorName nameType="Personal">Shazeer, Noam</creatorName>
+      <givenName>Noam</givenName>
+      <familyName>Shazeer</familyName>
+    </creator>
+  </creators>
+  <titles>
+    <title>Attention Is All You Need</title>
+  </titles>
+  <publisher>arXiv</publisher>
+  <publicationYear>2017</publicationYear>
+  <subjects>
+    <subject>Computation and Language (cs.CL)</subject>
+    <subject>Machine Learning (cs.LG)</subject>
+    <subject subjectScheme="FOS">Computer and information sciences</subject>
+  </subjects>
+  <dates>
+    <date dateType="Submitted">2017-06-12</date>
+  </dates>
+  <resourceType resourceTypeGeneral="Preprint">Article</resourceType>
+  <relatedIdentifiers>
+    <relatedIdentifier relatedIdentifierType="URL" relationType="IsVersionOf">https://arxiv.org/abs/1706.03762</relatedIdentifier>
+  </relatedIdentifiers>
+  <rightsList>
+    <rights rightsURI="http://arxiv.org/licenses/nonexclusive-distrib/1.0/">arXiv.org perpetual, non-exclusive license</rights>
+  </rightsList>
+</resource>"#;
+
+/// A page of a `ListRecords` response of the OAI-PMH interface in the `arXiv` metadata
+/// format, with two records and a resumption token of [`OAI_LIST_RECORDS_LAST`]
+pub const OAI_LIST_RECORDS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <responseDate>2024-04-15T12:00:00Z</responseDate>
+  <request verb="ListRecords" metadataPrefix="arXiv" set="cs">http://export.arxiv.org/oai2</request>
+  <ListRecords>
+    <record>
+      <header>
+        <identifier>oai:arXiv.org:0704.0002</identifier>
+        <datestamp>2008-12-13</datestamp>
+        <setSpec>cs</setSpec>
+        <setSpec>math</setSpec>
+      </header>
+      <metadata>
+        <arXiv xmlns="http://arxiv.org/OAI/arXiv/">
+          <id>0704.0002</id>
+          <created>2007-03-30</created>
+          <updated>2008-12-13</updated>
+          <authors>
+            <author><keyname>Streinu</keyname><forenames>Ileana</forenames></author>
+            <author><keyname>Theran</keyname><forenames>Louis</forenames></author>
+          </authors>
+          <title>Sparsity-certifying Graph Decompositions</title>
+          <categories>math.CO cs.CG</categories>
+          <comments>To appear in Graphs and Combinatorics</comments>
+          <license>http://arxiv.org/licenses/nonexclusive-distrib/1.0/</license>
+          <abstract>We describe a new algorithm, the $(k,\ell)$-pebble game with colors.</abstract>
+        </arXiv>
+      </metadata>
+    </record>
+    <record>
+      <header status="deleted">
+        <identifier>oai:arXiv.org:cs/9901001</identifier>
+        <datestamp>2009-01-01</datestamp>
+        <setSpec>cs</setSpec>
+      </header>
+    </record>
+    <resumptionToken cursor="0" completeListSize="3">6960524|1001</resumptionToken>
+  </ListRecords>
+</OAI-PMH>"#;
+
+/// The last page of the `ListRecords` response of [`OAI_LIST_RECORDS`], with one record and
+/// an empty resumption token
+pub const OAI_LIST_RECORDS_LAST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <responseDate>2024-04-15T12:00:30Z</responseDate>
+  <request verb="ListRecords" resumptionToken="6960524|1001">http://export.arxiv.org/oai2</request>
+  <ListRecords>
+    <record>
+      <header>
+        <identifier>oai:arXiv.org:0704.0046</identifier>
+        <datestamp>2010-01-26</datestamp>
+        <setSpec>cs</setSpec>
+        <setSpec>physics:quant-ph</setSpec>
+      </header>
+      <metadata>
+        <arXiv xmlns="http://arxiv.org/OAI/arXiv/">
+          <id>0704.0046</id>
+          <created>2007-04-01</created>
+          <authors>
+            <author><keyname>Buhrman</keyname><forenames>Harry</forenames></author>
+          </authors>
+          <title>A limit relation for entropy and channel capacity per unit cost</title>
+          <categories>quant-ph cs.IT math.IT</categories>
+          <journal-ref>J. Math. Phys. 48 (2007) 092102</journal-ref>
+          <doi>10.1063/1.2779138</doi>
+          <abstract>In a quantum mechanical model, Diosi, Feldmann and Kosloff arrived at a
+conjecture stating that the limit of the entropy of certain mixtures is the relative
+entropy as system size goes to infinity.</abstract>
+        </arXiv>
+      </metadata>
+    </record>
+    <resumptionToken cursor="2" completeListSize="3"/>
+  </ListRecords>
+</OAI-PMH>"#;
+
+/// A `GetRecord` response of the OAI-PMH interface in the `arXivRaw` metadata format, with
+/// the two versions of the article
+pub const OAI_GET_RECORD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <responseDate>2024-04-15T12:00:00Z</responseDate>
+  <request verb="GetRecord" identifier="oai:arXiv.org:0704.0001" metadataPrefix="arXivRaw">http://export.arxiv.org/oai2</request>
+  <GetRecord>
+    <record>
+      <header>
+        <identifier>oai:arXiv.org:0704.0001</identifier>
+        <datestamp>2008-11-13</datestamp>
+        <setSpec>physics:hep-ph</setSpec>
+      </header>
+      <metadata>
+        <arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+          <id>0704.0001</id>
+          <submitter>Pavel Nadolsky</submitter>
+          <version version="v1"><date>Mon, 2 Apr 2007 19:18:42 GMT</date><size>37kb</size><source_type>D</source_type></version>
+          <version version="v2"><date>Tue, 24 Jul 2007 20:10:27 GMT</date><size>38kb</size><source_type>D</source_type></version>
+          <title>Calculation of prompt diphoton production cross sections at Tevatron and LHC energies</title>
+          <authors>C. Bal\'azs, E. L. Berger, P. M. Nadolsky, C.-P. Yuan</authors>
+          <categories>hep-ph</categories>
+          <comments>37 pages, 15 figures; published version</comments>
+          <report-no>ANL-HEP-PR-07-12</report-no>
+          <journal-ref>Phys.Rev.D76:013009,2007</journal-ref>
+          <doi>10.1103/PhysRevD.76.013009</doi>
+          <abstract>A fully differential calculation in perturbative quantum chromodynamics is
+presented for the production of massive photon pairs at hadron colliders.</abstract>
+        </arXivRaw>
+      </metadata>
+    </record>
+  </GetRecord>
+</OAI-PMH>"#;
+
+/// A response of the OAI-PMH interface with a `noRecordsMatch` error, as returned for a
+/// request selecting no record, which the harvester treats as an empty list
+pub const OAI_NO_RECORDS_MATCH: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <responseDate>2024-04-15T12:00:00Z</responseDate>
+  <request verb="ListRecords" metadataPrefix="arXiv" from="2030-01-01">http://export.arxiv.org/oai2</request>
+  <error code="noRecordsMatch">The combination of the values of the from, until, set and metadataPrefix arguments results in an empty list.</error>
+</OAI-PMH>"#;
+
+/// A daily RSS feed of a category, with a new submission, a cross-listing and a replacement
+pub const RSS_FEED: &str = r#"<?xml version='1.0' encoding='UTF-8'?>
+<rss xmlns:arxiv="http://arxiv.org/schemas/atom" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:content="http://purl.org/rss/1.0/modules/content/" version="2.0">
+  <channel>
+    <title>hep-th updates on arXiv.org</title>
+    <link>http://rss.arxiv.org/rss/hep-th</link>
+    <description>hep-th updates on the arXiv.org e-print archive.</description>
+    <atom:link href="http://rss.arxiv.org/rss/hep-th" rel="self" type="application/rss+xml"/>
+    <language>en-us</language>
+    <lastBuildDate>Mon, 15 Apr 2024 04:00:00 +0000</lastBuildDate>
+    <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+    <item>
+      <title>Strings at finite temperature</title>
+      <link>https://arxiv.org/abs/2404.08000</link>
+      <description>arXiv:2404.08000v1 Announce Type: new
+Abstract: We study strings at finite temperature.</description>
+      <guid isPermaLink="false">oai:arXiv.org:2404.08000v1</guid>
+      <category>hep-th</category>
+      <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+      <arxiv:announce_type>new</arxiv:announce_type>
+      <dc:rights>http://creativecommons.org/licenses/by/4.0/</dc:rights>
+      <dc:creator>Jane Doe</dc:creator>
+    </item>
+    <item>
+      <title>A holographic dual</title>
+      <link>https://arxiv.org/abs/2404.08001</link>
+      <description>arXiv:2404.08001v1 Announce Type: cross
+Abstract: We study holography.</description>
+      <guid isPermaLink="false">oai:arXiv.org:2404.08001v1</guid>
+      <category>gr-qc</category>
+      <category>hep-th</category>
+      <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+      <arxiv:announce_type>cross</arxiv:announce_type>
+      <dc:rights>http://creativecommons.org/licenses/by/4.0/</dc:rights>
+      <dc:creator>Jane Doe, John Smith</dc:creator>
+    </item>
+    <item>
+      <title>Strings on the lattice</title>
+      <link>https://arxiv.org/abs/hep-th/9901001</link>
+      <description>arXiv:hep-th/9901001v3 Announce Type: replace
+Abstract: Revised.</description>
+      <guid isPermaLink="false">oai:arXiv.org:hep-th/9901001v3</guid>
+      <category>hep-th</category>
+      <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+      <arxiv:announce_type>replace</arxiv:announce_type>
+      <dc:creator>Juan Maldacena</dc:creator>
+    </item>
+  </channel>
+</rss>"#;
@@ -0,0 +1,77 @@
+//! Support for using identifiers and categories as GraphQL scalars with
+//! [`async-graphql`](::async_graphql), so that they are validated at the schema boundary.
+
+use crate::{ArxivCategoryId, ArxivId};
+use ::async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use std::str::FromStr;
+
+/// An arXiv identifier, such as `arXiv:2101.00001v1`
+#[Scalar(
+	name = "ArxivId",
+	specified_by_url = "https://info.arxiv.org/help/arxiv_identifier.html"
+)]
+impl ScalarType for ArxivId {
+	fn parse(value: Value) -> InputValueResult<Self> {
+		match &value {
+			Value::String(s) => Ok(Self::from_str(s)?),
+			_ => Err(InputValueError::expected_type(value)),
+		}
+	}
+
+	fn is_valid(value: &Value) -> bool {
+		matches!(value, Value::String(s) if Self::from_str(s).is_ok())
+	}
+
+	fn to_value(&self) -> Value {
+		Value::String(self.to_string())
+	}
+}
+
+/// An arXiv category, such as `cs.LG`
+#[Scalar(
+	name = "ArxivCategoryId",
+	specified_by_url = "https://arxiv.org/category_taxonomy"
+)]
+impl ScalarType for ArxivCategoryId {
+	fn parse(value: Value) -> InputValueResult<Self> {
+		match &value {
+			Value::String(s) => Ok(Self::from_str(s)?),
+			_ => Err(InputValueError::expected_type(value)),
+		}
+	}
+
+	fn is_valid(value: &Value) -> bool {
+		matches!(value, Value::String(s) if Self::from_str(s).is_ok())
+	}
+
+	fn to_value(&self) -> Value {
+		Value::String(self.to_string())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn graphql_scalar_round_trip() {
+		let id = ArxivId::from_str("arXiv:2101.00001v1").unwrap();
+		let value = id.to_value();
+		assert_eq!(value, Value::String(String::from("arXiv:2101.00001v1")));
+		assert_eq!(<ArxivId as ScalarType>::parse(value).unwrap(), id);
+
+		let category = ArxivCategoryId::from_str("cs.LG").unwrap();
+		assert_eq!(<ArxivCategoryId as ScalarType>::parse(category.to_value()).unwrap(), category);
+	}
+
+	#[test]
+	fn graphql_scalar_invalid() {
+		let value = Value::String(String::from("arXiv:2113.00001"));
+		assert!(!<ArxivId as ScalarType>::is_valid(&value));
+		let error = <ArxivId as ScalarType>::parse(value)
+			.unwrap_err()
+			.into_server_error(Default::default());
+		assert!(error.message.contains("month"));
+		assert!(<ArxivCategoryId as ScalarType>::parse(Value::Number(1.into())).is_err());
+	}
+}
@@ -0,0 +1,748 @@
+//! A harvester of the [OAI-PMH interface][oai] of arXiv.org, the standard way to bulk-harvest
+//! the metadata of articles.
+//!
+//! [oai]: https://info.arxiv.org/help/oa/index.html
+
+use crate::client::send;
+use crate::oai_metadata::{attribute, parse_date};
+use crate::query::encode_params;
+use crate::retry::RateLimiter;
+#[cfg(feature = "reqwest")]
+use crate::ReqwestTransport;
+use crate::{
+	ArxivApiError, ArxivCategoryId, ArxivIdentifier, ArxivOaiMetadata, ArxivRawMetadata,
+	HttpResponse, HttpTransport, OaiError, RetryPolicy, ARXIV_API_DELAY,
+};
+use ::futures_util::stream::{self, Stream};
+use ::quick_xml::events::{BytesStart, Event};
+use ::quick_xml::Reader;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use time::Date;
+
+/// The endpoint of the OAI-PMH interface of arXiv.org
+pub const ARXIV_OAI_URL: &str = "https://export.arxiv.org/oai2";
+
+/// The code of the OAI-PMH error reported when a list request matches no records
+const NO_RECORDS_MATCH: &str = "noRecordsMatch";
+
+/// A format of the metadata of the records of the OAI-PMH interface of arXiv.org
+///
+/// See also: [Metadata formats][formats]
+///
+/// [formats]: https://info.arxiv.org/help/oa/index.html#metadata-formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OaiMetadataFormat {
+	/// Simple Dublin Core, `oai_dc`, which every OAI-PMH interface supports
+	DublinCore,
+	/// The format of arXiv.org, `arXiv`, with the authors split into their names
+	Arxiv,
+	/// The format of arXiv.org before the authors were split, `arXivOld`
+	ArxivOld,
+	/// The raw metadata of arXiv.org, `arXivRaw`, including the history of versions
+	ArxivRaw,
+}
+
+impl OaiMetadataFormat {
+	/// The metadata prefix of the format in OAI-PMH requests
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::DublinCore => "oai_dc",
+			Self::Arxiv => "arXiv",
+			Self::ArxivOld => "arXivOld",
+			Self::ArxivRaw => "arXivRaw",
+		}
+	}
+}
+
+impl Display for OaiMetadataFormat {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+/// The header of a record of the OAI-PMH interface of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OaiHeader {
+	/// The identifier of the article, without a version
+	pub id: ArxivIdentifier,
+	/// When the record was last changed
+	pub datestamp: Date,
+	/// The specs of the sets of the record, such as `cs` or `physics:hep-th`
+	pub sets: Vec<String>,
+	/// Whether the record was deleted, in which case it has no metadata
+	pub deleted: bool,
+}
+
+/// A record of the OAI-PMH interface of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OaiRecord {
+	/// The header of the record
+	pub header: OaiHeader,
+	/// The XML of the metadata of the record, in the format requested, unless it was deleted
+	pub metadata: Option<String>,
+}
+
+impl OaiRecord {
+	/// Parses the metadata of the record in the `arXivRaw` format, unless it was deleted
+	#[must_use]
+	pub fn arxiv_raw(&self) -> Option<Result<ArxivRawMetadata, OaiError>> {
+		self.metadata.as_deref().map(ArxivRawMetadata::from_str)
+	}
+
+	/// Parses the metadata of the record in the `arXiv` format, unless it was deleted
+	#[must_use]
+	pub fn arxiv(&self) -> Option<Result<ArxivOaiMetadata, OaiError>> {
+		self.metadata.as_deref().map(ArxivOaiMetadata::from_str)
+	}
+}
+
+/// The arguments of a `ListRecords` or `ListIdentifiers` request of an [`OaiHarvester`]
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use time::macros::date;
+/// use arxiv::{ArxivCategoryId, OaiMetadataFormat, OaiRequest};
+///
+/// let request = OaiRequest::new(OaiMetadataFormat::ArxivRaw)
+///     .category(&ArxivCategoryId::from_str("hep-th").unwrap())
+///     .from(date!(2024 - 01 - 01))
+///     .until(date!(2024 - 01 - 31));
+/// assert_eq!(request.set_spec(), Some("physics:hep-th"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OaiRequest {
+	format: OaiMetadataFormat,
+	set: Option<String>,
+	from: Option<Date>,
+	until: Option<Date>,
+}
+
+impl OaiRequest {
+	/// Creates a request of all records, in the metadata format
+	#[must_use]
+	pub const fn new(format: OaiMetadataFormat) -> Self {
+		Self {
+			format,
+			set: None,
+			from: None,
+			until: None,
+		}
+	}
+
+	/// Selects the records of a set, such as `cs` or `physics:hep-th`
+	#[must_use]
+	pub fn set(mut self, spec: impl Into<String>) -> Self {
+		self.set = Some(spec.into());
+		self
+	}
+
+	/// Selects the records of the set containing the category
+	///
+	/// See also: [`ArxivCategoryId::to_oai_set_spec`]
+	#[must_use]
+	pub fn category(self, category: &ArxivCategoryId) -> Self {
+		self.set(category.to_oai_set_spec())
+	}
+
+	/// Selects the records changed on or after the date
+	#[must_use]
+	pub const fn from(mut self, date: Date) -> Self {
+		self.from = Some(date);
+		self
+	}
+
+	/// Selects the records changed on or before the date
+	#[must_use]
+	pub const fn until(mut self, date: Date) -> Self {
+		self.until = Some(date);
+		self
+	}
+
+	/// The metadata format of the records
+	#[must_use]
+	#[inline]
+	pub const fn format(&self) -> OaiMetadataFormat {
+		self.format
+	}
+
+	/// The spec of the set of the records, if any
+	#[must_use]
+	#[inline]
+	pub fn set_spec(&self) -> Option<&str> {
+		self.set.as_deref()
+	}
+
+	fn to_params(&self, verb: &'static str) -> Vec<(&'static str, String)> {
+		let mut params = vec![
+			("verb", String::from(verb)),
+			("metadataPrefix", String::from(self.format.as_str())),
+		];
+		params.extend(self.set.clone().map(|set| ("set", set)));
+		params.extend(self.from.map(|date| ("from", format_date(date))));
+		params.extend(self.until.map(|date| ("until", format_date(date))));
+		params
+	}
+}
+
+/// A harvester of the OAI-PMH interface of arXiv.org, sending its requests with an
+/// [`HttpTransport`]
+///
+/// Lists of records are requested page by page, following the resumption tokens of the
+/// interface, with the requests spaced by the rate limit of the harvester. Requests rejected
+/// with a `503 Service Unavailable` response are retried after its `Retry-After` delay, which
+/// is how the interface regulates harvesters.
+///
+/// # Examples
+/// ```no_run
+/// use futures_util::{pin_mut, StreamExt};
+/// use time::macros::date;
+/// use arxiv::{ArxivApiError, HttpTransport, OaiHarvester, OaiMetadataFormat, OaiRequest};
+///
+/// async fn run<T: HttpTransport>(harvester: &OaiHarvester<T>) -> Result<(), ArxivApiError> {
+///     let request = OaiRequest::new(OaiMetadataFormat::Arxiv)
+///         .set("cs")
+///         .from(date!(2024 - 01 - 01));
+///     let records = harvester.list_records(&request);
+///     pin_mut!(records);
+///     while let Some(record) = records.next().await {
+///         println!("{}", record?.header.id);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OaiHarvester<T> {
+	transport: T,
+	base_url: String,
+	limiter: Arc<RateLimiter>,
+	retry: RetryPolicy,
+}
+
+#[cfg(feature = "reqwest")]
+impl OaiHarvester<ReqwestTransport> {
+	/// Creates a harvester with a default HTTP client of [`reqwest`](::reqwest)
+	#[must_use]
+	pub fn new() -> Self {
+		Self::with_transport(ReqwestTransport::new())
+	}
+}
+
+#[cfg(feature = "reqwest")]
+impl Default for OaiHarvester<ReqwestTransport> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: HttpTransport> OaiHarvester<T> {
+	/// Creates a harvester sending its requests with the transport
+	#[must_use]
+	pub fn with_transport(transport: T) -> Self {
+		Self {
+			transport,
+			base_url: String::from(ARXIV_OAI_URL),
+			limiter: Arc::new(RateLimiter::new(ARXIV_API_DELAY)),
+			retry: RetryPolicy::default(),
+		}
+	}
+
+	/// Sets the base URL of the OAI-PMH interface
+	#[must_use]
+	pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+		self.base_url = url.into();
+		self
+	}
+
+	/// Sets the minimum interval between requests, which defaults to [`ARXIV_API_DELAY`],
+	/// and is shared by the clones of the harvester
+	#[must_use]
+	pub fn with_rate_limit(mut self, interval: Duration) -> Self {
+		self.limiter = Arc::new(RateLimiter::new(interval));
+		self
+	}
+
+	/// Sets how rejected requests are retried
+	#[must_use]
+	pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+		self.retry = policy;
+		self
+	}
+
+	/// The base URL of the OAI-PMH interface
+	#[must_use]
+	#[inline]
+	pub fn base_url(&self) -> &str {
+		&self.base_url
+	}
+
+	/// The transport sending the requests of the harvester
+	#[must_use]
+	#[inline]
+	pub fn transport(&self) -> &T {
+		&self.transport
+	}
+
+	/// Fetches the record of an article with a `GetRecord` request, in the metadata format
+	pub async fn get_record(
+		&self,
+		id: &ArxivIdentifier,
+		format: OaiMetadataFormat,
+	) -> Result<OaiRecord, ArxivApiError> {
+		let response = self.fetch(&get_record_params(id, format)).await?;
+		Ok(response.into_record()?)
+	}
+
+	/// Lists the records selected by the request with `ListRecords` requests, returning a
+	/// stream of all of them, which ends after the first error.
+	pub fn list_records<'a>(
+		&'a self,
+		request: &OaiRequest,
+	) -> impl Stream<Item = Result<OaiRecord, ArxivApiError>> + 'a {
+		self.harvest("ListRecords", request, |response| response.records)
+	}
+
+	/// Lists the headers of the records selected by the request with `ListIdentifiers`
+	/// requests, returning a stream of all of them, which ends after the first error.
+	pub fn list_identifiers<'a>(
+		&'a self,
+		request: &OaiRequest,
+	) -> impl Stream<Item = Result<OaiHeader, ArxivApiError>> + 'a {
+		self.harvest("ListIdentifiers", request, |response| response.headers)
+	}
+
+	fn harvest<'a, I: 'a>(
+		&'a self,
+		verb: &'static str,
+		request: &OaiRequest,
+		items: fn(OaiResponse) -> Vec<I>,
+	) -> impl Stream<Item = Result<I, ArxivApiError>> + 'a {
+		let state = (Some(request.to_params(verb)), VecDeque::new());
+		stream::unfold(state, move |(mut next, mut buffer)| async move {
+			loop {
+				if let Some(item) = buffer.pop_front() {
+					return Some((Ok(item), (next, buffer)));
+				}
+				let params = next.take()?;
+				match self.fetch(&params).await {
+					Ok(response) => {
+						next = response.resumption_token.clone().map(|token| {
+							vec![("verb", String::from(verb)), ("resumptionToken", token)]
+						});
+						buffer.extend(items(response));
+					}
+					Err(e) => return Some((Err(e), (None, buffer))),
+				}
+			}
+		})
+	}
+
+	async fn fetch(&self, params: &[(&str, String)]) -> Result<OaiResponse, ArxivApiError> {
+		let url = format!("{}?{}", self.base_url, encode_params(params));
+		let response = send(&self.transport, &url, &self.limiter, &self.retry).await?;
+		parse_oai_http_response(&response)
+	}
+}
+
+/// The parameters of a `GetRecord` request of the record of an article
+pub(crate) fn get_record_params(
+	id: &ArxivIdentifier,
+	format: OaiMetadataFormat,
+) -> [(&'static str, String); 3] {
+	[
+		("verb", String::from("GetRecord")),
+		("identifier", id.to_oai_identifier()),
+		("metadataPrefix", String::from(format.as_str())),
+	]
+}
+
+/// Parses a response of the OAI-PMH interface, checking its status code
+pub(crate) fn parse_oai_http_response(
+	response: &HttpResponse,
+) -> Result<OaiResponse, ArxivApiError> {
+	if !response.is_success() {
+		return Err(ArxivApiError::Status(response.status));
+	}
+	Ok(parse_oai_response(&String::from_utf8_lossy(&response.body))?)
+}
+
+/// The records or headers of a response of the OAI-PMH interface, and the token to resume
+/// the list with, if it is incomplete
+#[derive(Debug, Default)]
+pub(crate) struct OaiResponse {
+	pub(crate) records: Vec<OaiRecord>,
+	pub(crate) headers: Vec<OaiHeader>,
+	pub(crate) resumption_token: Option<String>,
+}
+
+impl OaiResponse {
+	/// The record of a response to a `GetRecord` request
+	pub(crate) fn into_record(self) -> Result<OaiRecord, OaiError> {
+		let record = self.records.into_iter().next();
+		record.ok_or(OaiError::MissingElement("record"))
+	}
+}
+
+/// The elements of a header, collected before they are validated
+#[derive(Default)]
+struct HeaderFields {
+	identifier: Option<String>,
+	datestamp: Option<String>,
+	sets: Vec<String>,
+	deleted: bool,
+}
+
+impl HeaderFields {
+	fn new(e: &BytesStart<'_>) -> Result<Self, OaiError> {
+		Ok(Self {
+			deleted: attribute(e, "status")?.as_deref() == Some("deleted"),
+			..Self::default()
+		})
+	}
+
+	fn on_text(&mut self, name: &[u8], text: String) {
+		match name {
+			b"identifier" => self.identifier = Some(text),
+			b"datestamp" => self.datestamp = Some(text),
+			b"setSpec" => self.sets.push(text),
+			_ => {}
+		}
+	}
+
+	fn into_header(self) -> Result<OaiHeader, OaiError> {
+		let identifier = self
+			.identifier
+			.ok_or(OaiError::MissingElement("identifier"))?;
+		let datestamp = self
+			.datestamp
+			.ok_or(OaiError::MissingElement("datestamp"))?;
+		Ok(OaiHeader {
+			id: ArxivIdentifier::from_oai_identifier(&identifier).map_err(OaiError::InvalidId)?,
+			datestamp: parse_date(datestamp)?,
+			sets: self.sets,
+			deleted: self.deleted,
+		})
+	}
+}
+
+fn format_date(date: Date) -> String {
+	format!("{:04}-{:02}-{:02}", date.year(), u8::from(date.month()), date.day())
+}
+
+/// Parses a response of the OAI-PMH interface to a `GetRecord`, `ListRecords` or
+/// `ListIdentifiers` request, keeping the metadata of the records as raw XML
+pub(crate) fn parse_oai_response(xml: &str) -> Result<OaiResponse, OaiError> {
+	let xml_error = |e: ::quick_xml::Error| OaiError::Xml(e.to_string());
+	let mut reader = Reader::from_str(xml);
+	let mut response = OaiResponse::default();
+	let mut error_code = None;
+	let mut header: Option<HeaderFields> = None;
+	let mut record: Option<(Option<OaiHeader>, Option<String>)> = None;
+	let mut text = String::new();
+
+	loop {
+		match reader.read_event() {
+			Ok(Event::Start(e)) => {
+				match e.local_name().as_ref() {
+					b"record" => record = Some((None, None)),
+					b"header" => header = Some(HeaderFields::new(&e)?),
+					b"error" => error_code = Some(attribute(&e, "code")?.unwrap_or_default()),
+					b"metadata" => {
+						let metadata = reader.read_text(e.name()).map_err(xml_error)?;
+						if let Some((_, slot)) = record.as_mut() {
+							*slot = Some(String::from(metadata.trim()));
+						}
+					}
+					_ => {}
+				}
+				text.clear();
+			}
+			Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+				b"error" => {
+					let code = attribute(&e, "code")?;
+					if code.as_deref() != Some(NO_RECORDS_MATCH) {
+						return Err(protocol_error(code, String::new()));
+					}
+				}
+				b"header" => {
+					let header = HeaderFields::new(&e)?.into_header()?;
+					response.headers.push(header);
+				}
+				_ => {}
+			},
+			Ok(Event::Text(e)) => {
+				text.push_str(&e.unescape().map_err(xml_error)?);
+			}
+			Ok(Event::CData(e)) => text.push_str(&String::from_utf8_lossy(&e)),
+			Ok(Event::End(e)) => {
+				let value = String::from(text.trim());
+				match e.local_name().as_ref() {
+					b"error" => {
+						let code = error_code.take();
+						if code.as_deref() != Some(NO_RECORDS_MATCH) {
+							return Err(protocol_error(code, value));
+						}
+					}
+					name @ (b"identifier" | b"datestamp" | b"setSpec") => {
+						if let Some(fields) = header.as_mut() {
+							fields.on_text(name, value);
+						}
+					}
+					b"header" => {
+						if let Some(fields) = header.take() {
+							let fields = fields.into_header()?;
+							match record.as_mut() {
+								Some((slot, _)) => *slot = Some(fields),
+								None => response.headers.push(fields),
+							}
+						}
+					}
+					b"record" => {
+						if let Some((fields, metadata)) = record.take() {
+							let header = fields.ok_or(OaiError::MissingElement("header"))?;
+							response.records.push(OaiRecord { header, metadata });
+						}
+					}
+					b"resumptionToken" if !value.is_empty() => {
+						response.resumption_token = Some(value);
+					}
+					_ => {}
+				}
+				text.clear();
+			}
+			Ok(Event::Eof) => break,
+			Ok(_) => {}
+			Err(e) => return Err(xml_error(e)),
+		}
+	}
+
+	Ok(response)
+}
+
+fn protocol_error(code: Option<String>, message: String) -> OaiError {
+	OaiError::Protocol {
+		code: code.unwrap_or_default(),
+		message,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::TransportFuture;
+	use ::futures_util::StreamExt;
+	use std::str::FromStr;
+	use std::sync::Mutex;
+	use time::macros::date;
+
+	const LIST_RECORDS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+  <responseDate>2024-01-02T00:00:00Z</responseDate>
+  <request verb="ListRecords" metadataPrefix="arXiv" set="cs">http://export.arxiv.org/oai2</request>
+  <ListRecords>
+    <record>
+      <header>
+        <identifier>oai:arXiv.org:0704.0002</identifier>
+        <datestamp>2008-12-13</datestamp>
+        <setSpec>cs</setSpec>
+        <setSpec>math</setSpec>
+      </header>
+      <metadata>
+        <arXiv xmlns="http://arxiv.org/OAI/arXiv/">
+          <id>0704.0002</id>
+          <title>Sparsity-certifying Graph Decompositions</title>
+        </arXiv>
+      </metadata>
+    </record>
+    <record>
+      <header status="deleted">
+        <identifier>oai:arXiv.org:cs/9901001</identifier>
+        <datestamp>2009-01-01</datestamp>
+        <setSpec>cs</setSpec>
+      </header>
+    </record>
+    <resumptionToken cursor="0" completeListSize="3">6960524|1001</resumptionToken>
+  </ListRecords>
+</OAI-PMH>"#;
+
+	const LIST_IDENTIFIERS: &str = r#"<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+  <ListIdentifiers>
+    <header>
+      <identifier>oai:arXiv.org:0704.0001</identifier>
+      <datestamp>2008-11-13</datestamp>
+      <setSpec>physics:hep-ph</setSpec>
+    </header>
+    <resumptionToken cursor="1" completeListSize="1"/>
+  </ListIdentifiers>
+</OAI-PMH>"#;
+
+	#[test]
+	fn parse_list_records() {
+		let response = parse_oai_response(LIST_RECORDS).unwrap();
+		assert_eq!(response.resumption_token.as_deref(), Some("6960524|1001"));
+		assert_eq!(response.records.len(), 2);
+
+		let record = &response.records[0];
+		assert_eq!(record.header.id, ArxivIdentifier::from_str("0704.0002").unwrap());
+		assert_eq!(record.header.datestamp, date!(2008 - 12 - 13));
+		assert_eq!(record.header.sets, ["cs", "math"]);
+		assert!(!record.header.deleted);
+		let metadata = record.metadata.as_deref().unwrap();
+		assert!(metadata.starts_with(r#"<arXiv xmlns="http://arxiv.org/OAI/arXiv/">"#));
+		assert!(metadata.ends_with("</arXiv>"));
+		assert!(matches!(record.arxiv(), Some(Err(OaiError::MissingElement("created")))));
+
+		let record = &response.records[1];
+		assert!(record.header.deleted);
+		assert_eq!(record.header.id.to_string(), "cs/9901001");
+		assert_eq!(record.metadata, None);
+	}
+
+	#[test]
+	fn parse_list_identifiers() {
+		let response = parse_oai_response(LIST_IDENTIFIERS).unwrap();
+		assert!(response.records.is_empty());
+		assert_eq!(response.headers.len(), 1);
+		assert_eq!(response.headers[0].sets, ["physics:hep-ph"]);
+		assert_eq!(response.resumption_token, None);
+	}
+
+	#[test]
+	fn parse_oai_errors() {
+		let error = |code: &str| {
+			format!(
+				r#"<OAI-PMH><error code="{}">No records match the request</error></OAI-PMH>"#,
+				code
+			)
+		};
+		let response = parse_oai_response(&error("noRecordsMatch")).unwrap();
+		assert!(response.records.is_empty() && response.resumption_token.is_none());
+		let response =
+			parse_oai_response(r#"<OAI-PMH><error code="noRecordsMatch"/></OAI-PMH>"#).unwrap();
+		assert!(response.records.is_empty() && response.headers.is_empty());
+		assert_eq!(
+			parse_oai_response(r#"<OAI-PMH><error code="badResumptionToken"/></OAI-PMH>"#)
+				.unwrap_err(),
+			OaiError::Protocol {
+				code: String::from("badResumptionToken"),
+				message: String::new(),
+			}
+		);
+		assert_eq!(
+			parse_oai_response(&error("badArgument")).unwrap_err(),
+			OaiError::Protocol {
+				code: String::from("badArgument"),
+				message: String::from("No records match the request"),
+			}
+		);
+		assert!(matches!(
+			parse_oai_response(&LIST_RECORDS.replace("2008-12-13", "13/12/2008")),
+			Err(OaiError::InvalidDate(_))
+		));
+		assert!(matches!(
+			parse_oai_response(&LIST_RECORDS.replace("oai:arXiv.org:0704", "0704")),
+			Err(OaiError::InvalidId(_))
+		));
+	}
+
+	#[test]
+	fn request_params() {
+		let request = OaiRequest::new(OaiMetadataFormat::ArxivRaw)
+			.category(&ArxivCategoryId::from_str("cs.AI").unwrap())
+			.from(date!(2024 - 01 - 01))
+			.until(date!(2024 - 01 - 31));
+		assert_eq!(
+			encode_params(&request.to_params("ListRecords")),
+			"verb=ListRecords&metadataPrefix=arXivRaw&set=cs&from=2024-01-01&until=2024-01-31"
+		);
+	}
+
+	/// A transport replying with scripted responses, and recording the URLs requested
+	struct Scripted {
+		responses: Mutex<VecDeque<HttpResponse>>,
+		urls: Mutex<Vec<String>>,
+	}
+
+	impl Scripted {
+		fn new(responses: Vec<HttpResponse>) -> OaiHarvester<Self> {
+			let transport = Self {
+				responses: Mutex::new(responses.into()),
+				urls: Mutex::default(),
+			};
+			OaiHarvester::with_transport(transport)
+				.with_rate_limit(Duration::ZERO)
+				.with_retry_policy(RetryPolicy::default().with_initial_backoff(Duration::ZERO))
+		}
+
+		fn urls(&self) -> Vec<String> {
+			self.urls.lock().unwrap().clone()
+		}
+	}
+
+	impl HttpTransport for Scripted {
+		fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+			self.urls.lock().unwrap().push(String::from(url));
+			let response = self.responses.lock().unwrap().pop_front();
+			Box::pin(std::future::ready(response.ok_or_else(|| "no response left".into())))
+		}
+	}
+
+	#[tokio::test]
+	async fn harvest_with_resumption_tokens() {
+		let last = LIST_RECORDS.replace("6960524|1001", "");
+		let harvester = Scripted::new(vec![
+			HttpResponse::new(200, LIST_RECORDS),
+			HttpResponse::new(503, "").with_header("Retry-After", "0"),
+			HttpResponse::new(200, last),
+		]);
+		let request = OaiRequest::new(OaiMetadataFormat::Arxiv).set("cs");
+		let records = harvester.list_records(&request).collect::<Vec<_>>().await;
+		assert_eq!(records.len(), 4);
+		assert!(records.iter().all(Result::is_ok));
+
+		let urls = harvester.transport().urls();
+		assert_eq!(urls.len(), 3);
+		assert_eq!(
+			urls[0],
+			"https://export.arxiv.org/oai2?verb=ListRecords&metadataPrefix=arXiv&set=cs"
+		);
+		assert_eq!(
+			urls[2],
+			"https://export.arxiv.org/oai2?verb=ListRecords&resumptionToken=6960524%7C1001"
+		);
+	}
+
+	#[tokio::test]
+	async fn harvest_identifiers_and_records() {
+		let harvester = Scripted::new(vec![
+			HttpResponse::new(200, LIST_IDENTIFIERS),
+			HttpResponse::new(200, LIST_RECORDS),
+			HttpResponse::new(500, ""),
+		]);
+		let request = OaiRequest::new(OaiMetadataFormat::DublinCore);
+		let headers = harvester
+			.list_identifiers(&request)
+			.collect::<Vec<_>>()
+			.await;
+		assert_eq!(headers.len(), 1);
+
+		let id = ArxivIdentifier::from_str("0704.0002v2").unwrap();
+		let record = harvester
+			.get_record(&id, OaiMetadataFormat::Arxiv)
+			.await
+			.unwrap();
+		assert_eq!(record.header.datestamp, date!(2008 - 12 - 13));
+		assert!(harvester.transport().urls()[1].contains("identifier=oai%3AarXiv.org%3A0704.0002&"));
+		assert!(matches!(
+			harvester.get_record(&id, OaiMetadataFormat::Arxiv).await,
+			Err(ArxivApiError::Status(500))
+		));
+	}
+}
@@ -0,0 +1,131 @@
+use crate::{ArxivArticleMetadata, ArxivAuthor, JournalRef};
+use std::fmt::Write;
+
+impl ArxivArticleMetadata {
+	/// A Hayagriva entry of the article, the YAML bibliography format of Typst, keyed by the
+	/// same citation key as [`ArxivArticleMetadata::to_bibtex`]
+	///
+	/// Articles with a journal reference have a periodical parent with the journal and volume
+	/// found in it, while other articles are of the `preprint` genre. The identifier of the
+	/// article and its DOI, or the DOI registered by arXiv.org, are serial numbers.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(feature = "oai")]
+	/// # {
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivArticleMetadata, ArxivRawMetadata};
+	///
+	/// let xml = r#"<arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+	///   <id>1706.03762</id>
+	///   <version version="v1"><date>Mon, 12 Jun 2017 17:57:34 GMT</date><size>1102kb</size></version>
+	///   <title>Attention Is All You Need</title>
+	///   <authors>Ashish Vaswani, Noam Shazeer</authors>
+	///   <categories>cs.CL cs.LG</categories>
+	///   <abstract>The dominant sequence transduction models...</abstract>
+	/// </arXivRaw>"#;
+	/// let metadata = ArxivArticleMetadata::from(ArxivRawMetadata::from_str(xml).unwrap());
+	///
+	/// assert_eq!(
+	///     metadata.to_hayagriva(),
+	///     r#"vaswani2017attention:
+	///   type: article
+	///   title: "Attention Is All You Need"
+	///   author: ["Vaswani, Ashish", "Shazeer, Noam"]
+	///   date: "2017-06-12"
+	///   genre: "preprint"
+	///   abstract: "The dominant sequence transduction models..."
+	///   url: "https://arxiv.org/abs/1706.03762"
+	///   serial-number:
+	///     arxiv: "1706.03762"
+	///     doi: "10.48550/arXiv.1706.03762"
+	/// "#
+	/// );
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn to_hayagriva(&self) -> String {
+		let journal_ref = self.journal_ref.as_deref().map(JournalRef::parse);
+		let year = self.published_year(journal_ref.as_ref());
+		// the date of publication in a journal is preferred over the date of the preprint
+		let date = match journal_ref
+			.as_ref()
+			.and_then(|journal_ref| journal_ref.year)
+		{
+			Some(year) => year.to_string(),
+			None => self
+				.latest_version()
+				.map_or_else(|| year.to_string(), |version| version.submitted.date().to_string()),
+		};
+		let authors = self
+			.authors
+			.iter()
+			.map(|name| quote(&ArxivAuthor::new(name.as_str()).inverted_name()))
+			.collect::<Vec<_>>();
+
+		let mut entry = format!("{}:\n  type: article\n", self.citation_key(year));
+		let _ = writeln!(entry, "  title: {}", quote(&self.title));
+		let _ = writeln!(entry, "  author: [{}]", authors.join(", "));
+		let _ = writeln!(entry, "  date: {}", quote(&date));
+		match journal_ref {
+			Some(journal_ref) => {
+				if let Some(pages) = &journal_ref.pages {
+					let _ = writeln!(entry, "  page-range: {}", quote(pages));
+				}
+				entry.push_str("  parent:\n    type: periodical\n");
+				let title = journal_ref.journal.as_ref().unwrap_or(&journal_ref.raw);
+				let _ = writeln!(entry, "    title: {}", quote(title));
+				if let Some(volume) = &journal_ref.volume {
+					let _ = writeln!(entry, "    volume: {}", quote(volume));
+				}
+			}
+			None => entry.push_str("  genre: \"preprint\"\n"),
+		}
+		let _ = writeln!(entry, "  abstract: {}", quote(&self.abstract_text));
+		let _ = writeln!(entry, "  url: {}", quote(&self.id.abs_url()));
+
+		entry.push_str("  serial-number:\n");
+		let _ = writeln!(entry, "    arxiv: {}", quote(&self.id.to_bare_string()));
+		if let Some(doi) = self.citation_doi() {
+			let _ = writeln!(entry, "    doi: {}", quote(&doi));
+		}
+		entry
+	}
+}
+
+/// Quotes a value as a double-quoted YAML string, collapsing its whitespace
+fn quote(value: &str) -> String {
+	let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+	format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn article_to_hayagriva() {
+		let mut metadata = ArxivArticleMetadata::fixture();
+		metadata.title = String::from("The \"Large N\" Limit of\n  Superconformal Field Theories");
+		metadata.abstract_text = String::from("We show that \\(N\\) is large...");
+		assert_eq!(
+			metadata.to_hayagriva(),
+			r#"maldacena1998large:
+  type: article
+  title: "The \"Large N\" Limit of Superconformal Field Theories"
+  author: ["Maldacena, Juan M."]
+  date: "1998"
+  page-range: "231-252"
+  parent:
+    type: periodical
+    title: "Adv.Theor.Math.Phys."
+    volume: "2"
+  abstract: "We show that \\(N\\) is large..."
+  url: "https://arxiv.org/abs/hep-th/9711200"
+  serial-number:
+    arxiv: "hep-th/9711200"
+    doi: "10.4310/ATMP.1998.v2.n2.a1"
+"#
+		);
+	}
+}
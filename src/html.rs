@@ -0,0 +1,130 @@
+use crate::{ArxivIdExtractor, ArxivIdentifier};
+use ::scraper::{ElementRef, Html, Node};
+
+/// The elements whose content is not visible text
+const HIDDEN_ELEMENTS: [&str; 5] = ["head", "script", "style", "template", "textarea"];
+
+/// The elements which are laid out within a line of text, so text is not split around them
+const INLINE_ELEMENTS: [&str; 24] = [
+	"a", "abbr", "b", "bdi", "bdo", "cite", "code", "data", "dfn", "em", "i", "kbd", "mark", "q",
+	"s", "samp", "small", "span", "strong", "sub", "sup", "time", "u", "var",
+];
+
+impl ArxivIdExtractor {
+	/// Finds the identifiers of an HTML page, such as a blog post or a forum thread, in the
+	/// links of its anchors and in its visible text, in order of their first occurrence and
+	/// without duplicates
+	///
+	/// The content of scripts, styles and the head of the page is skipped, and text split
+	/// by inline elements, such as `arXiv:<b>2101.00001</b>`, is joined back together.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivIdExtractor;
+	///
+	/// let html = r#"<p>Read <a href="https://arxiv.org/abs/1706.03762">the paper</a>
+	///   (arXiv:<b>1706.03762</b>) and hep-th/9711200.</p>
+	///   <script>track("2101.00001")</script>"#;
+	/// let ids = ArxivIdExtractor::new().extract_from_html(html);
+	/// assert_eq!(ids.iter().map(|id| id.to_bare_string()).collect::<Vec<_>>(), [
+	///     "1706.03762",
+	///     "hep-th/9711200",
+	/// ]);
+	/// ```
+	#[must_use]
+	pub fn extract_from_html(&self, html: &str) -> Vec<ArxivIdentifier> {
+		let document = Html::parse_document(html);
+		let mut text = String::new();
+		let mut links = Vec::new();
+		collect_visible(document.root_element(), &mut text, &mut links);
+
+		let mut found = Vec::new();
+		for (offset, href) in links {
+			found.extend(self.scan(href).map(|(_, id)| (offset, id)));
+		}
+		found.extend(self.scan(&text).map(|(range, id)| (range.start, id)));
+		// links are found before the text of their anchors, which the stable sort preserves
+		found.sort_by_key(|(offset, _)| *offset);
+
+		let mut ids = Vec::new();
+		for (_, id) in found {
+			if !ids.contains(&id) {
+				ids.push(id);
+			}
+		}
+		ids
+	}
+}
+
+/// Collects the visible text of an element, separating the text of block elements with
+/// line breaks, along with the links of its anchors and the offsets of the text at which
+/// they start
+fn collect_visible<'a>(
+	element: ElementRef<'a>,
+	text: &mut String,
+	links: &mut Vec<(usize, &'a str)>,
+) {
+	let name = element.value().name();
+	if HIDDEN_ELEMENTS.contains(&name) {
+		return;
+	}
+	let is_inline = INLINE_ELEMENTS.contains(&name);
+	if !is_inline {
+		text.push('\n');
+	}
+	if name == "a" {
+		links.extend(element.value().attr("href").map(|href| (text.len(), href)));
+	}
+
+	for child in element.children() {
+		if let Some(child) = ElementRef::wrap(child) {
+			collect_visible(child, text, links);
+		} else if let Node::Text(child) = child.value() {
+			text.push_str(child);
+		}
+	}
+	if !is_inline {
+		text.push('\n');
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn extract_from_html_page() {
+		let html = r#"<!DOCTYPE html>
+<html>
+<head><title>arXiv:2101.00009</title><style>.arXiv:after { content: "2101.00008" }</style></head>
+<body>
+  <div>See 2101.</div><div>00001 and <a href="/abs/2101.00002v2">arXiv:2101.00002v2</a></div>
+  <p>Cited as arXiv:<span>2101.00003</span>, <a href="https://example.com/">1706.03762</a>
+  <script>var id = "arXiv:2101.00004";</script>
+  <a href="https://arxiv.org/pdf/hep-th/9711200">hep-th/9711200</a> and
+  <a href="https://arxiv.org/abs/2101.00001">again</a></p>
+</body>
+</html>"#;
+		let ids = |extractor: ArxivIdExtractor| {
+			extractor
+				.extract_from_html(html)
+				.iter()
+				.map(ArxivIdentifier::to_bare_string)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			ids(ArxivIdExtractor::new()),
+			[
+				"2101.00002v2",
+				"2101.00003",
+				"1706.03762",
+				"hep-th/9711200",
+				"2101.00001"
+			]
+		);
+		assert_eq!(
+			ids(ArxivIdExtractor::new().with_bare_ids(false)),
+			["2101.00002v2", "2101.00003", "hep-th/9711200", "2101.00001"]
+		);
+	}
+}
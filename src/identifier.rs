@@ -16,6 +16,8 @@ pub enum ArxivIdError {
 	InvalidYear,
 	/// An invalid year outside of the inclusive [1, 99999] interval
 	InvalidId,
+	/// An invalid version, which must be at least 1
+	InvalidVersion,
 }
 
 impl Error for ArxivIdError {}
@@ -27,6 +29,7 @@ impl Display for ArxivIdError {
 			Self::InvalidMonth => write!(f, "A valid month must be between 1 and 12."),
 			Self::InvalidYear => write!(f, "A valid year must be be between 2007 and 2099."),
 			Self::InvalidId => write!(f, "A valid identifier must be between 1 and 99999."),
+			Self::InvalidVersion => write!(f, "A valid version must be at least 1."),
 		}
 	}
 }
@@ -44,7 +47,7 @@ impl Display for ArxivIdError {
 /// ```
 ///
 /// [arxiv-docs]: https://info.arxiv.org/help/arxiv_identifier.html
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ArxivId {
 	year: u16,
 	month: u8,
@@ -59,7 +62,9 @@ impl ArxivId {
 	pub const MAX_MONTH: u8 = 12u8;
 	pub const MIN_NUM_DIGITS: usize = 4usize;
 	pub const MAX_NUM_DIGITS: usize = 5usize;
+	pub const MIN_VERSION: u8 = 1u8;
 	pub(crate) const TOKEN_COLON: char = ':';
+	pub(crate) const PREFIX: &'static str = "arXiv:";
 	pub(crate) const TOKEN_DOT: char = '.';
 	pub(crate) const TOKEN_VERSION: char = 'v';
 
@@ -135,10 +140,16 @@ impl ArxivId {
 			return Err(ArxivIdError::InvalidMonth);
 		}
 
-		if !(Self::MIN_NUM_DIGITS..=Self::MAX_NUM_DIGITS).contains(&number.len()) {
+		if !(Self::MIN_NUM_DIGITS..=Self::MAX_NUM_DIGITS).contains(&number.len())
+			|| !number.bytes().all(|b| b.is_ascii_digit())
+		{
 			return Err(ArxivIdError::InvalidId);
 		}
 
+		if matches!(version, Some(v) if v < Self::MIN_VERSION) {
+			return Err(ArxivIdError::InvalidVersion);
+		}
+
 		Ok(unsafe { Self::new_unchecked(year, month, number, version) })
 	}
 
@@ -227,19 +238,43 @@ impl ArxivId {
 		self.version
 	}
 
-	/// Sets the version of the arXiv article.
+	/// The identifier without the `arXiv:` prefix, such as `2304.11188v1`, as used in URLs
+	pub(crate) fn to_bare_string(&self) -> String {
+		let mut s = self.to_string();
+		s.drain(..Self::PREFIX.len());
+		s
+	}
+
+	/// Parses an identifier with or without the `arXiv:` prefix, such as `2304.11188v1`
+	pub(crate) fn from_bare_str(s: &str) -> ArxivIdResult {
+		match s.starts_with(Self::PREFIX) {
+			true => Self::from_str(s),
+			false => Self::from_str(&format!("{}{}", Self::PREFIX, s)),
+		}
+	}
+
+	/// Sets the version of the arXiv article, returning an [`ArxivIdError`] if the version is
+	/// invalid, in which case the identifier is left unchanged.
 	///
 	/// # Examples
 	/// ```
 	/// use std::str::FromStr;
-	/// use arxiv::ArxivId;
+	/// use arxiv::{ArxivId, ArxivIdError};
 	///
 	/// let mut id = ArxivId::from_str("arXiv:2001.00001").unwrap();
-	/// id.set_version(1);
+	/// id.set_version(2)?.set_version(3)?;
+	/// assert_eq!(id.version(), Some(3));
+	/// assert_eq!(id.set_version(0), Err(ArxivIdError::InvalidVersion));
+	/// # Ok::<(), ArxivIdError>(())
 	/// ```
 	#[inline]
-	pub fn set_version(&mut self, version: u8) {
+	pub fn set_version(&mut self, version: u8) -> Result<&mut Self, ArxivIdError> {
+		if version < Self::MIN_VERSION {
+			return Err(ArxivIdError::InvalidVersion);
+		}
+
 		self.version = Some(version);
+		Ok(self)
 	}
 
 	/// Sets the version of the arXiv article to the latest version.
@@ -253,20 +288,95 @@ impl ArxivId {
 	/// id.set_latest();
 	/// ```
 	#[inline]
-	pub fn set_latest(&mut self) {
+	pub fn set_latest(&mut self) -> &mut Self {
 		self.version = None;
+		self
+	}
+
+	/// Attempts to repair an identifier that was misread by optical character recognition,
+	/// returning every valid candidate identifier, with the most likely candidate first.
+	///
+	/// OCR frequently confuses digits with similar-looking letters (such as `0` and `O`,
+	/// or `1` and `l`), so every such letter in a position where a digit is expected is
+	/// substituted, and each resulting candidate is validated. A misread `arXiv` prefix
+	/// is normalized, and a missing prefix is added.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let candidates = ArxivId::repair_ocr("arXlv:2O01.OOOl2vl");
+	/// assert_eq!(candidates, vec![ArxivId::from_str("arXiv:2001.00012v1").unwrap()]);
+	/// ```
+	pub fn repair_ocr(s: &str) -> Vec<Self> {
+		let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+		let body = match s.rsplit_once(Self::TOKEN_COLON) {
+			Some((_, body)) => body,
+			None => s.as_str(),
+		};
+
+		// "YYMM.NNNNNvVVV" is the longest possible identifier without its prefix
+		if body.chars().count() > 14 {
+			return Vec::new();
+		}
+
+		// every position has one or more possible characters,
+		// so the candidates are the cartesian product of all positions
+		let mut candidates = vec![String::from("arXiv:")];
+		for c in body.chars() {
+			let choices: &[char] = match c {
+				',' | '·' => &[Self::TOKEN_DOT],
+				'V' => &[Self::TOKEN_VERSION],
+				_ if !c.is_alphabetic() || c == Self::TOKEN_VERSION => &[c],
+				'O' | 'o' | 'D' | 'Q' => &['0'],
+				'l' | 'I' | 'i' | '|' | '!' => &['1'],
+				'Z' | 'z' => &['2'],
+				'S' | 's' => &['5'],
+				'G' | 'b' => &['6'],
+				'B' => &['8', '3'],
+				'g' | 'q' => &['9'],
+				_ => &[c],
+			};
+
+			candidates = candidates
+				.iter()
+				.flat_map(|prefix| {
+					choices.iter().map(move |choice| {
+						let mut candidate = prefix.clone();
+						candidate.push(*choice);
+						candidate
+					})
+				})
+				.collect();
+		}
+
+		let mut repaired: Vec<Self> = Vec::new();
+		for candidate in candidates {
+			if let Ok(id) = Self::from_str(&candidate) {
+				if !repaired.contains(&id) {
+					repaired.push(id);
+				}
+			}
+		}
+
+		repaired
 	}
 }
 
 impl Display for ArxivId {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		let mut binding = self.year.to_string();
-		let (_, half_year) = binding.as_mut_str().split_at(2);
+		let half_year = self.year % 100;
 
 		if self.number.len() == 4usize {
-			write!(f, "arXiv:{:02}{:02}.{:04}", half_year, self.month, self.number)
+			write!(f, "arXiv:{:02}{:02}.{:04}", half_year, self.month, self.number)?;
 		} else {
-			write!(f, "arXiv:{:02}{:02}.{:05}", half_year, self.month, self.number)
+			write!(f, "arXiv:{:02}{:02}.{:05}", half_year, self.month, self.number)?;
+		}
+
+		match self.version {
+			Some(v) => write!(f, "{}{}", Self::TOKEN_VERSION, v),
+			None => Ok(()),
 		}
 	}
 }
@@ -282,7 +392,7 @@ impl FromStr for ArxivId {
 		}
 
 		let inner_parts: Vec<&str> = parts[1].split(ArxivId::TOKEN_DOT).collect();
-		if inner_parts.len() != 2 {
+		if inner_parts.len() != 2 || inner_parts[0].len() != 4 || !inner_parts[0].is_ascii() {
 			return Err(ArxivIdError::Syntax);
 		}
 
@@ -338,6 +448,11 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn parse_arxiv_short_period() {
+		assert_eq!(ArxivId::from_str("arXiv:1.00001"), Err(ArxivIdError::Syntax));
+	}
+
 	#[test]
 	fn parse_arxiv_with_version() {
 		assert_eq!(
@@ -370,6 +485,34 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn arxiv_as_string_with_version() {
+		assert_eq!(
+			unsafe { ArxivId::new_unchecked(2014, 1, String::from("7878"), Some(12)).to_string() },
+			String::from("arXiv:1401.7878v12")
+		);
+	}
+
+	#[test]
+	fn repair_ocr_substitutions() {
+		assert_eq!(
+			ArxivId::repair_ocr("arXiv:l5Ol.OOOOl"),
+			vec![unsafe { ArxivId::new_unchecked_latest(2015, 1, String::from("00001")) }]
+		);
+		assert_eq!(
+			ArxivId::repair_ocr("15O1,0B001"),
+			vec![
+				unsafe { ArxivId::new_unchecked_latest(2015, 1, String::from("08001")) },
+				unsafe { ArxivId::new_unchecked_latest(2015, 1, String::from("03001")) },
+			]
+		);
+	}
+
+	#[test]
+	fn repair_ocr_unrepairable() {
+		assert!(ArxivId::repair_ocr("arXiv:hello").is_empty());
+	}
+
 	#[test]
 	fn parse_arxiv_invalid_year() {
 		assert_eq!(
@@ -386,6 +529,26 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn parse_arxiv_invalid_version() {
+		assert_eq!(
+			ArxivId::try_new(2007, 11, String::from("00001"), Some(0)),
+			Err(ArxivIdError::InvalidVersion)
+		);
+	}
+
+	#[test]
+	fn set_invalid_version() {
+		let mut id = ArxivId::try_new(2007, 11, String::from("00001"), Some(2)).unwrap();
+		assert_eq!(id.set_version(0), Err(ArxivIdError::InvalidVersion));
+		assert_eq!(id.version(), Some(2));
+	}
+
+	#[test]
+	fn parse_arxiv_non_digit_id() {
+		assert_eq!(ArxivId::from_str("arXiv:2001.0000a"), Err(ArxivIdError::InvalidId));
+	}
+
 	#[test]
 	fn parse_arxiv_invalid_id() {
 		assert_eq!(ArxivId::try_latest(2007, 11, String::new()), Err(ArxivIdError::InvalidId))
@@ -0,0 +1,199 @@
+use crate::links::{arxiv_url_path, ARXIV_ORIGIN};
+use crate::{ArxivId, ArxivIdError, ArxivIdOld, ArxivIdScheme};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// An identifier of either scheme used by arXiv.org, as found in sources that cover the whole
+/// history of arXiv.org, such as search results of the export API
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivIdentifier, ArxivIdScheme};
+///
+/// let id = ArxivIdentifier::from_str("hep-th/9901001v2").unwrap();
+/// assert_eq!(id.scheme(), ArxivIdScheme::Old);
+/// assert_eq!(id.version(), Some(2));
+///
+/// let id = ArxivIdentifier::from_str("2101.00001").unwrap();
+/// assert_eq!(id.to_string(), "arXiv:2101.00001");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ArxivIdentifier {
+	/// An identifier of the scheme used up to March 2007
+	Old(ArxivIdOld),
+	/// An identifier of the scheme used since April 2007
+	New(ArxivId),
+}
+
+impl ArxivIdentifier {
+	/// The scheme of the identifier
+	#[must_use]
+	#[inline]
+	pub const fn scheme(&self) -> ArxivIdScheme {
+		match self {
+			Self::Old(_) => ArxivIdScheme::Old,
+			Self::New(_) => ArxivIdScheme::New,
+		}
+	}
+
+	/// The version of the arXiv publication, if any
+	#[must_use]
+	#[inline]
+	pub const fn version(&self) -> Option<u8> {
+		match self {
+			Self::Old(id) => id.version(),
+			Self::New(id) => id.version(),
+		}
+	}
+
+	/// Sets the version of the arXiv article to the latest version.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivIdentifier;
+	///
+	/// let mut id = ArxivIdentifier::from_str("hep-th/9901001v2").unwrap();
+	/// assert_eq!(id.set_latest().to_string(), "hep-th/9901001");
+	/// ```
+	#[inline]
+	pub fn set_latest(&mut self) -> &mut Self {
+		match self {
+			Self::Old(id) => {
+				id.set_latest();
+			}
+			Self::New(id) => {
+				id.set_latest();
+			}
+		}
+		self
+	}
+
+	/// The identifier of the new scheme, if it is one
+	#[must_use]
+	#[inline]
+	pub const fn as_new(&self) -> Option<&ArxivId> {
+		match self {
+			Self::Old(_) => None,
+			Self::New(id) => Some(id),
+		}
+	}
+
+	/// The identifier of the old scheme, if it is one
+	#[must_use]
+	#[inline]
+	pub const fn as_old(&self) -> Option<&ArxivIdOld> {
+		match self {
+			Self::Old(id) => Some(id),
+			Self::New(_) => None,
+		}
+	}
+
+	/// The identifier without the `arXiv:` prefix, such as `2101.00001v1` or `hep-th/9901001`
+	#[must_use]
+	pub fn to_bare_string(&self) -> String {
+		match self {
+			Self::Old(id) => id.to_string(),
+			Self::New(id) => id.to_bare_string(),
+		}
+	}
+
+	/// The canonical URL of the abstract page, which includes the version if present
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivIdentifier;
+	///
+	/// let id = ArxivIdentifier::from_str("math.AG/0601001").unwrap();
+	/// assert_eq!(id.abs_url(), "https://arxiv.org/abs/math.AG/0601001");
+	/// ```
+	#[must_use]
+	pub fn abs_url(&self) -> String {
+		format!("{}/abs/{}", ARXIV_ORIGIN, self.to_bare_string())
+	}
+
+	/// Parses the identifier of an abstract page of arXiv.org of either scheme, such as
+	/// `http://arxiv.org/abs/hep-th/9901001v1`, returning an [`ArxivIdError::Syntax`] error if
+	/// the URL is not such a link.
+	///
+	/// See also: [`ArxivId::from_url`]
+	pub fn from_abs_url(url: &str) -> Result<Self, ArxivIdError> {
+		arxiv_url_path(url)
+			.and_then(|path| path.strip_prefix("abs/"))
+			.ok_or(ArxivIdError::Syntax)
+			.and_then(Self::from_str)
+	}
+}
+
+impl Display for ArxivIdentifier {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Old(id) => id.fmt(f),
+			Self::New(id) => id.fmt(f),
+		}
+	}
+}
+
+impl FromStr for ArxivIdentifier {
+	type Err = ArxivIdError;
+
+	/// Parses an identifier of either scheme, with or without the `arXiv:` prefix. Identifiers
+	/// with a slash are parsed with the old scheme, and the others with the new scheme.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.contains(ArxivIdOld::TOKEN_SLASH) {
+			true => ArxivIdOld::from_str(s).map(Self::Old),
+			false => ArxivId::from_bare_str(s).map(Self::New),
+		}
+	}
+}
+
+impl From<ArxivId> for ArxivIdentifier {
+	fn from(id: ArxivId) -> Self {
+		Self::New(id)
+	}
+}
+
+impl From<ArxivIdOld> for ArxivIdentifier {
+	fn from(id: ArxivIdOld) -> Self {
+		Self::Old(id)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_identifier_of_either_scheme() {
+		let id = ArxivIdentifier::from_str("arXiv:2101.00001v1").unwrap();
+		assert_eq!(id.as_new(), ArxivId::from_str("arXiv:2101.00001v1").ok().as_ref());
+		assert_eq!(id.to_bare_string(), "2101.00001v1");
+
+		let id = ArxivIdentifier::from_str("arXiv:hep-th/9901001").unwrap();
+		assert_eq!(id.as_old().map(|id| id.archive()), Some("hep-th"));
+		assert_eq!(id.to_string(), "hep-th/9901001");
+	}
+
+	#[test]
+	fn parse_identifier_invalid() {
+		assert_eq!(ArxivIdentifier::from_str(""), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivIdentifier::from_str("2113.00001"), Err(ArxivIdError::InvalidMonth));
+		assert_eq!(ArxivIdentifier::from_str("hep-th/9913001"), Err(ArxivIdError::InvalidMonth));
+	}
+
+	#[test]
+	fn parse_abs_url() {
+		let id = ArxivIdentifier::from_abs_url("http://arxiv.org/abs/cond-mat/0703999v2").unwrap();
+		assert_eq!(id.version(), Some(2));
+		assert_eq!(
+			ArxivIdentifier::from_abs_url("http://arxiv.org/abs/2101.00001v1"),
+			ArxivIdentifier::from_str("2101.00001v1")
+		);
+		assert_eq!(
+			ArxivIdentifier::from_abs_url("https://example.com/abs/2101.00001"),
+			Err(ArxivIdError::Syntax)
+		);
+	}
+}
@@ -0,0 +1,262 @@
+use crate::{ArxivCategoryId, ArxivId, ArxivIdError, ArxivIdScheme};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// Convenient type alias for a [`Result`] holding either an [`ArxivIdOld`] or [`ArxivIdError`]
+pub type ArxivIdOldResult = Result<ArxivIdOld, ArxivIdError>;
+
+/// A unique identifier of the scheme used by arXiv.org up to March 2007, such as
+/// `hep-th/9901001` or `math.AG/0601001`
+///
+/// The archive of an old identifier is kept as written, since it may be an archive that has
+/// since been merged into another one (such as `solv-int` or `alg-geom`).
+///
+/// See also: [`ArxivIdScheme::Old`], [Official arXiv.org documentation][arxiv-docs]
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::ArxivIdOld;
+///
+/// let id = ArxivIdOld::from_str("hep-th/9901001v2").unwrap();
+/// assert_eq!(id.archive(), "hep-th");
+/// assert_eq!(id.year(), 1999);
+/// assert_eq!(id.number(), 1);
+/// assert_eq!(id.version(), Some(2));
+/// ```
+///
+/// [arxiv-docs]: https://info.arxiv.org/help/arxiv_identifier.html#identifiers-up-to-march-2007-9107-0703
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArxivIdOld {
+	archive: String,
+	subject: Option<String>,
+	year: u16,
+	month: u8,
+	number: u16,
+	version: Option<u8>,
+}
+
+impl ArxivIdOld {
+	/// The year and month of the first identifier of the old scheme
+	pub const FIRST: (u16, u8) = (1991, 7);
+	/// The year and month of the last identifier of the old scheme
+	pub const LAST: (u16, u8) = (2007, 3);
+	pub const MIN_NUMBER: u16 = 1u16;
+	pub const MAX_NUMBER: u16 = 999u16;
+	pub(crate) const TOKEN_SLASH: char = '/';
+
+	/// The scheme of the identifier
+	pub const SCHEME: ArxivIdScheme = ArxivIdScheme::Old;
+
+	/// The archive of the arXiv publication, such as `hep-th`
+	#[must_use]
+	#[inline]
+	pub fn archive(&self) -> &str {
+		&self.archive
+	}
+
+	/// The subject class of the arXiv publication, if any, such as `AG` in `math.AG/0601001`
+	#[must_use]
+	#[inline]
+	pub fn subject_class(&self) -> Option<&str> {
+		self.subject.as_deref()
+	}
+
+	/// The category of the identifier, or [`None`] if the archive is not part of the
+	/// category taxonomy anymore
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ArxivIdOld};
+	///
+	/// let id = ArxivIdOld::from_str("math.AG/0601001").unwrap();
+	/// assert_eq!(id.category(), ArxivCategoryId::from_str("math.AG").ok());
+	/// assert_eq!(ArxivIdOld::from_str("solv-int/9901001").unwrap().category(), None);
+	/// ```
+	#[must_use]
+	pub fn category(&self) -> Option<ArxivCategoryId> {
+		match &self.subject {
+			Some(subject) => ArxivCategoryId::from_str(&format!(
+				"{}{}{}",
+				self.archive,
+				ArxivCategoryId::TOKEN_DELIM,
+				subject
+			)),
+			None => ArxivCategoryId::from_str(&self.archive),
+		}
+		.ok()
+	}
+
+	/// The year the arXiv publication was published in
+	#[must_use]
+	#[inline]
+	pub const fn year(&self) -> u16 {
+		self.year
+	}
+
+	/// The month the arXiv publication was published in
+	#[must_use]
+	#[inline]
+	pub const fn month(&self) -> u8 {
+		self.month
+	}
+
+	/// The sequence number of the arXiv publication within its archive and month
+	#[must_use]
+	#[inline]
+	pub const fn number(&self) -> u16 {
+		self.number
+	}
+
+	/// The version of the arXiv publication, if any
+	#[must_use]
+	#[inline]
+	pub const fn version(&self) -> Option<u8> {
+		self.version
+	}
+
+	/// Whether or not the identifier refers to the most recent version of the arXiv article
+	#[must_use]
+	#[inline]
+	pub const fn is_latest(&self) -> bool {
+		self.version.is_none()
+	}
+
+	/// Sets the version of the arXiv article to the latest version.
+	#[inline]
+	pub fn set_latest(&mut self) -> &mut Self {
+		self.version = None;
+		self
+	}
+}
+
+impl Display for ArxivIdOld {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{}", self.archive)?;
+		if let Some(subject) = &self.subject {
+			write!(f, "{}{}", ArxivCategoryId::TOKEN_DELIM, subject)?;
+		}
+		write!(
+			f,
+			"{}{:02}{:02}{:03}",
+			Self::TOKEN_SLASH,
+			self.year % 100,
+			self.month,
+			self.number
+		)?;
+
+		match self.version {
+			Some(v) => write!(f, "{}{}", ArxivId::TOKEN_VERSION, v),
+			None => Ok(()),
+		}
+	}
+}
+
+impl FromStr for ArxivIdOld {
+	type Err = ArxivIdError;
+
+	/// Parses an old identifier, with or without the `arXiv:` prefix.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.strip_prefix(ArxivId::PREFIX).unwrap_or(s);
+		let (archive, rest) = s
+			.split_once(Self::TOKEN_SLASH)
+			.ok_or(ArxivIdError::Syntax)?;
+		let (archive, subject) = match archive.split_once(ArxivCategoryId::TOKEN_DELIM) {
+			Some((archive, subject)) => (archive, Some(subject)),
+			None => (archive, None),
+		};
+
+		if archive.is_empty()
+			|| archive.starts_with('-')
+			|| archive.ends_with('-')
+			|| !archive.bytes().all(|b| b.is_ascii_lowercase() || b == b'-')
+			|| matches!(subject, Some(s) if s.is_empty() || !s.bytes().all(|b| b.is_ascii_alphanumeric()))
+		{
+			return Err(ArxivIdError::Syntax);
+		}
+
+		let (digits, version) = match rest.split_once(ArxivId::TOKEN_VERSION) {
+			Some((digits, version)) => (digits, Some(version)),
+			None => (rest, None),
+		};
+		if digits.len() != 7 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+			return Err(ArxivIdError::Syntax);
+		}
+
+		let year = digits[0..2]
+			.parse::<u16>()
+			.map_err(|_| ArxivIdError::Syntax)?;
+		let year = if year >= Self::FIRST.0 % 100 {
+			1900 + year
+		} else {
+			2000 + year
+		};
+		let month = digits[2..4]
+			.parse::<u8>()
+			.map_err(|_| ArxivIdError::Syntax)?;
+		let number = digits[4..7]
+			.parse::<u16>()
+			.map_err(|_| ArxivIdError::Syntax)?;
+
+		if !(ArxivId::MIN_MONTH..=ArxivId::MAX_MONTH).contains(&month) {
+			return Err(ArxivIdError::InvalidMonth);
+		}
+		if !(Self::FIRST..=Self::LAST).contains(&(year, month)) {
+			return Err(ArxivIdError::InvalidYear);
+		}
+		if !(Self::MIN_NUMBER..=Self::MAX_NUMBER).contains(&number) {
+			return Err(ArxivIdError::InvalidId);
+		}
+
+		let version = match version {
+			Some(v) => match v.parse::<u8>() {
+				Ok(v) if v >= ArxivId::MIN_VERSION => Some(v),
+				_ => return Err(ArxivIdError::InvalidVersion),
+			},
+			None => None,
+		};
+
+		Ok(Self {
+			archive: String::from(archive),
+			subject: subject.map(String::from),
+			year,
+			month,
+			number,
+			version,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_old_id() {
+		let id = ArxivIdOld::from_str("arXiv:math.AG/0601001").unwrap();
+		assert_eq!(id.archive(), "math");
+		assert_eq!(id.subject_class(), Some("AG"));
+		assert_eq!((id.year(), id.month(), id.number()), (2006, 1, 1));
+		assert_eq!(id.to_string(), "math.AG/0601001");
+	}
+
+	#[test]
+	fn old_id_round_trip() {
+		for s in ["hep-th/9107001", "cond-mat/0703999v12", "solv-int/9901001"] {
+			assert_eq!(ArxivIdOld::from_str(s).map(|id| id.to_string()), Ok(String::from(s)));
+		}
+	}
+
+	#[test]
+	fn parse_old_id_invalid() {
+		assert_eq!(ArxivIdOld::from_str("hep-th9901001"), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivIdOld::from_str("HEP-TH/9901001"), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivIdOld::from_str("hep-th/990100"), Err(ArxivIdError::Syntax));
+		assert_eq!(ArxivIdOld::from_str("hep-th/9913001"), Err(ArxivIdError::InvalidMonth));
+		assert_eq!(ArxivIdOld::from_str("hep-th/9106001"), Err(ArxivIdError::InvalidYear));
+		assert_eq!(ArxivIdOld::from_str("hep-th/0704001"), Err(ArxivIdError::InvalidYear));
+		assert_eq!(ArxivIdOld::from_str("hep-th/9901000"), Err(ArxivIdError::InvalidId));
+		assert_eq!(ArxivIdOld::from_str("hep-th/9901001v0"), Err(ArxivIdError::InvalidVersion));
+	}
+}
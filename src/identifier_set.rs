@@ -0,0 +1,261 @@
+use crate::ArxivIdentifier;
+use std::collections::BTreeMap;
+
+/// The version of a paper which an [`ArxivIdSet`] keeps when it is added more than once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VersionPolicy {
+	/// Keeps the highest version seen, such as `2101.00001v3` over `2101.00001v2`
+	#[default]
+	Highest,
+	/// Keeps the lowest version seen, such as `2101.00001v2` over `2101.00001v3`
+	Lowest,
+	/// Keeps no version, so that every identifier refers to the latest version
+	Unversioned,
+}
+
+/// A set of identifiers of either scheme, deduplicated by paper, such as to merge the
+/// references of an article found in several sources
+///
+/// Identifiers of the same paper with different versions, such as `2101.00001v1` and
+/// `2101.00001v2`, are kept once with the version chosen by the [`VersionPolicy`]. An
+/// identifier with a version is kept over one without, which refers to the latest version
+/// instead of a version that was seen. Identifiers are iterated in order.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivIdentifier, ArxivIdSet};
+///
+/// fn ids(s: &str) -> ArxivIdSet {
+///     s.split(' ').map(|id| ArxivIdentifier::from_str(id).unwrap()).collect()
+/// }
+///
+/// let crossref = ids("2101.00001v1 hep-th/9711200 1706.03762");
+/// let semantic_scholar = ids("2101.00001v3 1706.03762v7");
+///
+/// let merged = crossref.union(&semantic_scholar);
+/// assert_eq!(merged.len(), 3);
+/// assert_eq!(merged.to_bare_strings(), ["hep-th/9711200", "1706.03762v7", "2101.00001v3"]);
+/// assert_eq!(crossref.difference(&semantic_scholar).len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ArxivIdSet {
+	policy: VersionPolicy,
+	ids: BTreeMap<ArxivIdentifier, ArxivIdentifier>,
+}
+
+impl ArxivIdSet {
+	/// Creates an empty set, which keeps the highest version of each paper
+	#[must_use]
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the version which is kept of a paper added more than once, where the versions of
+	/// the identifiers already in the set are removed for [`VersionPolicy::Unversioned`]
+	#[must_use]
+	pub fn with_policy(mut self, policy: VersionPolicy) -> Self {
+		self.policy = policy;
+		let ids = std::mem::take(&mut self.ids);
+		self.extend(ids.into_values());
+		self
+	}
+
+	/// The version which is kept of a paper added more than once
+	#[must_use]
+	#[inline]
+	pub const fn policy(&self) -> VersionPolicy {
+		self.policy
+	}
+
+	/// The number of papers in the set
+	#[must_use]
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.ids.len()
+	}
+
+	/// Whether or not the set contains no paper
+	#[must_use]
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.ids.is_empty()
+	}
+
+	/// The identifiers of the set, in order of their papers
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = &ArxivIdentifier> + '_ {
+		self.ids.values()
+	}
+
+	/// The identifiers of the set without the `arXiv:` prefix (see
+	/// [`ArxivIdentifier::to_bare_string`]), in order of their papers
+	#[must_use]
+	pub fn to_bare_strings(&self) -> Vec<String> {
+		self.iter().map(ArxivIdentifier::to_bare_string).collect()
+	}
+
+	/// Whether or not the set contains the paper of the identifier, in any version
+	#[must_use]
+	pub fn contains(&self, id: &ArxivIdentifier) -> bool {
+		self.ids.contains_key(&paper(id))
+	}
+
+	/// The identifier kept in the set for the paper of the identifier, if any
+	#[must_use]
+	pub fn get(&self, id: &ArxivIdentifier) -> Option<&ArxivIdentifier> {
+		self.ids.get(&paper(id))
+	}
+
+	/// Adds an identifier, returning whether or not its paper was not already in the set.
+	/// The version kept of a paper already in the set is chosen by the [`VersionPolicy`].
+	pub fn insert(&mut self, mut id: ArxivIdentifier) -> bool {
+		if self.policy == VersionPolicy::Unversioned {
+			id.set_latest();
+		}
+		match self.ids.get_mut(&paper(&id)) {
+			Some(kept) => {
+				let replace = match (kept.version(), id.version()) {
+					(None, Some(_)) => true,
+					(Some(kept), Some(version)) => match self.policy {
+						VersionPolicy::Highest => version > kept,
+						VersionPolicy::Lowest => version < kept,
+						VersionPolicy::Unversioned => false,
+					},
+					_ => false,
+				};
+				if replace {
+					*kept = id;
+				}
+				false
+			}
+			None => {
+				self.ids.insert(paper(&id), id);
+				true
+			}
+		}
+	}
+
+	/// Removes the paper of the identifier, in any version, returning the identifier that
+	/// was kept for it
+	pub fn remove(&mut self, id: &ArxivIdentifier) -> Option<ArxivIdentifier> {
+		self.ids.remove(&paper(id))
+	}
+
+	/// The papers of either set, with the versions chosen by the policy of this set
+	#[must_use]
+	pub fn union(&self, other: &Self) -> Self {
+		let mut union = self.clone();
+		union.extend(other.iter().cloned());
+		union
+	}
+
+	/// The papers of both sets, with the versions chosen by the policy of this set
+	#[must_use]
+	pub fn intersection(&self, other: &Self) -> Self {
+		let mut intersection = Self::new().with_policy(self.policy);
+		for id in self.iter().filter(|id| other.contains(id)) {
+			intersection.insert(id.clone());
+			intersection.extend(other.get(id).cloned());
+		}
+		intersection
+	}
+
+	/// The papers of this set which are not in the other set, in any version
+	#[must_use]
+	pub fn difference(&self, other: &Self) -> Self {
+		Self {
+			policy: self.policy,
+			ids: self
+				.ids
+				.iter()
+				.filter(|(paper, _)| !other.ids.contains_key(paper))
+				.map(|(paper, id)| (paper.clone(), id.clone()))
+				.collect(),
+		}
+	}
+}
+
+impl Extend<ArxivIdentifier> for ArxivIdSet {
+	fn extend<I: IntoIterator<Item = ArxivIdentifier>>(&mut self, iter: I) {
+		for id in iter {
+			self.insert(id);
+		}
+	}
+}
+
+impl FromIterator<ArxivIdentifier> for ArxivIdSet {
+	fn from_iter<I: IntoIterator<Item = ArxivIdentifier>>(iter: I) -> Self {
+		let mut set = Self::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl IntoIterator for ArxivIdSet {
+	type Item = ArxivIdentifier;
+	type IntoIter = std::collections::btree_map::IntoValues<ArxivIdentifier, ArxivIdentifier>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.ids.into_values()
+	}
+}
+
+/// The identifier of the paper of an identifier, without its version
+fn paper(id: &ArxivIdentifier) -> ArxivIdentifier {
+	let mut paper = id.clone();
+	paper.set_latest();
+	paper
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	fn ids(s: &str) -> impl Iterator<Item = ArxivIdentifier> + '_ {
+		s.split(' ')
+			.map(|id| ArxivIdentifier::from_str(id).unwrap())
+	}
+
+	#[test]
+	fn dedup_by_paper() {
+		let input = "2101.00001v2 2101.00001 2101.00001v3 2101.00001v1 math/0601001 math/0601001v2";
+		let mut set = ids(input).collect::<ArxivIdSet>();
+		assert_eq!(set.to_bare_strings(), ["math/0601001v2", "2101.00001v3"]);
+		assert!(set.contains(&ArxivIdentifier::from_str("2101.00001v9").unwrap()));
+		assert!(!set.insert(ArxivIdentifier::from_str("2101.00001").unwrap()));
+		assert!(set.insert(ArxivIdentifier::from_str("2101.00002").unwrap()));
+
+		let set = set.with_policy(VersionPolicy::Lowest);
+		assert_eq!(set.len(), 3);
+		let mut lowest = ArxivIdSet::new().with_policy(VersionPolicy::Lowest);
+		lowest.extend(ids(input));
+		assert_eq!(lowest.to_bare_strings(), ["math/0601001v2", "2101.00001v1"]);
+
+		let unversioned = ArxivIdSet::new()
+			.with_policy(VersionPolicy::Unversioned)
+			.union(&lowest);
+		assert_eq!(unversioned.to_bare_strings(), ["math/0601001", "2101.00001"]);
+	}
+
+	#[test]
+	fn set_operations() {
+		let a = ids("2101.00001v1 2101.00002v2 2101.00003").collect::<ArxivIdSet>();
+		let b = ids("2101.00002v1 2101.00003v4 2101.00004").collect::<ArxivIdSet>();
+		assert_eq!(
+			a.union(&b).to_bare_strings(),
+			["2101.00001v1", "2101.00002v2", "2101.00003v4", "2101.00004"]
+		);
+		assert_eq!(a.intersection(&b).to_bare_strings(), ["2101.00002v2", "2101.00003v4"]);
+		assert_eq!(a.difference(&b).to_bare_strings(), ["2101.00001v1"]);
+
+		let mut a = a;
+		assert_eq!(
+			a.remove(&ArxivIdentifier::from_str("2101.00002").unwrap()),
+			ArxivIdentifier::from_str("2101.00002v2").ok()
+		);
+		assert_eq!(a.into_iter().count(), 2);
+	}
+}
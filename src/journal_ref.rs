@@ -0,0 +1,241 @@
+use crate::metadata::collapse_whitespace;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The earliest and latest years accepted as years of publication
+const YEARS: std::ops::RangeInclusive<u16> = 1900..=2099;
+
+/// A journal reference of an article, such as `Phys. Rev. D 103, 014001 (2021)`, with the
+/// journal, volume, pages and year found in it
+///
+/// Journal references are free text written by the authors, so their parts are found on a
+/// best-effort basis: the year is a year in parentheses or ending the reference, the volume
+/// is the first number following the name of the journal, and the pages follow the volume.
+/// The parts which cannot be found are left empty, while the whole reference is always kept.
+///
+/// # Examples
+/// ```
+/// use arxiv::JournalRef;
+///
+/// let journal_ref = JournalRef::parse("Phys. Rev. D 103, 014001 (2021)");
+/// assert_eq!(journal_ref.journal.as_deref(), Some("Phys. Rev. D"));
+/// assert_eq!(journal_ref.volume.as_deref(), Some("103"));
+/// assert_eq!(journal_ref.pages.as_deref(), Some("014001"));
+/// assert_eq!(journal_ref.year, Some(2021));
+///
+/// let journal_ref = JournalRef::parse("To appear in the proceedings");
+/// assert!(!journal_ref.is_structured());
+/// assert_eq!(journal_ref.to_string(), "To appear in the proceedings");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct JournalRef {
+	/// The journal reference as written, with its whitespace collapsed
+	pub raw: String,
+	/// The abbreviated name of the journal, such as `Phys. Rev. D`, if found
+	pub journal: Option<String>,
+	/// The volume of the journal, such as `103` or `0802`, if found
+	pub volume: Option<String>,
+	/// The pages or article number of the article, such as `436-444` or `014001`, if found
+	pub pages: Option<String>,
+	/// The year of publication, if found
+	pub year: Option<u16>,
+}
+
+impl JournalRef {
+	/// Parses a journal reference, finding as many of its parts as possible
+	#[must_use]
+	pub fn parse(raw: &str) -> Self {
+		let raw = collapse_whitespace(raw);
+		let (year, rest) = match find_year(&raw) {
+			Some((year, start, end)) => (Some(year), format!("{} {}", &raw[..start], &raw[end..])),
+			None => (None, raw.clone()),
+		};
+
+		let (journal, volume, pages) = match find_volume(&rest) {
+			Some((start, end)) => {
+				let journal = trim_journal(&rest[..start]);
+				(Some(journal), Some(String::from(&rest[start..end])), find_pages(&rest[end..]))
+			}
+			None => (None, None, None),
+		};
+		Self {
+			raw,
+			journal,
+			volume,
+			pages,
+			year,
+		}
+	}
+
+	/// Whether the journal and volume of the reference were found
+	#[must_use]
+	pub fn is_structured(&self) -> bool {
+		self.journal.is_some() && self.volume.is_some()
+	}
+}
+
+impl From<&str> for JournalRef {
+	fn from(raw: &str) -> Self {
+		Self::parse(raw)
+	}
+}
+
+impl Display for JournalRef {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.raw)
+	}
+}
+
+/// Finds the year of publication, preferably in parentheses such as `(2021)`, or otherwise
+/// ending the reference such as in `JHEP 0802:012,2008`, returning it with its span
+fn find_year(s: &str) -> Option<(u16, usize, usize)> {
+	let years = digit_runs(s)
+		.filter(|&(start, end)| end - start == 4)
+		.filter_map(|(start, end)| {
+			let year = s[start..end]
+				.parse()
+				.ok()
+				.filter(|year| YEARS.contains(year))?;
+			Some((year, start, end))
+		})
+		.collect::<Vec<_>>();
+
+	let parenthesized = years
+		.iter()
+		.find(|&&(_, start, end)| s[..start].ends_with('(') && s[end..].starts_with(')'));
+	if let Some(&(year, start, end)) = parenthesized {
+		return Some((year, start - 1, end + 1));
+	}
+	years.last().copied().filter(|&(_, start, end)| {
+		let before = s[..start].trim_end();
+		let ending = s[end..]
+			.trim_matches(|c: char| c == '.' || c.is_whitespace())
+			.is_empty();
+		ending && (before.ends_with(',') || s[..start].ends_with(' '))
+	})
+}
+
+/// Finds the volume, which is the first number preceded by the name of the journal
+fn find_volume(s: &str) -> Option<(usize, usize)> {
+	digit_runs(s).find(|&(start, _)| {
+		let journal = trim_journal(&s[..start]);
+		journal.chars().any(char::is_alphabetic)
+	})
+}
+
+/// Finds the pages following the volume, such as in `, 436-444`, skipping an issue number in
+/// parentheses such as in `521(7553), 436`
+fn find_pages(s: &str) -> Option<String> {
+	let mut s = s.trim_start();
+	if s.starts_with('(') {
+		let issue = s.find(')')?;
+		s = &s[issue + 1..];
+	}
+	let s = s.trim_start_matches(|c: char| c == ',' || c == ':' || c == ';' || c.is_whitespace());
+	let s = ["pp.", "p.", "pages", "page"]
+		.iter()
+		.find_map(|prefix| s.strip_prefix(prefix))
+		.unwrap_or(s);
+	let pages = s
+		.trim_start()
+		.split(|c: char| c == ',' || c == ';' || c == '(' || c.is_whitespace())
+		.next()?
+		.trim_end_matches('.');
+	let valid = pages.chars().any(|c| c.is_ascii_digit())
+		&& pages
+			.chars()
+			.all(|c| c.is_alphanumeric() || c == '-' || c == '\u{2013}');
+	match valid {
+		true => Some(String::from(pages)),
+		false => None,
+	}
+}
+
+/// Trims the name of a journal from the separators and volume markers preceding the volume
+fn trim_journal(s: &str) -> String {
+	let s = s.trim_end_matches(|c: char| c == ',' || c == ':' || c.is_whitespace());
+	let s = ["Vol.", "vol.", "Volume", "volume", "Vol", "vol"]
+		.iter()
+		.find_map(|marker| s.strip_suffix(marker))
+		.unwrap_or(s);
+	String::from(s.trim_end_matches(|c: char| c == ',' || c.is_whitespace()))
+}
+
+/// The spans of the runs of ASCII digits of a string
+fn digit_runs(s: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+	let bytes = s.as_bytes();
+	(0..bytes.len())
+		.filter(move |&i| bytes[i].is_ascii_digit() && (i == 0 || !bytes[i - 1].is_ascii_digit()))
+		.map(move |start| {
+			let len = bytes[start..]
+				.iter()
+				.take_while(|b| b.is_ascii_digit())
+				.count();
+			(start, start + len)
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The journal, volume, pages and year of a reference
+	type Parts = (Option<String>, Option<String>, Option<String>, Option<u16>);
+
+	fn parts(raw: &str) -> Parts {
+		let journal_ref = JournalRef::parse(raw);
+		(journal_ref.journal, journal_ref.volume, journal_ref.pages, journal_ref.year)
+	}
+
+	fn some(journal: &str, volume: &str, pages: Option<&str>, year: Option<u16>) -> Parts {
+		(Some(String::from(journal)), Some(String::from(volume)), pages.map(String::from), year)
+	}
+
+	#[test]
+	fn parse_journal_refs() {
+		let cases = [
+			("Phys.Rev.D76:013009,2007", some("Phys.Rev.D", "76", Some("013009"), Some(2007))),
+			(
+				"J. Math. Phys. 48 (2007) 092102",
+				some("J. Math. Phys.", "48", Some("092102"), Some(2007)),
+			),
+			(
+				"Nature 521(7553), 436-444 (2015)",
+				some("Nature", "521", Some("436-444"), Some(2015)),
+			),
+			("JHEP 0802:012,2008", some("JHEP", "0802", Some("012"), Some(2008))),
+			(
+				"Astron. Astrophys. 595, A1 (2016)",
+				some("Astron. Astrophys.", "595", Some("A1"), Some(2016)),
+			),
+			(
+				"J. Phys. A: Math. Theor. 41 (2008) 235204",
+				some("J. Phys. A: Math. Theor.", "41", Some("235204"), Some(2008)),
+			),
+			(
+				"Graphs and Combinatorics, Vol. 25, pp. 213-230",
+				some("Graphs and Combinatorics", "25", Some("213-230"), None),
+			),
+			(
+				"Phys. Rev. Lett. 116,\n  061102",
+				some("Phys. Rev. Lett.", "116", Some("061102"), None),
+			),
+		];
+		for (raw, expected) in cases {
+			assert_eq!(parts(raw), expected, "{}", raw);
+		}
+	}
+
+	#[test]
+	fn parse_journal_refs_partially() {
+		let journal_ref = JournalRef::parse("Proceedings of NeurIPS 2024");
+		assert_eq!(journal_ref.year, Some(2024));
+		assert_eq!(journal_ref.journal, None);
+		assert!(!journal_ref.is_structured());
+
+		assert_eq!(parts("Accepted"), (None, None, None, None));
+		assert_eq!(parts(""), (None, None, None, None));
+		assert_eq!(JournalRef::from(" Nature  521 ").raw, "Nature 521");
+		assert_eq!(parts("Nature 521"), some("Nature", "521", None, None));
+	}
+}
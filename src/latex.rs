@@ -0,0 +1,200 @@
+use crate::doi::strip_prefix_ignore_case;
+use crate::{ArxivIdExtractor, ArxivIdMatch, ArxivIdentifier};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// An arXiv reference found in a LaTeX file by [`scan_latex_dir`] or [`scan_latex_file`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivLatexRef {
+	/// The path of the file
+	pub path: PathBuf,
+	/// The identifier referenced
+	pub id: ArxivIdentifier,
+	/// The line of the reference, starting at 1
+	pub line: usize,
+	/// The column of the reference in characters, starting at 1
+	pub column: usize,
+}
+
+/// Finds the arXiv references in LaTeX source, such as a `.tex` document or the `.bbl`
+/// bibliography generated by BibTeX, along with their lines and columns
+///
+/// References are the identifiers of `\eprint{...}` macros, identifiers mentioned with
+/// arXiv (such as `arXiv:2101.00001`), and links to arxiv.org, while other numbers are not
+/// taken as identifiers (see [`ArxivIdExtractor::with_bare_ids`]). Comments are ignored.
+///
+/// # Examples
+/// ```
+/// let bbl = r"\bibitem{vaswani}
+/// A.~Vaswani et al., \emph{Attention Is All You Need}, \eprint{1706.03762}.
+/// % \bibitem{draft} arXiv:2101.00001
+/// \bibitem{maldacena}
+/// J.~Maldacena, \url{https://arxiv.org/abs/hep-th/9711200}.";
+/// let refs = arxiv::scan_latex(bbl);
+/// assert_eq!(refs.len(), 2);
+/// assert_eq!((refs[0].line, refs[0].column), (2, 62));
+/// assert_eq!(refs[1].id.to_string(), "hep-th/9711200");
+/// ```
+pub fn scan_latex(text: &str) -> Vec<ArxivIdMatch> {
+	let extractor = ArxivIdExtractor::new().with_bare_ids(false);
+	let mut refs = Vec::new();
+	for (i, line) in text.lines().enumerate() {
+		let line = strip_comment(line);
+		let mut found = extractor.scan(line).collect::<Vec<_>>();
+		for (range, id) in eprints(line) {
+			if !found.iter().any(|(other, _)| range.contains(&other.start)) {
+				found.push((range, id));
+			}
+		}
+		found.sort_by_key(|(range, _)| range.start);
+
+		refs.extend(found.into_iter().map(|(range, id)| ArxivIdMatch {
+			id,
+			line: i + 1,
+			column: line[..range.start].chars().count() + 1,
+		}));
+	}
+	refs
+}
+
+/// Finds the arXiv references in a LaTeX file (see [`scan_latex`]), where text which is not
+/// valid UTF-8 is read with its invalid bytes replaced
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn scan_latex_file(path: impl AsRef<Path>) -> io::Result<Vec<ArxivLatexRef>> {
+	let path = path.as_ref();
+	let bytes = fs::read(path)?;
+	Ok(scan_latex(&String::from_utf8_lossy(&bytes))
+		.into_iter()
+		.map(|found| ArxivLatexRef {
+			path: path.to_path_buf(),
+			id: found.id,
+			line: found.line,
+			column: found.column,
+		})
+		.collect())
+}
+
+/// Finds the arXiv references in the `.tex` and `.bbl` files of a directory and of its
+/// subdirectories (see [`scan_latex`]), such as to check the bibliography of a paper before
+/// submitting it
+///
+/// Files are scanned in the order of their paths.
+///
+/// # Errors
+/// Returns an error if the directory or one of its files cannot be read.
+pub fn scan_latex_dir(dir: impl AsRef<Path>) -> io::Result<Vec<ArxivLatexRef>> {
+	let mut paths = Vec::new();
+	collect_latex_files(dir.as_ref(), &mut paths)?;
+	paths.sort();
+
+	let mut refs = Vec::new();
+	for path in paths {
+		refs.extend(scan_latex_file(path)?);
+	}
+	Ok(refs)
+}
+
+/// Collects the paths of the `.tex` and `.bbl` files of a directory, recursively
+fn collect_latex_files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+		if path.is_dir() {
+			collect_latex_files(&path, paths)?;
+		} else if path
+			.extension()
+			.and_then(|extension| extension.to_str())
+			.map_or(false, |extension| {
+				extension.eq_ignore_ascii_case("tex") || extension.eq_ignore_ascii_case("bbl")
+			}) {
+			paths.push(path);
+		}
+	}
+	Ok(())
+}
+
+/// Removes the comment of a line, starting at a `%` which is not escaped as `\%`
+fn strip_comment(line: &str) -> &str {
+	let mut escaped = false;
+	for (i, byte) in line.bytes().enumerate() {
+		match byte {
+			b'%' if !escaped => return &line[..i],
+			b'\\' => escaped = !escaped,
+			_ => escaped = false,
+		}
+	}
+	line
+}
+
+/// The identifiers of the `\eprint{...}` macros of a line, with the byte ranges of their
+/// arguments
+fn eprints(line: &str) -> Vec<(std::ops::Range<usize>, ArxivIdentifier)> {
+	const MACRO: &str = "\\eprint{";
+
+	let mut found = Vec::new();
+	let mut from = 0usize;
+	while let Some(offset) = line[from..].find(MACRO) {
+		let start = from + offset + MACRO.len();
+		let end = match line[start..].find('}') {
+			Some(len) => start + len,
+			None => break,
+		};
+		let argument = line[start..end].trim();
+		let id = strip_prefix_ignore_case(argument, "arXiv:").unwrap_or(argument);
+		if let Ok(id) = ArxivIdentifier::from_str(id) {
+			found.push((start..end, id));
+		}
+		from = end;
+	}
+	found
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scan_latex_source() {
+		let tex = "As shown in~\\cite{vaswani} (arXiv:1706.03762), 100\\% of 2101.00001 % arXiv:2101.00002
+\\bibitem{a} \\eprint{arXiv:2101.00003} \\eprint{hep-th/9711200} \\eprint{2101.0000x}
+\\bibitem{b} \\href{https://arxiv.org/abs/2101.00004v2}{arXiv:2101.00004v2}";
+		assert_eq!(
+			scan_latex(tex)
+				.into_iter()
+				.map(|found| (found.line, found.column, found.id.to_bare_string()))
+				.collect::<Vec<_>>(),
+			[
+				(1, 29, String::from("1706.03762")),
+				(2, 21, String::from("2101.00003")),
+				(2, 47, String::from("hep-th/9711200")),
+				(3, 41, String::from("2101.00004v2")),
+				(3, 55, String::from("2101.00004v2")),
+			]
+		);
+	}
+
+	#[test]
+	fn scan_latex_files_of_dir() {
+		let dir = std::env::temp_dir().join(format!("arxiv-latex-{}", std::process::id()));
+		fs::create_dir_all(dir.join("sections")).unwrap();
+		fs::write(dir.join("main.bbl"), "\\eprint{1706.03762}\n").unwrap();
+		fs::write(dir.join("sections/intro.tex"), b"caf\xe9\n\nsee arXiv:2101.00001\n").unwrap();
+		fs::write(dir.join("notes.txt"), "arXiv:2101.00002").unwrap();
+
+		let refs = scan_latex_dir(&dir).unwrap();
+		assert_eq!(
+			refs.iter()
+				.map(|found| (found.path.strip_prefix(&dir).unwrap(), found.line))
+				.collect::<Vec<_>>(),
+			[
+				(Path::new("main.bbl"), 1),
+				(Path::new("sections/intro.tex"), 3)
+			]
+		);
+		fs::remove_dir_all(dir).unwrap();
+	}
+}
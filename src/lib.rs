@@ -1,12 +1,171 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "scrape")]
+mod abs_page;
+mod announcement;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(any(feature = "atom", feature = "scrape"))]
+mod article;
+#[cfg(feature = "atom")]
+mod atom;
+mod author;
+mod bibcode;
+mod bibtex;
+#[cfg(feature = "blocking")]
+mod blocking;
+#[cfg(feature = "bson")]
+mod bson;
 mod category;
+mod category_set;
+mod citation;
+#[cfg(feature = "clap")]
+mod clap;
+mod classification;
+#[cfg(feature = "client")]
+mod client;
+mod comments;
+mod compare;
+pub mod compat;
+#[cfg(any(test, feature = "conformance"))]
+pub mod conformance;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "datacite")]
+mod datacite;
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+mod datetime;
+mod doi;
+mod email;
+mod encoding;
+mod extract;
+#[cfg(feature = "test-util")]
+pub mod fixtures;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "client")]
+mod harvest;
+mod hayagriva;
+#[cfg(feature = "scrape")]
+mod html;
 mod identifier;
+mod identifier_any;
+mod identifier_old;
+mod identifier_set;
+mod journal_ref;
+mod latex;
+mod linkify;
+mod links;
+#[cfg(feature = "scrape")]
+mod listing;
+#[cfg(feature = "manifest")]
+mod manifest;
+mod metadata;
+#[cfg(feature = "test-util")]
+mod mock;
+mod oai;
+#[cfg(feature = "oai")]
+mod oai_metadata;
+mod param;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod pattern;
+#[cfg(feature = "pdf")]
+mod pdf;
+#[cfg(feature = "client")]
+mod query;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "redis")]
+mod redis;
+mod report_number;
+#[cfg(feature = "client")]
+mod retry;
+mod ris;
+#[cfg(feature = "rss")]
+mod rss;
+mod schedule;
+#[cfg(feature = "schemars")]
+mod schemars;
+mod scholarly;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+#[cfg(feature = "sqlx")]
+mod sqlx;
 mod stamp;
-mod subject_tables;
+mod subject;
+mod taxonomy;
+#[cfg(feature = "client")]
+mod transport;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+pub use crate::announcement::*;
+#[cfg(feature = "arrow")]
+pub use crate::arrow::*;
+#[cfg(any(feature = "atom", feature = "scrape"))]
+pub use crate::article::*;
+#[cfg(feature = "atom")]
+pub use crate::atom::*;
+pub use crate::author::*;
+pub use crate::bibtex::*;
+#[cfg(feature = "blocking")]
+pub use crate::blocking::*;
 pub use crate::category::*;
+pub use crate::category_set::*;
+pub use crate::citation::*;
+#[cfg(feature = "clap")]
+pub use crate::clap::*;
+pub use crate::classification::*;
+#[cfg(feature = "client")]
+pub use crate::client::*;
+pub use crate::comments::*;
+pub use crate::compare::*;
+#[cfg(feature = "datacite")]
+pub use crate::datacite::*;
+pub use crate::doi::*;
+pub use crate::email::*;
+pub use crate::extract::*;
+#[cfg(feature = "client")]
+pub use crate::harvest::*;
 pub use crate::identifier::*;
+pub use crate::identifier_any::*;
+pub use crate::identifier_old::*;
+pub use crate::identifier_set::*;
+pub use crate::journal_ref::*;
+pub use crate::latex::*;
+pub use crate::linkify::*;
+pub use crate::links::*;
+#[cfg(feature = "scrape")]
+pub use crate::listing::*;
+#[cfg(feature = "manifest")]
+pub use crate::manifest::*;
+pub use crate::metadata::*;
+#[cfg(feature = "test-util")]
+pub use crate::mock::*;
+#[cfg(feature = "oai")]
+pub use crate::oai_metadata::*;
+pub use crate::param::*;
+#[cfg(feature = "pdf")]
+pub use crate::pdf::*;
+#[cfg(feature = "client")]
+pub use crate::query::*;
+#[cfg(feature = "rayon")]
+pub use crate::rayon::*;
+pub use crate::report_number::*;
+#[cfg(feature = "client")]
+pub use crate::retry::RetryPolicy;
+#[cfg(feature = "rss")]
+pub use crate::rss::*;
+pub use crate::schedule::*;
+pub use crate::scholarly::*;
+#[cfg(feature = "snapshot")]
+pub use crate::snapshot::*;
 pub use crate::stamp::*;
+pub use crate::subject::*;
+pub use crate::taxonomy::*;
+#[cfg(feature = "client")]
+pub use crate::transport::*;
 
 /// Represents the versioned grammar that defines an arXiv identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,198 @@
+use crate::ArxivIdExtractor;
+
+/// The template of [`ArxivLinkifier::markdown`]
+const MARKDOWN_TEMPLATE: &str = "[{text}]({url})";
+
+/// The template of [`ArxivLinkifier::html`]
+const HTML_TEMPLATE: &str = "<a href=\"{url}\">{text}</a>";
+
+/// A rewriter of the identifiers mentioned in text into links to their abstract pages, such
+/// as for the messages of chat bots or the pages of static site generators
+///
+/// Links are written with a template, where `{text}` is replaced by the text of the
+/// identifier (including an `arXiv:` prefix), `{id}` by the identifier without its prefix,
+/// and `{url}` by the URL of its abstract page. Identifiers which are already part of a URL,
+/// of the text of a Markdown or HTML link, or of a code span are left as they are.
+///
+/// # Examples
+/// ```
+/// use arxiv::ArxivLinkifier;
+///
+/// let text = "See arXiv:1706.03762v7 and [hep-th/9711200](https://arxiv.org/abs/hep-th/9711200).";
+/// assert_eq!(
+///     ArxivLinkifier::markdown().linkify(text),
+///     "See [arXiv:1706.03762v7](https://arxiv.org/abs/1706.03762v7) and \
+///      [hep-th/9711200](https://arxiv.org/abs/hep-th/9711200)."
+/// );
+///
+/// let linkifier = ArxivLinkifier::with_template("{{< arxiv \"{id}\" >}}");
+/// assert_eq!(linkifier.linkify("See 1706.03762."), "See {{< arxiv \"1706.03762\" >}}.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArxivLinkifier {
+	template: String,
+	extractor: ArxivIdExtractor,
+}
+
+impl ArxivLinkifier {
+	/// Creates a rewriter into Markdown links, such as
+	/// `[arXiv:2101.00001](https://arxiv.org/abs/2101.00001)`
+	#[must_use]
+	pub fn markdown() -> Self {
+		Self::with_template(MARKDOWN_TEMPLATE)
+	}
+
+	/// Creates a rewriter into HTML links, such as
+	/// `<a href="https://arxiv.org/abs/2101.00001">arXiv:2101.00001</a>`
+	#[must_use]
+	pub fn html() -> Self {
+		Self::with_template(HTML_TEMPLATE)
+	}
+
+	/// Creates a rewriter into links written with a template of `{text}`, `{id}` and `{url}`
+	#[must_use]
+	pub fn with_template(template: impl Into<String>) -> Self {
+		Self {
+			template: template.into(),
+			extractor: ArxivIdExtractor::new(),
+		}
+	}
+
+	/// Sets the extractor which finds the identifiers, such as to only rewrite identifiers
+	/// mentioned with arXiv (see [`ArxivIdExtractor::with_bare_ids`])
+	#[must_use]
+	#[inline]
+	pub fn with_extractor(mut self, extractor: ArxivIdExtractor) -> Self {
+		self.extractor = extractor;
+		self
+	}
+
+	/// The template of the links
+	#[must_use]
+	#[inline]
+	pub fn template(&self) -> &str {
+		&self.template
+	}
+
+	/// The extractor which finds the identifiers
+	#[must_use]
+	#[inline]
+	pub const fn extractor(&self) -> ArxivIdExtractor {
+		self.extractor
+	}
+
+	/// Rewrites the identifiers of the text into links
+	#[must_use]
+	pub fn linkify(&self, text: &str) -> String {
+		let mut linked = String::with_capacity(text.len());
+		let mut copied = 0usize;
+		for (range, id) in self.extractor.scan(text) {
+			if is_linked(&text[..range.start], &text[range.end..]) {
+				continue;
+			}
+			linked.push_str(&text[copied..range.start]);
+			linked.push_str(
+				&self
+					.template
+					.replace("{text}", &text[range.clone()])
+					.replace("{id}", &id.to_bare_string())
+					.replace("{url}", &id.abs_url()),
+			);
+			copied = range.end;
+		}
+		linked.push_str(&text[copied..]);
+		linked
+	}
+}
+
+impl Default for ArxivLinkifier {
+	fn default() -> Self {
+		Self::markdown()
+	}
+}
+
+/// Whether or not an identifier between the text before and after it is already part of a
+/// URL, of the text of a link, or of a code span
+fn is_linked(before: &str, after: &str) -> bool {
+	let word = before
+		.rsplit(|c: char| c.is_whitespace() || ['(', '<', '"', '\''].contains(&c))
+		.next()
+		.unwrap_or_default();
+	word.contains('/')
+		|| word.contains('=')
+		|| before.ends_with('`')
+		|| after.starts_with('`')
+		|| after.starts_with(']')
+		|| after.starts_with("</a>")
+		|| in_code_span(before)
+		|| in_markdown_link(before, after)
+		|| in_html_link(before)
+}
+
+/// Whether or not the text before a position leaves a code span open, with an odd number of
+/// backticks
+fn in_code_span(before: &str) -> bool {
+	before.matches('`').count() % 2 == 1
+}
+
+/// Whether or not a position is within the text of a Markdown link, after an opening bracket
+/// which is closed after it by `](`
+fn in_markdown_link(before: &str, after: &str) -> bool {
+	let opened = before
+		.rfind('[')
+		.map_or(false, |open| !before[open..].contains(']'));
+	let closed = after.find(']').map_or(false, |close| {
+		!after[..close].contains('[') && after[close + 1..].starts_with('(')
+	});
+	opened && closed
+}
+
+/// Whether or not the text before a position leaves an HTML link open
+fn in_html_link(before: &str) -> bool {
+	before
+		.rfind("<a ")
+		.map_or(false, |open| !before[open..].contains("</a>"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn linkify_text() {
+		let text =
+			"Compare 2101.00001, <a href=\"https://arxiv.org/abs/2101.00002\">2101.00002</a>, \
+		            `2101.00003`, /abs/2101.00004 and ?id=2101.00005 with (math.AG/0601001v2).";
+		assert_eq!(
+			ArxivLinkifier::html().linkify(text),
+			"Compare <a href=\"https://arxiv.org/abs/2101.00001\">2101.00001</a>, \
+			 <a href=\"https://arxiv.org/abs/2101.00002\">2101.00002</a>, `2101.00003`, \
+			 /abs/2101.00004 and ?id=2101.00005 with \
+			 (<a href=\"https://arxiv.org/abs/math.AG/0601001v2\">math.AG/0601001v2</a>)."
+		);
+
+		let linkifier =
+			ArxivLinkifier::default().with_extractor(ArxivIdExtractor::new().with_bare_ids(false));
+		assert_eq!(
+			linkifier.linkify("2101.00001 or arXiv 2101.00002"),
+			"2101.00001 or arXiv [2101.00002](https://arxiv.org/abs/2101.00002)"
+		);
+		assert_eq!(linkifier.template(), "[{text}]({url})");
+	}
+
+	#[test]
+	fn linkify_skips_code_spans_and_links() {
+		let text = "`see 2101.00001 here`, [see 2101.00002 here](https://example.org), \
+		            <a href=\"https://example.org\">see 2101.00003 here</a> and [2101.00004 draft]";
+		assert_eq!(
+			ArxivLinkifier::markdown().linkify(text),
+			"`see 2101.00001 here`, [see 2101.00002 here](https://example.org), \
+			 <a href=\"https://example.org\">see 2101.00003 here</a> and \
+			 [[2101.00004](https://arxiv.org/abs/2101.00004) draft]"
+		);
+		assert_eq!(
+			ArxivLinkifier::markdown().linkify("`code` then 2101.00005"),
+			"`code` then [2101.00005](https://arxiv.org/abs/2101.00005)"
+		);
+	}
+}
@@ -0,0 +1,556 @@
+use crate::{ArxivCategoryId, ArxivCategoryIdError, ArxivId, ArxivIdError, ArxivIdResult};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The origin of the canonical URLs of arXiv.org
+pub(crate) const ARXIV_ORIGIN: &str = "https://arxiv.org";
+
+/// The origin of ar5iv, which renders articles of arXiv.org as HTML
+const AR5IV_ORIGIN: &str = "https://ar5iv.labs.arxiv.org";
+
+/// The origin of alphaXiv, which hosts discussions of articles of arXiv.org
+const ALPHAXIV_ORIGIN: &str = "https://www.alphaxiv.org";
+
+/// Hostnames of arXiv.org other than its subdomains, including historical hostnames
+const ARXIV_HOSTS: &[&str] = &["arxiv.org", "xxx.lanl.gov"];
+
+/// Checks if the hostname belongs to arXiv.org, which includes its subdomains (such as
+/// `export.arxiv.org` or mirrors like `de.arxiv.org`) and historical hostnames
+/// (such as `xxx.lanl.gov`), ignoring case and an explicit port
+pub(crate) fn is_arxiv_host(host: &str) -> bool {
+	let host = host.split(':').next().unwrap_or_default();
+	ARXIV_HOSTS.iter().any(|h| {
+		host.eq_ignore_ascii_case(h)
+			|| (host.len() > h.len()
+				&& host.as_bytes()[host.len() - h.len() - 1] == b'.'
+				&& host[host.len() - h.len()..].eq_ignore_ascii_case(h))
+	})
+}
+
+/// Splits a URL of arXiv.org into its path (without the leading slash), ignoring the scheme,
+/// query and fragment, or returns [`None`] if the URL does not belong to arXiv.org
+pub(crate) fn arxiv_url_path(url: &str) -> Option<&str> {
+	let url = url.trim();
+	let url = url
+		.strip_prefix("https://")
+		.or_else(|| url.strip_prefix("http://"))
+		.unwrap_or(url);
+	let url = url.split(['?', '#']).next().unwrap_or_default();
+	let (host, path) = url.split_once('/')?;
+
+	match is_arxiv_host(host) {
+		true => Some(path.trim_end_matches('/')),
+		false => None,
+	}
+}
+
+impl ArxivId {
+	/// The canonical URL of the abstract page, which includes the version if present
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+	/// assert_eq!(id.abs_url(), "https://arxiv.org/abs/2304.11188v1");
+	/// ```
+	#[must_use]
+	pub fn abs_url(&self) -> String {
+		self.url(ArxivEndpoint::Abs)
+	}
+
+	/// The canonical URL of the PDF, which includes the version if present
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2304.11188").unwrap();
+	/// assert_eq!(id.pdf_url(), "https://arxiv.org/pdf/2304.11188");
+	/// ```
+	#[must_use]
+	pub fn pdf_url(&self) -> String {
+		self.url(ArxivEndpoint::Pdf)
+	}
+
+	/// The canonical URL of the source files, which includes the version if present
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2304.11188v2").unwrap();
+	/// assert_eq!(id.src_url(), "https://arxiv.org/src/2304.11188v2");
+	/// ```
+	#[must_use]
+	pub fn src_url(&self) -> String {
+		self.url(ArxivEndpoint::Src)
+	}
+
+	/// The canonical URL of the HTML rendering, which includes the version if present
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+	/// assert_eq!(id.html_url(), "https://arxiv.org/html/2304.11188v1");
+	/// ```
+	#[must_use]
+	pub fn html_url(&self) -> String {
+		self.url(ArxivEndpoint::Html)
+	}
+
+	/// The URL of the HTML rendering by ar5iv, which does not include the version,
+	/// since ar5iv only renders the latest version of an article
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:1910.06709v2").unwrap();
+	/// assert_eq!(id.ar5iv_url(), "https://ar5iv.labs.arxiv.org/html/1910.06709");
+	/// ```
+	#[must_use]
+	pub fn ar5iv_url(&self) -> String {
+		let mut article = self.clone();
+		article.set_latest();
+		format!("{}/html/{}", AR5IV_ORIGIN, article.to_bare_string())
+	}
+
+	/// The URL of the article on alphaXiv, which includes the version if present
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+	/// assert_eq!(id.alphaxiv_url(), "https://www.alphaxiv.org/abs/2304.11188v1");
+	/// ```
+	#[must_use]
+	pub fn alphaxiv_url(&self) -> String {
+		format!("{}/abs/{}", ALPHAXIV_ORIGIN, self.to_bare_string())
+	}
+
+	/// Parses the identifier of an abstract, PDF, source or HTML link of arXiv.org, returning an
+	/// [`ArxivIdError::Syntax`] error if the URL is not such a link.
+	///
+	/// Besides `arxiv.org`, links of its subdomains (such as `export.arxiv.org`, `www.arxiv.org`
+	/// or mirrors like `de.arxiv.org`) and historical hostnames (such as `xxx.lanl.gov`) are
+	/// recognized, with or without a scheme.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let expected = ArxivId::from_str("arXiv:2304.11188v1");
+	/// assert_eq!(ArxivId::from_url("https://arxiv.org/abs/2304.11188v1"), expected);
+	/// assert_eq!(ArxivId::from_url("http://export.arxiv.org/pdf/2304.11188v1.pdf"), expected);
+	/// assert_eq!(ArxivId::from_url("xxx.lanl.gov/abs/2304.11188v1"), expected);
+	/// ```
+	///
+	/// See also: [`ArxivUrl`]
+	pub fn from_url(url: &str) -> ArxivIdResult {
+		match ArxivUrl::from_str(url) {
+			Ok(ArxivUrl::Article(id, _)) => Ok(id),
+			Err(ArxivUrlError::InvalidArxivId(e)) => Err(e),
+			_ => Err(ArxivIdError::Syntax),
+		}
+	}
+
+	fn url(&self, endpoint: ArxivEndpoint) -> String {
+		format!("{}/{}/{}", ARXIV_ORIGIN, endpoint.as_str(), self.to_bare_string())
+	}
+}
+
+impl ArxivCategoryId {
+	/// The canonical URL of a listing page of the category
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ListingKind};
+	///
+	/// let category = ArxivCategoryId::from_str("cs.LG").unwrap();
+	/// assert_eq!(category.listing_url(ListingKind::New), "https://arxiv.org/list/cs.LG/new");
+	/// assert_eq!(
+	///     category.listing_url(ListingKind::Month(2024, 3)),
+	///     "https://arxiv.org/list/cs.LG/2403"
+	/// );
+	/// ```
+	#[must_use]
+	pub fn listing_url(&self, kind: ListingKind) -> String {
+		ArxivUrl::Listing(*self, kind).to_string()
+	}
+}
+
+/// An error that can occur when parsing URLs of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArxivUrlError {
+	/// The URL does not belong to arXiv.org, or is not a link to an article or listing page
+	Syntax,
+	/// The identifier of the article is invalid
+	InvalidArxivId(ArxivIdError),
+	/// The category of the listing page is invalid
+	InvalidCategory(ArxivCategoryIdError),
+}
+
+impl Error for ArxivUrlError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Syntax => None,
+			Self::InvalidArxivId(e) => Some(e),
+			Self::InvalidCategory(e) => Some(e),
+		}
+	}
+}
+
+impl Display for ArxivUrlError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Syntax => {
+				write!(f, "The URL is not a link to an article or listing page of arXiv.org.")
+			}
+			Self::InvalidArxivId(e) => write!(f, "The URL has an invalid identifier: {}", e),
+			Self::InvalidCategory(e) => write!(f, "The URL has an invalid category: {}", e),
+		}
+	}
+}
+
+/// A page of an article on arXiv.org
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArxivEndpoint {
+	/// The abstract page, such as `https://arxiv.org/abs/2101.00001`
+	Abs,
+	/// The PDF, such as `https://arxiv.org/pdf/2101.00001`
+	Pdf,
+	/// The source files, such as `https://arxiv.org/src/2101.00001`
+	Src,
+	/// The HTML rendering, such as `https://arxiv.org/html/2101.00001`
+	Html,
+	/// The trackbacks, such as `https://arxiv.org/tb/2101.00001`
+	Trackback,
+}
+
+impl ArxivEndpoint {
+	/// The first segment of the path of the endpoint, such as `abs`
+	#[must_use]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Abs => "abs",
+			Self::Pdf => "pdf",
+			Self::Src => "src",
+			Self::Html => "html",
+			Self::Trackback => "tb",
+		}
+	}
+}
+
+/// A kind of listing page of a category on arXiv.org
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListingKind {
+	/// The new submissions of the last announcement, such as `https://arxiv.org/list/cs.LG/new`
+	New,
+	/// The submissions of the last 5 announcements, such as `https://arxiv.org/list/cs.LG/recent`
+	Recent,
+	/// The submissions of a year and month, such as `https://arxiv.org/list/cs.LG/2403`
+	Month(u16, u8),
+}
+
+impl Display for ListingKind {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::New => write!(f, "new"),
+			Self::Recent => write!(f, "recent"),
+			Self::Month(year, month) => write!(f, "{:02}{:02}", year % 100, month),
+		}
+	}
+}
+
+impl FromStr for ListingKind {
+	type Err = ArxivUrlError;
+
+	/// Parses a kind of listing, where months are either in the `YYMM` or `YYYY-MM` form
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (year, month) = match s {
+			"new" => return Ok(Self::New),
+			"recent" | "pastweek" => return Ok(Self::Recent),
+			_ if s.len() == 4 && s.bytes().all(|b| b.is_ascii_digit()) => {
+				(s[..2].parse::<u16>().map(expand_year), &s[2..])
+			}
+			_ => match s.split_once('-') {
+				Some((year, month)) if year.len() == 4 => (year.parse::<u16>(), month),
+				_ => return Err(ArxivUrlError::Syntax),
+			},
+		};
+
+		match (year, month.parse::<u8>()) {
+			(Ok(year), Ok(month))
+				if s.bytes().all(|b| b.is_ascii_digit() || b == b'-')
+					&& (1..=12).contains(&month) =>
+			{
+				Ok(Self::Month(year, month))
+			}
+			_ => Err(ArxivUrlError::Syntax),
+		}
+	}
+}
+
+/// Expands a 2-digit year of arXiv, which started accepting submissions in 1991
+pub(crate) const fn expand_year(year: u16) -> u16 {
+	match year {
+		91..=99 => 1900 + year,
+		_ => 2000 + year,
+	}
+}
+
+/// A structured link to a page of arXiv.org, either for an article or a listing of a category
+///
+/// Links are parsed from any hostname of arXiv.org (see [`ArxivId::from_url`]), with or
+/// without a scheme, and are displayed as canonical `https://arxiv.org` URLs.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivEndpoint, ArxivId, ArxivUrl, ListingKind};
+///
+/// let url = ArxivUrl::from_str("http://export.arxiv.org/pdf/2101.00001v1.pdf").unwrap();
+/// assert_eq!(url.id(), ArxivId::from_str("arXiv:2101.00001v1").ok().as_ref());
+/// assert_eq!(url.endpoint(), Some(ArxivEndpoint::Pdf));
+/// assert_eq!(url.to_string(), "https://arxiv.org/pdf/2101.00001v1");
+///
+/// let url = ArxivUrl::from_str("https://arxiv.org/list/cs.LG/2024-03").unwrap();
+/// assert!(matches!(url, ArxivUrl::Listing(_, ListingKind::Month(2024, 3))));
+/// assert_eq!(url.to_string(), "https://arxiv.org/list/cs.LG/2403");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArxivUrl {
+	/// A page of an article
+	Article(ArxivId, ArxivEndpoint),
+	/// A listing page of a category
+	Listing(ArxivCategoryId, ListingKind),
+}
+
+impl ArxivUrl {
+	/// The identifier of the article, if the link is a page of an article
+	#[must_use]
+	pub const fn id(&self) -> Option<&ArxivId> {
+		match self {
+			Self::Article(id, _) => Some(id),
+			Self::Listing(..) => None,
+		}
+	}
+
+	/// The endpoint, if the link is a page of an article
+	#[must_use]
+	pub const fn endpoint(&self) -> Option<ArxivEndpoint> {
+		match self {
+			Self::Article(_, endpoint) => Some(*endpoint),
+			Self::Listing(..) => None,
+		}
+	}
+
+	/// The category, if the link is a listing page
+	#[must_use]
+	pub const fn category(&self) -> Option<&ArxivCategoryId> {
+		match self {
+			Self::Article(..) => None,
+			Self::Listing(category, _) => Some(category),
+		}
+	}
+}
+
+impl Display for ArxivUrl {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Article(id, endpoint) => {
+				write!(f, "{}/{}/{}", ARXIV_ORIGIN, endpoint.as_str(), id.to_bare_string())
+			}
+			Self::Listing(category, kind) => {
+				write!(f, "{}/list/{}/{}", ARXIV_ORIGIN, category, kind)
+			}
+		}
+	}
+}
+
+impl FromStr for ArxivUrl {
+	type Err = ArxivUrlError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let path = arxiv_url_path(s).ok_or(ArxivUrlError::Syntax)?;
+		let (first, rest) = path.split_once('/').ok_or(ArxivUrlError::Syntax)?;
+		let (endpoint, id) = match first {
+			"abs" => (ArxivEndpoint::Abs, rest),
+			"pdf" => (ArxivEndpoint::Pdf, rest.strip_suffix(".pdf").unwrap_or(rest)),
+			"src" | "e-print" => (ArxivEndpoint::Src, rest),
+			"html" => (ArxivEndpoint::Html, rest),
+			"tb" => (ArxivEndpoint::Trackback, rest),
+			"list" => {
+				let (category, kind) = rest.split_once('/').ok_or(ArxivUrlError::Syntax)?;
+				let category =
+					ArxivCategoryId::from_str(category).map_err(ArxivUrlError::InvalidCategory)?;
+				return Ok(Self::Listing(category, ListingKind::from_str(kind)?));
+			}
+			_ => return Err(ArxivUrlError::Syntax),
+		};
+
+		ArxivId::from_str(&format!("{}{}", ArxivId::PREFIX, id))
+			.map(|id| Self::Article(id, endpoint))
+			.map_err(ArxivUrlError::InvalidArxivId)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn urls_of_four_digit_identifier() {
+		let id = ArxivId::from_str("arXiv:0704.0001").unwrap();
+		assert_eq!(id.abs_url(), "https://arxiv.org/abs/0704.0001");
+		assert_eq!(id.pdf_url(), "https://arxiv.org/pdf/0704.0001");
+		assert_eq!(id.src_url(), "https://arxiv.org/src/0704.0001");
+		assert_eq!(id.html_url(), "https://arxiv.org/html/0704.0001");
+		assert_eq!(id.ar5iv_url(), "https://ar5iv.labs.arxiv.org/html/0704.0001");
+		assert_eq!(id.alphaxiv_url(), "https://www.alphaxiv.org/abs/0704.0001");
+	}
+
+	#[test]
+	fn listing_urls() {
+		let category = ArxivCategoryId::from_str("hep-th").unwrap();
+		assert_eq!(
+			category.listing_url(ListingKind::Recent),
+			"https://arxiv.org/list/hep-th/recent"
+		);
+		assert_eq!(
+			category.listing_url(ListingKind::Month(1999, 1)),
+			"https://arxiv.org/list/hep-th/9901"
+		);
+
+		assert_eq!(ListingKind::from_str("9901"), Ok(ListingKind::Month(1999, 1)));
+		assert_eq!(ListingKind::from_str("2024-02"), Ok(ListingKind::Month(2024, 2)));
+		for kind in [
+			"\u{20ac}\t",
+			"a\u{20ac}",
+			"99\u{e9}",
+			"9913",
+			"2024-\u{20ac}",
+		] {
+			assert_eq!(ListingKind::from_str(kind), Err(ArxivUrlError::Syntax), "kind: {:?}", kind);
+		}
+		assert!(ArxivUrl::from_str("https://arxiv.org/list/cs.LG/a\u{20ac}").is_err());
+	}
+
+	#[test]
+	fn parse_ar5iv_url() {
+		let id = ArxivId::from_str("arXiv:1910.06709v2").unwrap();
+		assert_eq!(
+			ArxivId::from_url(&id.ar5iv_url()),
+			Ok(ArxivId::from_str("arXiv:1910.06709").unwrap())
+		);
+	}
+
+	#[test]
+	fn recognize_arxiv_hosts() {
+		for host in [
+			"arxiv.org",
+			"ARXIV.ORG",
+			"export.arxiv.org",
+			"www.arxiv.org",
+			"de.arxiv.org",
+			"xxx.lanl.gov",
+			"arxiv.org:443",
+		] {
+			assert!(is_arxiv_host(host), "host: {:?}", host);
+		}
+		for host in [
+			"notarxiv.org",
+			"arxiv.org.example.com",
+			"example.com",
+			"lanl.gov",
+			"www.lanl.gov",
+			"",
+		] {
+			assert!(!is_arxiv_host(host), "host: {:?}", host);
+		}
+		assert!(ArxivId::from_url("https://www.lanl.gov/abs/2101.00001").is_err());
+	}
+
+	#[test]
+	fn parse_url_round_trip() {
+		let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+		for url in [id.abs_url(), id.pdf_url(), id.src_url(), id.html_url()] {
+			assert_eq!(ArxivId::from_url(&url), Ok(id.clone()), "url: {:?}", url);
+		}
+	}
+
+	#[test]
+	fn parse_arxiv_url() {
+		let id = ArxivId::from_str("arXiv:2101.00001").unwrap();
+		assert_eq!(
+			ArxivUrl::from_str("arxiv.org/tb/2101.00001"),
+			Ok(ArxivUrl::Article(id.clone(), ArxivEndpoint::Trackback))
+		);
+		assert_eq!(
+			ArxivUrl::from_str("https://arxiv.org/e-print/2101.00001"),
+			Ok(ArxivUrl::Article(id, ArxivEndpoint::Src))
+		);
+
+		let category = ArxivCategoryId::from_str("hep-th").unwrap();
+		for (url, kind) in [
+			("https://arxiv.org/list/hep-th/new", ListingKind::New),
+			("https://arxiv.org/list/hep-th/pastweek?show=25", ListingKind::Recent),
+			("https://arxiv.org/list/hep-th/9912", ListingKind::Month(1999, 12)),
+		] {
+			assert_eq!(ArxivUrl::from_str(url), Ok(ArxivUrl::Listing(category, kind)));
+		}
+	}
+
+	#[test]
+	fn parse_arxiv_url_invalid() {
+		assert_eq!(
+			ArxivUrl::from_str("https://arxiv.org/list/hep-th/2413"),
+			Err(ArxivUrlError::Syntax)
+		);
+		assert_eq!(
+			ArxivUrl::from_str("https://arxiv.org/list/hep-th/24-3"),
+			Err(ArxivUrlError::Syntax)
+		);
+		assert_eq!(
+			ArxivUrl::from_str("https://arxiv.org/list/hep-th/+1+2"),
+			Err(ArxivUrlError::Syntax)
+		);
+		assert!(matches!(
+			ArxivUrl::from_str("https://arxiv.org/list/cs.XX/new"),
+			Err(ArxivUrlError::InvalidCategory(_))
+		));
+	}
+
+	#[test]
+	fn parse_url_invalid() {
+		assert_eq!(
+			ArxivId::from_url("https://example.com/abs/2304.11188"),
+			Err(ArxivIdError::Syntax)
+		);
+		assert_eq!(
+			ArxivId::from_url("https://arxiv.org/list/cs.LG/new"),
+			Err(ArxivIdError::Syntax)
+		);
+		assert_eq!(
+			ArxivId::from_url("https://arxiv.org/abs/2304.111"),
+			Err(ArxivIdError::InvalidId)
+		);
+		assert_eq!(
+			ArxivId::from_url("https://www.arxiv.org/abs/2304.11188/?context=cs#top"),
+			ArxivId::from_str("arXiv:2304.11188")
+		);
+	}
+}
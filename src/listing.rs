@@ -0,0 +1,362 @@
+//! A scraper of the [listings][list] of the new submissions of the categories of arXiv.org,
+//! which are updated as soon as the articles are announced.
+//!
+//! [list]: https://info.arxiv.org/help/view.html
+
+use crate::{AnnouncementType, ArxivCategoryId, ArxivCategorySet, ArxivIdError, ArxivIdentifier};
+use ::scraper::{ElementRef, Html, Selector};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// An error that can occur when scraping a page of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScrapeError {
+	/// An article of the page is missing a required element
+	MissingElement(&'static str),
+	/// An article of the page has an invalid identifier
+	InvalidId(ArxivIdError),
+	/// An article of the page has no valid category
+	NoCategory,
+	/// An article of the page has an invalid submission date
+	InvalidDate(String),
+}
+
+impl Error for ScrapeError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidId(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for ScrapeError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::MissingElement(name) => write!(f, "An article is missing its {}.", name),
+			Self::InvalidId(e) => write!(f, "An article has an invalid arXiv identifier: {}", e),
+			Self::NoCategory => write!(f, "An article has no valid arXiv category."),
+			Self::InvalidDate(s) => write!(f, "An article has an invalid date \"{}\".", s),
+		}
+	}
+}
+
+/// An entry of the listing of the new submissions of a category of arXiv.org, announcing a
+/// new article or a new version of an article
+///
+/// The title and abstract are normalized by collapsing their line breaks and indentation into
+/// single spaces.
+///
+/// # Examples
+/// ```
+/// use arxiv::{AnnouncementType, ArxivListingEntry};
+///
+/// let html = r#"<dl id="articles">
+///   <h3>New submissions (showing 1 of 1 entries)</h3>
+///   <dt>
+///     <a name="item1">[1]</a>
+///     <a href="/abs/1706.03762" title="Abstract" id="1706.03762">arXiv:1706.03762</a>
+///     [<a href="/pdf/1706.03762" title="Download PDF">pdf</a>]
+///   </dt>
+///   <dd>
+///     <div class="meta">
+///       <div class="list-title mathjax"><span class="descriptor">Title:</span>
+///         Attention Is All You Need
+///       </div>
+///       <div class="list-authors">
+///         <a href="https://arxiv.org/a/vaswani_a_1">Ashish Vaswani</a>,
+///         <a href="https://arxiv.org/a/shazeer_n_1">Noam Shazeer</a>
+///       </div>
+///       <div class="list-subjects"><span class="descriptor">Subjects:</span>
+///         <span class="primary-subject">Computation and Language (cs.CL)</span>;
+///         Machine Learning (cs.LG)
+///       </div>
+///       <p class="mathjax">The dominant sequence transduction models...</p>
+///     </div>
+///   </dd>
+/// </dl>"#;
+///
+/// let entries = ArxivListingEntry::from_html(html).unwrap();
+/// assert_eq!(entries[0].title, "Attention Is All You Need");
+/// assert_eq!(entries[0].announce_type, AnnouncementType::New);
+/// assert_eq!(entries[0].categories.to_string(), "cs.CL cs.LG");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivListingEntry {
+	/// The identifier of the article, without a version
+	pub id: ArxivIdentifier,
+	/// The title of the article
+	pub title: String,
+	/// The names of the authors of the article, in order
+	pub authors: Vec<String>,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// The comments of the authors, if any, such as the number of pages
+	pub comments: Option<String>,
+	/// The journal reference of the published version of the article, if any
+	pub journal_ref: Option<String>,
+	/// The abstract of the article, which is not listed for replacements
+	pub abstract_text: Option<String>,
+	/// How the article was announced in the category of the listing
+	pub announce_type: AnnouncementType,
+}
+
+impl ArxivListingEntry {
+	/// The primary category of the article if it is cross-listed from it into the category of the listing
+	#[must_use]
+	pub fn cross_listed_from(&self) -> Option<ArxivCategoryId> {
+		match self.announce_type.is_cross_list() {
+			true => Some(self.categories.primary()),
+			false => None,
+		}
+	}
+
+	/// Scrapes the entries of the listing of the new submissions of a category of arXiv.org,
+	/// such as `https://arxiv.org/list/cs.LG/new` given by
+	/// [`ArxivCategoryId::listing_url`] with [`ListingKind::New`](crate::ListingKind::New),
+	/// in order.
+	///
+	/// The announcement type of an entry is given by the heading of its section, which lists
+	/// either new submissions, cross-listings or replacements, and by the notes of the entry.
+	///
+	/// # Errors
+	/// An error is returned if an entry is missing its identifier or title, or has no valid
+	/// category.
+	pub fn from_html(html: &str) -> Result<Vec<Self>, ScrapeError> {
+		let document = Html::parse_document(html);
+		let mut entries = Vec::new();
+		let mut heading = String::new();
+		let mut item = None;
+		for element in document.select(&selector("h3, dt, dd")) {
+			match element.value().name() {
+				"h3" => heading = text(element),
+				"dt" => item = Some(parse_item(element, &heading)?),
+				_ => {
+					if let Some((id, announce_type)) = item.take() {
+						entries.push(parse_meta(element, id, announce_type)?);
+					}
+				}
+			}
+		}
+		Ok(entries)
+	}
+}
+
+/// Parses the identifier and the announcement type of an entry from its `<dt>` element
+fn parse_item(
+	dt: ElementRef<'_>,
+	heading: &str,
+) -> Result<(ArxivIdentifier, AnnouncementType), ScrapeError> {
+	let id = dt
+		.select(&selector("a[href]"))
+		.filter_map(|a| a.value().attr("href")?.split_once("/abs/"))
+		.map(|(_, id)| id)
+		.next()
+		.ok_or(ScrapeError::MissingElement("identifier"))?;
+	let id = ArxivIdentifier::from_str(id).map_err(ScrapeError::InvalidId)?;
+
+	let notes = text(dt);
+	let replaced = heading.starts_with("Replace") || notes.contains("(replaced)");
+	let cross = heading.starts_with("Cross") || notes.contains("cross-list");
+	Ok((id, AnnouncementType::from_flags(replaced, cross)))
+}
+
+/// Parses the metadata of an entry from its `<dd>` element
+fn parse_meta(
+	dd: ElementRef<'_>,
+	id: ArxivIdentifier,
+	announce_type: AnnouncementType,
+) -> Result<ArxivListingEntry, ScrapeError> {
+	let subjects = field(dd, ".list-subjects", "Subjects:").unwrap_or_default();
+	let categories = parse_subjects(&subjects)?;
+	Ok(ArxivListingEntry {
+		id,
+		title: field(dd, ".list-title", "Title:").ok_or(ScrapeError::MissingElement("title"))?,
+		authors: dd
+			.select(&selector(".list-authors a"))
+			.map(text)
+			.filter(|name| !name.is_empty())
+			.collect(),
+		categories,
+		comments: field(dd, ".list-comments", "Comments:"),
+		journal_ref: field(dd, ".list-journal-ref", "Journal-ref:"),
+		abstract_text: dd
+			.select(&selector("p"))
+			.map(text)
+			.find(|text| !text.is_empty()),
+		announce_type,
+	})
+}
+
+/// Parses the categories of subjects such as `Machine Learning (cs.LG); Optimization and
+/// Control (math.OC)`, where the first one is the primary category
+pub(crate) fn parse_subjects(subjects: &str) -> Result<ArxivCategorySet, ScrapeError> {
+	let mut categories = subjects
+		.split('(')
+		.filter_map(|s| s.split_once(')'))
+		.filter_map(|(category, _)| ArxivCategoryId::from_str(category).ok());
+	let primary = categories.next().ok_or(ScrapeError::NoCategory)?;
+	Ok(ArxivCategorySet::new(primary, categories))
+}
+
+/// The text of the first element matching the selector, without its descriptor
+pub(crate) fn field(element: ElementRef<'_>, selectors: &str, descriptor: &str) -> Option<String> {
+	let text = text(element.select(&selector(selectors)).next()?);
+	let text = text.strip_prefix(descriptor).unwrap_or(&text).trim();
+	match text.is_empty() {
+		true => None,
+		false => Some(String::from(text)),
+	}
+}
+
+pub(crate) fn selector(selectors: &str) -> Selector {
+	Selector::parse(selectors).expect("the selector is valid")
+}
+
+/// The text of an element, with its runs of whitespace collapsed into single spaces
+pub(crate) fn text(element: ElementRef<'_>) -> String {
+	let text = element.text().collect::<String>();
+	text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const LISTING: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head><title>High Energy Physics - Theory</title></head>
+<body>
+<div id="dlpage">
+  <h1>High Energy Physics - Theory</h1>
+  <dl id="articles">
+    <h3>New submissions (showing 1 of 1 entries)</h3>
+    <dt>
+      <a name="item1">[1]</a>
+      <a href="/abs/2404.08001" title="Abstract" id="2404.08001">arXiv:2404.08001</a>
+      [<a href="/pdf/2404.08001" title="Download PDF">pdf</a>, <a href="/format/2404.08001">other</a>]
+    </dt>
+    <dd>
+      <div class="meta">
+        <div class="list-title mathjax"><span class="descriptor">Title:</span>
+          A holographic
+          dual
+        </div>
+        <div class="list-authors"><a href="/a/doe_j_1">Jane Doe</a>, <a href="/a/smith_j_1">John Smith</a></div>
+        <div class="list-comments mathjax"><span class="descriptor">Comments:</span> 12 pages, 3 figures</div>
+        <div class="list-subjects"><span class="descriptor">Subjects:</span>
+          <span class="primary-subject">High Energy Physics - Theory (hep-th)</span>; General Relativity and Quantum Cosmology (gr-qc)
+        </div>
+        <p class="mathjax">We study
+          holography.</p>
+      </div>
+    </dd>
+  </dl>
+  <dl id="articles">
+    <h3>Cross submissions (showing 1 of 1 entries)</h3>
+    <dt>
+      <a name="item2">[2]</a>
+      <a href="/abs/2404.07001" title="Abstract" id="2404.07001">arXiv:2404.07001</a>
+      (cross-list from gr-qc)
+    </dt>
+    <dd>
+      <div class="meta">
+        <div class="list-title mathjax"><span class="descriptor">Title:</span> Gravitational waves</div>
+        <div class="list-authors"><a href="/a/author_a_1">A. Author</a></div>
+        <div class="list-subjects"><span class="descriptor">Subjects:</span>
+          <span class="primary-subject">General Relativity and Quantum Cosmology (gr-qc)</span>; High Energy Physics - Theory (hep-th)
+        </div>
+      </div>
+    </dd>
+  </dl>
+  <dl id="articles">
+    <h3>Replacement submissions (showing 2 of 2 entries)</h3>
+    <dt>
+      <a name="item3">[3]</a>
+      <a href="/abs/hep-th/0601001" title="Abstract" id="hep-th/0601001">arXiv:hep-th/0601001</a>
+      (replaced)
+    </dt>
+    <dd>
+      <div class="meta">
+        <div class="list-title mathjax"><span class="descriptor">Title:</span> An old article</div>
+        <div class="list-authors"><a href="/a/author_b_1">B. Author</a></div>
+        <div class="list-journal-ref"><span class="descriptor">Journal-ref:</span> Phys. Rev. D 73 (2006) 1</div>
+        <div class="list-subjects"><span class="descriptor">Subjects:</span>
+          <span class="primary-subject">High Energy Physics - Theory (hep-th)</span>
+        </div>
+      </div>
+    </dd>
+    <dt>
+      <a name="item4">[4]</a>
+      <a href="/abs/2301.00001" title="Abstract" id="2301.00001">arXiv:2301.00001</a>
+      (replaced, cross-list from math-ph)
+    </dt>
+    <dd>
+      <div class="meta">
+        <div class="list-title mathjax"><span class="descriptor">Title:</span> A cross-listed replacement</div>
+        <div class="list-authors"><a href="/a/author_c_1">C. Author</a></div>
+        <div class="list-subjects"><span class="descriptor">Subjects:</span>
+          <span class="primary-subject">Mathematical Physics (math-ph)</span>; High Energy Physics - Theory (hep-th)
+        </div>
+      </div>
+    </dd>
+  </dl>
+</div>
+</body>
+</html>"#;
+
+	#[test]
+	fn scrape_listing() {
+		let entries = ArxivListingEntry::from_html(LISTING).unwrap();
+		let types = entries
+			.iter()
+			.map(|entry| entry.announce_type)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			types,
+			[
+				AnnouncementType::New,
+				AnnouncementType::Cross,
+				AnnouncementType::Replace,
+				AnnouncementType::ReplaceCross,
+			]
+		);
+
+		let entry = &entries[0];
+		assert_eq!(entry.id.to_string(), "arXiv:2404.08001");
+		assert_eq!(entry.title, "A holographic dual");
+		assert_eq!(entry.authors, ["Jane Doe", "John Smith"]);
+		assert_eq!(entry.categories.to_string(), "hep-th gr-qc");
+		assert_eq!(entry.comments.as_deref(), Some("12 pages, 3 figures"));
+		assert_eq!(entry.abstract_text.as_deref(), Some("We study holography."));
+
+		assert_eq!(entry.cross_listed_from(), None);
+		assert_eq!(
+			entries[1]
+				.cross_listed_from()
+				.map(|c| c.to_string())
+				.as_deref(),
+			Some("gr-qc")
+		);
+		assert_eq!(entries[2].id.to_string(), "hep-th/0601001");
+		assert_eq!(entries[2].journal_ref.as_deref(), Some("Phys. Rev. D 73 (2006) 1"));
+		assert_eq!(entries[2].abstract_text, None);
+	}
+
+	#[test]
+	fn scrape_listing_errors() {
+		assert_eq!(ArxivListingEntry::from_html("<html></html>"), Ok(Vec::new()));
+		assert_eq!(
+			ArxivListingEntry::from_html("<dl><dt><a name=\"item1\">[1]</a></dt><dd></dd></dl>"),
+			Err(ScrapeError::MissingElement("identifier"))
+		);
+		assert_eq!(
+			ArxivListingEntry::from_html(
+				"<dl><dt><a href=\"/abs/2404.08001\">arXiv:2404.08001</a></dt><dd></dd></dl>"
+			),
+			Err(ScrapeError::NoCategory)
+		);
+	}
+}
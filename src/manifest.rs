@@ -0,0 +1,415 @@
+//! A parser of the manifests of the [bulk data][bulk] of arXiv.org on Amazon S3.
+//!
+//! [bulk]: https://info.arxiv.org/help/bulk_data_s3.html
+
+use crate::links::expand_year;
+use crate::{ArxivIdError, ArxivIdOld, ArxivIdentifier};
+use ::quick_xml::events::Event;
+use ::quick_xml::Reader;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use time::macros::format_description;
+use time::PrimitiveDateTime;
+
+/// The bucket of the bulk data of arXiv.org on Amazon S3, which is requester-pays
+pub const ARXIV_BULK_BUCKET: &str = "arxiv";
+
+/// An error that can occur when parsing a manifest of the bulk data of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestError {
+	/// The manifest is not well-formed XML
+	Xml(String),
+	/// The root element is neither `arXivPDF` nor `arXivSRC`
+	UnknownKind(String),
+	/// A file is missing a required element
+	MissingElement(&'static str),
+	/// A file has an element with an invalid value, such as a size that is not a number
+	InvalidElement {
+		/// The name of the element
+		name: &'static str,
+		/// The invalid value of the element
+		value: String,
+	},
+	/// A file has an invalid first or last item
+	InvalidId(ArxivIdError),
+}
+
+impl Error for ManifestError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidId(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for ManifestError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Xml(e) => write!(f, "The manifest is not well-formed XML: {}", e),
+			Self::UnknownKind(root) => {
+				write!(f, "The manifest has an unknown root element <{}>.", root)
+			}
+			Self::MissingElement(name) => write!(f, "A file is missing the <{}> element.", name),
+			Self::InvalidElement { name, value } => {
+				write!(f, "A file has an invalid <{}> element \"{}\".", name, value)
+			}
+			Self::InvalidId(e) => write!(f, "A file has an invalid arXiv identifier: {}", e),
+		}
+	}
+}
+
+/// The kind of the files of a manifest of the bulk data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BulkKind {
+	/// The PDFs of the articles, listed by `pdf/arXiv_pdf_manifest.xml`
+	Pdf,
+	/// The source files of the articles, listed by `src/arXiv_src_manifest.xml`
+	Source,
+}
+
+impl BulkKind {
+	/// The key of the manifest of the kind in the bucket of the bulk data
+	#[must_use]
+	pub const fn manifest_key(self) -> &'static str {
+		match self {
+			Self::Pdf => "pdf/arXiv_pdf_manifest.xml",
+			Self::Source => "src/arXiv_src_manifest.xml",
+		}
+	}
+}
+
+/// A tar file of the bulk data, containing the articles of a month between two identifiers
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BulkFile {
+	/// The key of the file in the bucket, such as `src/arXiv_src_0001_001.tar`
+	pub filename: String,
+	/// The MD5 checksum of the file, in lowercase hexadecimal
+	pub md5: String,
+	/// The MD5 checksum of the content of the file, in lowercase hexadecimal
+	pub content_md5: String,
+	/// The size of the file, in bytes
+	pub size: u64,
+	/// The number of articles in the file
+	pub num_items: u32,
+	/// The sequence number of the file within its month, starting at 1
+	pub seq_num: u32,
+	/// The first article of the file
+	pub first_item: ArxivIdentifier,
+	/// The last article of the file
+	pub last_item: ArxivIdentifier,
+	/// The year and month of the articles of the file
+	pub month: (u16, u8),
+	/// When the file was last updated
+	pub timestamp: PrimitiveDateTime,
+}
+
+impl BulkFile {
+	/// The S3 URL of the file, such as `s3://arxiv/src/arXiv_src_0001_001.tar`
+	#[must_use]
+	pub fn s3_url(&self) -> String {
+		format!("s3://{}/{}", ARXIV_BULK_BUCKET, self.filename)
+	}
+}
+
+/// A manifest of the bulk data of arXiv.org, listing the tar files of PDFs or source files
+/// with their checksums, sizes and the articles they contain
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{BulkKind, BulkManifest};
+///
+/// let manifest = BulkManifest::from_str(r#"<arXivSRC>
+///   <file>
+///     <content_md5sum>cacbfede21d5dfef26f367ec99384546</content_md5sum>
+///     <filename>src/arXiv_src_0001_001.tar</filename>
+///     <first_item>astro-ph0001001</first_item>
+///     <last_item>quant-ph0001119</last_item>
+///     <md5sum>949ae880fbaf4649a02a7f45e4a8bd16</md5sum>
+///     <num_items>2364</num_items>
+///     <seq_num>1</seq_num>
+///     <size>225605507</size>
+///     <timestamp>2010-12-23 00:13:59</timestamp>
+///     <yymm>0001</yymm>
+///   </file>
+/// </arXivSRC>"#).unwrap();
+///
+/// assert_eq!(manifest.kind, BulkKind::Source);
+/// assert_eq!(manifest.files[0].month, (2000, 1));
+/// assert_eq!(manifest.files[0].first_item.to_string(), "astro-ph/0001001");
+/// assert_eq!(manifest.total_size(), 225605507);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BulkManifest {
+	/// The kind of the files of the manifest
+	pub kind: BulkKind,
+	/// The files of the manifest, in order
+	pub files: Vec<BulkFile>,
+}
+
+impl BulkManifest {
+	/// The total size of the files of the manifest, in bytes
+	#[must_use]
+	pub fn total_size(&self) -> u64 {
+		self.files.iter().map(|file| file.size).sum()
+	}
+
+	/// The files of the articles of the months between two months, inclusive, which are given
+	/// as years and months
+	pub fn files_between(
+		&self,
+		from: (u16, u8),
+		until: (u16, u8),
+	) -> impl Iterator<Item = &BulkFile> + '_ {
+		self.files
+			.iter()
+			.filter(move |file| (from..=until).contains(&file.month))
+	}
+}
+
+impl FromStr for BulkManifest {
+	type Err = ManifestError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let xml_error = |e: ::quick_xml::Error| ManifestError::Xml(e.to_string());
+		let mut reader = Reader::from_str(s);
+		let mut kind = None;
+		let mut files = Vec::new();
+		let mut file: Option<FileFields> = None;
+		let mut text = String::new();
+
+		loop {
+			match reader.read_event() {
+				Ok(Event::Start(e)) => {
+					match e.local_name().as_ref() {
+						b"file" => file = Some(FileFields::default()),
+						b"arXivPDF" => kind = Some(BulkKind::Pdf),
+						b"arXivSRC" => kind = Some(BulkKind::Source),
+						root if kind.is_none() => {
+							let root = String::from_utf8_lossy(root).into_owned();
+							return Err(ManifestError::UnknownKind(root));
+						}
+						_ => {}
+					}
+					text.clear();
+				}
+				Ok(Event::Text(e)) => text.push_str(&e.unescape().map_err(xml_error)?),
+				Ok(Event::End(e)) => {
+					if e.local_name().as_ref() == b"file" {
+						if let Some(fields) = file.take() {
+							files.push(fields.into_file()?);
+						}
+					} else if let Some(fields) = file.as_mut() {
+						fields.on_text(e.local_name().as_ref(), text.trim());
+					}
+					text.clear();
+				}
+				Ok(Event::Eof) => break,
+				Ok(_) => {}
+				Err(e) => return Err(xml_error(e)),
+			}
+		}
+
+		Ok(Self {
+			kind: kind.ok_or_else(|| ManifestError::UnknownKind(String::new()))?,
+			files,
+		})
+	}
+}
+
+/// The elements of a file, collected before they are validated
+#[derive(Default)]
+struct FileFields {
+	filename: Option<String>,
+	md5: Option<String>,
+	content_md5: Option<String>,
+	size: Option<String>,
+	num_items: Option<String>,
+	seq_num: Option<String>,
+	first_item: Option<String>,
+	last_item: Option<String>,
+	yymm: Option<String>,
+	timestamp: Option<String>,
+}
+
+impl FileFields {
+	fn on_text(&mut self, name: &[u8], text: &str) {
+		let field = match name {
+			b"filename" => &mut self.filename,
+			b"md5sum" => &mut self.md5,
+			b"content_md5sum" => &mut self.content_md5,
+			b"size" => &mut self.size,
+			b"num_items" => &mut self.num_items,
+			b"seq_num" => &mut self.seq_num,
+			b"first_item" => &mut self.first_item,
+			b"last_item" => &mut self.last_item,
+			b"yymm" => &mut self.yymm,
+			b"timestamp" => &mut self.timestamp,
+			_ => return,
+		};
+		*field = Some(String::from(text));
+	}
+
+	fn into_file(self) -> Result<BulkFile, ManifestError> {
+		let yymm = required(self.yymm, "yymm")?;
+		let timestamp = required(self.timestamp, "timestamp")?;
+		Ok(BulkFile {
+			filename: required(self.filename, "filename")?,
+			md5: parse_md5(required(self.md5, "md5sum")?, "md5sum")?,
+			content_md5: parse_md5(
+				required(self.content_md5, "content_md5sum")?,
+				"content_md5sum",
+			)?,
+			size: parse_number(required(self.size, "size")?, "size")?,
+			num_items: parse_number(required(self.num_items, "num_items")?, "num_items")?,
+			seq_num: parse_number(required(self.seq_num, "seq_num")?, "seq_num")?,
+			first_item: parse_item(&required(self.first_item, "first_item")?)?,
+			last_item: parse_item(&required(self.last_item, "last_item")?)?,
+			month: parse_yymm(&yymm).ok_or(ManifestError::InvalidElement {
+				name: "yymm",
+				value: yymm,
+			})?,
+			timestamp: PrimitiveDateTime::parse(
+				&timestamp,
+				&format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+			)
+			.map_err(|_| ManifestError::InvalidElement {
+				name: "timestamp",
+				value: timestamp,
+			})?,
+		})
+	}
+}
+
+fn required(value: Option<String>, name: &'static str) -> Result<String, ManifestError> {
+	value.ok_or(ManifestError::MissingElement(name))
+}
+
+fn parse_number<N: FromStr>(value: String, name: &'static str) -> Result<N, ManifestError> {
+	value
+		.parse()
+		.map_err(|_| ManifestError::InvalidElement { name, value })
+}
+
+/// Validates an MD5 checksum, normalizing it to lowercase
+fn parse_md5(value: String, name: &'static str) -> Result<String, ManifestError> {
+	match value.len() == 32 && value.bytes().all(|b| b.is_ascii_hexdigit()) {
+		true => Ok(value.to_ascii_lowercase()),
+		false => Err(ManifestError::InvalidElement { name, value }),
+	}
+}
+
+/// Parses an item of a manifest, where identifiers of the old scheme have no slash, such as
+/// `astro-ph0001001`
+fn parse_item(item: &str) -> Result<ArxivIdentifier, ManifestError> {
+	let split = item.len().saturating_sub(7);
+	match item.get(..split) {
+		Some(archive) if archive.bytes().any(|b| b.is_ascii_alphabetic()) => {
+			let id = format!("{}/{}", archive, &item[split..]);
+			ArxivIdOld::from_str(&id)
+				.map(ArxivIdentifier::Old)
+				.map_err(ManifestError::InvalidId)
+		}
+		_ => ArxivIdentifier::from_str(item).map_err(ManifestError::InvalidId),
+	}
+}
+
+fn parse_yymm(yymm: &str) -> Option<(u16, u8)> {
+	if yymm.len() != 4 || !yymm.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	let month = yymm[2..]
+		.parse()
+		.ok()
+		.filter(|month| (1..=12).contains(month))?;
+	Some((expand_year(yymm[..2].parse().ok()?), month))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MANIFEST: &str = r#"<?xml version='1.0' standalone='yes'?>
+<arXivPDF>
+  <file>
+    <content_md5sum>CACBFEDE21D5DFEF26F367EC99384546</content_md5sum>
+    <filename>pdf/arXiv_pdf_9912_001.tar</filename>
+    <first_item>cond-mat9912001</first_item>
+    <last_item>math9912120</last_item>
+    <md5sum>949ae880fbaf4649a02a7f45e4a8bd16</md5sum>
+    <num_items>1200</num_items>
+    <seq_num>1</seq_num>
+    <size>500000000</size>
+    <timestamp>2010-12-23 00:13:59</timestamp>
+    <yymm>9912</yymm>
+  </file>
+  <file>
+    <content_md5sum>0123456789abcdef0123456789abcdef</content_md5sum>
+    <filename>pdf/arXiv_pdf_1501_001.tar</filename>
+    <first_item>1501.00001</first_item>
+    <last_item>1501.01000</last_item>
+    <md5sum>fedcba9876543210fedcba9876543210</md5sum>
+    <num_items>1000</num_items>
+    <seq_num>1</seq_num>
+    <size>524288000</size>
+    <timestamp>2015-02-01 12:00:00</timestamp>
+    <yymm>1501</yymm>
+  </file>
+</arXivPDF>"#;
+
+	#[test]
+	fn parse_manifest() {
+		let manifest = BulkManifest::from_str(MANIFEST).unwrap();
+		assert_eq!(manifest.kind, BulkKind::Pdf);
+		assert_eq!(manifest.files.len(), 2);
+		assert_eq!(manifest.total_size(), 1_024_288_000);
+
+		let file = &manifest.files[0];
+		assert_eq!(file.content_md5, "cacbfede21d5dfef26f367ec99384546");
+		assert_eq!(file.first_item.to_string(), "cond-mat/9912001");
+		assert_eq!(file.last_item.to_string(), "math/9912120");
+		assert_eq!(file.month, (1999, 12));
+		assert_eq!(file.timestamp.year(), 2010);
+		assert_eq!(file.s3_url(), "s3://arxiv/pdf/arXiv_pdf_9912_001.tar");
+
+		let file = &manifest.files[1];
+		assert_eq!(file.first_item, ArxivIdentifier::from_str("1501.00001").unwrap());
+		assert_eq!(file.month, (2015, 1));
+		assert_eq!(manifest.files_between((2000, 1), (2015, 6)).count(), 1);
+	}
+
+	#[test]
+	fn parse_manifest_invalid() {
+		assert_eq!(
+			BulkManifest::from_str("<arXivMETA></arXivMETA>"),
+			Err(ManifestError::UnknownKind(String::from("arXivMETA")))
+		);
+		assert_eq!(
+			BulkManifest::from_str(&MANIFEST.replace("<size>500000000", "<size>500MB")),
+			Err(ManifestError::InvalidElement {
+				name: "size",
+				value: String::from("500MB"),
+			})
+		);
+		assert!(matches!(
+			BulkManifest::from_str(&MANIFEST.replace("<yymm>9912", "<yymm>9913")),
+			Err(ManifestError::InvalidElement { name: "yymm", .. })
+		));
+		assert!(matches!(
+			BulkManifest::from_str(&MANIFEST.replace("fedcba98", "xyz")),
+			Err(ManifestError::InvalidElement { name: "md5sum", .. })
+		));
+		assert!(matches!(
+			BulkManifest::from_str(&MANIFEST.replace("math9912120", "math991212")),
+			Err(ManifestError::InvalidId(_))
+		));
+		assert_eq!(
+			BulkManifest::from_str(&MANIFEST.replace("<seq_num>1</seq_num>", "")),
+			Err(ManifestError::MissingElement("seq_num"))
+		);
+	}
+}
@@ -0,0 +1,285 @@
+use crate::{ArxivCategorySet, ArxivIdentifier, JournalRef};
+use std::ops::RangeInclusive;
+use time::{Date, OffsetDateTime};
+
+/// A version of an article, and when it was submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArticleVersion {
+	/// The number of the version, starting at 1
+	pub version: u8,
+	/// When the version was submitted
+	pub submitted: OffsetDateTime,
+	/// The size of the submission of the version in kilobytes, if known
+	pub size_kb: Option<u32>,
+}
+
+/// The versions of an article, ordered by their number
+///
+/// # Examples
+/// ```
+/// use arxiv::{ArticleVersion, VersionHistory};
+/// use time::macros::datetime;
+///
+/// let history = VersionHistory::new([
+///     ArticleVersion::new(2, datetime!(2017-06-19 17:55:23 UTC)),
+///     ArticleVersion::new(1, datetime!(2017-06-12 17:57:34 UTC)),
+/// ]);
+/// assert_eq!(history.latest().map(|version| version.version), Some(2));
+/// assert_eq!(
+///     history.submitted_range(),
+///     Some(datetime!(2017-06-12 17:57:34 UTC)..=datetime!(2017-06-19 17:55:23 UTC))
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionHistory {
+	versions: Vec<ArticleVersion>,
+}
+
+impl ArticleVersion {
+	/// Creates a version submitted at the given time, of an unknown size
+	#[must_use]
+	pub const fn new(version: u8, submitted: OffsetDateTime) -> Self {
+		Self {
+			version,
+			submitted,
+			size_kb: None,
+		}
+	}
+}
+
+impl VersionHistory {
+	/// Creates a history of versions, ordering them by their number and keeping the first of
+	/// the versions with the same number
+	pub fn new<I>(versions: I) -> Self
+	where
+		I: IntoIterator<Item = ArticleVersion>,
+	{
+		let mut versions = versions.into_iter().collect::<Vec<_>>();
+		versions.sort_by_key(|version| version.version);
+		versions.dedup_by_key(|version| version.version);
+		Self { versions }
+	}
+
+	/// The versions, ordered by their number
+	#[must_use]
+	#[inline]
+	pub fn as_slice(&self) -> &[ArticleVersion] {
+		&self.versions
+	}
+
+	/// The number of versions
+	#[must_use]
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.versions.len()
+	}
+
+	/// Whether there are no versions
+	#[must_use]
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.versions.is_empty()
+	}
+
+	/// The version with the number, if any
+	#[must_use]
+	pub fn get(&self, version: u8) -> Option<&ArticleVersion> {
+		self.versions.iter().find(|v| v.version == version)
+	}
+
+	/// The first version
+	#[must_use]
+	pub fn first(&self) -> Option<&ArticleVersion> {
+		self.versions.first()
+	}
+
+	/// The latest version
+	#[must_use]
+	pub fn latest(&self) -> Option<&ArticleVersion> {
+		self.versions.last()
+	}
+
+	/// The range from the earliest to the latest submission of the versions
+	#[must_use]
+	pub fn submitted_range(&self) -> Option<RangeInclusive<OffsetDateTime>> {
+		let first = self
+			.versions
+			.iter()
+			.map(|version| version.submitted)
+			.min()?;
+		let last = self
+			.versions
+			.iter()
+			.map(|version| version.submitted)
+			.max()?;
+		Some(first..=last)
+	}
+
+	/// Iterates over the versions, ordered by their number
+	pub fn iter(&self) -> impl Iterator<Item = &ArticleVersion> + '_ {
+		self.versions.iter()
+	}
+}
+
+impl From<Vec<ArticleVersion>> for VersionHistory {
+	fn from(versions: Vec<ArticleVersion>) -> Self {
+		Self::new(versions)
+	}
+}
+
+impl From<VersionHistory> for Vec<ArticleVersion> {
+	fn from(history: VersionHistory) -> Self {
+		history.versions
+	}
+}
+
+impl FromIterator<ArticleVersion> for VersionHistory {
+	fn from_iter<I: IntoIterator<Item = ArticleVersion>>(versions: I) -> Self {
+		Self::new(versions)
+	}
+}
+
+impl IntoIterator for VersionHistory {
+	type Item = ArticleVersion;
+	type IntoIter = std::vec::IntoIter<ArticleVersion>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.versions.into_iter()
+	}
+}
+
+/// The metadata of an article of arXiv.org, whichever source it was read from
+///
+/// The metadata snapshot reads into it directly, and the articles of the export API and of
+/// the abstract pages, and the records of the OAI-PMH interface convert into it with
+/// [`From`], so that code handling articles does not depend on their source. The fields a
+/// source does not describe are left empty, such as the submitter of the articles of the
+/// export API.
+///
+/// The title and abstract are normalized by collapsing their line breaks and indentation into
+/// single spaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivArticleMetadata {
+	/// The identifier of the article, without a version
+	pub id: ArxivIdentifier,
+	/// The name of the submitter of the article, if known
+	pub submitter: Option<String>,
+	/// The versions of the article, which are empty if the source does not describe them
+	pub versions: VersionHistory,
+	/// The title of the article
+	pub title: String,
+	/// The abstract of the article
+	pub abstract_text: String,
+	/// The names of the authors of the article, in order
+	pub authors: Vec<String>,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// The URL of the license of the article, if any
+	pub license: Option<String>,
+	/// The journal reference of the published version of the article, if any
+	pub journal_ref: Option<String>,
+	/// The DOI of the published version of the article, if any
+	pub doi: Option<String>,
+	/// The comments of the authors, if any, such as the number of pages
+	pub comments: Option<String>,
+	/// The report number of the article, if any
+	pub report_no: Option<String>,
+	/// When the metadata of the article was last updated, if known
+	pub updated: Option<Date>,
+}
+
+impl ArxivArticleMetadata {
+	/// The latest version of the article, if its versions are known
+	#[must_use]
+	pub fn latest_version(&self) -> Option<&ArticleVersion> {
+		self.versions.latest()
+	}
+
+	/// The year of the latest version of the article, or the year of its identifier if its
+	/// versions are unknown, as used in citations
+	pub(crate) fn citation_year(&self) -> u16 {
+		self.latest_version()
+			.and_then(|version| u16::try_from(version.submitted.year()).ok())
+			.unwrap_or_else(|| match &self.id {
+				ArxivIdentifier::Old(id) => id.year(),
+				ArxivIdentifier::New(id) => id.year(),
+			})
+	}
+
+	/// The year of the journal reference of the article, if it names one, or otherwise the
+	/// year of its latest version (see [`ArxivArticleMetadata::citation_year`]), as used in
+	/// citations of published articles
+	pub(crate) fn published_year(&self, journal_ref: Option<&JournalRef>) -> u16 {
+		journal_ref
+			.and_then(|journal_ref| journal_ref.year)
+			.unwrap_or_else(|| self.citation_year())
+	}
+
+	/// The DOI of the article, or otherwise the DOI registered by arXiv.org for articles of
+	/// the new scheme, as used in citations
+	pub(crate) fn citation_doi(&self) -> Option<String> {
+		match (&self.doi, &self.id) {
+			(Some(doi), _) => Some(doi.clone()),
+			(None, ArxivIdentifier::New(id)) => Some(id.to_doi()),
+			(None, ArxivIdentifier::Old(_)) => None,
+		}
+	}
+
+	/// The metadata of hep-th/9711200, a published article of the old scheme, which the
+	/// tests of the exporters start from
+	#[cfg(test)]
+	pub(crate) fn fixture() -> Self {
+		use std::str::FromStr;
+
+		Self {
+			id: ArxivIdentifier::from_str("hep-th/9711200").unwrap(),
+			submitter: None,
+			versions: VersionHistory::default(),
+			title: String::from("The Large N Limit of\n  Superconformal Field Theories"),
+			abstract_text: String::from("We show that the large N limit..."),
+			authors: vec![String::from("Juan M. Maldacena")],
+			categories: ArxivCategorySet::from_str("hep-th").unwrap(),
+			license: None,
+			journal_ref: Some(String::from("Adv.Theor.Math.Phys.2:231-252,1998")),
+			doi: Some(String::from("10.4310/ATMP.1998.v2.n2.a1")),
+			comments: None,
+			report_no: None,
+			updated: None,
+		}
+	}
+}
+
+/// Collapses runs of whitespace, including line breaks, into single spaces
+pub(crate) fn collapse_whitespace(s: &str) -> String {
+	s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use time::macros::datetime;
+
+	#[test]
+	fn version_history() {
+		let mut v3 = ArticleVersion::new(3, datetime!(2020-03-01 00:00 UTC));
+		v3.size_kb = Some(120);
+		let history = VersionHistory::new([
+			v3,
+			ArticleVersion::new(1, datetime!(2020-01-01 00:00 UTC)),
+			ArticleVersion::new(2, datetime!(2020-02-01 00:00 UTC)),
+			ArticleVersion::new(1, datetime!(2021-01-01 00:00 UTC)),
+		]);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history.first().unwrap().submitted, datetime!(2020-01-01 00:00 UTC));
+		assert_eq!(history.latest().unwrap().size_kb, Some(120));
+		assert_eq!(history.get(2).unwrap().version, 2);
+		assert!(history.get(4).is_none());
+		assert_eq!(
+			history.submitted_range(),
+			Some(datetime!(2020-01-01 00:00 UTC)..=datetime!(2020-03-01 00:00 UTC))
+		);
+		assert_eq!(VersionHistory::default().submitted_range(), None);
+	}
+}
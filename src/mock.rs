@@ -0,0 +1,210 @@
+use crate::{HttpResponse, HttpTransport, TransportError, TransportFuture};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// A transport replying with canned responses instead of requesting arXiv.org, and recording
+/// the URLs requested, for testing code built on [`ArxivClient`](crate::ArxivClient) and
+/// [`OaiHarvester`](crate::OaiHarvester), or on `ArxivBlockingClient` with the `blocking`
+/// feature
+///
+/// Requests are answered with the queued responses in order, then with the response of the
+/// first route whose pattern the URL contains. A request left unanswered fails with a
+/// [`TransportError`].
+///
+/// The responses of the APIs of arXiv.org in the [`fixtures`](crate::fixtures) module can be
+/// used as bodies of the responses.
+///
+/// # Examples
+/// ```
+/// use arxiv::fixtures::{ATOM_SEARCH, RSS_FEED};
+/// use arxiv::{ArxivCategoryId, ArxivClient, HttpResponse, MockTransport, Query};
+/// use std::str::FromStr;
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = MockTransport::new()
+///     .with_response(HttpResponse::new(200, ATOM_SEARCH))
+///     .with_route("rss.arxiv.org", HttpResponse::new(200, RSS_FEED));
+/// let client = ArxivClient::with_transport(transport).with_rate_limit(Duration::ZERO);
+///
+/// let articles = client.search(&Query::new().title("attention")).await.unwrap();
+/// assert_eq!(articles[0].title, "Attention Is All You Need");
+///
+/// let category = ArxivCategoryId::from_str("hep-th").unwrap();
+/// assert_eq!(client.fetch_rss(&category).await.unwrap().len(), 3);
+/// assert_eq!(client.transport().requests()[1], "https://rss.arxiv.org/rss/hep-th");
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+	responses: Mutex<VecDeque<HttpResponse>>,
+	routes: Vec<(String, HttpResponse)>,
+	requests: Mutex<Vec<String>>,
+}
+
+impl MockTransport {
+	/// Creates a transport without any response, failing every request
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Queues a response, answering a single request after the responses queued before it
+	#[must_use]
+	pub fn with_response(self, response: HttpResponse) -> Self {
+		self.push_response(response);
+		self
+	}
+
+	/// Adds a route answering every request with a URL containing the pattern with the
+	/// response, once the queued responses have been used up
+	#[must_use]
+	pub fn with_route(mut self, pattern: impl Into<String>, response: HttpResponse) -> Self {
+		self.routes.push((pattern.into(), response));
+		self
+	}
+
+	/// Queues a response, answering a single request after the responses queued before it,
+	/// such as while a client is already using the transport
+	pub fn push_response(&self, response: HttpResponse) {
+		lock(&self.responses).push_back(response);
+	}
+
+	/// The URLs requested, in order
+	#[must_use]
+	pub fn requests(&self) -> Vec<String> {
+		lock(&self.requests).clone()
+	}
+
+	/// The number of queued responses which have not been used yet
+	#[must_use]
+	pub fn remaining(&self) -> usize {
+		lock(&self.responses).len()
+	}
+}
+
+impl MockTransport {
+	/// Answers a request with the next queued response, or with the response of its route
+	fn respond(&self, url: &str) -> Result<HttpResponse, TransportError> {
+		lock(&self.requests).push(String::from(url));
+		let queued = lock(&self.responses).pop_front();
+		queued
+			.or_else(|| {
+				self.routes
+					.iter()
+					.find(|(pattern, _)| url.contains(pattern.as_str()))
+					.map(|(_, response)| response.clone())
+			})
+			.ok_or_else(|| format!("no mock response for {}", url).into())
+	}
+}
+
+impl HttpTransport for MockTransport {
+	fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+		Box::pin(std::future::ready(self.respond(url)))
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl crate::BlockingHttpTransport for MockTransport {
+	fn get(&self, url: &str) -> Result<HttpResponse, TransportError> {
+		self.respond(url)
+	}
+}
+
+/// Locks the mutex, even if a test panicked while holding it
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+	mutex
+		.lock()
+		.unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::fixtures::*;
+	use crate::{
+		ArxivApiError, ArxivClient, ArxivId, ArxivIdentifier, OaiHarvester, OaiMetadataFormat,
+		OaiRequest, Query,
+	};
+	use futures_util::TryStreamExt;
+	use std::str::FromStr;
+	use std::time::Duration;
+
+	#[tokio::test]
+	async fn mock_client() {
+		let transport = MockTransport::new()
+			.with_response(HttpResponse::new(200, ATOM_SEARCH))
+			.with_response(HttpResponse::new(200, ATOM_EMPTY))
+			.with_route("oai2", HttpResponse::new(200, OAI_GET_RECORD));
+		let client = ArxivClient::with_transport(transport).with_rate_limit(Duration::ZERO);
+
+		let articles = client
+			.search(&Query::new().title("attention"))
+			.await
+			.unwrap();
+		assert_eq!(articles.len(), 2);
+		assert_eq!(articles[1].id.to_string(), "hep-th/9901001v1");
+		assert_eq!(articles[1].doi.as_deref(), Some("10.1103/PhysRevD.60.1"));
+		assert!(client.search(&Query::new()).await.unwrap().is_empty());
+		assert_eq!(client.transport().remaining(), 0);
+
+		let id = ArxivIdentifier::from_str("0704.0001").unwrap();
+		for _ in 0..2 {
+			assert_eq!(client.versions(&id).await.unwrap().len(), 2);
+		}
+		assert!(matches!(client.search(&Query::new()).await, Err(ArxivApiError::Transport(_))));
+		assert_eq!(client.transport().requests().len(), 5);
+	}
+
+	#[tokio::test]
+	async fn mock_datacite_cross_check() {
+		let transport = MockTransport::new()
+			.with_route("api.datacite.org", HttpResponse::new(200, DATACITE_RECORD))
+			.with_route("export.arxiv.org", HttpResponse::new(200, ATOM_SEARCH));
+		let client = ArxivClient::with_transport(transport).with_rate_limit(Duration::ZERO);
+
+		let id = ArxivId::from_str("arXiv:1706.03762").unwrap();
+		assert_eq!(client.cross_check_datacite(&id).await.unwrap(), []);
+		assert_eq!(
+			client.transport().requests()[1],
+			"https://api.datacite.org/dois/application/vnd.datacite.datacite+xml/10.48550/arXiv.1706.03762"
+		);
+
+		let transport = MockTransport::new().with_response(HttpResponse::new(404, ""));
+		let client = ArxivClient::with_transport(transport).with_rate_limit(Duration::ZERO);
+		assert!(matches!(client.fetch_datacite(&id).await, Err(ArxivApiError::Status(404))));
+	}
+
+	#[tokio::test]
+	async fn mock_harvester() {
+		let transport = MockTransport::new()
+			.with_response(HttpResponse::new(200, OAI_LIST_RECORDS))
+			.with_response(HttpResponse::new(200, OAI_LIST_RECORDS_LAST));
+		let harvester = OaiHarvester::with_transport(transport).with_rate_limit(Duration::ZERO);
+		let request = OaiRequest::new(OaiMetadataFormat::Arxiv).set("cs");
+
+		let records = harvester
+			.list_records(&request)
+			.try_collect::<Vec<_>>()
+			.await
+			.unwrap();
+		assert_eq!(records.len(), 3);
+		assert!(records.iter().all(|record| match record.arxiv() {
+			Some(metadata) => metadata.is_ok(),
+			None => record.header.deleted,
+		}));
+		assert!(harvester.transport().requests()[1].ends_with("resumptionToken=6960524%7C1001"));
+
+		harvester
+			.transport()
+			.push_response(HttpResponse::new(200, OAI_NO_RECORDS_MATCH));
+		let records = harvester
+			.list_records(&request)
+			.try_collect::<Vec<_>>()
+			.await
+			.unwrap();
+		assert!(records.is_empty());
+	}
+}
@@ -0,0 +1,211 @@
+use crate::doi::strip_prefix_ignore_case;
+use crate::{
+	ArxivCategoryId, ArxivGroup, ArxivId, ArxivIdError, ArxivIdOld, ArxivIdOldResult,
+	ArxivIdResult, ArxivIdentifier,
+};
+use std::str::FromStr;
+
+/// The prefix of the identifiers of records in the OAI-PMH interface of arXiv.org
+const OAI_PREFIX: &str = "oai:arXiv.org:";
+
+impl ArxivId {
+	/// The identifier of the record of the article in the OAI-PMH interface of arXiv.org,
+	/// such as `oai:arXiv.org:2101.00001`
+	///
+	/// Records are kept for articles rather than for each of their versions,
+	/// so the version of the identifier (if any) is not included.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_str("arXiv:2101.00001v3").unwrap();
+	/// assert_eq!(id.to_oai_identifier(), "oai:arXiv.org:2101.00001");
+	/// ```
+	#[must_use]
+	pub fn to_oai_identifier(&self) -> String {
+		let mut article = self.clone();
+		article.set_latest();
+		format!("{}{}", OAI_PREFIX, article.to_bare_string())
+	}
+
+	/// Parses an OAI-PMH identifier of arXiv.org, such as `oai:arXiv.org:2101.00001`,
+	/// returning an [`ArxivIdError::Syntax`] error if it is not of that form.
+	///
+	/// See also: [`ArxivIdOld::from_oai_identifier`]
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivId;
+	///
+	/// let id = ArxivId::from_oai_identifier("oai:arXiv.org:2101.00001");
+	/// assert_eq!(id, ArxivId::from_str("arXiv:2101.00001"));
+	/// ```
+	pub fn from_oai_identifier(s: &str) -> ArxivIdResult {
+		let id = strip_oai_prefix(s)?;
+		Self::from_str(&format!("{}{}", Self::PREFIX, id))
+	}
+}
+
+impl ArxivIdOld {
+	/// The identifier of the record of the article in the OAI-PMH interface of arXiv.org,
+	/// such as `oai:arXiv.org:hep-th/9901001`, which does not include the version
+	///
+	/// Records are identified by archive only, so the subject class of an identifier such as
+	/// `math.AG/0601001` is not included either.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivIdOld;
+	///
+	/// let id = ArxivIdOld::from_str("hep-th/9901001v2").unwrap();
+	/// assert_eq!(id.to_oai_identifier(), "oai:arXiv.org:hep-th/9901001");
+	///
+	/// let id = ArxivIdOld::from_str("math.AG/0601001").unwrap();
+	/// assert_eq!(id.to_oai_identifier(), "oai:arXiv.org:math/0601001");
+	/// ```
+	#[must_use]
+	pub fn to_oai_identifier(&self) -> String {
+		format!(
+			"{}{}{}{:02}{:02}{:03}",
+			OAI_PREFIX,
+			self.archive(),
+			Self::TOKEN_SLASH,
+			self.year() % 100,
+			self.month(),
+			self.number()
+		)
+	}
+
+	/// Parses an OAI-PMH identifier of arXiv.org of the old scheme, such as
+	/// `oai:arXiv.org:hep-th/9901001`, returning an [`ArxivIdError::Syntax`] error if it is
+	/// not of that form.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivIdOld;
+	///
+	/// let id = ArxivIdOld::from_oai_identifier("oai:arXiv.org:math.AG/0601001");
+	/// assert_eq!(id, ArxivIdOld::from_str("math.AG/0601001"));
+	/// ```
+	pub fn from_oai_identifier(s: &str) -> ArxivIdOldResult {
+		Self::from_str(strip_oai_prefix(s)?)
+	}
+}
+
+impl ArxivIdentifier {
+	/// The identifier of the record of the article in the OAI-PMH interface of arXiv.org,
+	/// which does not include the version
+	///
+	/// See also: [`ArxivId::to_oai_identifier`], [`ArxivIdOld::to_oai_identifier`]
+	#[must_use]
+	pub fn to_oai_identifier(&self) -> String {
+		match self {
+			Self::Old(id) => id.to_oai_identifier(),
+			Self::New(id) => id.to_oai_identifier(),
+		}
+	}
+
+	/// Parses an OAI-PMH identifier of arXiv.org of either scheme, such as
+	/// `oai:arXiv.org:2101.00001` or `oai:arXiv.org:hep-th/9901001`
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivIdentifier;
+	///
+	/// let id = ArxivIdentifier::from_oai_identifier("oai:arXiv.org:hep-th/9901001").unwrap();
+	/// assert!(id.as_old().is_some());
+	/// ```
+	pub fn from_oai_identifier(s: &str) -> Result<Self, ArxivIdError> {
+		Self::from_str(strip_oai_prefix(s)?)
+	}
+}
+
+impl ArxivCategoryId {
+	/// The spec of the set of the OAI-PMH interface of arXiv.org containing the category,
+	/// which is the archive within the group for physics, such as `physics:hep-th`, and the
+	/// group otherwise, such as `cs`
+	///
+	/// Sets are not as fine-grained as categories, so harvesting the set of a category also
+	/// returns the records of the other categories of its group or archive.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let category = ArxivCategoryId::from_str("hep-th").unwrap();
+	/// assert_eq!(category.to_oai_set_spec(), "physics:hep-th");
+	///
+	/// let category = ArxivCategoryId::from_str("cs.AI").unwrap();
+	/// assert_eq!(category.to_oai_set_spec(), "cs");
+	/// ```
+	#[must_use]
+	pub fn to_oai_set_spec(&self) -> String {
+		match self.group() {
+			ArxivGroup::Physics => format!("{}:{}", self.group(), self.archive()),
+			group => group.to_string(),
+		}
+	}
+}
+
+fn strip_oai_prefix(s: &str) -> Result<&str, ArxivIdError> {
+	strip_prefix_ignore_case(s.trim(), OAI_PREFIX).ok_or(ArxivIdError::Syntax)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn oai_identifier_round_trip() {
+		let id = ArxivId::from_str("arXiv:0704.0001").unwrap();
+		assert_eq!(ArxivId::from_oai_identifier(&id.to_oai_identifier()), Ok(id));
+
+		let id = ArxivIdOld::from_str("cond-mat/0703999").unwrap();
+		assert_eq!(ArxivIdOld::from_oai_identifier(&id.to_oai_identifier()), Ok(id));
+	}
+
+	#[test]
+	fn parse_oai_identifier_invalid() {
+		assert_eq!(ArxivId::from_oai_identifier("2101.00001"), Err(ArxivIdError::Syntax));
+		assert_eq!(
+			ArxivId::from_oai_identifier("oai:arXiv.org:hep-th/9901001"),
+			Err(ArxivIdError::Syntax)
+		);
+		assert_eq!(
+			ArxivIdOld::from_oai_identifier("oai:example.org:hep-th/9901001"),
+			Err(ArxivIdError::Syntax)
+		);
+	}
+
+	#[test]
+	fn oai_identifier_any_scheme() {
+		for (s, expected) in [
+			("oai:arXiv.org:2101.00001", "oai:arXiv.org:2101.00001"),
+			("oai:arXiv.org:hep-th/9901001", "oai:arXiv.org:hep-th/9901001"),
+			("oai:arXiv.org:math.AG/0601001", "oai:arXiv.org:math/0601001"),
+		] {
+			assert_eq!(
+				ArxivIdentifier::from_oai_identifier(s)
+					.unwrap()
+					.to_oai_identifier(),
+				expected
+			);
+		}
+		assert_eq!(ArxivIdentifier::from_oai_identifier("2101.00001"), Err(ArxivIdError::Syntax));
+	}
+
+	#[test]
+	fn oai_set_spec() {
+		let spec = |s: &str| ArxivCategoryId::from_str(s).unwrap().to_oai_set_spec();
+		assert_eq!(spec("astro-ph.GA"), "physics:astro-ph");
+		assert_eq!(spec("quant-ph"), "physics:quant-ph");
+		assert_eq!(spec("math.AG"), "math");
+		assert_eq!(spec("q-bio.NC"), "q-bio");
+	}
+}
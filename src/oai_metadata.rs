@@ -0,0 +1,781 @@
+//! Parsers of the `arXiv` and `arXivRaw` metadata formats of the [OAI-PMH interface][oai] of
+//! arXiv.org.
+//!
+//! [oai]: https://info.arxiv.org/help/oa/index.html
+
+use crate::author::split_authors;
+use crate::metadata::collapse_whitespace;
+use crate::{
+	ArticleVersion, ArxivArticleMetadata, ArxivAuthor, ArxivCategoryIdError, ArxivCategorySet,
+	ArxivIdError, ArxivIdentifier, VersionHistory,
+};
+use ::quick_xml::events::{BytesStart, Event};
+use ::quick_xml::Reader;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use time::format_description::well_known::Rfc2822;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
+/// An error that can occur when parsing a response of the OAI-PMH interface of arXiv.org,
+/// or the metadata of its records
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OaiError {
+	/// The response is not well-formed XML
+	Xml(String),
+	/// A record is missing a required element
+	MissingElement(&'static str),
+	/// A record has an invalid identifier
+	InvalidId(ArxivIdError),
+	/// A record has an invalid category
+	InvalidCategory(ArxivCategoryIdError),
+	/// A record has an invalid date or datestamp
+	InvalidDate(String),
+	/// A record has an element with an invalid value, such as the size of a version
+	InvalidElement {
+		/// The name of the element
+		name: &'static str,
+		/// The invalid value of the element
+		value: String,
+	},
+	/// The interface reported an error instead of results, such as a bad argument
+	Protocol {
+		/// The code of the error, such as `badArgument` or `idDoesNotExist`
+		code: String,
+		/// The description of the error
+		message: String,
+	},
+}
+
+impl Error for OaiError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidId(e) => Some(e),
+			Self::InvalidCategory(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for OaiError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Xml(e) => write!(f, "The response is not well-formed XML: {}", e),
+			Self::MissingElement(name) => write!(f, "A record is missing the <{}> element.", name),
+			Self::InvalidId(e) => write!(f, "A record has an invalid arXiv identifier: {}", e),
+			Self::InvalidCategory(e) => write!(f, "A record has an invalid category: {}", e),
+			Self::InvalidDate(s) => write!(f, "A record has an invalid date \"{}\".", s),
+			Self::InvalidElement { name, value } => {
+				write!(f, "A record has an invalid <{}> element \"{}\".", name, value)
+			}
+			Self::Protocol { code, message } => {
+				write!(f, "The OAI-PMH interface returned the error {}: {}", code, message)
+			}
+		}
+	}
+}
+
+/// A version of an article, as described by the `arXivRaw` metadata format
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivRawVersion {
+	/// The number of the version, starting at 1
+	pub version: u8,
+	/// When the version was submitted
+	pub date: OffsetDateTime,
+	/// The size of the submission of the version, in kilobytes
+	pub size_kb: u32,
+	/// The type of the source of the version, such as `D` for a TeX source, if any
+	pub source_type: Option<String>,
+}
+
+impl From<ArxivRawVersion> for ArticleVersion {
+	fn from(version: ArxivRawVersion) -> Self {
+		Self {
+			version: version.version,
+			submitted: version.date,
+			size_kb: Some(version.size_kb),
+		}
+	}
+}
+
+/// The metadata of an article in the `arXivRaw` format of the OAI-PMH interface of
+/// arXiv.org, which keeps the authors as submitted and lists every version of the article
+///
+/// The title and abstract are normalized by collapsing their line breaks and indentation into
+/// single spaces.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::ArxivRawMetadata;
+///
+/// let xml = r#"<arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+///   <id>0704.0001</id>
+///   <submitter>Pavel Nadolsky</submitter>
+///   <version version="v1"><date>Mon, 2 Apr 2007 19:18:42 GMT</date><size>37kb</size></version>
+///   <version version="v2"><date>Tue, 24 Jul 2007 20:10:27 GMT</date><size>37kb</size></version>
+///   <title>Calculation of prompt diphoton production cross sections at Tevatron and
+///   LHC energies</title>
+///   <authors>C. Bal\'azs, E. L. Berger, P. M. Nadolsky, C.-P. Yuan</authors>
+///   <categories>hep-ph</categories>
+///   <abstract>A fully differential calculation in perturbative quantum chromodynamics...</abstract>
+/// </arXivRaw>"#;
+///
+/// let metadata = ArxivRawMetadata::from_str(xml).unwrap();
+/// assert_eq!(metadata.versions.len(), 2);
+/// assert_eq!(metadata.versions[1].size_kb, 37);
+/// assert_eq!(metadata.categories.to_string(), "hep-ph");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivRawMetadata {
+	/// The identifier of the article, without a version
+	pub id: ArxivIdentifier,
+	/// The name of the submitter of the article, if any
+	pub submitter: Option<String>,
+	/// The versions of the article, in order
+	pub versions: Vec<ArxivRawVersion>,
+	/// The title of the article
+	pub title: String,
+	/// The authors of the article, as submitted
+	pub authors: String,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// The comments of the authors, if any, such as the number of pages
+	pub comments: Option<String>,
+	/// The report number of the article, if any
+	pub report_no: Option<String>,
+	/// The codes of the Mathematics Subject Classification of the article, if any
+	pub msc_class: Option<String>,
+	/// The codes of the ACM Computing Classification System of the article, if any
+	pub acm_class: Option<String>,
+	/// The journal reference of the published version of the article, if any
+	pub journal_ref: Option<String>,
+	/// The DOI of the published version of the article, if any
+	pub doi: Option<String>,
+	/// The URL of the license of the article, if any
+	pub license: Option<String>,
+	/// The abstract of the article
+	pub abstract_text: String,
+}
+
+/// An author of an article, as described by the `arXiv` metadata format
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivOaiAuthor {
+	/// The family name of the author, or the name of a collaboration
+	pub keyname: String,
+	/// The given names or initials of the author, if any
+	pub forenames: Option<String>,
+	/// The suffix of the name of the author, such as `Jr`, if any
+	pub suffix: Option<String>,
+	/// The affiliations of the author
+	pub affiliations: Vec<String>,
+}
+
+impl ArxivOaiAuthor {
+	/// The full name of the author, from the forenames to the suffix
+	#[must_use]
+	pub fn name(&self) -> String {
+		let parts = [
+			self.forenames.as_deref(),
+			Some(self.keyname.as_str()),
+			self.suffix.as_deref(),
+		];
+		parts
+			.iter()
+			.flatten()
+			.copied()
+			.collect::<Vec<_>>()
+			.join(" ")
+	}
+}
+
+impl From<ArxivOaiAuthor> for ArxivAuthor {
+	fn from(author: ArxivOaiAuthor) -> Self {
+		Self {
+			name: author.name(),
+			affiliations: author.affiliations,
+		}
+	}
+}
+
+/// The metadata of an article in the `arXiv` format of the OAI-PMH interface of arXiv.org,
+/// which splits the names of the authors
+///
+/// The title and abstract are normalized by collapsing their line breaks and indentation into
+/// single spaces.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::ArxivOaiMetadata;
+///
+/// let xml = r#"<arXiv xmlns="http://arxiv.org/OAI/arXiv/">
+///   <id>0704.0002</id>
+///   <created>2007-03-31</created>
+///   <updated>2008-12-13</updated>
+///   <authors>
+///     <author><keyname>Streinu</keyname><forenames>Ileana</forenames></author>
+///     <author><keyname>Theran</keyname><forenames>Louis</forenames></author>
+///   </authors>
+///   <title>Sparsity-certifying Graph Decompositions</title>
+///   <categories>math.CO cs.CG</categories>
+///   <abstract>We describe a new algorithm...</abstract>
+/// </arXiv>"#;
+///
+/// let metadata = ArxivOaiMetadata::from_str(xml).unwrap();
+/// assert_eq!(metadata.authors[0].name(), "Ileana Streinu");
+/// assert_eq!(metadata.created.year(), 2007);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivOaiMetadata {
+	/// The identifier of the article, without a version
+	pub id: ArxivIdentifier,
+	/// When the first version of the article was submitted
+	pub created: Date,
+	/// When the last version of the article was submitted, if there are several
+	pub updated: Option<Date>,
+	/// The authors of the article, in order
+	pub authors: Vec<ArxivOaiAuthor>,
+	/// The title of the article
+	pub title: String,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// The comments of the authors, if any, such as the number of pages
+	pub comments: Option<String>,
+	/// The report number of the article, if any
+	pub report_no: Option<String>,
+	/// The codes of the Mathematics Subject Classification of the article, if any
+	pub msc_class: Option<String>,
+	/// The codes of the ACM Computing Classification System of the article, if any
+	pub acm_class: Option<String>,
+	/// The journal reference of the published version of the article, if any
+	pub journal_ref: Option<String>,
+	/// The DOI of the published version of the article, if any
+	pub doi: Option<String>,
+	/// The URL of the license of the article, if any
+	pub license: Option<String>,
+	/// The abstract of the article
+	pub abstract_text: String,
+}
+
+impl ArxivRawMetadata {
+	/// The history of the versions of the article
+	#[must_use]
+	pub fn version_history(&self) -> VersionHistory {
+		VersionHistory::new(self.versions.iter().cloned().map(ArticleVersion::from))
+	}
+}
+
+impl FromStr for ArxivRawMetadata {
+	type Err = OaiError;
+
+	/// Parses the metadata of a record in the `arXivRaw` format
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut fields = RawFields::default();
+		walk(s, &mut fields)?;
+		fields.into_metadata()
+	}
+}
+
+impl FromStr for ArxivOaiMetadata {
+	type Err = OaiError;
+
+	/// Parses the metadata of a record in the `arXiv` format
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut fields = ArxivFields::default();
+		walk(s, &mut fields)?;
+		fields.into_metadata()
+	}
+}
+
+impl From<ArxivRawMetadata> for ArxivArticleMetadata {
+	/// Converts the metadata of a record in the `arXivRaw` format, splitting its authors as
+	/// submitted into their names
+	fn from(metadata: ArxivRawMetadata) -> Self {
+		Self {
+			versions: metadata.version_history(),
+			id: metadata.id,
+			submitter: metadata.submitter,
+			title: metadata.title,
+			abstract_text: metadata.abstract_text,
+			authors: split_authors(&metadata.authors),
+			categories: metadata.categories,
+			license: metadata.license,
+			journal_ref: metadata.journal_ref,
+			doi: metadata.doi,
+			comments: metadata.comments,
+			report_no: metadata.report_no,
+			updated: None,
+		}
+	}
+}
+
+impl From<ArxivOaiMetadata> for ArxivArticleMetadata {
+	/// Converts the metadata of a record in the `arXiv` format, which does not describe the
+	/// versions of the article, keeping the date of its last version as its update date, if
+	/// it has several versions
+	fn from(metadata: ArxivOaiMetadata) -> Self {
+		Self {
+			id: metadata.id,
+			submitter: None,
+			versions: VersionHistory::default(),
+			title: metadata.title,
+			abstract_text: metadata.abstract_text,
+			authors: metadata.authors.iter().map(ArxivOaiAuthor::name).collect(),
+			categories: metadata.categories,
+			license: metadata.license,
+			journal_ref: metadata.journal_ref,
+			doi: metadata.doi,
+			comments: metadata.comments,
+			report_no: metadata.report_no,
+			updated: metadata.updated,
+		}
+	}
+}
+
+/// Collects the elements of a metadata document while it is walked
+trait Fields {
+	fn on_start(&mut self, e: &BytesStart<'_>) -> Result<(), OaiError>;
+
+	fn on_end(&mut self, name: &[u8], text: String) -> Result<(), OaiError>;
+}
+
+/// Walks the elements of a metadata document, passing the whitespace-collapsed text of each
+/// element to the fields when it ends
+fn walk<F: Fields>(xml: &str, fields: &mut F) -> Result<(), OaiError> {
+	let xml_error = |e: ::quick_xml::Error| OaiError::Xml(e.to_string());
+	let mut reader = Reader::from_str(xml);
+	let mut text = String::new();
+
+	loop {
+		match reader.read_event() {
+			Ok(Event::Start(e)) => {
+				fields.on_start(&e)?;
+				text.clear();
+			}
+			Ok(Event::Text(e)) => text.push_str(&e.unescape().map_err(xml_error)?),
+			Ok(Event::CData(e)) => text.push_str(&String::from_utf8_lossy(&e)),
+			Ok(Event::End(e)) => {
+				fields.on_end(e.local_name().as_ref(), collapse_whitespace(&text))?;
+				text.clear();
+			}
+			Ok(Event::Eof) => return Ok(()),
+			Ok(_) => {}
+			Err(e) => return Err(xml_error(e)),
+		}
+	}
+}
+
+/// The elements shared by the `arXiv` and `arXivRaw` formats
+#[derive(Default)]
+struct CommonFields {
+	id: Option<String>,
+	title: Option<String>,
+	categories: Option<String>,
+	comments: Option<String>,
+	report_no: Option<String>,
+	msc_class: Option<String>,
+	acm_class: Option<String>,
+	journal_ref: Option<String>,
+	doi: Option<String>,
+	license: Option<String>,
+	abstract_text: Option<String>,
+}
+
+impl CommonFields {
+	fn on_end(&mut self, name: &[u8], text: String) {
+		let field = match name {
+			b"id" => &mut self.id,
+			b"title" => &mut self.title,
+			b"categories" => &mut self.categories,
+			b"comments" => &mut self.comments,
+			b"report-no" => &mut self.report_no,
+			b"msc-class" => &mut self.msc_class,
+			b"acm-class" => &mut self.acm_class,
+			b"journal-ref" => &mut self.journal_ref,
+			b"doi" => &mut self.doi,
+			b"license" => &mut self.license,
+			b"abstract" => &mut self.abstract_text,
+			_ => return,
+		};
+		*field = Some(text).filter(|text| !text.is_empty());
+	}
+}
+
+/// The version of an `arXivRaw` record being walked
+#[derive(Default)]
+struct VersionFields {
+	version: Option<String>,
+	date: Option<String>,
+	size: Option<String>,
+	source_type: Option<String>,
+}
+
+impl VersionFields {
+	fn into_version(self) -> Result<ArxivRawVersion, OaiError> {
+		let version = self.version.ok_or(OaiError::MissingElement("version"))?;
+		let date = self.date.ok_or(OaiError::MissingElement("date"))?;
+		let size = self.size.ok_or(OaiError::MissingElement("size"))?;
+		Ok(ArxivRawVersion {
+			version: version
+				.strip_prefix('v')
+				.and_then(|n| n.parse().ok())
+				.filter(|&n| n > 0)
+				.ok_or_else(|| OaiError::InvalidElement {
+					name: "version",
+					value: version.clone(),
+				})?,
+			date: OffsetDateTime::parse(&date, &Rfc2822)
+				.map_err(|_| OaiError::InvalidDate(date))?,
+			size_kb: size
+				.strip_suffix("kb")
+				.and_then(|n| n.parse().ok())
+				.ok_or_else(|| OaiError::InvalidElement {
+					name: "size",
+					value: size.clone(),
+				})?,
+			source_type: self.source_type.filter(|s| !s.is_empty()),
+		})
+	}
+}
+
+#[derive(Default)]
+struct RawFields {
+	common: CommonFields,
+	submitter: Option<String>,
+	authors: Option<String>,
+	version: Option<VersionFields>,
+	versions: Vec<ArxivRawVersion>,
+}
+
+impl Fields for RawFields {
+	fn on_start(&mut self, e: &BytesStart<'_>) -> Result<(), OaiError> {
+		if e.local_name().as_ref() == b"version" {
+			self.version = Some(VersionFields {
+				version: attribute(e, "version")?,
+				..VersionFields::default()
+			});
+		}
+		Ok(())
+	}
+
+	fn on_end(&mut self, name: &[u8], text: String) -> Result<(), OaiError> {
+		if let Some(version) = self.version.as_mut() {
+			match name {
+				b"date" => version.date = Some(text),
+				b"size" => version.size = Some(text),
+				b"source_type" => version.source_type = Some(text),
+				b"version" => {
+					if let Some(version) = self.version.take() {
+						self.versions.push(version.into_version()?);
+					}
+				}
+				_ => {}
+			}
+			return Ok(());
+		}
+
+		match name {
+			b"submitter" => self.submitter = Some(text),
+			b"authors" => self.authors = Some(text),
+			_ => self.common.on_end(name, text),
+		}
+		Ok(())
+	}
+}
+
+impl RawFields {
+	fn into_metadata(self) -> Result<ArxivRawMetadata, OaiError> {
+		let common = self.common;
+		Ok(ArxivRawMetadata {
+			id: parse_id(common.id)?,
+			submitter: self.submitter,
+			versions: self.versions,
+			title: common.title.ok_or(OaiError::MissingElement("title"))?,
+			authors: self.authors.ok_or(OaiError::MissingElement("authors"))?,
+			categories: parse_categories(common.categories)?,
+			comments: common.comments,
+			report_no: common.report_no,
+			msc_class: common.msc_class,
+			acm_class: common.acm_class,
+			journal_ref: common.journal_ref,
+			doi: common.doi,
+			license: common.license,
+			abstract_text: common
+				.abstract_text
+				.ok_or(OaiError::MissingElement("abstract"))?,
+		})
+	}
+}
+
+#[derive(Default)]
+struct ArxivFields {
+	common: CommonFields,
+	created: Option<String>,
+	updated: Option<String>,
+	author: Option<ArxivOaiAuthor>,
+	authors: Vec<ArxivOaiAuthor>,
+}
+
+impl Fields for ArxivFields {
+	fn on_start(&mut self, e: &BytesStart<'_>) -> Result<(), OaiError> {
+		if e.local_name().as_ref() == b"author" {
+			self.author = Some(ArxivOaiAuthor {
+				keyname: String::new(),
+				forenames: None,
+				suffix: None,
+				affiliations: Vec::new(),
+			});
+		}
+		Ok(())
+	}
+
+	fn on_end(&mut self, name: &[u8], text: String) -> Result<(), OaiError> {
+		if let Some(author) = self.author.as_mut() {
+			match name {
+				b"keyname" => author.keyname = text,
+				b"forenames" => author.forenames = Some(text),
+				b"suffix" => author.suffix = Some(text),
+				b"affiliation" => author.affiliations.push(text),
+				b"author" => self.authors.extend(self.author.take()),
+				_ => {}
+			}
+			return Ok(());
+		}
+
+		match name {
+			b"created" => self.created = Some(text),
+			b"updated" => self.updated = Some(text),
+			_ => self.common.on_end(name, text),
+		}
+		Ok(())
+	}
+}
+
+impl ArxivFields {
+	fn into_metadata(self) -> Result<ArxivOaiMetadata, OaiError> {
+		let common = self.common;
+		let created = self.created.ok_or(OaiError::MissingElement("created"))?;
+		Ok(ArxivOaiMetadata {
+			id: parse_id(common.id)?,
+			created: parse_date(created)?,
+			updated: self.updated.map(parse_date).transpose()?,
+			authors: self.authors,
+			title: common.title.ok_or(OaiError::MissingElement("title"))?,
+			categories: parse_categories(common.categories)?,
+			comments: common.comments,
+			report_no: common.report_no,
+			msc_class: common.msc_class,
+			acm_class: common.acm_class,
+			journal_ref: common.journal_ref,
+			doi: common.doi,
+			license: common.license,
+			abstract_text: common
+				.abstract_text
+				.ok_or(OaiError::MissingElement("abstract"))?,
+		})
+	}
+}
+
+fn parse_id(id: Option<String>) -> Result<ArxivIdentifier, OaiError> {
+	let id = id.ok_or(OaiError::MissingElement("id"))?;
+	ArxivIdentifier::from_str(&id).map_err(OaiError::InvalidId)
+}
+
+/// Parses the categories of a record, where the archives which are no longer part of the
+/// taxonomy, as listed by older records, are replaced by their successors like in snapshots
+/// (see [`ArxivCategorySet`])
+fn parse_categories(categories: Option<String>) -> Result<ArxivCategorySet, OaiError> {
+	let categories = categories.ok_or(OaiError::MissingElement("categories"))?;
+	ArxivCategorySet::from_str(&categories).map_err(OaiError::InvalidCategory)
+}
+
+/// Parses a date of the form `YYYY-MM-DD`, such as a datestamp
+pub(crate) fn parse_date(s: String) -> Result<Date, OaiError> {
+	Date::parse(&s, &format_description!("[year]-[month]-[day]"))
+		.map_err(|_| OaiError::InvalidDate(s))
+}
+
+/// The unescaped value of an attribute of an element, if present
+pub(crate) fn attribute(e: &BytesStart<'_>, name: &str) -> Result<Option<String>, OaiError> {
+	match e.try_get_attribute(name) {
+		Ok(Some(attr)) => attr
+			.unescape_value()
+			.map(|value| Some(value.into_owned()))
+			.map_err(|e| OaiError::Xml(e.to_string())),
+		Ok(None) => Ok(None),
+		Err(e) => Err(OaiError::Xml(e.to_string())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use time::macros::{date, datetime};
+
+	const ARXIV_RAW: &str = r#"<arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <id>0704.0001</id>
+  <submitter>Pavel Nadolsky</submitter>
+  <version version="v1">
+    <date>Mon, 2 Apr 2007 19:18:42 GMT</date>
+    <size>37kb</size>
+    <source_type>D</source_type>
+  </version>
+  <version version="v2">
+    <date>Tue, 24 Jul 2007 20:10:27 GMT</date>
+    <size>37kb</size>
+    <source_type>D</source_type>
+  </version>
+  <title>Calculation of prompt diphoton production cross sections at Tevatron and
+  LHC energies</title>
+  <authors>C. Bal\'azs, E. L. Berger, P. M. Nadolsky, C.-P. Yuan</authors>
+  <categories>hep-ph</categories>
+  <comments>37 pages, 15 figures; published version</comments>
+  <report-no>ANL-HEP-PR-07-12</report-no>
+  <journal-ref>Phys.Rev.D76:013009,2007</journal-ref>
+  <doi>10.1103/PhysRevD.76.013009</doi>
+  <license>http://arxiv.org/licenses/nonexclusive-distrib/1.0/</license>
+  <abstract>  A fully differential calculation in perturbative quantum chromodynamics is
+presented for the production of massive photon pairs at hadron colliders.
+</abstract>
+</arXivRaw>"#;
+
+	const ARXIV: &str = r#"<arXiv xmlns="http://arxiv.org/OAI/arXiv/">
+  <id>hep-th/9901001</id>
+  <created>1999-01-04</created>
+  <authors>
+    <author>
+      <keyname>Maldacena</keyname>
+      <forenames>Juan</forenames>
+      <affiliation>Harvard</affiliation>
+    </author>
+    <author>
+      <keyname>Smith</keyname>
+      <forenames>John</forenames>
+      <suffix>Jr</suffix>
+    </author>
+    <author><keyname>ATLAS Collaboration</keyname></author>
+  </authors>
+  <title>A title</title>
+  <categories>hep-th gr-qc</categories>
+  <msc-class>81T30</msc-class>
+  <abstract>An abstract.</abstract>
+</arXiv>"#;
+
+	#[test]
+	fn parse_arxiv_raw() {
+		let metadata = ArxivRawMetadata::from_str(ARXIV_RAW).unwrap();
+		assert_eq!(metadata.id, ArxivIdentifier::from_str("0704.0001").unwrap());
+		assert_eq!(metadata.submitter.as_deref(), Some("Pavel Nadolsky"));
+		assert_eq!(
+			metadata.versions[0],
+			ArxivRawVersion {
+				version: 1,
+				date: datetime!(2007-04-02 19:18:42 UTC),
+				size_kb: 37,
+				source_type: Some(String::from("D")),
+			}
+		);
+		assert_eq!(metadata.versions[1].version, 2);
+		let history = metadata.version_history();
+		assert_eq!(history.latest().and_then(|version| version.size_kb), Some(37));
+		assert_eq!(
+			metadata.title,
+			"Calculation of prompt diphoton production cross sections at Tevatron and LHC energies"
+		);
+		assert_eq!(metadata.authors, r"C. Bal\'azs, E. L. Berger, P. M. Nadolsky, C.-P. Yuan");
+		assert_eq!(metadata.report_no.as_deref(), Some("ANL-HEP-PR-07-12"));
+		assert_eq!(metadata.msc_class, None);
+		assert!(metadata
+			.abstract_text
+			.starts_with("A fully differential calculation in"));
+		assert!(metadata.abstract_text.ends_with("at hadron colliders."));
+	}
+
+	#[test]
+	fn parse_arxiv() {
+		let metadata = ArxivOaiMetadata::from_str(ARXIV).unwrap();
+		assert_eq!(metadata.id.to_string(), "hep-th/9901001");
+		assert_eq!(metadata.created, date!(1999 - 01 - 04));
+		assert_eq!(metadata.updated, None);
+		assert_eq!(metadata.authors.len(), 3);
+		assert_eq!(metadata.authors[0].affiliations, ["Harvard"]);
+		assert_eq!(metadata.authors[1].name(), "John Smith Jr");
+		assert_eq!(metadata.authors[2].name(), "ATLAS Collaboration");
+		let author = ArxivAuthor::from(metadata.authors[1].clone());
+		assert_eq!(author.keyname(), "Smith");
+		assert_eq!(author.suffix(), Some("Jr"));
+		assert_eq!(metadata.categories.to_string(), "hep-th gr-qc");
+		assert_eq!(metadata.msc_class.as_deref(), Some("81T30"));
+	}
+
+	#[test]
+	fn parse_legacy_categories() {
+		let record = ARXIV.replace("hep-th gr-qc", "chao-dyn solv-int nlin.SI");
+		let metadata = ArxivOaiMetadata::from_str(&record).unwrap();
+		assert_eq!(metadata.categories.to_string(), "nlin.CD nlin.SI");
+
+		let record = ARXIV_RAW.replace("<categories>hep-ph", "<categories>alg-geom math.AG");
+		let metadata = ArxivRawMetadata::from_str(&record).unwrap();
+		assert_eq!(metadata.categories.to_string(), "math.AG");
+	}
+
+	#[test]
+	fn metadata_from_records() {
+		let metadata = ArxivArticleMetadata::from(ArxivRawMetadata::from_str(ARXIV_RAW).unwrap());
+		assert_eq!(metadata.submitter.as_deref(), Some("Pavel Nadolsky"));
+		assert_eq!(
+			metadata.authors,
+			[
+				r"C. Bal\'azs",
+				"E. L. Berger",
+				"P. M. Nadolsky",
+				"C.-P. Yuan"
+			]
+		);
+		assert_eq!(metadata.latest_version().map(|version| version.version), Some(2));
+		assert_eq!(metadata.report_numbers()[0].institution(), Some("ANL"));
+
+		let metadata = ArxivArticleMetadata::from(ArxivOaiMetadata::from_str(ARXIV).unwrap());
+		assert_eq!(metadata.id.to_string(), "hep-th/9901001");
+		assert_eq!(metadata.authors, ["Juan Maldacena", "John Smith Jr", "ATLAS Collaboration"]);
+		assert!(metadata.versions.is_empty());
+		assert_eq!(metadata.submitter, None);
+	}
+
+	#[test]
+	fn parse_metadata_invalid() {
+		assert_eq!(
+			ArxivRawMetadata::from_str(&ARXIV_RAW.replace("37kb", "37")),
+			Err(OaiError::InvalidElement {
+				name: "size",
+				value: String::from("37"),
+			})
+		);
+		assert!(matches!(
+			ArxivRawMetadata::from_str(&ARXIV_RAW.replace("version=\"v2\"", "version=\"v0\"")),
+			Err(OaiError::InvalidElement {
+				name: "version",
+				..
+			})
+		));
+		assert!(matches!(
+			ArxivRawMetadata::from_str(&ARXIV_RAW.replace("Mon, 2 Apr", "2 April")),
+			Err(OaiError::InvalidDate(_))
+		));
+		assert!(matches!(
+			ArxivOaiMetadata::from_str(&ARXIV.replace("hep-th gr-qc", "hep-th xyz.AB")),
+			Err(OaiError::InvalidCategory(_))
+		));
+		assert_eq!(
+			ArxivOaiMetadata::from_str(&ARXIV.replace("<created>1999-01-04</created>", "")),
+			Err(OaiError::MissingElement("created"))
+		);
+		assert!(matches!(ArxivOaiMetadata::from_str("<arXiv><id></title>"), Err(OaiError::Xml(_))));
+	}
+}
@@ -0,0 +1,181 @@
+use crate::{ArxivId, ArxivIdError};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// An identifier as a segment of a URL path, such as `/papers/2101.00001v1`
+///
+/// Unlike [`ArxivId`], the `arXiv:` prefix is optional when parsing, and is left out when
+/// displaying, since the colon is reserved in URLs.
+///
+/// With the `serde` feature, it deserializes from either form, so that it can be used with
+/// path extractors based on `serde`, such as `actix_web::web::Path<ArxivIdParam>`. With the
+/// `axum` feature, it is also an extractor of its own, which rejects invalid identifiers
+/// with a helpful `400 Bad Request` response.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivId, ArxivIdParam};
+///
+/// let param = ArxivIdParam::from_str("2101.00001v1").unwrap();
+/// assert_eq!(*param, ArxivId::from_str("arXiv:2101.00001v1").unwrap());
+/// assert_eq!(param.to_string(), "2101.00001v1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArxivIdParam(pub ArxivId);
+
+impl ArxivIdParam {
+	/// The identifier of the path segment
+	#[must_use]
+	#[inline]
+	pub fn into_inner(self) -> ArxivId {
+		self.0
+	}
+}
+
+impl Deref for ArxivIdParam {
+	type Target = ArxivId;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl From<ArxivId> for ArxivIdParam {
+	fn from(id: ArxivId) -> Self {
+		Self(id)
+	}
+}
+
+impl From<ArxivIdParam> for ArxivId {
+	fn from(param: ArxivIdParam) -> Self {
+		param.0
+	}
+}
+
+impl Display for ArxivIdParam {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.0.to_bare_string())
+	}
+}
+
+impl FromStr for ArxivIdParam {
+	type Err = ArxivIdError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		ArxivId::from_bare_str(s).map(Self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for ArxivIdParam {
+	fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		crate::serde::bare::serialize(&self.0, serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for ArxivIdParam {
+	fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		crate::serde::bare::deserialize(deserializer).map(Self)
+	}
+}
+
+/// The rejection of the [`ArxivIdParam`] extractor
+#[cfg(feature = "axum")]
+#[derive(Debug)]
+pub enum ArxivIdRejection {
+	/// The path has no parameters, or more than one parameter
+	Path(::axum::extract::rejection::PathRejection),
+	/// The path parameter is not a valid identifier
+	InvalidArxivId {
+		/// The path parameter
+		value: String,
+		/// The reason the identifier is invalid
+		source: ArxivIdError,
+	},
+}
+
+#[cfg(feature = "axum")]
+impl Display for ArxivIdRejection {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Path(e) => write!(f, "{}", e),
+			Self::InvalidArxivId { value, source } => {
+				write!(f, "\"{}\" is not a valid arXiv identifier: {}", value, source)
+			}
+		}
+	}
+}
+
+#[cfg(feature = "axum")]
+impl std::error::Error for ArxivIdRejection {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Path(e) => Some(e),
+			Self::InvalidArxivId { source, .. } => Some(source),
+		}
+	}
+}
+
+#[cfg(feature = "axum")]
+impl ::axum::response::IntoResponse for ArxivIdRejection {
+	fn into_response(self) -> ::axum::response::Response {
+		match self {
+			Self::Path(e) => e.into_response(),
+			Self::InvalidArxivId { .. } => {
+				(::axum::http::StatusCode::BAD_REQUEST, self.to_string()).into_response()
+			}
+		}
+	}
+}
+
+/// Extracts the only parameter of the path as an identifier.
+#[cfg(feature = "axum")]
+impl<S: Send + Sync> ::axum::extract::FromRequestParts<S> for ArxivIdParam {
+	type Rejection = ArxivIdRejection;
+
+	async fn from_request_parts(
+		parts: &mut ::axum::http::request::Parts,
+		state: &S,
+	) -> Result<Self, Self::Rejection> {
+		let ::axum::extract::Path(value) =
+			::axum::extract::Path::<String>::from_request_parts(parts, state)
+				.await
+				.map_err(ArxivIdRejection::Path)?;
+
+		match Self::from_str(&value) {
+			Ok(param) => Ok(param),
+			Err(source) => Err(ArxivIdRejection::InvalidArxivId { value, source }),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_param() {
+		let expected = ArxivId::from_str("arXiv:2101.00001").unwrap();
+		assert_eq!(ArxivIdParam::from_str("2101.00001").map(ArxivId::from), Ok(expected.clone()));
+		assert_eq!(ArxivIdParam::from_str("arXiv:2101.00001").map(ArxivId::from), Ok(expected));
+		assert_eq!(ArxivIdParam::from_str("2113.00001"), Err(ArxivIdError::InvalidMonth));
+	}
+
+	#[cfg(feature = "axum")]
+	#[test]
+	fn rejection_is_bad_request() {
+		use ::axum::response::IntoResponse;
+
+		let rejection = ArxivIdRejection::InvalidArxivId {
+			value: String::from("2113.00001"),
+			source: ArxivIdError::InvalidMonth,
+		};
+		assert!(rejection
+			.to_string()
+			.starts_with("\"2113.00001\" is not a valid arXiv identifier"));
+		assert_eq!(rejection.into_response().status(), ::axum::http::StatusCode::BAD_REQUEST);
+	}
+}
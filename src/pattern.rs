@@ -0,0 +1,16 @@
+//! Regular expressions of the canonical string forms, for schemas of API descriptions
+
+/// The pattern of an identifier, such as `arXiv:2101.00001v1`
+pub(crate) const ARXIV_ID_PATTERN: &str =
+	r"^arXiv:(0[7-9]|[1-9][0-9])(0[1-9]|1[0-2])\.[0-9]{4,5}(v[1-9][0-9]*)?$";
+
+/// The pattern of an old identifier, such as `hep-th/9901001` or `math.AG/0601001`
+pub(crate) const ARXIV_ID_OLD_PATTERN: &str = r"^(arXiv:)?[a-z]([a-z-]*[a-z])?(\.[A-Za-z0-9]+)?/(9[1-9]|0[0-7])(0[1-9]|1[0-2])[0-9]{3}(v[1-9][0-9]*)?$";
+
+/// The pattern of a category, such as `cs.LG` or `hep-th`
+pub(crate) const ARXIV_CATEGORY_PATTERN: &str = r"^[a-z]+(-[a-z]+)*(\.[A-Za-z]+(-[A-Za-z]+)*)?$";
+
+/// The pattern of a stamp in its short form, such as `arXiv:2101.00001v1 [cs.LG] 1 Jan 2021`
+/// or `arXiv:2101.00001v1 1 Jan 2021` for a stamp without a category
+#[cfg(feature = "schemars")]
+pub(crate) const ARXIV_STAMP_PATTERN: &str = r"^arXiv:[0-9]{4}\.[0-9]{4,5}(v[1-9][0-9]*)?( \[[a-z]+(-[a-z]+)*(\.[A-Za-z]+(-[A-Za-z]+)*)?\])? [1-9][0-9]? [A-Z][a-z]{2} [0-9]{4}$";
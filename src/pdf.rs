@@ -0,0 +1,208 @@
+use crate::{ArxivIdExtractor, ArxivIdentifier, ArxivStamp, ArxivStampError, ArxivStampResult};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+
+/// An error of finding the identifiers or the stamp of a PDF
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArxivPdfError {
+	/// The text of the PDF could not be extracted, such as for unreadable or damaged files
+	Extract(String),
+	/// The text of the PDF does not contain a valid stamp
+	Stamp(ArxivStampError),
+}
+
+impl Error for ArxivPdfError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Extract(_) => None,
+			Self::Stamp(e) => Some(e),
+		}
+	}
+}
+
+impl Display for ArxivPdfError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Extract(message) => {
+				write!(f, "The text of the PDF could not be extracted: {}.", message)
+			}
+			Self::Stamp(e) => write!(f, "The PDF does not contain a valid stamp: {}", e),
+		}
+	}
+}
+
+impl From<pdf_extract::OutputError> for ArxivPdfError {
+	fn from(e: pdf_extract::OutputError) -> Self {
+		Self::Extract(e.to_string())
+	}
+}
+
+impl ArxivStamp {
+	/// Finds the stamp of a PDF downloaded from arXiv.org, such as to recover the identifier
+	/// of a file which was renamed
+	///
+	/// The text of each page is extracted in order, and the first stamp found is returned (see
+	/// [`ArxivStamp::from_extracted_text`]), including a rotated stamp extracted as lines of
+	/// single characters among the other lines of the page. The error of the first page is
+	/// returned if no page contains a valid stamp.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use arxiv::ArxivStamp;
+	///
+	/// let stamp = ArxivStamp::find_in_pdf("downloads/paper.pdf").unwrap();
+	/// println!("{}", stamp.id());
+	/// ```
+	pub fn find_in_pdf(path: impl AsRef<Path>) -> Result<Self, ArxivPdfError> {
+		let pages = pdf_extract::extract_text_by_pages(path)?;
+		let mut error = None;
+		for page in &pages {
+			match find_stamp(page) {
+				Ok(stamp) => return Ok(stamp),
+				Err(e) => {
+					error.get_or_insert(e);
+				}
+			}
+		}
+		Err(ArxivPdfError::Stamp(error.unwrap_or(ArxivStampError::NotEnoughComponents)))
+	}
+}
+
+/// Finds the arXiv identifiers in the text of a PDF, in order of their first occurrence and
+/// without duplicates, such as the identifier of a downloaded article and of its references
+///
+/// Identifiers are found as with [`ArxivIdExtractor::new`], so a stamp extracted as lines of
+/// single characters is not found (see [`ArxivStamp::find_in_pdf`]).
+///
+/// # Examples
+/// ```no_run
+/// let ids = arxiv::find_ids_in_pdf("downloads/paper.pdf").unwrap();
+/// for id in ids {
+///     println!("{}", id);
+/// }
+/// ```
+pub fn find_ids_in_pdf(path: impl AsRef<Path>) -> Result<Vec<ArxivIdentifier>, ArxivPdfError> {
+	let text = pdf_extract::extract_text(path)?;
+	let mut ids = Vec::new();
+	for (_, id) in ArxivIdExtractor::new().scan(&text) {
+		if !ids.contains(&id) {
+			ids.push(id);
+		}
+	}
+	Ok(ids)
+}
+
+/// Finds the stamp of the text of a page, either in the whole text or in a run of lines
+/// of at most one character
+fn find_stamp(page: &str) -> ArxivStampResult {
+	let error = match ArxivStamp::from_extracted_text(page) {
+		Ok(stamp) => return Ok(stamp),
+		Err(e) => e,
+	};
+
+	let lines = page.lines().collect::<Vec<&str>>();
+	lines
+		.split(|line| line.trim().chars().count() > 1)
+		.filter(|run| run.len() > 1)
+		.find_map(|run| ArxivStamp::from_extracted_text(&run.join("\n")).ok())
+		.ok_or(error)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	/// Writes a PDF of one page with a line of text for each string
+	fn write_pdf(name: &str, lines: &[&str]) -> std::path::PathBuf {
+		let mut content = String::from("BT /F1 10 Tf 72 720 Td 12 TL");
+		for line in lines {
+			content.push_str(&format!(" ({}) Tj T*", line));
+		}
+		content.push_str(" ET");
+
+		let objects = [
+			String::from("<< /Type /Catalog /Pages 2 0 R >>"),
+			String::from("<< /Type /Pages /Kids [3 0 R] /Count 1 >>"),
+			String::from(
+				"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+				 /Resources << /Font << /F1 4 0 R >> >> /Contents 5 0 R >>",
+			),
+			String::from(
+				"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica \
+				 /Encoding /WinAnsiEncoding >>",
+			),
+			format!("<< /Length {} >>\nstream\n{}\nendstream", content.len(), content),
+		];
+
+		let mut pdf = String::from("%PDF-1.4\n");
+		let mut offsets = Vec::new();
+		for (i, object) in objects.iter().enumerate() {
+			offsets.push(pdf.len());
+			pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+		}
+		let xref = pdf.len();
+		pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+		for offset in offsets {
+			pdf.push_str(&format!("{:010} 00000 n \n", offset));
+		}
+		pdf.push_str(&format!(
+			"trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+			objects.len() + 1,
+			xref
+		));
+
+		let path = std::env::temp_dir().join(format!("arxiv-{}-{}.pdf", name, std::process::id()));
+		std::fs::write(&path, pdf).unwrap();
+		path
+	}
+
+	#[test]
+	fn find_in_pdf() {
+		let path = write_pdf(
+			"stamp",
+			&[
+				"arXiv:1706.03762v7  [cs.CL]  2 Aug 2023",
+				"Attention Is All You Need",
+				"as in [arXiv:1409.0473] and hep-th/9711200, or arXiv:1409.0473",
+			],
+		);
+		assert_eq!(
+			ArxivStamp::find_in_pdf(&path),
+			ArxivStamp::from_str("arXiv:1706.03762v7 [cs.CL] 2 Aug 2023")
+				.map_err(ArxivPdfError::Stamp)
+		);
+		assert_eq!(
+			find_ids_in_pdf(&path)
+				.unwrap()
+				.iter()
+				.map(ArxivIdentifier::to_bare_string)
+				.collect::<Vec<_>>(),
+			["1706.03762v7", "1409.0473", "hep-th/9711200"]
+		);
+		let _ = std::fs::remove_file(path);
+
+		let path = write_pdf("no-stamp", &["Attention Is All You Need"]);
+		assert!(matches!(ArxivStamp::find_in_pdf(&path), Err(ArxivPdfError::Stamp(_))));
+		assert_eq!(find_ids_in_pdf(&path), Ok(Vec::new()));
+		let _ = std::fs::remove_file(path);
+	}
+
+	#[test]
+	fn find_stamp_in_lines_of_characters() {
+		let page =
+			"Attention Is All You Need\n\na\nr\nX\ni\nv\n:\n1\n7\n0\n6\n.\n0\n3\n7\n6\n2\n\n\
+		            [\nc\ns\n.\nC\nL\n]\n\n2\n\nA\nu\ng\n\n2\n0\n2\n3\nAbstract";
+		assert_eq!(find_stamp(page), ArxivStamp::from_str("arXiv:1706.03762 [cs.CL] 2 Aug 2023"));
+	}
+
+	#[test]
+	fn unreadable_pdf() {
+		let path =
+			std::env::temp_dir().join(format!("arxiv-unreadable-{}.pdf", std::process::id()));
+		std::fs::write(&path, "not a PDF").unwrap();
+		assert!(matches!(find_ids_in_pdf(&path), Err(ArxivPdfError::Extract(_))));
+		let _ = std::fs::remove_file(path);
+	}
+}
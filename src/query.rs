@@ -0,0 +1,423 @@
+use crate::ArxivCategoryId;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A field of articles that can be searched with the export API of arXiv.org
+///
+/// See also: [Details of query construction][query]
+///
+/// [query]: https://info.arxiv.org/help/api/user-manual.html#query_details
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchField {
+	/// The title, with the `ti` prefix
+	Title,
+	/// The authors, with the `au` prefix
+	Author,
+	/// The abstract, with the `abs` prefix
+	Abstract,
+	/// The comments, with the `co` prefix
+	Comment,
+	/// The journal reference, with the `jr` prefix
+	JournalRef,
+	/// The categories, with the `cat` prefix
+	Category,
+	/// The report numbers, with the `rn` prefix
+	ReportNumber,
+	/// All of the fields above, with the `all` prefix
+	All,
+}
+
+impl SearchField {
+	/// The prefix of the field in search queries, such as `ti`
+	#[must_use]
+	#[inline]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Title => "ti",
+			Self::Author => "au",
+			Self::Abstract => "abs",
+			Self::Comment => "co",
+			Self::JournalRef => "jr",
+			Self::Category => "cat",
+			Self::ReportNumber => "rn",
+			Self::All => "all",
+		}
+	}
+}
+
+/// The order in which the export API of arXiv.org sorts results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortBy {
+	/// By relevance to the query, which is the default
+	Relevance,
+	/// By the date of the last update of the articles
+	LastUpdatedDate,
+	/// By the date of submission of the first version of the articles
+	SubmittedDate,
+}
+
+impl Display for SortBy {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(match self {
+			Self::Relevance => "relevance",
+			Self::LastUpdatedDate => "lastUpdatedDate",
+			Self::SubmittedDate => "submittedDate",
+		})
+	}
+}
+
+/// The direction in which the export API of arXiv.org sorts results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+	/// From the oldest or least relevant result
+	Ascending,
+	/// From the most recent or most relevant result, which is the default
+	Descending,
+}
+
+impl Display for SortOrder {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(match self {
+			Self::Ascending => "ascending",
+			Self::Descending => "descending",
+		})
+	}
+}
+
+/// The boolean operator at the top level of a search expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+	And,
+	Or,
+	AndNot,
+	/// An expression written by hand, which is always grouped when combined
+	Unknown,
+}
+
+impl Operator {
+	const fn as_str(&self) -> &'static str {
+		match self {
+			Self::And => "AND",
+			Self::Or => "OR",
+			Self::AndNot => "ANDNOT",
+			Self::Unknown => "",
+		}
+	}
+}
+
+/// A search query of the export API of arXiv.org, with the parameters of the page of results
+///
+/// Terms added one after another must all match, and queries can be combined with
+/// [`Query::and`], [`Query::or`] and [`Query::and_not`], which group the other query in
+/// parentheses when needed. Terms with spaces are quoted as phrases.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivCategoryId, Query, SortBy};
+///
+/// let category = ArxivCategoryId::from_str("cs.LG").unwrap();
+/// let query = Query::new()
+///     .category(&category)
+///     .author("Hinton")
+///     .and(Query::new().title("dropout").or(Query::new().title("neural network")))
+///     .max_results(100)
+///     .sort_by(SortBy::SubmittedDate);
+///
+/// assert_eq!(
+///     query.search_query(),
+///     r#"cat:cs.LG AND au:Hinton AND (ti:dropout OR ti:"neural network")"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+	expr: String,
+	operator: Option<Operator>,
+	start: usize,
+	max_results: Option<usize>,
+	sort_by: Option<SortBy>,
+	sort_order: Option<SortOrder>,
+}
+
+impl Query {
+	/// The maximum number of results the export API returns for a single request
+	pub const MAX_RESULTS_PER_REQUEST: usize = 2000;
+
+	/// Creates an empty query
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a query from a search expression written by hand, such as
+	/// `ti:dropout AND au:Hinton`
+	#[must_use]
+	pub fn raw(expr: impl Into<String>) -> Self {
+		let expr = expr.into();
+		let operator = expr.trim().contains(' ').then_some(Operator::Unknown);
+		Self {
+			expr,
+			operator,
+			..Self::default()
+		}
+	}
+
+	/// Adds a term that must match in the field
+	#[must_use]
+	pub fn field(self, field: SearchField, value: &str) -> Self {
+		let value = value.replace('"', "");
+		let value = value.trim();
+		let term = match value.contains(char::is_whitespace) {
+			true => format!("{}:\"{}\"", field.as_str(), value),
+			false => format!("{}:{}", field.as_str(), value),
+		};
+		self.combine(Operator::And, term, None)
+	}
+
+	/// Adds a term that must match in the title
+	#[must_use]
+	pub fn title(self, value: &str) -> Self {
+		self.field(SearchField::Title, value)
+	}
+
+	/// Adds a term that must match in the authors
+	#[must_use]
+	pub fn author(self, value: &str) -> Self {
+		self.field(SearchField::Author, value)
+	}
+
+	/// Adds a term that must match in the abstract
+	#[must_use]
+	pub fn abstract_text(self, value: &str) -> Self {
+		self.field(SearchField::Abstract, value)
+	}
+
+	/// Adds a term that must match in any field
+	#[must_use]
+	pub fn all(self, value: &str) -> Self {
+		self.field(SearchField::All, value)
+	}
+
+	/// Restricts the results to articles listed in the category
+	#[must_use]
+	pub fn category(self, category: &ArxivCategoryId) -> Self {
+		self.field(SearchField::Category, &category.to_string())
+	}
+
+	/// Combines the search terms of the query with those of another query, which must both
+	/// match
+	#[must_use]
+	pub fn and(self, other: Query) -> Self {
+		self.combine(Operator::And, other.expr, other.operator)
+	}
+
+	/// Combines the search terms of the query with those of another query, either of which
+	/// must match
+	#[must_use]
+	pub fn or(self, other: Query) -> Self {
+		self.combine(Operator::Or, other.expr, other.operator)
+	}
+
+	/// Combines the search terms of the query with those of another query, which must not
+	/// match
+	#[must_use]
+	pub fn and_not(self, other: Query) -> Self {
+		self.combine(Operator::AndNot, other.expr, other.operator)
+	}
+
+	/// Sets the index of the first result, starting at 0
+	#[must_use]
+	pub fn start(mut self, start: usize) -> Self {
+		self.start = start;
+		self
+	}
+
+	/// Sets the maximum number of results, which the export API caps at
+	/// [`Query::MAX_RESULTS_PER_REQUEST`]
+	#[must_use]
+	pub fn max_results(mut self, max_results: usize) -> Self {
+		self.max_results = Some(max_results);
+		self
+	}
+
+	/// Sets the order of the results
+	#[must_use]
+	pub fn sort_by(mut self, sort_by: SortBy) -> Self {
+		self.sort_by = Some(sort_by);
+		self
+	}
+
+	/// Sets the direction of the order of the results
+	#[must_use]
+	pub fn sort_order(mut self, sort_order: SortOrder) -> Self {
+		self.sort_order = Some(sort_order);
+		self
+	}
+
+	/// The search expression of the query, as sent in the `search_query` parameter
+	#[must_use]
+	#[inline]
+	pub fn search_query(&self) -> &str {
+		&self.expr
+	}
+
+	/// The index of the first result
+	#[must_use]
+	#[inline]
+	pub const fn start_index(&self) -> usize {
+		self.start
+	}
+
+	/// The maximum number of results, if set
+	#[must_use]
+	#[inline]
+	pub const fn max_results_per_page(&self) -> Option<usize> {
+		self.max_results
+	}
+
+	/// The parameters of the query string of a request to the export API
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Query;
+	///
+	/// let query = Query::new().title("dropout").start(10).max_results(5);
+	/// assert_eq!(
+	///     query.to_params(),
+	///     vec![
+	///         ("search_query", String::from("ti:dropout")),
+	///         ("start", String::from("10")),
+	///         ("max_results", String::from("5")),
+	///     ]
+	/// );
+	/// ```
+	#[must_use]
+	pub fn to_params(&self) -> Vec<(&'static str, String)> {
+		let mut params = vec![("search_query", self.expr.clone())];
+		if self.start > 0 {
+			params.push(("start", self.start.to_string()));
+		}
+		if let Some(max_results) = self.max_results {
+			params.push(("max_results", max_results.to_string()));
+		}
+		if let Some(sort_by) = self.sort_by {
+			params.push(("sortBy", sort_by.to_string()));
+		}
+		if let Some(sort_order) = self.sort_order {
+			params.push(("sortOrder", sort_order.to_string()));
+		}
+		params
+	}
+
+	fn combine(mut self, operator: Operator, expr: String, other: Option<Operator>) -> Self {
+		if expr.is_empty() {
+			return self;
+		}
+		if self.expr.is_empty() {
+			self.expr = expr;
+			self.operator = other;
+			return self;
+		}
+
+		if matches!(self.operator, Some(op) if op != operator) {
+			self.expr = format!("({})", self.expr);
+		}
+		self.expr = match other {
+			Some(_) => format!("{} {} ({})", self.expr, operator.as_str(), expr),
+			None => format!("{} {} {}", self.expr, operator.as_str(), expr),
+		};
+		self.operator = Some(operator);
+		self
+	}
+}
+
+/// Encodes parameters as the query string of a URL, in the
+/// `application/x-www-form-urlencoded` format
+pub(crate) fn encode_params(params: &[(&str, String)]) -> String {
+	let mut encoded = String::new();
+	for (i, (name, value)) in params.iter().enumerate() {
+		if i > 0 {
+			encoded.push('&');
+		}
+		encode_component(&mut encoded, name);
+		encoded.push('=');
+		encode_component(&mut encoded, value);
+	}
+	encoded
+}
+
+fn encode_component(encoded: &mut String, s: &str) {
+	for byte in s.bytes() {
+		match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'*' => {
+				encoded.push(char::from(byte))
+			}
+			b' ' => encoded.push('+'),
+			_ => encoded.push_str(&format!("%{:02X}", byte)),
+		}
+	}
+}
+
+impl From<&str> for Query {
+	fn from(expr: &str) -> Self {
+		Self::raw(expr)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn query_terms() {
+		let query = Query::new()
+			.title("dropout")
+			.author(" Geoffrey \"Hinton\" ");
+		assert_eq!(query.search_query(), r#"ti:dropout AND au:"Geoffrey Hinton""#);
+		assert_eq!(Query::new().search_query(), "");
+	}
+
+	#[test]
+	fn query_grouping() {
+		let either = Query::new().all("electron").or(Query::new().all("proton"));
+		let query = either.clone().and_not(Query::new().title("review"));
+		assert_eq!(query.search_query(), "(all:electron OR all:proton) ANDNOT ti:review");
+
+		let query = Query::new().all("quark").and_not(either);
+		assert_eq!(query.search_query(), "all:quark ANDNOT (all:electron OR all:proton)");
+
+		let query = Query::new()
+			.and(Query::new().title("a").title("b"))
+			.title("c");
+		assert_eq!(query.search_query(), "ti:a AND ti:b AND ti:c");
+
+		let query = Query::raw("ti:a OR ti:b").title("c");
+		assert_eq!(query.search_query(), "(ti:a OR ti:b) AND ti:c");
+	}
+
+	#[test]
+	fn encode_query_string() {
+		let query = Query::new()
+			.title("neural network")
+			.category(&"cs.LG".parse().unwrap());
+		assert_eq!(
+			encode_params(&query.start(20).to_params()),
+			"search_query=ti%3A%22neural+network%22+AND+cat%3Acs.LG&start=20"
+		);
+		assert_eq!(encode_params(&[("id_list", String::from("é"))]), "id_list=%C3%A9");
+	}
+
+	#[test]
+	fn query_params() {
+		let query = Query::raw("ti:dropout AND au:Hinton")
+			.sort_by(SortBy::LastUpdatedDate)
+			.sort_order(SortOrder::Ascending);
+		assert_eq!(
+			query.to_params(),
+			vec![
+				("search_query", String::from("ti:dropout AND au:Hinton")),
+				("sortBy", String::from("lastUpdatedDate")),
+				("sortOrder", String::from("ascending")),
+			]
+		);
+	}
+}
@@ -0,0 +1,83 @@
+//! Support for parsing and extracting identifiers in bulk on all cores with
+//! [`rayon`](::rayon), such as when scanning millions of documents.
+
+use crate::{ArxivIdError, ArxivIdExtractor, ArxivIdentifier};
+use ::rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Parses many identifiers of either scheme in parallel (see [`ArxivIdentifier::from_str`]),
+/// returning the result of each identifier in the order of the input
+///
+/// # Examples
+/// ```
+/// let results = arxiv::parse_many_par(vec!["2101.00001", "hep-th/9711200", "2101.0000x"]);
+/// assert!(results[0].is_ok() && results[1].is_ok() && results[2].is_err());
+/// ```
+pub fn parse_many_par<I>(ids: I) -> Vec<Result<ArxivIdentifier, ArxivIdError>>
+where
+	I: IntoParallelIterator,
+	I::Iter: IndexedParallelIterator,
+	I::Item: AsRef<str>,
+{
+	ids.into_par_iter()
+		.map(|id| ArxivIdentifier::from_str(id.as_ref()))
+		.collect()
+}
+
+impl ArxivIdExtractor {
+	/// Finds the identifiers of many texts in parallel (see [`ArxivIdExtractor::scan`]),
+	/// returning the identifiers of each text with their byte ranges, in the order of the input
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivIdExtractor;
+	///
+	/// let texts = ["See arXiv:2101.00001.", "No identifiers here.", "hep-th/9711200"];
+	/// let found = ArxivIdExtractor::new().extract_from_texts_par(&texts[..]);
+	/// assert_eq!(found.iter().map(Vec::len).collect::<Vec<_>>(), [1, 0, 1]);
+	/// assert_eq!(found[0][0].0, 4..20);
+	/// ```
+	#[must_use]
+	pub fn extract_from_texts_par<I>(&self, texts: I) -> Vec<Vec<(Range<usize>, ArxivIdentifier)>>
+	where
+		I: IntoParallelIterator,
+		I::Iter: IndexedParallelIterator,
+		I::Item: AsRef<str>,
+	{
+		texts
+			.into_par_iter()
+			.map(|text| self.scan(text.as_ref()).collect())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_and_extract_in_order() {
+		let ids = (0..1000u32)
+			.map(|n| format!("2101.{:05}", n + 1))
+			.collect::<Vec<_>>();
+		let parsed = parse_many_par(&ids);
+		assert_eq!(
+			parsed
+				.iter()
+				.map(|id| id.as_ref().unwrap().to_bare_string())
+				.collect::<Vec<_>>(),
+			ids
+		);
+
+		let texts = ids
+			.iter()
+			.map(|id| format!("see arXiv:{} and arXiv:{}v2", id, id))
+			.collect::<Vec<_>>();
+		let found = ArxivIdExtractor::new().extract_from_texts_par(texts);
+		assert!(found
+			.iter()
+			.zip(&ids)
+			.all(|(found, id)| found.len() == 2 && found[0].1.to_bare_string() == *id));
+	}
+}
@@ -0,0 +1,68 @@
+//! Support for using identifiers and categories as keys and values in [`redis`](::redis)
+//! commands, as strings in their canonical forms.
+//!
+//! # Examples
+//! ```
+//! use std::str::FromStr;
+//! use arxiv::ArxivId;
+//! use redis::{FromRedisValue, ToRedisArgs, Value};
+//!
+//! let id = ArxivId::from_str("arXiv:2101.00001v1").unwrap();
+//! assert_eq!(id.to_redis_args(), vec![b"arXiv:2101.00001v1".to_vec()]);
+//!
+//! let value = Value::BulkString(b"arXiv:2101.00001v1".to_vec());
+//! assert_eq!(ArxivId::from_redis_value(value).unwrap(), id);
+//! ```
+
+use crate::{ArxivCategoryId, ArxivId};
+use ::redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+use std::str::FromStr;
+
+macro_rules! impl_redis_string {
+	($ty:ty) => {
+		impl ToRedisArgs for $ty {
+			fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+				out.write_arg_fmt(self)
+			}
+		}
+
+		impl ToSingleRedisArg for $ty {}
+
+		impl FromRedisValue for $ty {
+			fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+				let s = String::from_redis_value_ref(v)?;
+				<$ty>::from_str(&s).map_err(|e| ParsingError::from(e.to_string()))
+			}
+
+			fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+				Self::from_redis_value_ref(&v)
+			}
+		}
+	};
+}
+
+impl_redis_string!(ArxivId);
+impl_redis_string!(ArxivCategoryId);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn redis_round_trip() {
+		let category = ArxivCategoryId::from_str("cs.LG").unwrap();
+		let args = category.to_redis_args();
+		assert_eq!(args, vec![b"cs.LG".to_vec()]);
+		let value = Value::BulkString(args.into_iter().next().unwrap());
+		assert_eq!(ArxivCategoryId::from_redis_value(value).unwrap(), category);
+	}
+
+	#[test]
+	fn redis_invalid() {
+		assert!(ArxivId::from_redis_value(Value::BulkString(b"2101".to_vec())).is_err());
+		assert!(ArxivId::from_redis_value(Value::Nil).is_err());
+		assert!(
+			ArxivCategoryId::from_redis_value(Value::SimpleString(String::from("cs.XYZ"))).is_err()
+		);
+	}
+}
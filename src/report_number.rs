@@ -0,0 +1,152 @@
+use crate::ArxivArticleMetadata;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// An error that can occur when parsing a report number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportNumberError {
+	/// The report number has no letter or digit
+	Empty,
+	/// The report number contains a comma or semicolon, which separate report numbers
+	Separator,
+}
+
+impl Error for ReportNumberError {}
+
+impl Display for ReportNumberError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Empty => f.write_str("A report number must have a letter or digit."),
+			Self::Separator => {
+				f.write_str("A report number must not contain a comma or semicolon.")
+			}
+		}
+	}
+}
+
+/// A report number an institution assigned to an article, such as `CERN-TH-2021-123`
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::ReportNumber;
+///
+/// let numbers = ReportNumber::parse_list("CERN-TH-2021-123; DESY 21-001, MIT-CTP/5300");
+/// assert_eq!(numbers.len(), 3);
+/// assert_eq!(numbers[1].as_str(), "DESY 21-001");
+/// assert_eq!(numbers[1].institution(), Some("DESY"));
+///
+/// let number = ReportNumber::from_str(" SLAC-PUB-17600 ").unwrap();
+/// assert_eq!(number.to_string(), "SLAC-PUB-17600");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReportNumber(String);
+
+impl ReportNumber {
+	/// Parses the report numbers of the `report-no` field of an article, which are separated
+	/// by semicolons or commas, skipping the empty ones
+	#[must_use]
+	pub fn parse_list(s: &str) -> Vec<Self> {
+		s.split([';', ','])
+			.filter_map(|number| Self::from_str(number).ok())
+			.collect()
+	}
+
+	/// The report number, such as `CERN-TH-2021-123`
+	#[must_use]
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// The leading letters of the report number, which usually abbreviate the institution
+	/// that assigned it, such as `CERN` for `CERN-TH-2021-123`, if any
+	#[must_use]
+	pub fn institution(&self) -> Option<&str> {
+		let end = self
+			.0
+			.find(|c: char| !c.is_alphabetic())
+			.unwrap_or(self.0.len());
+		match end {
+			0 => None,
+			_ => Some(&self.0[..end]),
+		}
+	}
+}
+
+impl FromStr for ReportNumber {
+	type Err = ReportNumberError;
+
+	/// Parses a single report number, collapsing its whitespace
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.contains([';', ',']) {
+			return Err(ReportNumberError::Separator);
+		}
+		if !s.chars().any(char::is_alphanumeric) {
+			return Err(ReportNumberError::Empty);
+		}
+		Ok(Self(s.split_whitespace().collect::<Vec<_>>().join(" ")))
+	}
+}
+
+impl Display for ReportNumber {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.0)
+	}
+}
+
+impl AsRef<str> for ReportNumber {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl From<ReportNumber> for String {
+	fn from(number: ReportNumber) -> Self {
+		number.0
+	}
+}
+
+impl ArxivArticleMetadata {
+	/// The report numbers of the article, parsed from its `report-no` field
+	#[must_use]
+	pub fn report_numbers(&self) -> Vec<ReportNumber> {
+		self.report_no
+			.as_deref()
+			.map(ReportNumber::parse_list)
+			.unwrap_or_default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_report_numbers() {
+		let numbers = ReportNumber::parse_list(
+			" FERMILAB-PUB-21-123-T ;;  IPMU21-0001,\n KEK  Preprint 2021-1, ",
+		);
+		let numbers = numbers.iter().map(ReportNumber::as_str).collect::<Vec<_>>();
+		assert_eq!(
+			numbers,
+			[
+				"FERMILAB-PUB-21-123-T",
+				"IPMU21-0001",
+				"KEK Preprint 2021-1"
+			]
+		);
+		assert!(ReportNumber::parse_list("").is_empty());
+	}
+
+	#[test]
+	fn parse_report_number() {
+		let number = ReportNumber::from_str("IPMU21-0001").unwrap();
+		assert_eq!(number.institution(), Some("IPMU"));
+		assert_eq!(ReportNumber::from_str("2021-17").unwrap().institution(), None);
+		assert_eq!(ReportNumber::from_str(" - "), Err(ReportNumberError::Empty));
+		assert_eq!(ReportNumber::from_str("A-1; B-2"), Err(ReportNumberError::Separator));
+		assert_eq!(String::from(number), "IPMU21-0001");
+	}
+}
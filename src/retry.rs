@@ -0,0 +1,169 @@
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+/// How a client retries requests the export API of arXiv.org rejected because it was
+/// overloaded or the client was too aggressive, which are the `429 Too Many Requests` and
+/// `503 Service Unavailable` responses
+///
+/// Requests are retried after the delay of the `Retry-After` header of the response if it has
+/// one, or after an exponential backoff otherwise, starting at the initial backoff and
+/// doubling with each retry up to the maximum backoff.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use arxiv::RetryPolicy;
+///
+/// let policy = RetryPolicy::default().with_max_retries(5);
+/// assert_eq!(policy.backoff(0), Duration::from_secs(3));
+/// assert_eq!(policy.backoff(2), Duration::from_secs(12));
+/// assert_eq!(policy.backoff(10), Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryPolicy {
+	max_retries: u32,
+	initial_backoff: Duration,
+	max_backoff: Duration,
+}
+
+impl RetryPolicy {
+	/// A policy that never retries requests
+	pub const NEVER: Self = Self {
+		max_retries: 0,
+		initial_backoff: Duration::ZERO,
+		max_backoff: Duration::ZERO,
+	};
+
+	/// Sets the maximum number of retries of a request
+	#[must_use]
+	pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
+
+	/// Sets the backoff before the first retry
+	#[must_use]
+	pub const fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+		self.initial_backoff = backoff;
+		self
+	}
+
+	/// Sets the maximum backoff between retries
+	#[must_use]
+	pub const fn with_max_backoff(mut self, backoff: Duration) -> Self {
+		self.max_backoff = backoff;
+		self
+	}
+
+	/// The maximum number of retries of a request
+	#[must_use]
+	#[inline]
+	pub const fn max_retries(&self) -> u32 {
+		self.max_retries
+	}
+
+	/// The backoff before the retry following the given number of retries
+	#[must_use]
+	pub fn backoff(&self, retries: u32) -> Duration {
+		let factor = 2u32.saturating_pow(retries);
+		self.initial_backoff
+			.checked_mul(factor)
+			.map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+	}
+
+	/// Whether a response with the HTTP status code should be retried
+	pub(crate) const fn is_retryable(status: u16) -> bool {
+		matches!(status, 429 | 503)
+	}
+}
+
+impl Default for RetryPolicy {
+	/// Retries requests up to 3 times, with a backoff from 3 seconds up to a minute
+	fn default() -> Self {
+		Self {
+			max_retries: 3,
+			initial_backoff: Duration::from_secs(3),
+			max_backoff: Duration::from_secs(60),
+		}
+	}
+}
+
+/// Parses the value of a `Retry-After` header, which is either a number of seconds or an
+/// HTTP date, into the delay from now
+pub(crate) fn parse_retry_after(value: &str, now: OffsetDateTime) -> Option<Duration> {
+	let value = value.trim();
+	if let Ok(seconds) = value.parse::<u64>() {
+		return Some(Duration::from_secs(seconds));
+	}
+
+	let date = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+	Some(Duration::try_from(date - now).unwrap_or(Duration::ZERO))
+}
+
+/// Spaces requests by a minimum interval, across all of the clones of a client
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+	interval: Duration,
+	next: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+	pub(crate) fn new(interval: Duration) -> Self {
+		Self {
+			interval,
+			next: Mutex::new(None),
+		}
+	}
+
+	/// Reserves the next slot for a request, returning how long to wait before sending it
+	pub(crate) fn reserve(&self) -> Duration {
+		let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+		let now = Instant::now();
+		let at = match *next {
+			Some(at) if at > now => at,
+			_ => now,
+		};
+		*next = Some(at + self.interval);
+		at - now
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use time::macros::datetime;
+
+	#[test]
+	fn retry_backoff() {
+		let policy = RetryPolicy::default()
+			.with_initial_backoff(Duration::from_millis(500))
+			.with_max_backoff(Duration::from_secs(5));
+		assert_eq!(policy.backoff(1), Duration::from_secs(1));
+		assert_eq!(policy.backoff(4), Duration::from_secs(5));
+		assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+		assert_eq!(RetryPolicy::NEVER.max_retries(), 0);
+	}
+
+	#[test]
+	fn retry_after_header() {
+		let now = datetime!(2015-10-21 07:27:00 UTC);
+		assert_eq!(parse_retry_after(" 120 ", now), Some(Duration::from_secs(120)));
+		assert_eq!(
+			parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+			Some(Duration::from_secs(60))
+		);
+		assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now), Some(Duration::ZERO));
+		assert_eq!(parse_retry_after("soon", now), None);
+	}
+
+	#[test]
+	fn rate_limiter_spaces_requests() {
+		let limiter = RateLimiter::new(Duration::from_secs(3));
+		assert_eq!(limiter.reserve(), Duration::ZERO);
+		let wait = limiter.reserve();
+		assert!(wait > Duration::from_secs(2) && wait <= Duration::from_secs(3));
+		assert!(limiter.reserve() > Duration::from_secs(5));
+	}
+}
@@ -0,0 +1,117 @@
+use crate::metadata::collapse_whitespace;
+use crate::{ArxivArticleMetadata, ArxivAuthor, JournalRef};
+use std::fmt::Write;
+
+impl ArxivArticleMetadata {
+	/// A RIS record of the article, which can be imported into reference managers such as
+	/// EndNote, Zotero or Mendeley
+	///
+	/// Articles with a journal reference or a DOI are journal articles (`TY  - JOUR`), with
+	/// the journal, volume and pages found in their journal reference, while other articles
+	/// are unpublished works (`TY  - UNPB`). Authors are written as `Keyname, Forenames`,
+	/// and articles without a DOI of their own are given the DOI registered by arXiv.org.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(feature = "oai")]
+	/// # {
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivArticleMetadata, ArxivRawMetadata};
+	///
+	/// let xml = r#"<arXivRaw xmlns="http://arxiv.org/OAI/arXivRaw/">
+	///   <id>1706.03762</id>
+	///   <version version="v1"><date>Mon, 12 Jun 2017 17:57:34 GMT</date><size>1102kb</size></version>
+	///   <title>Attention Is All You Need</title>
+	///   <authors>Ashish Vaswani, Noam Shazeer</authors>
+	///   <categories>cs.CL cs.LG</categories>
+	///   <abstract>The dominant sequence transduction models...</abstract>
+	/// </arXivRaw>"#;
+	/// let metadata = ArxivArticleMetadata::from(ArxivRawMetadata::from_str(xml).unwrap());
+	///
+	/// assert_eq!(
+	///     metadata.to_ris(),
+	///     "TY  - UNPB
+	/// AU  - Vaswani, Ashish
+	/// AU  - Shazeer, Noam
+	/// TI  - Attention Is All You Need
+	/// PY  - 2017
+	/// AB  - The dominant sequence transduction models...
+	/// DO  - 10.48550/arXiv.1706.03762
+	/// UR  - https://arxiv.org/abs/1706.03762
+	/// ER  - \n"
+	/// );
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn to_ris(&self) -> String {
+		let journal_ref = self.journal_ref.as_deref().map(JournalRef::parse);
+		let kind = match journal_ref.is_some() || self.doi.is_some() {
+			true => "JOUR",
+			false => "UNPB",
+		};
+
+		let mut fields = vec![("TY", String::from(kind))];
+		fields.extend(
+			self.authors
+				.iter()
+				.map(|name| ("AU", ArxivAuthor::new(name.as_str()).inverted_name())),
+		);
+		fields.push(("TI", self.title.clone()));
+		fields.push(("PY", self.published_year(journal_ref.as_ref()).to_string()));
+		fields.push(("AB", self.abstract_text.clone()));
+		if let Some(journal_ref) = journal_ref {
+			if let Some(journal) = journal_ref.journal {
+				fields.push(("JO", journal));
+			}
+			if let Some(volume) = journal_ref.volume {
+				fields.push(("VL", volume));
+			}
+			if let Some(pages) = journal_ref.pages {
+				let mut pages = pages.splitn(2, ['-', '\u{2013}']);
+				fields.extend(pages.next().map(|start| ("SP", String::from(start))));
+				fields.extend(pages.next().map(|end| ("EP", String::from(end))));
+			}
+		}
+		fields.extend(self.citation_doi().map(|doi| ("DO", doi)));
+		fields.push(("UR", self.id.abs_url()));
+
+		let mut record = String::new();
+		for (tag, value) in fields {
+			let _ = writeln!(record, "{}  - {}", tag, collapse_whitespace(&value));
+		}
+		record.push_str("ER  - \n");
+		record
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn article_to_ris() {
+		let mut metadata = ArxivArticleMetadata::fixture();
+		metadata.authors = vec![
+			String::from("Juan M. Maldacena"),
+			String::from("Martin Luther King Jr."),
+			String::from("ATLAS Collaboration"),
+		];
+		metadata.doi = None;
+		assert_eq!(
+			metadata.to_ris(),
+			"TY  - JOUR
+AU  - Maldacena, Juan M.
+AU  - King, Martin Luther, Jr.
+AU  - ATLAS Collaboration
+TI  - The Large N Limit of Superconformal Field Theories
+PY  - 1998
+AB  - We show that the large N limit...
+JO  - Adv.Theor.Math.Phys.
+VL  - 2
+SP  - 231
+EP  - 252
+UR  - https://arxiv.org/abs/hep-th/9711200
+ER  - \n"
+		);
+	}
+}
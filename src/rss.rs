@@ -0,0 +1,340 @@
+//! A parser of the daily [RSS feeds][rss] of the categories of arXiv.org.
+//!
+//! [rss]: https://info.arxiv.org/help/rss.html
+
+use crate::metadata::collapse_whitespace;
+use crate::{
+	AnnouncementType, AnnouncementTypeError, ArxivCategoryId, ArxivCategorySet, ArxivIdError,
+	ArxivIdentifier,
+};
+use ::quick_xml::events::Event;
+use ::quick_xml::Reader;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+/// The base URL of the RSS feeds of the categories of arXiv.org
+pub const ARXIV_RSS_URL: &str = "https://rss.arxiv.org/rss";
+
+/// An error that can occur when parsing an RSS feed of arXiv.org
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RssError {
+	/// The feed is not well-formed XML
+	Xml(String),
+	/// An item is missing a required element
+	MissingElement(&'static str),
+	/// An item has an invalid identifier
+	InvalidId(ArxivIdError),
+	/// An item has no valid category
+	NoCategory,
+	/// An item has an invalid announcement type
+	InvalidAnnouncementType(AnnouncementTypeError),
+	/// An item has an invalid publication date
+	InvalidDate(String),
+}
+
+impl Error for RssError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidId(e) => Some(e),
+			Self::InvalidAnnouncementType(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for RssError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Xml(e) => write!(f, "The feed is not well-formed XML: {}", e),
+			Self::MissingElement(name) => write!(f, "An item is missing the <{}> element.", name),
+			Self::InvalidId(e) => write!(f, "An item has an invalid arXiv identifier: {}", e),
+			Self::NoCategory => write!(f, "An item has no valid arXiv category."),
+			Self::InvalidAnnouncementType(e) => {
+				write!(f, "An item has an invalid announcement type: {}", e)
+			}
+			Self::InvalidDate(s) => write!(f, "An item has an invalid date \"{}\".", s),
+		}
+	}
+}
+
+/// An item of the daily RSS feed of a category of arXiv.org, announcing a new article or a
+/// new version of an article
+///
+/// The title and abstract are normalized by collapsing their line breaks and indentation into
+/// single spaces.
+///
+/// # Examples
+/// ```
+/// use arxiv::{AnnouncementType, ArxivFeedEntry};
+///
+/// let rss = r#"<rss xmlns:arxiv="http://arxiv.org/schemas/atom"
+///   xmlns:dc="http://purl.org/dc/elements/1.1/" version="2.0">
+///   <channel>
+///     <title>cs.CL updates on arXiv.org</title>
+///     <item>
+///       <title>Attention Is All You Need</title>
+///       <link>https://arxiv.org/abs/1706.03762</link>
+///       <description>arXiv:1706.03762v7 Announce Type: replace
+/// Abstract: The dominant sequence transduction models...</description>
+///       <guid isPermaLink="false">oai:arXiv.org:1706.03762v7</guid>
+///       <category>cs.CL</category>
+///       <category>cs.LG</category>
+///       <pubDate>Wed, 02 Aug 2023 00:00:00 -0400</pubDate>
+///       <arxiv:announce_type>replace</arxiv:announce_type>
+///       <dc:creator>Ashish Vaswani, Noam Shazeer</dc:creator>
+///     </item>
+///   </channel>
+/// </rss>"#;
+///
+/// let entries = ArxivFeedEntry::from_rss(rss).unwrap();
+/// assert_eq!(entries[0].id.version(), Some(7));
+/// assert_eq!(entries[0].announce_type, AnnouncementType::Replace);
+/// assert_eq!(entries[0].authors, ["Ashish Vaswani", "Noam Shazeer"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ArxivFeedEntry {
+	/// The identifier of the article, including the version announced
+	pub id: ArxivIdentifier,
+	/// The title of the article
+	pub title: String,
+	/// The names of the authors of the article, in order
+	pub authors: Vec<String>,
+	/// The abstract of the article
+	pub summary: String,
+	/// The primary category of the article, and the categories it is cross-listed in
+	pub categories: ArxivCategorySet,
+	/// How the article was announced in the category of the feed
+	pub announce_type: AnnouncementType,
+	/// The URL of the license of the article, if any
+	pub rights: Option<String>,
+	/// When the article was announced
+	pub published: OffsetDateTime,
+}
+
+impl ArxivFeedEntry {
+	/// The primary category of the article if it is cross-listed from it into the category of the feed
+	#[must_use]
+	pub fn cross_listed_from(&self) -> Option<ArxivCategoryId> {
+		match self.announce_type.is_cross_list() {
+			true => Some(self.categories.primary()),
+			false => None,
+		}
+	}
+
+	/// Parses the items of an RSS feed of a category of arXiv.org, such as
+	/// `https://rss.arxiv.org/rss/cs.LG`.
+	///
+	/// Identifiers, categories and announcement types are validated with the parsers of the
+	/// crate, where the first category of an item is its primary category.
+	pub fn from_rss(xml: &str) -> Result<Vec<Self>, RssError> {
+		let xml_error = |e: ::quick_xml::Error| RssError::Xml(e.to_string());
+		let mut reader = Reader::from_str(xml);
+		let mut entries = Vec::new();
+		let mut item: Option<ItemFields> = None;
+		let mut text = String::new();
+
+		loop {
+			match reader.read_event() {
+				Ok(Event::Start(e)) => {
+					if e.local_name().as_ref() == b"item" {
+						item = Some(ItemFields::default());
+					}
+					text.clear();
+				}
+				Ok(Event::Text(e)) => text.push_str(&e.unescape().map_err(xml_error)?),
+				Ok(Event::CData(e)) => text.push_str(&String::from_utf8_lossy(&e)),
+				Ok(Event::End(e)) => {
+					if e.local_name().as_ref() == b"item" {
+						if let Some(fields) = item.take() {
+							entries.push(fields.into_entry()?);
+						}
+					} else if let Some(fields) = item.as_mut() {
+						fields.on_text(e.local_name().as_ref(), text.trim());
+					}
+					text.clear();
+				}
+				Ok(Event::Eof) => break,
+				Ok(_) => {}
+				Err(e) => return Err(xml_error(e)),
+			}
+		}
+
+		Ok(entries)
+	}
+}
+
+impl ArxivCategoryId {
+	/// The URL of the daily RSS feed of the category
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivCategoryId;
+	///
+	/// let category = ArxivCategoryId::from_str("cs.LG").unwrap();
+	/// assert_eq!(category.rss_url(), "https://rss.arxiv.org/rss/cs.LG");
+	/// ```
+	#[must_use]
+	pub fn rss_url(&self) -> String {
+		format!("{}/{}", ARXIV_RSS_URL, self)
+	}
+}
+
+/// The elements of an item, collected before they are validated
+#[derive(Default)]
+struct ItemFields {
+	guid: Option<String>,
+	title: Option<String>,
+	description: Option<String>,
+	categories: Vec<String>,
+	announce_type: Option<String>,
+	creator: Option<String>,
+	rights: Option<String>,
+	pub_date: Option<String>,
+}
+
+impl ItemFields {
+	fn on_text(&mut self, name: &[u8], text: &str) {
+		let text = String::from(text);
+		match name {
+			b"guid" => self.guid = Some(text),
+			b"title" => self.title = Some(text),
+			b"description" => self.description = Some(text),
+			b"category" => self.categories.push(text),
+			b"announce_type" => self.announce_type = Some(text),
+			b"creator" => self.creator = Some(text),
+			b"rights" => self.rights = Some(text),
+			b"pubDate" => self.pub_date = Some(text),
+			_ => {}
+		}
+	}
+
+	fn into_entry(self) -> Result<ArxivFeedEntry, RssError> {
+		let guid = self.guid.ok_or(RssError::MissingElement("guid"))?;
+		let id = ArxivIdentifier::from_oai_identifier(&guid).map_err(RssError::InvalidId)?;
+
+		let mut categories = self
+			.categories
+			.iter()
+			.filter_map(|category| ArxivCategoryId::from_str(category).ok());
+		let primary = categories.next().ok_or(RssError::NoCategory)?;
+
+		let announce_type = self
+			.announce_type
+			.ok_or(RssError::MissingElement("arxiv:announce_type"))?;
+		let pub_date = self.pub_date.ok_or(RssError::MissingElement("pubDate"))?;
+
+		Ok(ArxivFeedEntry {
+			id,
+			title: collapse_whitespace(&self.title.ok_or(RssError::MissingElement("title"))?),
+			authors: self
+				.creator
+				.as_deref()
+				.unwrap_or_default()
+				.split(',')
+				.map(collapse_whitespace)
+				.filter(|name| !name.is_empty())
+				.collect(),
+			summary: summary(self.description.as_deref().unwrap_or_default()),
+			categories: ArxivCategorySet::new(primary, categories),
+			announce_type: AnnouncementType::from_str(&announce_type)
+				.map_err(RssError::InvalidAnnouncementType)?,
+			rights: self.rights.filter(|rights| !rights.is_empty()),
+			published: OffsetDateTime::parse(&pub_date, &Rfc2822)
+				.map_err(|_| RssError::InvalidDate(pub_date))?,
+		})
+	}
+}
+
+/// The abstract of the description of an item, which follows a line with the identifier and
+/// the announcement type
+fn summary(description: &str) -> String {
+	let summary = match description.split_once("Abstract:") {
+		Some((_, summary)) => summary,
+		None => description,
+	};
+	collapse_whitespace(summary)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FEED: &str = r#"<?xml version='1.0' encoding='UTF-8'?>
+<rss xmlns:arxiv="http://arxiv.org/schemas/atom" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:atom="http://www.w3.org/2005/Atom" version="2.0">
+  <channel>
+    <title>hep-th updates on arXiv.org</title>
+    <link>http://rss.arxiv.org/rss/hep-th</link>
+    <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+    <item>
+      <title>A holographic
+  dual</title>
+      <link>https://arxiv.org/abs/2404.08001</link>
+      <description>arXiv:2404.08001v1 Announce Type: cross
+Abstract: We study   holography.</description>
+      <guid isPermaLink="false">oai:arXiv.org:2404.08001v1</guid>
+      <category>gr-qc</category>
+      <category>hep-th</category>
+      <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+      <arxiv:announce_type>cross</arxiv:announce_type>
+      <dc:rights>http://creativecommons.org/licenses/by/4.0/</dc:rights>
+      <dc:creator>Jane Doe, John Smith,  ATLAS Collaboration</dc:creator>
+    </item>
+    <item>
+      <title>Strings on the lattice</title>
+      <link>https://arxiv.org/abs/hep-th/9901001</link>
+      <description>arXiv:hep-th/9901001v3 Announce Type: replace
+Abstract: Revised.</description>
+      <guid isPermaLink="false">oai:arXiv.org:hep-th/9901001v3</guid>
+      <category>hep-th</category>
+      <pubDate>Mon, 15 Apr 2024 00:00:00 -0400</pubDate>
+      <arxiv:announce_type>replace</arxiv:announce_type>
+      <dc:creator>Juan Maldacena</dc:creator>
+    </item>
+  </channel>
+</rss>"#;
+
+	#[test]
+	fn parse_rss_feed() {
+		let entries = ArxivFeedEntry::from_rss(FEED).unwrap();
+		assert_eq!(entries.len(), 2);
+
+		let entry = &entries[0];
+		assert_eq!(entry.id, ArxivIdentifier::from_str("2404.08001v1").unwrap());
+		assert_eq!(entry.title, "A holographic dual");
+		assert_eq!(entry.authors, ["Jane Doe", "John Smith", "ATLAS Collaboration"]);
+		assert_eq!(entry.summary, "We study holography.");
+		assert_eq!(entry.categories.to_string(), "gr-qc hep-th");
+		assert_eq!(entry.announce_type, AnnouncementType::Cross);
+		assert_eq!(entry.cross_listed_from(), Some(entry.categories.primary()));
+		assert!(entry.rights.is_some());
+		assert_eq!(entry.published.offset().whole_hours(), -4);
+
+		let entry = &entries[1];
+		assert_eq!(entry.id.to_string(), "hep-th/9901001v3");
+		assert_eq!(entry.announce_type, AnnouncementType::Replace);
+		assert_eq!(entry.cross_listed_from(), None);
+		assert_eq!(entry.rights, None);
+	}
+
+	#[test]
+	fn parse_rss_feed_invalid() {
+		assert!(matches!(
+			ArxivFeedEntry::from_rss(&FEED.replace(">cross<", ">crossed<")),
+			Err(RssError::InvalidAnnouncementType(_))
+		));
+		assert!(matches!(
+			ArxivFeedEntry::from_rss(&FEED.replace("oai:arXiv.org:2404", "2404")),
+			Err(RssError::InvalidId(_))
+		));
+		assert_eq!(
+			ArxivFeedEntry::from_rss(&FEED.replace("<category>hep-th</category>", "")),
+			Err(RssError::NoCategory)
+		);
+		assert_eq!(ArxivFeedEntry::from_rss("<rss><channel></channel></rss>"), Ok(Vec::new()));
+	}
+}
@@ -0,0 +1,258 @@
+use std::collections::BTreeSet;
+use time::{Date, Month, Weekday};
+
+/// The announcement calendar of arXiv, which is used to figure out on which days new
+/// submissions are announced.
+///
+/// arXiv announces new submissions from Sunday through Thursday (US Eastern time), and
+/// skips announcements during its published holiday closures. The default calendar observes
+/// the recurring holidays listed under [`ArxivCalendar::DEFAULT_HOLIDAYS`], and additional
+/// one-off closures can be added (or removed) at runtime as arXiv publishes its schedule.
+///
+/// See also: [Official arXiv.org documentation][arxiv-docs]
+///
+/// # Examples
+/// ```
+/// use arxiv::ArxivCalendar;
+/// use time::{Date, Month};
+///
+/// let calendar = ArxivCalendar::new();
+/// let friday = Date::from_calendar_date(2023, Month::September, 29).unwrap();
+/// assert_eq!(
+///     calendar.next_announcement_after(friday),
+///     Some(Date::from_calendar_date(2023, Month::October, 1).unwrap())
+/// );
+/// ```
+///
+/// [arxiv-docs]: https://info.arxiv.org/help/availability.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArxivCalendar {
+	default_holidays: bool,
+	closures: BTreeSet<Date>,
+	reopenings: BTreeSet<Date>,
+}
+
+/// A recurring holiday on which arXiv does not announce new submissions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArxivHoliday {
+	/// New Year's Day, on the 1st of January
+	NewYearsDay,
+	/// Juneteenth, on the 19th of June
+	Juneteenth,
+	/// Independence Day, on the 4th of July
+	IndependenceDay,
+	/// Thanksgiving, on the fourth Thursday of November
+	Thanksgiving,
+	/// Christmas Day, on the 25th of December
+	ChristmasDay,
+}
+
+impl ArxivHoliday {
+	/// The date of the holiday in the given year
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivHoliday;
+	/// use time::{Date, Month};
+	///
+	/// assert_eq!(
+	///     ArxivHoliday::Thanksgiving.date_in(2023),
+	///     Date::from_calendar_date(2023, Month::November, 23).ok()
+	/// );
+	/// ```
+	pub fn date_in(self, year: i32) -> Option<Date> {
+		match self {
+			Self::NewYearsDay => Date::from_calendar_date(year, Month::January, 1).ok(),
+			Self::Juneteenth => Date::from_calendar_date(year, Month::June, 19).ok(),
+			Self::IndependenceDay => Date::from_calendar_date(year, Month::July, 4).ok(),
+			Self::Thanksgiving => {
+				let first = Date::from_calendar_date(year, Month::November, 1).ok()?;
+				let offset = (Weekday::Thursday.number_days_from_monday() + 7
+					- first.weekday().number_days_from_monday())
+					% 7;
+				Date::from_calendar_date(year, Month::November, 1 + offset + 21).ok()
+			}
+			Self::ChristmasDay => Date::from_calendar_date(year, Month::December, 25).ok(),
+		}
+	}
+}
+
+impl ArxivCalendar {
+	/// The weekdays on which arXiv announces new submissions
+	pub const ANNOUNCEMENT_WEEKDAYS: [Weekday; 5] = [
+		Weekday::Sunday,
+		Weekday::Monday,
+		Weekday::Tuesday,
+		Weekday::Wednesday,
+		Weekday::Thursday,
+	];
+
+	/// The recurring holidays observed by the default calendar
+	pub const DEFAULT_HOLIDAYS: [ArxivHoliday; 5] = [
+		ArxivHoliday::NewYearsDay,
+		ArxivHoliday::Juneteenth,
+		ArxivHoliday::IndependenceDay,
+		ArxivHoliday::Thanksgiving,
+		ArxivHoliday::ChristmasDay,
+	];
+
+	/// Creates a calendar that observes the default recurring holidays.
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			default_holidays: true,
+			closures: BTreeSet::new(),
+			reopenings: BTreeSet::new(),
+		}
+	}
+
+	/// Creates a calendar that only skips weekends, without any holidays.
+	#[inline]
+	pub fn without_holidays() -> Self {
+		Self {
+			default_holidays: false,
+			..Self::new()
+		}
+	}
+
+	/// Adds a one-off closure, on which no submissions will be announced.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivCalendar;
+	/// use time::{Date, Month};
+	///
+	/// let mut calendar = ArxivCalendar::new();
+	/// let date = Date::from_calendar_date(2023, Month::December, 31).unwrap();
+	/// calendar.add_closure(date);
+	/// assert!(calendar.is_holiday(date));
+	/// ```
+	pub fn add_closure(&mut self, date: Date) {
+		self.reopenings.remove(&date);
+		self.closures.insert(date);
+	}
+
+	/// Removes a closure, including the default holidays, so that submissions
+	/// are announced on that date if it falls on an announcement weekday.
+	pub fn remove_closure(&mut self, date: Date) {
+		self.closures.remove(&date);
+		if self.is_default_holiday(date) {
+			self.reopenings.insert(date);
+		}
+	}
+
+	/// The one-off closures that were added at runtime
+	#[inline]
+	pub fn closures(&self) -> impl Iterator<Item = Date> + '_ {
+		self.closures.iter().copied()
+	}
+
+	/// Whether or not arXiv is closed for a holiday on the given date
+	pub fn is_holiday(&self, date: Date) -> bool {
+		if self.reopenings.contains(&date) {
+			return false;
+		}
+
+		self.closures.contains(&date) || self.is_default_holiday(date)
+	}
+
+	/// Whether or not arXiv announces new submissions on the given date
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ArxivCalendar;
+	/// use time::{Date, Month};
+	///
+	/// let calendar = ArxivCalendar::new();
+	/// let christmas = Date::from_calendar_date(2023, Month::December, 25).unwrap();
+	/// assert!(!calendar.is_announcement_day(christmas));
+	/// ```
+	pub fn is_announcement_day(&self, date: Date) -> bool {
+		Self::ANNOUNCEMENT_WEEKDAYS.contains(&date.weekday()) && !self.is_holiday(date)
+	}
+
+	/// The first announcement day strictly after the given date
+	pub fn next_announcement_after(&self, date: Date) -> Option<Date> {
+		let mut current = date.next_day()?;
+		while !self.is_announcement_day(current) {
+			current = current.next_day()?;
+		}
+
+		Some(current)
+	}
+
+	/// The first announcement day on or after the given date
+	pub fn next_announcement_on_or_after(&self, date: Date) -> Option<Date> {
+		match self.is_announcement_day(date) {
+			true => Some(date),
+			false => self.next_announcement_after(date),
+		}
+	}
+
+	fn is_default_holiday(&self, date: Date) -> bool {
+		self.default_holidays
+			&& Self::DEFAULT_HOLIDAYS
+				.iter()
+				.any(|h| h.date_in(date.year()) == Some(date))
+	}
+}
+
+impl Default for ArxivCalendar {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn date(year: i32, month: Month, day: u8) -> Date {
+		Date::from_calendar_date(year, month, day).unwrap()
+	}
+
+	#[test]
+	fn thanksgiving_dates() {
+		assert_eq!(ArxivHoliday::Thanksgiving.date_in(2022), Some(date(2022, Month::November, 24)));
+		assert_eq!(ArxivHoliday::Thanksgiving.date_in(2023), Some(date(2023, Month::November, 23)));
+		assert_eq!(ArxivHoliday::Thanksgiving.date_in(2024), Some(date(2024, Month::November, 28)));
+	}
+
+	#[test]
+	fn next_announcement_skips_weekend() {
+		let calendar = ArxivCalendar::new();
+		assert_eq!(
+			calendar.next_announcement_after(date(2023, Month::September, 28)),
+			Some(date(2023, Month::October, 1))
+		);
+	}
+
+	#[test]
+	fn next_announcement_skips_holiday() {
+		let calendar = ArxivCalendar::new();
+		assert_eq!(
+			calendar.next_announcement_after(date(2023, Month::November, 22)),
+			Some(date(2023, Month::November, 26))
+		);
+		let calendar = ArxivCalendar::without_holidays();
+		assert_eq!(
+			calendar.next_announcement_after(date(2023, Month::November, 22)),
+			Some(date(2023, Month::November, 23))
+		);
+	}
+
+	#[test]
+	fn runtime_closures() {
+		let mut calendar = ArxivCalendar::new();
+		let closure = date(2023, Month::October, 2);
+		calendar.add_closure(closure);
+		assert!(!calendar.is_announcement_day(closure));
+
+		calendar.remove_closure(closure);
+		assert!(calendar.is_announcement_day(closure));
+
+		let christmas = date(2023, Month::December, 25);
+		calendar.remove_closure(christmas);
+		assert!(calendar.is_announcement_day(christmas));
+	}
+}
@@ -0,0 +1,130 @@
+//! Support for generating JSON schemas of identifiers, categories and stamps with
+//! [`schemars`](::schemars), describing their canonical string forms.
+
+use crate::pattern::{
+	ARXIV_CATEGORY_PATTERN, ARXIV_ID_OLD_PATTERN, ARXIV_ID_PATTERN, ARXIV_STAMP_PATTERN,
+};
+use crate::{ArxivArchive, ArxivCategoryId, ArxivGroup, ArxivId, ArxivIdOld, ArxivStamp};
+use ::schemars::gen::SchemaGenerator;
+use ::schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use ::schemars::JsonSchema;
+
+/// A string schema with a description, and either a pattern or a list of allowed values
+fn string_schema(description: &str, pattern: Option<&str>, values: Vec<String>) -> Schema {
+	SchemaObject {
+		instance_type: Some(InstanceType::String.into()),
+		metadata: Some(Box::new(Metadata {
+			description: Some(String::from(description)),
+			..Default::default()
+		})),
+		string: pattern.map(|pattern| {
+			Box::new(StringValidation {
+				pattern: Some(String::from(pattern)),
+				..Default::default()
+			})
+		}),
+		enum_values: match values.is_empty() {
+			true => None,
+			false => Some(values.into_iter().map(Into::into).collect()),
+		},
+		..Default::default()
+	}
+	.into()
+}
+
+macro_rules! impl_json_schema {
+	($ty:ty, $name:literal, $description:literal, $pattern:expr, $values:expr) => {
+		impl JsonSchema for $ty {
+			fn schema_name() -> String {
+				String::from($name)
+			}
+
+			fn json_schema(_: &mut SchemaGenerator) -> Schema {
+				string_schema($description, $pattern, $values)
+			}
+		}
+	};
+}
+
+impl_json_schema!(
+	ArxivId,
+	"ArxivId",
+	"An arXiv identifier, such as arXiv:2101.00001v1",
+	Some(ARXIV_ID_PATTERN),
+	Vec::new()
+);
+impl_json_schema!(
+	ArxivIdOld,
+	"ArxivIdOld",
+	"An arXiv identifier of the scheme used up to March 2007, such as hep-th/9901001",
+	Some(ARXIV_ID_OLD_PATTERN),
+	Vec::new()
+);
+impl_json_schema!(
+	ArxivCategoryId,
+	"ArxivCategoryId",
+	"An arXiv category, such as cs.LG",
+	Some(ARXIV_CATEGORY_PATTERN),
+	Vec::new()
+);
+impl_json_schema!(
+	ArxivStamp,
+	"ArxivStamp",
+	"An arXiv stamp, such as arXiv:2101.00001v1 [cs.LG] 1 Jan 2021",
+	Some(ARXIV_STAMP_PATTERN),
+	Vec::new()
+);
+impl_json_schema!(
+	ArxivArchive,
+	"ArxivArchive",
+	"An arXiv archive, such as hep-th",
+	None,
+	ArxivArchive::ALL.iter().map(ToString::to_string).collect()
+);
+impl_json_schema!(ArxivGroup, "ArxivGroup", "An arXiv group, such as physics", None, {
+	let mut groups = ArxivArchive::ALL
+		.iter()
+		.map(|archive| ArxivGroup::from(*archive).to_string())
+		.collect::<Vec<_>>();
+	groups.sort();
+	groups.dedup();
+	groups
+});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::schemars::schema_for;
+	use std::str::FromStr;
+	use time::{Date, Month};
+
+	#[test]
+	fn identifier_schema() {
+		let schema = schema_for!(ArxivId).schema;
+		assert_eq!(schema.instance_type, Some(InstanceType::String.into()));
+		assert_eq!(schema.string.unwrap().pattern.as_deref(), Some(ARXIV_ID_PATTERN));
+	}
+
+	#[test]
+	fn stamp_schema_matches_stamps() {
+		let pattern = regex::Regex::new(ARXIV_STAMP_PATTERN).unwrap();
+		let id = ArxivId::from_str("arXiv:2101.00001v1").unwrap();
+		let category = ArxivCategoryId::from_str("cs.LG").ok();
+		let submitted = Date::from_calendar_date(2021, Month::January, 1).unwrap();
+		for stamp in [
+			ArxivStamp::new(id.clone(), category, submitted),
+			ArxivStamp::new(id, None, submitted),
+		] {
+			let stamp = stamp.to_string();
+			assert!(pattern.is_match(&stamp), "{}", stamp);
+			assert!(ArxivStamp::from_str(&stamp).is_ok());
+		}
+	}
+
+	#[test]
+	fn group_schema() {
+		let values = schema_for!(ArxivGroup).schema.enum_values.unwrap();
+		assert_eq!(values.len(), 8);
+		assert!(values.contains(&"q-bio".into()));
+	}
+}
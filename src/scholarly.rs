@@ -0,0 +1,143 @@
+use crate::{ArxivId, ArxivIdOld};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An identifier of a paper in the form accepted by the Semantic Scholar API,
+/// such as `arXiv:2101.00001`
+///
+/// Semantic Scholar keeps papers rather than each of their versions,
+/// so the version of the identifier (if any) is not included.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivId, SemanticScholarId};
+///
+/// let id = ArxivId::from_str("arXiv:2101.00001v2").unwrap();
+/// let paper = SemanticScholarId::from(&id);
+/// assert_eq!(paper.as_str(), "arXiv:2101.00001");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SemanticScholarId(String);
+
+impl SemanticScholarId {
+	/// The identifier as a string, such as `arXiv:2101.00001`
+	#[must_use]
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+impl Display for SemanticScholarId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.0)
+	}
+}
+
+impl From<&ArxivId> for SemanticScholarId {
+	fn from(id: &ArxivId) -> Self {
+		let mut article = id.clone();
+		article.set_latest();
+		Self(article.to_string())
+	}
+}
+
+impl From<&ArxivIdOld> for SemanticScholarId {
+	fn from(id: &ArxivIdOld) -> Self {
+		let mut article = id.clone();
+		article.set_latest();
+		Self(format!("{}{}", ArxivId::PREFIX, article))
+	}
+}
+
+/// An identifier of a work in the form accepted and returned by the OpenAlex API, which is the
+/// lowercase URL of its DataCite DOI, such as `https://doi.org/10.48550/arxiv.2101.00001`
+///
+/// This matches the `doi` field of works returned by OpenAlex, and can be parsed back into
+/// an [`ArxivId`] with [`ArxivId::from_doi`].
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivId, OpenAlexId};
+///
+/// let id = ArxivId::from_str("arXiv:2101.00001").unwrap();
+/// let work = OpenAlexId::from(&id);
+/// assert_eq!(work.as_str(), "https://doi.org/10.48550/arxiv.2101.00001");
+/// assert_eq!(ArxivId::from_doi(work.as_str()), Ok(id));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OpenAlexId(String);
+
+impl OpenAlexId {
+	/// The identifier as a string, such as `https://doi.org/10.48550/arxiv.2101.00001`
+	#[must_use]
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+impl Display for OpenAlexId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.0)
+	}
+}
+
+impl From<&ArxivId> for OpenAlexId {
+	fn from(id: &ArxivId) -> Self {
+		Self(id.to_doi_url().to_ascii_lowercase())
+	}
+}
+
+impl ArxivId {
+	/// The identifier of the paper in the Semantic Scholar API
+	///
+	/// See also: [`SemanticScholarId`]
+	#[must_use]
+	#[inline]
+	pub fn to_semantic_scholar_id(&self) -> SemanticScholarId {
+		SemanticScholarId::from(self)
+	}
+
+	/// The identifier of the work in the OpenAlex API
+	///
+	/// See also: [`OpenAlexId`]
+	#[must_use]
+	#[inline]
+	pub fn to_openalex_id(&self) -> OpenAlexId {
+		OpenAlexId::from(self)
+	}
+}
+
+impl ArxivIdOld {
+	/// The identifier of the paper in the Semantic Scholar API
+	///
+	/// See also: [`SemanticScholarId`]
+	#[must_use]
+	#[inline]
+	pub fn to_semantic_scholar_id(&self) -> SemanticScholarId {
+		SemanticScholarId::from(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::str::FromStr;
+
+	#[test]
+	fn semantic_scholar_id() {
+		let id = ArxivId::from_str("arXiv:0704.0001v1").unwrap();
+		assert_eq!(id.to_semantic_scholar_id().to_string(), "arXiv:0704.0001");
+
+		let id = ArxivIdOld::from_str("hep-th/9901001v3").unwrap();
+		assert_eq!(id.to_semantic_scholar_id().as_str(), "arXiv:hep-th/9901001");
+	}
+
+	#[test]
+	fn openalex_id() {
+		let id = ArxivId::from_str("arXiv:2304.11188v1").unwrap();
+		assert_eq!(id.to_openalex_id().as_str(), "https://doi.org/10.48550/arxiv.2304.11188");
+	}
+}
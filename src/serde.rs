@@ -0,0 +1,327 @@
+//! Support for serializing and deserializing identifiers, categories and stamps with
+//! [`serde`](::serde), using their canonical string forms.
+//!
+//! Identifiers can instead be serialized as structures of their components with the
+//! [`parts`] module, for schemas where each component must be queryable on its own, or
+//! without the `arXiv:` prefix with the [`bare`] module.
+
+use crate::{
+	ArxivArchive, ArxivCategoryId, ArxivCategorySet, ArxivGroup, ArxivId, ArxivIdOld, ArxivStamp,
+};
+use ::serde::de::{Error, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// Deserializes a string with the parser of the type
+struct ParseVisitor<T> {
+	expecting: &'static str,
+	parse: fn(&str) -> Result<T, String>,
+}
+
+impl<'de, T> Visitor<'de> for ParseVisitor<T> {
+	type Value = T;
+
+	fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.expecting)
+	}
+
+	fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+		(self.parse)(v).map_err(E::custom)
+	}
+}
+
+macro_rules! impl_serde_str {
+	($ty:ty, $expecting:literal) => {
+		impl_serde_str!($ty, $expecting, |s| <$ty>::from_str(s).map_err(|e| e.to_string()));
+	};
+	($ty:ty, $expecting:literal, $parse:expr) => {
+		impl Serialize for $ty {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.collect_str(self)
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $ty {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				deserializer.deserialize_str(ParseVisitor {
+					expecting: $expecting,
+					parse: $parse,
+				})
+			}
+		}
+	};
+}
+
+impl_serde_str!(ArxivId, "an arXiv identifier such as arXiv:2101.00001");
+impl_serde_str!(ArxivIdOld, "an old arXiv identifier such as hep-th/9901001");
+impl_serde_str!(ArxivCategoryId, "an arXiv category such as cs.LG");
+impl_serde_str!(ArxivCategorySet, "space-separated arXiv categories such as cs.LG stat.ML");
+impl_serde_str!(ArxivStamp, "an arXiv stamp such as arXiv:2101.00001v1 [cs.LG] 1 Jan 2021");
+impl_serde_str!(ArxivArchive, "an arXiv archive such as hep-th", |s| {
+	ArxivArchive::from_str(s).map_err(|_| format!("The archive \"{}\" is not valid.", s))
+});
+impl_serde_str!(ArxivGroup, "an arXiv group such as physics", |s| {
+	ArxivGroup::from_str(s).map_err(|_| format!("The group \"{}\" is not valid.", s))
+});
+
+/// Serializes identifiers without the `arXiv:` prefix, such as `2101.00001v1`, as commonly
+/// found in spreadsheets and CSV files. Deserializing accepts identifiers with or without
+/// the prefix.
+///
+/// # Examples
+/// ```
+/// use arxiv::ArxivId;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "arxiv::serde::bare")]
+///     id: ArxivId,
+/// }
+/// ```
+pub mod bare {
+	use super::*;
+
+	/// Serializes an identifier without the `arXiv:` prefix.
+	pub fn serialize<S: Serializer>(id: &ArxivId, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&id.to_bare_string())
+	}
+
+	/// Deserializes an identifier with or without the `arXiv:` prefix.
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ArxivId, D::Error> {
+		deserializer.deserialize_str(ParseVisitor {
+			expecting: "an arXiv identifier such as 2101.00001",
+			parse: |s| ArxivId::from_bare_str(s).map_err(|e| e.to_string()),
+		})
+	}
+}
+
+/// Serializes identifiers as structures of their components rather than strings, such as
+/// `{"year": 2021, "month": 1, "number": "00001", "version": 1}` for an [`ArxivId`], or
+/// `{"archive": "math", "subject_class": "AG", "year": 2006, "month": 1, "number": 1,
+/// "version": null}` for an [`ArxivIdOld`].
+///
+/// # Examples
+/// ```
+/// use arxiv::ArxivId;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Paper {
+///     #[serde(with = "arxiv::serde::parts")]
+///     id: ArxivId,
+/// }
+/// ```
+pub mod parts {
+	use super::*;
+	use ::serde::de::DeserializeOwned;
+
+	/// An identifier that can be serialized as a structure of its components
+	pub trait Parts: Sized + private::Sealed {
+		#[doc(hidden)]
+		type Repr: Serialize + DeserializeOwned;
+		#[doc(hidden)]
+		fn to_parts(&self) -> Self::Repr;
+		#[doc(hidden)]
+		fn from_parts(parts: Self::Repr) -> Result<Self, String>;
+	}
+
+	mod private {
+		pub trait Sealed {}
+		impl Sealed for crate::ArxivId {}
+		impl Sealed for crate::ArxivIdOld {}
+	}
+
+	#[derive(Serialize, Deserialize)]
+	#[serde(crate = "::serde")]
+	#[doc(hidden)]
+	pub struct ArxivIdParts {
+		year: u16,
+		month: u8,
+		number: String,
+		version: Option<u8>,
+	}
+
+	#[derive(Serialize, Deserialize)]
+	#[serde(crate = "::serde")]
+	#[doc(hidden)]
+	pub struct ArxivIdOldParts {
+		archive: String,
+		subject_class: Option<String>,
+		year: u16,
+		month: u8,
+		number: u16,
+		version: Option<u8>,
+	}
+
+	impl Parts for ArxivId {
+		type Repr = ArxivIdParts;
+
+		fn to_parts(&self) -> Self::Repr {
+			ArxivIdParts {
+				year: self.year(),
+				month: self.month(),
+				number: self.number(),
+				version: self.version(),
+			}
+		}
+
+		fn from_parts(parts: Self::Repr) -> Result<Self, String> {
+			ArxivId::try_new(parts.year, parts.month, parts.number, parts.version)
+				.map_err(|e| e.to_string())
+		}
+	}
+
+	impl Parts for ArxivIdOld {
+		type Repr = ArxivIdOldParts;
+
+		fn to_parts(&self) -> Self::Repr {
+			ArxivIdOldParts {
+				archive: String::from(self.archive()),
+				subject_class: self.subject_class().map(String::from),
+				year: self.year(),
+				month: self.month(),
+				number: self.number(),
+				version: self.version(),
+			}
+		}
+
+		fn from_parts(parts: Self::Repr) -> Result<Self, String> {
+			let mut s = parts.archive;
+			if let Some(subject) = parts.subject_class {
+				s.push(ArxivCategoryId::TOKEN_DELIM);
+				s.push_str(&subject);
+			}
+			s.push_str(&format!(
+				"{}{:02}{:02}{:03}",
+				ArxivIdOld::TOKEN_SLASH,
+				parts.year % 100,
+				parts.month,
+				parts.number
+			));
+			if let Some(version) = parts.version {
+				s.push_str(&format!("{}{}", ArxivId::TOKEN_VERSION, version));
+			}
+
+			match ArxivIdOld::from_str(&s) {
+				Ok(id) if id.year() == parts.year => Ok(id),
+				Ok(_) => Err(crate::ArxivIdError::InvalidYear.to_string()),
+				Err(e) => Err(e.to_string()),
+			}
+		}
+	}
+
+	/// Serializes an identifier as a structure of its components.
+	pub fn serialize<T: Parts, S: Serializer>(id: &T, serializer: S) -> Result<S::Ok, S::Error> {
+		id.to_parts().serialize(serializer)
+	}
+
+	/// Deserializes an identifier from a structure of its components.
+	pub fn deserialize<'de, T: Parts, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<T, D::Error> {
+		T::from_parts(T::Repr::deserialize(deserializer)?).map_err(D::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn serde_round_trip() {
+		let id = ArxivId::from_str("arXiv:2101.00001v1").unwrap();
+		let json = serde_json::to_string(&id).unwrap();
+		assert_eq!(json, r#""arXiv:2101.00001v1""#);
+		assert_eq!(serde_json::from_str::<ArxivId>(&json).unwrap(), id);
+
+		let archive: ArxivArchive = serde_json::from_str(r#""hep-th""#).unwrap();
+		assert_eq!(archive, ArxivArchive::HepTh);
+		assert_eq!(serde_json::to_string(&ArxivGroup::QBio).unwrap(), r#""q-bio""#);
+	}
+
+	#[test]
+	fn serde_invalid() {
+		let error = serde_json::from_str::<ArxivGroup>(r#""foo""#).unwrap_err();
+		assert!(error
+			.to_string()
+			.starts_with("The group \"foo\" is not valid."));
+		assert!(serde_json::from_str::<ArxivCategoryId>(r#""cs.XYZ""#).is_err());
+		assert!(serde_json::from_str::<ArxivId>("2101").is_err());
+	}
+
+	#[test]
+	fn serde_bare() {
+		#[derive(Debug, PartialEq, Serialize, Deserialize)]
+		#[serde(crate = "::serde")]
+		struct Row {
+			#[serde(with = "bare")]
+			id: ArxivId,
+			categories: ArxivCategorySet,
+		}
+
+		let row: Row =
+			serde_json::from_str(r#"{ "id": "2101.00001v1", "categories": "cs.LG stat.ML" }"#)
+				.unwrap();
+		assert_eq!(row.id, ArxivId::from_str("arXiv:2101.00001v1").unwrap());
+		assert_eq!(row.categories.len(), 2);
+		assert_eq!(
+			serde_json::to_string(&row).unwrap(),
+			r#"{"id":"2101.00001v1","categories":"cs.LG stat.ML"}"#
+		);
+
+		let row: Row =
+			serde_json::from_str(r#"{ "id": "arXiv:2101.00001", "categories": "cs.LG" }"#).unwrap();
+		assert!(row.id.is_latest());
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	#[serde(crate = "::serde")]
+	struct Paper {
+		#[serde(with = "parts")]
+		id: ArxivId,
+		#[serde(with = "parts")]
+		old: ArxivIdOld,
+	}
+
+	#[test]
+	fn serde_parts_round_trip() {
+		let paper = Paper {
+			id: ArxivId::from_str("arXiv:2101.00001v1").unwrap(),
+			old: ArxivIdOld::from_str("math.AG/0601001").unwrap(),
+		};
+		let json = serde_json::to_value(&paper).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({
+				"id": { "year": 2021, "month": 1, "number": "00001", "version": 1 },
+				"old": {
+					"archive": "math",
+					"subject_class": "AG",
+					"year": 2006,
+					"month": 1,
+					"number": 1,
+					"version": null
+				}
+			})
+		);
+		assert_eq!(serde_json::from_value::<Paper>(json).unwrap(), paper);
+	}
+
+	#[test]
+	fn serde_parts_invalid() {
+		let json = serde_json::json!({
+			"id": { "year": 2021, "month": 13, "number": "00001", "version": null },
+			"old": { "archive": "hep-th", "subject_class": null, "year": 1999, "month": 1, "number": 1, "version": null }
+		});
+		let error = serde_json::from_value::<Paper>(json).unwrap_err();
+		assert!(error.to_string().contains("month"));
+
+		let json = serde_json::json!({
+			"id": { "year": 2021, "month": 1, "number": "00001", "version": null },
+			"old": { "archive": "hep-th", "subject_class": null, "year": 2099, "month": 1, "number": 1, "version": null }
+		});
+		assert!(serde_json::from_value::<Paper>(json).is_err());
+	}
+}
@@ -0,0 +1,307 @@
+//! A reader of the [metadata snapshot][kaggle] of arXiv.org, which is distributed as JSON Lines.
+//!
+//! [kaggle]: https://www.kaggle.com/datasets/Cornell-University/arxiv
+
+use crate::metadata::collapse_whitespace;
+use crate::{
+	ArticleVersion, ArxivArticleMetadata, ArxivCategoryIdError, ArxivCategorySet, ArxivIdError,
+	ArxivIdentifier, VersionHistory,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{self, BufRead, BufReader, Lines, Read};
+use std::str::FromStr;
+use time::format_description::well_known::Rfc2822;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
+/// An error that can occur when reading the metadata snapshot, with the line of the record
+/// for the errors of a single record
+#[derive(Debug)]
+pub enum SnapshotError {
+	/// The snapshot could not be read
+	Io(io::Error),
+	/// A record is not a valid JSON object of the snapshot
+	Json(usize, String),
+	/// A record has an invalid identifier
+	InvalidId(usize, ArxivIdError),
+	/// A record has an invalid category
+	InvalidCategory(usize, ArxivCategoryIdError),
+	/// A record has an invalid version
+	InvalidVersion(usize, String),
+	/// A record has an invalid date
+	InvalidDate(usize, String),
+}
+
+impl SnapshotError {
+	/// The line of the record with the error, starting at 1, unless the snapshot could not be
+	/// read
+	#[must_use]
+	pub const fn line(&self) -> Option<usize> {
+		match self {
+			Self::Io(_) => None,
+			Self::Json(line, _)
+			| Self::InvalidId(line, _)
+			| Self::InvalidCategory(line, _)
+			| Self::InvalidVersion(line, _)
+			| Self::InvalidDate(line, _) => Some(*line),
+		}
+	}
+}
+
+impl Error for SnapshotError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Io(e) => Some(e),
+			Self::InvalidId(_, e) => Some(e),
+			Self::InvalidCategory(_, e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl Display for SnapshotError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Io(e) => write!(f, "The snapshot could not be read: {}", e),
+			Self::Json(line, e) => write!(f, "The record of line {} is invalid: {}", line, e),
+			Self::InvalidId(line, e) => {
+				write!(f, "The record of line {} has an invalid identifier: {}", line, e)
+			}
+			Self::InvalidCategory(line, e) => {
+				write!(f, "The record of line {} has an invalid category: {}", line, e)
+			}
+			Self::InvalidVersion(line, s) => {
+				write!(f, "The record of line {} has an invalid version \"{}\".", line, s)
+			}
+			Self::InvalidDate(line, s) => {
+				write!(f, "The record of line {} has an invalid date \"{}\".", line, s)
+			}
+		}
+	}
+}
+
+impl From<io::Error> for SnapshotError {
+	fn from(e: io::Error) -> Self {
+		Self::Io(e)
+	}
+}
+
+/// A streaming reader of the metadata snapshot of arXiv.org, yielding the record of each
+/// line of the snapshot as it is read
+///
+/// Identifiers, categories, versions and dates are validated with the parsers of the crate.
+/// An invalid record yields an error without ending the iteration, so that it can be skipped,
+/// while a read error ends it.
+///
+/// # Examples
+/// ```
+/// use arxiv::MetadataSnapshotReader;
+///
+/// let snapshot = r#"{"id":"0704.0001","submitter":"Pavel Nadolsky","authors":"C. Bal\\'azs, E. L. Berger","title":"Calculation of prompt diphoton production cross sections","comments":"37 pages, 15 figures","journal-ref":"Phys.Rev.D76:013009,2007","doi":"10.1103/PhysRevD.76.013009","report-no":"ANL-HEP-PR-07-12","categories":"hep-ph","license":null,"abstract":"  A fully differential calculation...\n","versions":[{"version":"v1","created":"Mon, 2 Apr 2007 19:18:42 GMT"}],"update_date":"2008-11-13","authors_parsed":[["Balázs","C.",""],["Berger","E. L.",""]]}"#;
+///
+/// for metadata in MetadataSnapshotReader::from_reader(snapshot.as_bytes()) {
+///     let metadata = metadata.unwrap();
+///     assert_eq!(metadata.authors, ["C. Balázs", "E. L. Berger"]);
+///     assert_eq!(metadata.versions.len(), 1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MetadataSnapshotReader<R> {
+	lines: Lines<BufReader<R>>,
+	line: usize,
+	done: bool,
+}
+
+impl<R: Read> MetadataSnapshotReader<R> {
+	/// Creates a reader of the snapshot, such as a file of `arxiv-metadata-oai-snapshot.json`
+	pub fn from_reader(reader: R) -> Self {
+		Self {
+			lines: BufReader::new(reader).lines(),
+			line: 0,
+			done: false,
+		}
+	}
+}
+
+impl<R: Read> Iterator for MetadataSnapshotReader<R> {
+	type Item = Result<ArxivArticleMetadata, SnapshotError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while !self.done {
+			let line = match self.lines.next()? {
+				Ok(line) => line,
+				Err(e) => {
+					self.done = true;
+					return Some(Err(SnapshotError::Io(e)));
+				}
+			};
+			self.line += 1;
+			if !line.trim().is_empty() {
+				return Some(parse_record(&line, self.line));
+			}
+		}
+		None
+	}
+}
+
+/// A record of the snapshot, as serialized
+#[derive(::serde::Deserialize)]
+#[serde(crate = "::serde")]
+struct Record {
+	id: String,
+	submitter: Option<String>,
+	#[serde(default)]
+	authors: String,
+	title: String,
+	comments: Option<String>,
+	#[serde(rename = "journal-ref")]
+	journal_ref: Option<String>,
+	doi: Option<String>,
+	#[serde(rename = "report-no")]
+	report_no: Option<String>,
+	categories: String,
+	license: Option<String>,
+	#[serde(rename = "abstract")]
+	abstract_text: String,
+	#[serde(default)]
+	versions: Vec<RecordVersion>,
+	update_date: Option<String>,
+	#[serde(default)]
+	authors_parsed: Vec<Vec<String>>,
+}
+
+#[derive(::serde::Deserialize)]
+#[serde(crate = "::serde")]
+struct RecordVersion {
+	version: String,
+	created: String,
+}
+
+fn parse_record(s: &str, line: usize) -> Result<ArxivArticleMetadata, SnapshotError> {
+	let record: Record =
+		::serde_json::from_str(s).map_err(|e| SnapshotError::Json(line, e.to_string()))?;
+
+	let versions = record
+		.versions
+		.into_iter()
+		.map(|version| parse_version(version, line))
+		.collect::<Result<VersionHistory, _>>()?;
+	let updated = match record.update_date {
+		Some(date) => Some(
+			Date::parse(&date, &format_description!("[year]-[month]-[day]"))
+				.map_err(|_| SnapshotError::InvalidDate(line, date))?,
+		),
+		None => None,
+	};
+	let authors = match record.authors_parsed.is_empty() {
+		true => split_authors(&record.authors),
+		false => record
+			.authors_parsed
+			.iter()
+			.map(|parts| join_name(parts))
+			.collect(),
+	};
+
+	Ok(ArxivArticleMetadata {
+		id: ArxivIdentifier::from_str(record.id.trim())
+			.map_err(|e| SnapshotError::InvalidId(line, e))?,
+		submitter: record.submitter,
+		versions,
+		title: collapse_whitespace(&record.title),
+		abstract_text: collapse_whitespace(&record.abstract_text),
+		authors,
+		categories: ArxivCategorySet::from_str(&record.categories)
+			.map_err(|e| SnapshotError::InvalidCategory(line, e))?,
+		license: non_empty(record.license),
+		journal_ref: non_empty(record.journal_ref),
+		doi: non_empty(record.doi),
+		comments: non_empty(record.comments),
+		report_no: non_empty(record.report_no),
+		updated,
+	})
+}
+
+fn parse_version(version: RecordVersion, line: usize) -> Result<ArticleVersion, SnapshotError> {
+	let number = version
+		.version
+		.strip_prefix('v')
+		.and_then(|n| n.parse().ok())
+		.filter(|&n| n > 0)
+		.ok_or_else(|| SnapshotError::InvalidVersion(line, version.version.clone()))?;
+	let submitted = OffsetDateTime::parse(&version.created, &Rfc2822)
+		.map_err(|_| SnapshotError::InvalidDate(line, version.created))?;
+	Ok(ArticleVersion::new(number, submitted))
+}
+
+/// Joins the parts of a parsed name, which are the keyname, the forenames and the suffix
+fn join_name(parts: &[String]) -> String {
+	let order = [1, 0, 2];
+	let parts = order.iter().filter_map(|&i| parts.get(i));
+	collapse_whitespace(&parts.map(String::as_str).collect::<Vec<_>>().join(" "))
+}
+
+/// Splits the authors of a record without parsed names, which are separated by commas and
+/// the last of them by `and`
+fn split_authors(authors: &str) -> Vec<String> {
+	authors
+		.split(',')
+		.flat_map(|part| part.split(" and "))
+		.map(collapse_whitespace)
+		.filter(|name| !name.is_empty())
+		.collect()
+}
+
+fn non_empty(s: Option<String>) -> Option<String> {
+	s.map(|s| collapse_whitespace(&s)).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use time::macros::{date, datetime};
+
+	const SNAPSHOT: &str = r#"{"id":"0704.0001","submitter":"Pavel Nadolsky","authors":"C. Bal\\'azs, E. L. Berger, P. M. Nadolsky, C.-P. Yuan","title":"Calculation of prompt diphoton production cross sections at Tevatron and\n  LHC energies","comments":"37 pages, 15 figures; published version","journal-ref":"Phys.Rev.D76:013009,2007","doi":"10.1103/PhysRevD.76.013009","report-no":"ANL-HEP-PR-07-12","categories":"hep-ph","license":null,"abstract":"  A fully differential calculation in perturbative quantum chromodynamics is\npresented.\n","versions":[{"version":"v1","created":"Mon, 2 Apr 2007 19:18:42 GMT"},{"version":"v2","created":"Tue, 24 Jul 2007 20:10:27 GMT"}],"update_date":"2008-11-13","authors_parsed":[["Balázs","C.",""],["Berger","E. L.",""],["Nadolsky","P. M.",""],["Yuan","C. -P.",""]]}
+
+{"id":"math/0406033","submitter":null,"authors":"Jane Doe and John Smith","title":"A title","comments":null,"journal-ref":null,"doi":null,"report-no":null,"categories":"math.AG math.CO","license":null,"abstract":"An abstract.","versions":[],"update_date":null}
+{"id":"0704.0003","categories":"hep-ph"}
+{"id":"0704.0004","authors":"","title":"T","categories":"xyz.AB","abstract":"A","license":null,"submitter":null,"comments":null,"journal-ref":null,"doi":null,"report-no":null,"update_date":null}
+{"id":"solv-int/9901001","submitter":null,"authors":"A. Author","title":"Legacy","comments":null,"journal-ref":null,"doi":null,"report-no":null,"categories":"solv-int nlin.SI","license":null,"abstract":"A","versions":[],"update_date":null}
+"#;
+
+	#[test]
+	fn read_snapshot() {
+		let records = MetadataSnapshotReader::from_reader(SNAPSHOT.as_bytes()).collect::<Vec<_>>();
+		assert_eq!(records.len(), 5);
+
+		let metadata = records[0].as_ref().unwrap();
+		assert_eq!(metadata.id, ArxivIdentifier::from_str("0704.0001").unwrap());
+		assert_eq!(
+			metadata.title,
+			"Calculation of prompt diphoton production cross sections at Tevatron and LHC energies"
+		);
+		assert_eq!(metadata.authors[0], "C. Balázs");
+		assert_eq!(metadata.authors[3], "C. -P. Yuan");
+		assert_eq!(metadata.versions.as_slice()[1].submitted, datetime!(2007-07-24 20:10:27 UTC));
+		assert_eq!(metadata.latest_version().map(|version| version.version), Some(2));
+		assert_eq!(metadata.updated, Some(date!(2008 - 11 - 13)));
+		assert_eq!(metadata.license, None);
+		assert_eq!(
+			metadata.abstract_text,
+			"A fully differential calculation in perturbative quantum chromodynamics is presented."
+		);
+
+		let metadata = records[1].as_ref().unwrap();
+		assert_eq!(metadata.id.to_string(), "math/0406033");
+		assert_eq!(metadata.authors, ["Jane Doe", "John Smith"]);
+		assert_eq!(metadata.categories.cross().len(), 1);
+
+		assert!(matches!(records[2], Err(SnapshotError::Json(4, _))));
+		assert!(matches!(records[3], Err(SnapshotError::InvalidCategory(5, _))));
+		assert_eq!(records[3].as_ref().unwrap_err().line(), Some(5));
+
+		// legacy archives are replaced by the categories they were merged into
+		let metadata = records[4].as_ref().unwrap();
+		assert_eq!(metadata.categories.to_string(), "nlin.SI");
+	}
+}
@@ -0,0 +1,55 @@
+//! Support for binding identifiers and categories as query parameters and reading them from
+//! result rows with [`sqlx`](::sqlx), stored as `TEXT` in their canonical string forms.
+//!
+//! The implementations are generic over the database, so they apply to every database
+//! supported by `sqlx` whose strings are `TEXT`, such as PostgreSQL, SQLite and MySQL.
+
+use crate::{ArxivCategoryId, ArxivId};
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::types::Type;
+use ::sqlx::Database;
+use std::str::FromStr;
+
+macro_rules! impl_sqlx_text {
+	($ty:ty) => {
+		impl<DB: Database> Type<DB> for $ty
+		where
+			String: Type<DB>,
+		{
+			fn type_info() -> DB::TypeInfo {
+				<String as Type<DB>>::type_info()
+			}
+
+			fn compatible(ty: &DB::TypeInfo) -> bool {
+				<String as Type<DB>>::compatible(ty)
+			}
+		}
+
+		impl<'q, DB: Database> Encode<'q, DB> for $ty
+		where
+			String: Encode<'q, DB>,
+		{
+			fn encode_by_ref(
+				&self,
+				buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+			) -> Result<IsNull, BoxDynError> {
+				<String as Encode<'q, DB>>::encode(self.to_string(), buf)
+			}
+		}
+
+		impl<'r, DB: Database> Decode<'r, DB> for $ty
+		where
+			&'r str: Decode<'r, DB>,
+		{
+			fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+				let s = <&'r str as Decode<'r, DB>>::decode(value)?;
+				Ok(<$ty>::from_str(s)?)
+			}
+		}
+	};
+}
+
+impl_sqlx_text!(ArxivId);
+impl_sqlx_text!(ArxivCategoryId);
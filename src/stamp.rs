@@ -1,6 +1,7 @@
-use crate::{ArxivCategoryId, ArxivId, ArxivIdError};
+use crate::{ArxivCategoryId, ArxivCategoryIdError, ArxivId, ArxivIdError};
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
 use std::str::FromStr;
 use time::error::Parse as TimeParseError;
 use time::macros::format_description;
@@ -12,6 +13,10 @@ type DateParseResult = Result<Date, TimeParseError>;
 
 /// An error that can occur when parsing and validating arXiv stamps
 ///
+/// Errors for an invalid component of a parsed stamp carry the underlying error as their
+/// [`Error::source`], along with the component and its byte span in the input string
+/// (see [`ArxivStampError::token`] and [`ArxivStampError::span`]).
+///
 /// # Examples
 /// ```
 /// use std::str::FromStr;
@@ -19,27 +24,175 @@ type DateParseResult = Result<Date, TimeParseError>;
 ///
 /// let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2000");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArxivStampError {
-	InvalidArxivId(ArxivIdError),
-	InvalidDate(TimeParseError),
-	InvalidCategory,
+	/// The identifier is invalid
+	InvalidArxivId {
+		source: ArxivIdError,
+		token: String,
+		span: ArxivStampSpan,
+	},
+	/// The submitted date is invalid
+	InvalidDate {
+		source: TimeParseError,
+		token: String,
+		span: ArxivStampSpan,
+	},
+	/// The category is invalid, either because it is not surrounded by brackets (in which case
+	/// the source is an [`ArxivCategoryIdError::Syntax`] error), or because it is not a valid
+	/// category (in which case the source holds the suggested categories, if any)
+	InvalidCategory {
+		source: ArxivCategoryIdError,
+		token: String,
+		span: ArxivStampSpan,
+	},
 	NotEnoughComponents,
+	/// The archive of the category did not accept submissions in the period of the identifier
+	InconsistentCategory,
+	/// The submitted date is earlier than the period of the identifier,
+	/// or implausibly far after it
+	InconsistentDate,
+	/// The submitted date is out of the range of dates supported by stamps, when converting it
+	/// from another date and time library
+	DateOutOfRange,
+}
+
+impl ArxivStampError {
+	/// The byte span of the invalid component in the input string, if the error
+	/// occurred when parsing a component
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivStamp;
+	///
+	/// let input = "arXiv:2001.00001 [cs.XX] 1 Jan 2000";
+	/// let span = ArxivStamp::from_str(input).unwrap_err().span().unwrap();
+	/// assert_eq!(&input[span.range()], "[cs.XX]");
+	/// ```
+	#[must_use]
+	pub const fn span(&self) -> Option<ArxivStampSpan> {
+		match self {
+			Self::InvalidArxivId { span, .. }
+			| Self::InvalidDate { span, .. }
+			| Self::InvalidCategory { span, .. } => Some(*span),
+			Self::NotEnoughComponents
+			| Self::InconsistentCategory
+			| Self::InconsistentDate
+			| Self::DateOutOfRange => None,
+		}
+	}
+
+	/// The invalid component of the input string, if the error occurred when parsing a component
+	#[must_use]
+	pub fn token(&self) -> Option<&str> {
+		match self {
+			Self::InvalidArxivId { token, .. }
+			| Self::InvalidDate { token, .. }
+			| Self::InvalidCategory { token, .. } => Some(token),
+			Self::NotEnoughComponents
+			| Self::InconsistentCategory
+			| Self::InconsistentDate
+			| Self::DateOutOfRange => None,
+		}
+	}
 }
 
-impl Error for ArxivStampError {}
+impl Error for ArxivStampError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidArxivId { source, .. } => Some(source),
+			Self::InvalidDate { source, .. } => Some(source),
+			Self::InvalidCategory { source, .. } => Some(source),
+			Self::NotEnoughComponents
+			| Self::InconsistentCategory
+			| Self::InconsistentDate
+			| Self::DateOutOfRange => None,
+		}
+	}
+}
 
 impl Display for ArxivStampError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
 		match self {
-			Self::InvalidArxivId(e) => write!(f, "Invalid arXiv ID: {}", e),
-			Self::InvalidDate(e) => write!(f, "Invalid date: {}", e),
-			Self::InvalidCategory => write!(f, "Invalid category"),
+			Self::InvalidArxivId {
+				source,
+				token,
+				span,
+			} => {
+				write!(f, "Invalid arXiv ID \"{}\" at {}: {}", token, span, source)
+			}
+			Self::InvalidDate {
+				source,
+				token,
+				span,
+			} => {
+				write!(f, "Invalid date \"{}\" at {}: {}", token, span, source)
+			}
+			Self::InvalidCategory {
+				source,
+				token,
+				span,
+			} => {
+				write!(f, "Invalid category \"{}\" at {}: {}", token, span, source)
+			}
 			Self::NotEnoughComponents => write!(f, "Not enough components"),
+			Self::InconsistentCategory => {
+				write!(f, "Category did not accept submissions in the period of the identifier")
+			}
+			Self::InconsistentDate => {
+				write!(f, "Submitted date is inconsistent with the identifier")
+			}
+			Self::DateOutOfRange => write!(f, "Submitted date is out of the supported range"),
 		}
 	}
 }
 
+/// The byte span of a component in the input string of a stamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArxivStampSpan {
+	start: usize,
+	end: usize,
+}
+
+impl ArxivStampSpan {
+	/// The span of a part of the input, where the part must be a slice of the input
+	fn of(input: &str, part: &str) -> Self {
+		let start = part.as_ptr() as usize - input.as_ptr() as usize;
+		Self {
+			start,
+			end: start + part.len(),
+		}
+	}
+
+	/// The byte offset of the start of the component
+	#[must_use]
+	#[inline]
+	pub const fn start(&self) -> usize {
+		self.start
+	}
+
+	/// The byte offset right after the end of the component
+	#[must_use]
+	#[inline]
+	pub const fn end(&self) -> usize {
+		self.end
+	}
+
+	/// The byte range of the component, which can be used to index the input string
+	#[must_use]
+	#[inline]
+	pub const fn range(&self) -> Range<usize> {
+		self.start..self.end
+	}
+}
+
+impl Display for ArxivStampSpan {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "bytes {}..{}", self.start, self.end)
+	}
+}
+
 /// A stamp that is added onto the side of PDF version of arXiv articles
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ArxivStamp {
@@ -51,8 +204,13 @@ pub struct ArxivStamp {
 impl ArxivStamp {
 	pub(crate) const TOKEN_SPACE: char = ' ';
 
-	/// Manually create a new [`ArxivStamp`] from the given components.
+	/// The maximum number of years between the period of the identifier and the submitted
+	/// date for a stamp to be considered consistent, as later versions of an article can be
+	/// submitted years after the first version
+	pub const MAX_YEARS_AFTER_ID: i32 = 20i32;
 
+	/// Manually create a new [`ArxivStamp`] from the given components.
+	///
 	/// # Examples
 	/// ```
 	/// use arxiv::{ArxivArchive, ArxivCategoryId, ArxivId, ArxivStamp};
@@ -73,6 +231,45 @@ impl ArxivStamp {
 		}
 	}
 
+	/// Creates a new [`ArxivStamp`] submitted on the current date, in UTC.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ArxivId, ArxivStamp};
+	///
+	/// let stamp = ArxivStamp::new_submitted_today(
+	///     ArxivId::from_str("arXiv:2001.00001").unwrap(),
+	///     ArxivCategoryId::from_str("cs.LG").ok(),
+	/// );
+	/// assert!(stamp.submitted().year() >= 2020);
+	/// ```
+	#[cfg(feature = "clock")]
+	pub fn new_submitted_today(id: ArxivId, category: Option<ArxivCategoryId>) -> Self {
+		Self::new(id, category, time::OffsetDateTime::now_utc().date())
+	}
+
+	/// Creates a builder, which validates the components of the stamp when it is built.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ArxivId, ArxivStamp};
+	/// use time::{Date, Month};
+	///
+	/// let stamp = ArxivStamp::builder()
+	///     .id(ArxivId::from_str("arXiv:2011.00001").unwrap())
+	///     .category(ArxivCategoryId::from_str("cs.LG").unwrap())
+	///     .submitted(Date::from_calendar_date(2020, Month::November, 2).unwrap())
+	///     .build()
+	///     .unwrap();
+	/// assert_eq!(stamp.to_string(), "arXiv:2011.00001 [cs.LG] 2 Nov 2020");
+	/// ```
+	#[inline]
+	pub fn builder() -> ArxivStampBuilder {
+		ArxivStampBuilder::default()
+	}
+
 	/// The unique arXiv identifier of the stamp
 	#[must_use]
 	#[inline]
@@ -80,6 +277,15 @@ impl ArxivStamp {
 		&self.id
 	}
 
+	/// The version of the arXiv article, as given by the identifier of the stamp
+	///
+	/// See also: [`ArxivId::version`]
+	#[must_use]
+	#[inline]
+	pub const fn version(&self) -> Option<u8> {
+		self.id.version()
+	}
+
 	/// The category of the stamp
 	#[must_use]
 	#[inline]
@@ -96,36 +302,291 @@ impl ArxivStamp {
 	pub const fn submitted(&self) -> Date {
 		self.submitted
 	}
+
+	/// The submitted date in the ISO 8601 calendar date format, such as `2020-01-31`
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivStamp;
+	///
+	/// let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// assert_eq!(stamp.submitted_iso8601(), "2020-01-01");
+	/// ```
+	#[must_use]
+	pub fn submitted_iso8601(&self) -> String {
+		format!(
+			"{:04}-{:02}-{:02}",
+			self.submitted.year(),
+			u8::from(self.submitted.month()),
+			self.submitted.day()
+		)
+	}
+
+	/// Sets the unique arXiv identifier, returning an [`ArxivStampError`] if it is inconsistent
+	/// with the category or submitted date (see [`ArxivStampBuilder::build`]), in which case
+	/// the stamp is left unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivId, ArxivStamp, ArxivStampError};
+	///
+	/// let mut stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// stamp.set_id(ArxivId::from_str("arXiv:2001.00002").unwrap())?;
+	/// assert_eq!(
+	///     stamp.set_id(ArxivId::from_str("arXiv:2003.00001").unwrap()),
+	///     Err(ArxivStampError::InconsistentDate)
+	/// );
+	/// # Ok::<(), ArxivStampError>(())
+	/// ```
+	pub fn set_id(&mut self, id: ArxivId) -> Result<&mut Self, ArxivStampError> {
+		check_category(&id, self.category.as_ref())?;
+		check_submitted(&id, self.submitted)?;
+
+		self.id = id;
+		Ok(self)
+	}
+
+	/// Sets or removes the category, returning an [`ArxivStampError::InconsistentCategory`] error
+	/// if its archive did not accept submissions in the period of the identifier, in which case
+	/// the stamp is left unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivCategoryId, ArxivStamp, ArxivStampError};
+	///
+	/// let mut stamp = ArxivStamp::from_str("arXiv:0801.0001 [hep-th] 1 Jan 2008").unwrap();
+	/// stamp.set_category(ArxivCategoryId::from_str("gr-qc").ok())?.set_category(None)?;
+	/// assert_eq!(stamp.category(), None);
+	/// assert_eq!(
+	///     stamp.set_category(ArxivCategoryId::from_str("econ.GN").ok()),
+	///     Err(ArxivStampError::InconsistentCategory)
+	/// );
+	/// # Ok::<(), ArxivStampError>(())
+	/// ```
+	pub fn set_category(
+		&mut self,
+		category: Option<ArxivCategoryId>,
+	) -> Result<&mut Self, ArxivStampError> {
+		check_category(&self.id, category.as_ref())?;
+
+		self.category = category;
+		Ok(self)
+	}
+
+	/// Sets the submitted date, returning an [`ArxivStampError::InconsistentDate`] error if it
+	/// is inconsistent with the identifier (see [`ArxivStamp::validate`]), in which case the
+	/// stamp is left unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivStamp, ArxivStampError};
+	/// use time::{Date, Month};
+	///
+	/// let mut stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// stamp.set_submitted(Date::from_calendar_date(2019, Month::December, 31).unwrap())?;
+	/// assert_eq!(
+	///     stamp.set_submitted(Date::from_calendar_date(2019, Month::November, 30).unwrap()),
+	///     Err(ArxivStampError::InconsistentDate)
+	/// );
+	/// # Ok::<(), ArxivStampError>(())
+	/// ```
+	pub fn set_submitted(&mut self, submitted: Date) -> Result<&mut Self, ArxivStampError> {
+		check_submitted(&self.id, submitted)?;
+
+		self.submitted = submitted;
+		Ok(self)
+	}
+
+	/// Checks if the components of the stamp are consistent with each other, which is a
+	/// cheap sanity check for stamps that were parsed from scanned documents.
+	///
+	/// Returns an [`ArxivStampError::InconsistentCategory`] error if the archive of the category
+	/// did not accept submissions in the period of the identifier, or an
+	/// [`ArxivStampError::InconsistentDate`] error if the submitted date is earlier than
+	/// the month before the period of the identifier, or more than [`ArxivStamp::MAX_YEARS_AFTER_ID`] years
+	/// after it.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::{ArxivStamp, ArxivStampError};
+	///
+	/// let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// assert_eq!(stamp.validate(), Ok(()));
+	///
+	/// let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2920").unwrap();
+	/// assert_eq!(stamp.validate(), Err(ArxivStampError::InconsistentDate));
+	/// ```
+	pub fn validate(&self) -> Result<(), ArxivStampError> {
+		check_category(&self.id, self.category.as_ref())?;
+		check_submitted(&self.id, self.submitted)
+	}
+
+	/// Whether or not the components of the stamp are consistent with each other
+	///
+	/// See also: [`ArxivStamp::validate`]
+	#[must_use]
+	#[inline]
+	pub fn is_consistent(&self) -> bool {
+		self.validate().is_ok()
+	}
+}
+
+/// A builder for an [`ArxivStamp`], as an alternative to [`ArxivStamp::new`] that
+/// validates the components together
+///
+/// See also: [`ArxivStamp::builder`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArxivStampBuilder {
+	id: Option<ArxivId>,
+	category: Option<ArxivCategoryId>,
+	submitted: Option<Date>,
+}
+
+impl ArxivStampBuilder {
+	/// Sets the unique arXiv identifier, which is required
+	#[must_use]
+	#[inline]
+	pub fn id(mut self, id: ArxivId) -> Self {
+		self.id = Some(id);
+		self
+	}
+
+	/// Sets the category, which is optional
+	#[must_use]
+	#[inline]
+	pub fn category(mut self, category: ArxivCategoryId) -> Self {
+		self.category = Some(category);
+		self
+	}
+
+	/// Sets the submitted date, which is required
+	#[must_use]
+	#[inline]
+	pub fn submitted(mut self, submitted: Date) -> Self {
+		self.submitted = Some(submitted);
+		self
+	}
+
+	/// Creates the stamp, returning an error if:
+	///  - the identifier or submitted date is missing ([`ArxivStampError::NotEnoughComponents`])
+	///  - the archive of the category did not accept submissions in the period of the
+	///    identifier ([`ArxivStampError::InconsistentCategory`])
+	///  - the submitted date is inconsistent with the period of the identifier
+	///    ([`ArxivStampError::InconsistentDate`])
+	///
+	/// See also: [`ArxivStamp::validate`]
+	pub fn build(self) -> ArxivStampResult {
+		match (self.id, self.submitted) {
+			(Some(id), Some(submitted)) => {
+				check_category(&id, self.category.as_ref())?;
+				check_submitted(&id, submitted)?;
+				Ok(ArxivStamp::new(id, self.category, submitted))
+			}
+			_ => Err(ArxivStampError::NotEnoughComponents),
+		}
+	}
+}
+
+/// Checks that the archive of the category accepted submissions in the period of the identifier
+fn check_category(id: &ArxivId, category: Option<&ArxivCategoryId>) -> Result<(), ArxivStampError> {
+	match category {
+		Some(c) if !c.archive().active_range().contains_id(id) => {
+			Err(ArxivStampError::InconsistentCategory)
+		}
+		_ => Ok(()),
+	}
+}
+
+/// Checks that the submitted date is neither earlier than the month before the period of the
+/// identifier, nor implausibly far after it
+///
+/// Articles submitted on the last days of a month are often announced, and so numbered, in
+/// the next month, such as `2001.00001` submitted on 31 Dec 2019.
+fn check_submitted(id: &ArxivId, submitted: Date) -> Result<(), ArxivStampError> {
+	let months = |year: i32, month: u8| year * 12 + i32::from(month) - 1;
+	let period = months(i32::from(id.year()), id.month());
+	let is_earlier = months(submitted.year(), u8::from(submitted.month())) < period - 1;
+	let is_too_late = submitted.year() - i32::from(id.year()) > ArxivStamp::MAX_YEARS_AFTER_ID;
+
+	match is_earlier || is_too_late {
+		true => Err(ArxivStampError::InconsistentDate),
+		false => Ok(()),
+	}
+}
+
+/// The format of the month of the submitted date when displaying an [`ArxivStamp`]
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivStamp, StampFormat};
+///
+/// let stamp = ArxivStamp::from_str("arXiv:2009.00001 [cs.LG] 4 Sep 2020").unwrap();
+/// assert_eq!(stamp.to_string(), "arXiv:2009.00001 [cs.LG] 4 Sep 2020");
+/// assert_eq!(stamp.display(StampFormat::Long).to_string(), "arXiv:2009.00001 [cs.LG] 4 September 2020");
+/// assert_eq!(stamp.display(StampFormat::Legacy).to_string(), "arXiv:2009.00001 [cs.LG] 4 Sept 2020");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StampFormat {
+	/// The first three letters of the month name (such as "Jun"), as used by arXiv
+	#[default]
+	Short,
+	/// The full month name (such as "June")
+	Long,
+	/// The abbreviations of earlier releases of this crate, which are the same as
+	/// [`StampFormat::Short`] except for "June", "July" and "Sept"
+	Legacy,
+}
+
+/// Displays an [`ArxivStamp`] in a given [`StampFormat`]
+///
+/// See also: [`ArxivStamp::display`]
+#[derive(Debug, Clone, Copy)]
+pub struct ArxivStampDisplay<'a> {
+	stamp: &'a ArxivStamp,
+	format: StampFormat,
+}
+
+impl ArxivStamp {
+	/// Displays the stamp with the month of the submitted date in the given format.
+	/// The [`Display`] implementation uses [`StampFormat::Short`].
+	#[must_use]
+	#[inline]
+	pub const fn display(&self, format: StampFormat) -> ArxivStampDisplay<'_> {
+		ArxivStampDisplay {
+			stamp: self,
+			format,
+		}
+	}
 }
 
 impl Display for ArxivStamp {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		// A stamp string is *at least* 25 characters long:
-		// - 16: longest possible arXiv identifier
-		// - 2: string length of a day
-		// - 3: string length of an abbreviated month
-		// - 4: string length of a 4-digit year
-		let mut partial_stamp_str = String::with_capacity(16usize);
-		partial_stamp_str.push_str(&self.id.to_string());
-		match &self.category {
-			Some(c) => {
-				// This is the longest possible length of a category string,
-				// such as "cond-mat.quant-gas"
-				partial_stamp_str.reserve(18usize);
-				partial_stamp_str.push_str(" [");
-				partial_stamp_str.push_str(&c.to_string());
-				partial_stamp_str.push(']');
-			}
-			None => (),
+		self.display(StampFormat::default()).fmt(f)
+	}
+}
+
+impl Display for ArxivStampDisplay<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let stamp = self.stamp;
+
+		// every component is written directly into the formatter, without allocating
+		write!(f, "{}", stamp.id)?;
+		if let Some(c) = &stamp.category {
+			write!(f, " [{}]", c)?;
 		}
 
 		write!(
 			f,
-			"{} {} {} {}",
-			partial_stamp_str,
-			self.submitted.day(),
-			month_as_abbr(self.submitted.month()),
-			self.submitted.year()
+			" {} {} {}",
+			stamp.submitted.day(),
+			format_month(stamp.submitted.month(), self.format),
+			stamp.submitted.year()
 		)
 	}
 }
@@ -134,47 +595,152 @@ impl FromStr for ArxivStamp {
 	type Err = ArxivStampError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let parts = s.splitn(2, ArxivStamp::TOKEN_SPACE).collect::<Vec<&str>>();
+		Self::parse(s, false)
+	}
+}
 
-		if parts.len() == 1 {
-			return Err(ArxivStampError::NotEnoughComponents);
-		}
+impl ArxivStamp {
+	/// Parses a stamp leniently, as found in transcriptions and text extracted from scanned
+	/// documents. Compared to [`ArxivStamp::from_str`]:
+	///  - components may be separated by any run of Unicode whitespace (such as double spaces
+	///    or non-breaking spaces), and surrounding whitespace is ignored
+	///  - the category is parsed with [`ArxivCategoryId::parse_lenient`]
+	///  - the date may have a zero-padded day and a short or long month name in any case,
+	///    or be in the ISO 8601 calendar date format (such as `2020-01-31`)
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivStamp;
+	///
+	/// let stamp = ArxivStamp::parse_lenient("arXiv:2001.00001 [CS.lg] 01 january 2020").unwrap();
+	/// assert_eq!(stamp, ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap());
+	/// ```
+	pub fn parse_lenient(s: &str) -> ArxivStampResult {
+		Self::parse(s, true)
+	}
 
-		let arxiv_id = ArxivId::from_str(parts[0]);
-		if let Err(e) = arxiv_id {
-			return Err(ArxivStampError::InvalidArxivId(e));
-		}
+	/// Reconstructs a stamp from the noisy output of extracting text from the left margin of
+	/// a PDF, where the stamp is rotated. Extraction tools often output one character per
+	/// line, output the characters in reverse order, or surround the stamp with other text.
+	///
+	/// The lines are joined back together (where empty lines are treated as spaces if every
+	/// line contains at most one character), and the stamp is searched for in both the
+	/// original and the reversed order of characters, then parsed leniently (see
+	/// [`ArxivStamp::parse_lenient`]). The error of the original order is returned if neither
+	/// order contains a valid stamp, where any span refers to the reconstructed stamp rather
+	/// than to the extracted text.
+	///
+	/// # Examples
+	/// ```
+	/// use std::str::FromStr;
+	/// use arxiv::ArxivStamp;
+	///
+	/// let expected = ArxivStamp::from_str("arXiv:2001.00001v2 [cs.LG] 1 Jan 2020");
+	/// assert_eq!(ArxivStamp::from_extracted_text("0202 naJ 1 ]GL.sc[ 2v10000.1002:viXra"), expected);
+	/// ```
+	pub fn from_extracted_text(text: &str) -> ArxivStampResult {
+		let text = join_extracted_lines(text);
+		let reversed = text.chars().rev().collect::<String>();
+
+		Self::find_in_text(&text).or_else(|e| Self::find_in_text(&reversed).map_err(|_| e))
+	}
+
+	/// Parses the first stamp in the text, ignoring any text around it
+	fn find_in_text(text: &str) -> ArxivStampResult {
+		let start = text
+			.find("arXiv:")
+			.ok_or(ArxivStampError::NotEnoughComponents)?;
+		let tokens = text[start..].split_whitespace().collect::<Vec<&str>>();
+		let len = match tokens.get(1) {
+			Some(token) if token.starts_with('[') => 5usize,
+			_ => 4usize,
+		};
 
-		// category is opitional, so we need to check if the second part is a category
-		// and decide which index to use to parse each component
-		let part2_is_category = parts[1].starts_with('[');
+		Self::parse_lenient(&tokens[..len.min(tokens.len())].join(" "))
+	}
+
+	fn parse(s: &str, lenient: bool) -> ArxivStampResult {
+		// text extraction from PDFs often produces runs of spaces or non-breaking spaces,
+		// so components are separated by any run of whitespace in lenient mode, while the
+		// components themselves are kept as slices of the input to report their spans
+		let input = s;
+		let s = match lenient {
+			true => s.trim(),
+			false => s,
+		};
+
+		let (id_str, rest) =
+			split_component(s, lenient).ok_or(ArxivStampError::NotEnoughComponents)?;
+		let arxiv_id =
+			ArxivId::from_str(id_str).map_err(|source| ArxivStampError::InvalidArxivId {
+				source,
+				token: String::from(id_str),
+				span: ArxivStampSpan::of(input, id_str),
+			})?;
+
+		// category is optional, so we need to check if the rest starts with a category
+		// and decide which part to parse as the date
 		let mut category: Option<ArxivCategoryId> = None;
-		let date: DateParseResult;
-
-		if part2_is_category {
-			let category_date = parts[1]
-				.splitn(2, ArxivStamp::TOKEN_SPACE)
-				.collect::<Vec<&str>>();
-
-			let str_in_brackets =
-				parse_brackets(category_date[0]).map_err(|_| ArxivStampError::InvalidCategory)?;
-			let parsed_category = ArxivCategoryId::from_str(&str_in_brackets);
-			if parsed_category.is_err() {
-				return Err(ArxivStampError::InvalidCategory);
-			}
+		let mut date_str = rest;
 
-			category = parsed_category.ok();
-			date = parse_date(category_date[1]);
-		} else {
-			date = parse_date(parts[1]);
-		}
+		if rest.starts_with('[') {
+			let (category_str, rest) =
+				split_component(rest, lenient).ok_or(ArxivStampError::NotEnoughComponents)?;
+			let invalid_category = |source| ArxivStampError::InvalidCategory {
+				source,
+				token: String::from(category_str),
+				span: ArxivStampSpan::of(input, category_str),
+			};
+
+			let str_in_brackets = parse_brackets(category_str)
+				.map_err(|_| invalid_category(ArxivCategoryIdError::Syntax))?;
+			let parsed_category = match lenient {
+				true => ArxivCategoryId::parse_lenient(&str_in_brackets),
+				false => ArxivCategoryId::from_str(&str_in_brackets),
+			};
 
-		if let Err(e) = date {
-			return Err(ArxivStampError::InvalidDate(e));
+			category = Some(parsed_category.map_err(invalid_category)?);
+			date_str = rest;
 		}
 
-		// if we got this far, we can safely unwrap the results
-		Ok(Self::new(arxiv_id.unwrap(), category, date.unwrap()))
+		let date =
+			parse_date(date_str, lenient).map_err(|source| ArxivStampError::InvalidDate {
+				source,
+				token: String::from(date_str),
+				span: ArxivStampSpan::of(input, date_str),
+			})?;
+
+		Ok(Self::new(arxiv_id, category, date))
+	}
+}
+
+/// Splits the first component of a stamp from the rest, where components are separated by
+/// a space, or by any run of whitespace in lenient mode
+fn split_component(s: &str, lenient: bool) -> Option<(&str, &str)> {
+	match lenient {
+		true => s
+			.split_once(char::is_whitespace)
+			.map(|(component, rest)| (component, rest.trim_start())),
+		false => s.split_once(ArxivStamp::TOKEN_SPACE),
+	}
+}
+
+/// Joins the lines of extracted text, where text with at most one character per line
+/// is joined character by character, with empty lines as spaces
+fn join_extracted_lines(text: &str) -> String {
+	let lines = text.lines().map(str::trim).collect::<Vec<&str>>();
+	let is_char_per_line = lines.len() > 1 && lines.iter().all(|line| line.chars().count() <= 1);
+
+	match is_char_per_line {
+		true => lines
+			.iter()
+			.map(|line| match line.is_empty() {
+				true => " ",
+				false => line,
+			})
+			.collect(),
+		false => lines.join(" "),
 	}
 }
 
@@ -191,20 +757,13 @@ pub(super) fn brackets_match(s: &str) -> bool {
 	s.starts_with('[') && s.ends_with(']')
 }
 
-const fn month_as_abbr<'a>(month: Month) -> &'a str {
-	match month {
-		Month::January => "Jan",
-		Month::February => "Feb",
-		Month::March => "Mar",
-		Month::April => "Apr",
-		Month::May => "May",
-		Month::June => "June",
-		Month::July => "July",
-		Month::August => "Aug",
-		Month::September => "Sept",
-		Month::October => "Oct",
-		Month::November => "Nov",
-		Month::December => "Dec",
+fn format_month(month: Month, format: StampFormat) -> &'static str {
+	let name = month_name(month);
+	match (format, month) {
+		(StampFormat::Long, _) => name,
+		(StampFormat::Legacy, Month::June | Month::July) => name,
+		(StampFormat::Legacy, Month::September) => "Sept",
+		(StampFormat::Short | StampFormat::Legacy, _) => &name[..3],
 	}
 }
 
@@ -216,10 +775,82 @@ const fn month_as_abbr<'a>(month: Month) -> &'a str {
 /// See also: [`time` documentation for format descriptions][time-format-desc]
 ///
 /// [time-format-desc]: https://time-rs.github.io/book/api/format-description.html
-fn parse_date(date_str: &str) -> DateParseResult {
+///
+/// In lenient mode, the day may be zero-padded, and the month may be any abbreviation
+/// of at least three letters of the month name (such as "Sept"), in any case, which are
+/// normalized before parsing. The date may also be in the ISO 8601 calendar date format,
+/// such as "2000-01-31".
+fn parse_date(date_str: &str, lenient: bool) -> DateParseResult {
+	match lenient {
+		true if !date_str.contains(char::is_whitespace) => {
+			Date::parse(date_str, &format_description!("[year]-[month]-[day]"))
+		}
+		true => parse_date_strict(&normalize_date(date_str)),
+		false => parse_date_strict(date_str),
+	}
+}
+
+fn parse_date_strict(date_str: &str) -> DateParseResult {
 	Date::parse(date_str, &format_description!("[day padding:none] [month repr:short] [year]"))
 }
 
+/// Normalizes a date in the form of "01 January 2000" to "1 Jan 2000"
+fn normalize_date(date_str: &str) -> String {
+	let parts = date_str.split_whitespace().collect::<Vec<&str>>();
+	if parts.len() != 3 {
+		return String::from(date_str);
+	}
+
+	let day = match parts[0].trim_start_matches('0') {
+		"" => parts[0],
+		day => day,
+	};
+	let month = MONTHS
+		.iter()
+		.find(|month| {
+			let name = month_name(**month);
+			parts[1].len() >= 3
+				&& name
+					.get(..parts[1].len())
+					.map_or(false, |prefix| prefix.eq_ignore_ascii_case(parts[1]))
+		})
+		.map_or(parts[1], |month| &month_name(*month)[..3]);
+
+	format!("{} {} {}", day, month, parts[2])
+}
+
+const MONTHS: [Month; 12] = [
+	Month::January,
+	Month::February,
+	Month::March,
+	Month::April,
+	Month::May,
+	Month::June,
+	Month::July,
+	Month::August,
+	Month::September,
+	Month::October,
+	Month::November,
+	Month::December,
+];
+
+const fn month_name(month: Month) -> &'static str {
+	match month {
+		Month::January => "January",
+		Month::February => "February",
+		Month::March => "March",
+		Month::April => "April",
+		Month::May => "May",
+		Month::June => "June",
+		Month::July => "July",
+		Month::August => "August",
+		Month::September => "September",
+		Month::October => "October",
+		Month::November => "November",
+		Month::December => "December",
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -249,6 +880,25 @@ mod tests {
 		assert_eq!(stamp.to_string(), "arXiv:2011.00001 1 Jan 2011");
 	}
 
+	#[test]
+	fn display_stamp_round_trip_every_month() {
+		for month in MONTHS {
+			let stamp = ArxivStamp::new(
+				ArxivId::from_str("arXiv:2001.00001").unwrap(),
+				None,
+				Date::from_calendar_date(2020, month, 1).unwrap(),
+			);
+			assert_eq!(ArxivStamp::from_str(&stamp.to_string()), Ok(stamp));
+		}
+	}
+
+	#[test]
+	fn display_stamp_with_version() {
+		let stamp = ArxivStamp::from_str("arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007").unwrap();
+		assert_eq!(stamp.version(), Some(1));
+		assert_eq!(stamp.to_string(), "arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007");
+	}
+
 	#[test]
 	fn parse_stamp() {
 		let stamp = "arXiv:2001.00001 [cs.LG] 1 Jan 2000";
@@ -277,6 +927,13 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn parse_stamp_bare_archive_category() {
+		let stamp = ArxivStamp::from_str("arXiv:0712.0001 [hep-th] 4 Dec 2007").unwrap();
+		assert_eq!(stamp.category(), ArxivCategoryId::try_new(ArxivArchive::HepTh, "").as_ref());
+		assert_eq!(stamp.to_string(), "arXiv:0712.0001 [hep-th] 4 Dec 2007");
+	}
+
 	#[test]
 	fn parse_stamp_empty() {
 		let stamp = "";
@@ -295,7 +952,14 @@ mod tests {
 	fn parse_stamp_invalid_category() {
 		let stamp = "arXiv:2001.00001 [cs.LG 1 Jan 2000";
 		let parsed = ArxivStamp::from_str(stamp);
-		assert_eq!(parsed, Err(ArxivStampError::InvalidCategory));
+		assert_eq!(
+			parsed,
+			Err(ArxivStampError::InvalidCategory {
+				source: ArxivCategoryIdError::Syntax,
+				token: String::from("[cs.LG"),
+				span: span(17, 23),
+			})
+		);
 	}
 
 	#[test]
@@ -304,29 +968,221 @@ mod tests {
 		let parsed = ArxivStamp::from_str(stamp);
 
 		// hack to get a ComponentRange error
-		let date = parse_date("32 Jan 2000").unwrap_err();
+		let date = parse_date("32 Jan 2000", false).unwrap_err();
 
-		assert_eq!(parsed, Err(ArxivStampError::InvalidDate(date)));
+		assert_eq!(
+			parsed,
+			Err(ArxivStampError::InvalidDate {
+				source: date,
+				token: String::from("32 Jan 2000"),
+				span: span(25, 36),
+			})
+		);
 	}
 
 	#[test]
 	fn parse_stamp_invalid_date_month() {
 		let stamp = "arXiv:2001.00001 [cs.LG] 1 Zan 2000";
 		let parsed = ArxivStamp::from_str(stamp);
-		assert_eq!(parsed, Err(invalid_date_component("month")));
+		assert_eq!(parsed, Err(invalid_date_component("month", "1 Zan 2000", span(25, 35))));
 	}
 
 	#[test]
 	fn parse_stamp_invalid_date_year() {
 		let stamp = "arXiv:2001.00001 [cs.LG] 1 Jan 200";
 		let parsed = ArxivStamp::from_str(stamp);
-		assert_eq!(parsed, Err(invalid_date_component("year")));
+		assert_eq!(parsed, Err(invalid_date_component("year", "1 Jan 200", span(25, 34))));
+	}
+
+	fn invalid_date_component(
+		component: &'static str,
+		token: &str,
+		span: ArxivStampSpan,
+	) -> ArxivStampError {
+		ArxivStampError::InvalidDate {
+			source: TimeParseError::ParseFromDescription(ParseFromDescription::InvalidComponent(
+				component,
+			)),
+			token: String::from(token),
+			span,
+		}
+	}
+
+	fn span(start: usize, end: usize) -> ArxivStampSpan {
+		ArxivStampSpan { start, end }
+	}
+
+	#[test]
+	fn parse_stamp_invalid_id_span() {
+		let stamp = "arXiv:2001.001 [cs.LG] 1 Jan 2000";
+		let error = ArxivStamp::from_str(stamp).unwrap_err();
+		assert_eq!(error.span(), Some(span(0, 14)));
+		assert_eq!(error.token(), Some("arXiv:2001.001"));
+		assert_eq!(
+			error.to_string(),
+			format!(
+				"Invalid arXiv ID \"arXiv:2001.001\" at bytes 0..14: {}",
+				ArxivIdError::InvalidId
+			)
+		);
+	}
+
+	#[test]
+	fn stamp_error_source() {
+		let error = ArxivStamp::from_str("arXiv:2001.00001 [cs.LGG] 1 Jan 2000").unwrap_err();
+		let source = error
+			.source()
+			.unwrap()
+			.downcast_ref::<ArxivCategoryIdError>();
+		assert_eq!(source.map(|e| e.suggestions().len()), Some(1));
+		assert!(ArxivStampError::NotEnoughComponents.source().is_none());
+	}
+
+	#[test]
+	fn parse_stamp_lenient_spans() {
+		let stamp = "  arXiv:2001.00001\u{a0} [cs.XX]  1 Jan 2000 ";
+		let span = ArxivStamp::parse_lenient(stamp)
+			.unwrap_err()
+			.span()
+			.unwrap();
+		assert_eq!(&stamp[span.range()], "[cs.XX]");
+
+		let stamp = "arXiv:2001.00001  32  Jan 2000 ";
+		let span = ArxivStamp::parse_lenient(stamp)
+			.unwrap_err()
+			.span()
+			.unwrap();
+		assert_eq!(&stamp[span.range()], "32  Jan 2000");
+	}
+
+	#[test]
+	fn build_stamp_missing_components() {
+		let id = ArxivId::from_str("arXiv:2001.00001").unwrap();
+		assert_eq!(ArxivStamp::builder().build(), Err(ArxivStampError::NotEnoughComponents));
+		assert_eq!(ArxivStamp::builder().id(id).build(), Err(ArxivStampError::NotEnoughComponents));
+	}
+
+	#[test]
+	fn build_stamp_inconsistent() {
+		let builder = ArxivStamp::builder()
+			.id(ArxivId::from_str("arXiv:0801.0001").unwrap())
+			.submitted(Date::from_calendar_date(2007, Month::November, 30).unwrap());
+		assert_eq!(builder.clone().build(), Err(ArxivStampError::InconsistentDate));
+
+		// econ was introduced in 2017
+		let builder = builder
+			.submitted(Date::from_calendar_date(2008, Month::January, 1).unwrap())
+			.category(ArxivCategoryId::from_str("econ.GN").unwrap());
+		assert_eq!(builder.build(), Err(ArxivStampError::InconsistentCategory));
+	}
+
+	#[test]
+	fn set_stamp_components_unchanged_on_error() {
+		let mut stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+		let original = stamp.clone();
+		assert!(stamp
+			.set_id(ArxivId::from_str("arXiv:2101.00001").unwrap())
+			.is_err());
+		assert!(stamp
+			.set_submitted(Date::from_calendar_date(1999, Month::January, 1).unwrap())
+			.is_err());
+		assert_eq!(stamp, original);
+	}
+
+	#[test]
+	fn validate_stamp() {
+		let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 31 Dec 2040").unwrap();
+		assert!(stamp.is_consistent());
+
+		let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2041").unwrap();
+		assert_eq!(stamp.validate(), Err(ArxivStampError::InconsistentDate));
+
+		// submitted on the last day of the month before the period of the identifier
+		let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 31 Dec 2019").unwrap();
+		assert!(stamp.is_consistent());
+		let stamp = ArxivStamp::builder()
+			.id(ArxivId::from_str("arXiv:0801.0001").unwrap())
+			.submitted(Date::from_calendar_date(2007, Month::December, 31).unwrap())
+			.build()
+			.unwrap();
+		assert_eq!(stamp.validate(), Ok(()));
+
+		let stamp = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 30 Nov 2019").unwrap();
+		assert!(!stamp.is_consistent());
+
+		let stamp = ArxivStamp::from_str("arXiv:0801.0001 [econ.GN] 1 Jan 2008").unwrap();
+		assert_eq!(stamp.validate(), Err(ArxivStampError::InconsistentCategory));
+	}
+
+	#[test]
+	fn parse_stamp_lenient_dates() {
+		let expected = ArxivStamp::from_str("arXiv:2001.00001 4 Sep 2020");
+		for stamp in [
+			"arXiv:2001.00001 04 Sep 2020",
+			"arXiv:2001.00001 4 September 2020",
+			"arXiv:2001.00001 4 SEPT 2020",
+			"arXiv:2001.00001 4 sep 2020",
+		] {
+			assert_eq!(ArxivStamp::parse_lenient(stamp), expected, "stamp: {:?}", stamp);
+		}
+		assert!(ArxivStamp::from_str("arXiv:2001.00001 4 September 2020").is_err());
+		assert!(ArxivStamp::parse_lenient("arXiv:2001.00001 00 Sep 2020").is_err());
 	}
 
-	fn invalid_date_component(component: &'static str) -> ArxivStampError {
-		ArxivStampError::InvalidDate(TimeParseError::ParseFromDescription(
-			ParseFromDescription::InvalidComponent(component),
-		))
+	#[test]
+	fn parse_stamp_lenient_whitespace() {
+		let stamp = " arXiv:2001.00001\u{a0}\u{a0}[cs.LG]  1\t Jan\n2020 ";
+		assert_eq!(
+			ArxivStamp::parse_lenient(stamp),
+			ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020")
+		);
+		assert!(ArxivStamp::from_str(stamp).is_err());
+	}
+
+	#[test]
+	fn parse_stamp_from_extracted_text() {
+		let expected = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 1 Jan 2020");
+
+		let char_per_line = "arXiv:2001.00001 [cs.LG] 1 Jan 2020"
+			.chars()
+			.map(|c| {
+				if c == ' ' {
+					String::from("\n")
+				} else {
+					format!("{}\n", c)
+				}
+			})
+			.collect::<String>();
+		assert_eq!(ArxivStamp::from_extracted_text(&char_per_line), expected);
+
+		let reversed_char_per_line = char_per_line.lines().rev().collect::<Vec<_>>().join("\n");
+		assert_eq!(ArxivStamp::from_extracted_text(&reversed_char_per_line), expected);
+
+		let surrounded = "Page 1\narXiv:2001.00001  [cs.LG]\n1 Jan 2020 Abstract";
+		assert_eq!(ArxivStamp::from_extracted_text(surrounded), expected);
+
+		assert_eq!(
+			ArxivStamp::from_extracted_text("no stamp here"),
+			Err(ArxivStampError::NotEnoughComponents)
+		);
+	}
+
+	#[test]
+	fn parse_stamp_lenient_iso8601() {
+		let stamp = ArxivStamp::parse_lenient("arXiv:2001.00001 [cs.LG] 2020-01-31").unwrap();
+		assert_eq!(stamp.to_string(), "arXiv:2001.00001 [cs.LG] 31 Jan 2020");
+		assert_eq!(stamp.submitted_iso8601(), "2020-01-31");
+		assert!(ArxivStamp::from_str("arXiv:2001.00001 [cs.LG] 2020-01-31").is_err());
+		assert!(matches!(
+			ArxivStamp::parse_lenient("arXiv:2001.00001 2020-02-30"),
+			Err(ArxivStampError::InvalidDate { .. })
+		));
+	}
+
+	#[test]
+	fn parse_stamp_missing_date() {
+		let parsed = ArxivStamp::from_str("arXiv:2001.00001 [cs.LG]");
+		assert_eq!(parsed, Err(ArxivStampError::NotEnoughComponents));
 	}
 
 	#[test]
@@ -338,9 +1194,9 @@ mod tests {
 
 	#[test]
 	fn test_brackets_match() {
-		assert_eq!(false, brackets_match(""));
-		assert_eq!(true, brackets_match("[]"));
-		assert_eq!(false, brackets_match("{}"));
-		assert_eq!(false, brackets_match("()"));
+		assert!(!brackets_match(""));
+		assert!(brackets_match("[]"));
+		assert!(!brackets_match("{}"));
+		assert!(!brackets_match("()"));
 	}
 }
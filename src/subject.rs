@@ -0,0 +1,480 @@
+use crate::ArxivArchive;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// Generates a subject class enum for an archive, where each variant is declared with its
+/// canonical identifier and its human-readable name, in the canonical (sorted) order.
+macro_rules! subject_enum {
+	(
+		$(#[$meta:meta])*
+		$name:ident {
+			$($variant:ident => $id:literal, $display:literal,)+
+		}
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+		pub enum $name {
+			$(
+				#[doc = $display]
+				$variant,
+			)+
+		}
+
+		impl $name {
+			/// Every subject class of the archive, in canonical order
+			pub const ALL: &'static [Self] = &[$(Self::$variant,)+];
+
+			/// The identifiers of every subject class of the archive, in canonical order
+			pub const IDS: &'static [&'static str] = &[$($id,)+];
+
+			/// A compile-time perfect hash map from identifiers to subject classes
+			const LOOKUP: phf::Map<&'static str, Self> = phf::phf_map! {
+				$($id => $name::$variant,)+
+			};
+
+			/// The canonical identifier of the subject class, as used in category identifiers
+			#[must_use]
+			#[inline]
+			pub const fn as_str(self) -> &'static str {
+				match self {
+					$(Self::$variant => $id,)+
+				}
+			}
+
+			/// The human-readable name of the subject class
+			#[must_use]
+			#[inline]
+			pub const fn name(self) -> &'static str {
+				match self {
+					$(Self::$variant => $display,)+
+				}
+			}
+		}
+
+		impl Display for $name {
+			fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+				f.write_str(self.as_str())
+			}
+		}
+
+		impl FromStr for $name {
+			type Err = ();
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				Self::LOOKUP.get(s).copied().ok_or(())
+			}
+		}
+	};
+}
+
+subject_enum! {
+	/// The subject classes of the Astrophysics (`astro-ph`) archive
+	AstroPhSubject {
+		CO => "CO", "Cosmology and Nongalactic Astrophysics",
+		EP => "EP", "Earth and Planetary Astrophysics",
+		GA => "GA", "Astrophysics of Galaxies",
+		HE => "HE", "High Energy Astrophysical Phenomena",
+		IM => "IM", "Instrumentation and Methods for Astrophysics",
+		SR => "SR", "Solar and Stellar Astrophysics",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Condensed Matter (`cond-mat`) archive
+	CondMatSubject {
+		DisNn => "dis-nn", "Disordered Systems and Neural Networks",
+		MesHall => "mes-hall", "Mesoscale and Nanoscale Physics",
+		MtrlSci => "mtrl-sci", "Materials Science",
+		Other => "other", "Other Condensed Matter",
+		QuantGas => "quant-gas", "Quantum Gases",
+		Soft => "soft", "Soft Condensed Matter",
+		StatMech => "stat-mech", "Statistical Mechanics",
+		StrEl => "str-el", "Strongly Correlated Electrons",
+		SuprCon => "supr-con", "Superconductivity",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Computer Science (`cs`) archive
+	CsSubject {
+		AI => "AI", "Artificial Intelligence",
+		AR => "AR", "Hardware Architecture",
+		CC => "CC", "Computational Complexity",
+		CE => "CE", "Computational Engineering, Finance, and Science",
+		CG => "CG", "Computational Geometry",
+		CL => "CL", "Computation and Language",
+		CR => "CR", "Cryptography and Security",
+		CV => "CV", "Computer Vision and Pattern Recognition",
+		CY => "CY", "Computers and Society",
+		DB => "DB", "Databases",
+		DC => "DC", "Distributed, Parallel, and Cluster Computing",
+		DL => "DL", "Digital Libraries",
+		DM => "DM", "Discrete Mathematics",
+		DS => "DS", "Data Structures and Algorithms",
+		ET => "ET", "Emerging Technologies",
+		FL => "FL", "Formal Languages and Automata Theory",
+		GL => "GL", "General Literature",
+		GR => "GR", "Graphics",
+		GT => "GT", "Computer Science and Game Theory",
+		HC => "HC", "Human-Computer Interaction",
+		IR => "IR", "Information Retrieval",
+		IT => "IT", "Information Theory",
+		LG => "LG", "Machine Learning",
+		LO => "LO", "Logic in Computer Science",
+		MA => "MA", "Multiagent Systems",
+		MM => "MM", "Multimedia",
+		MS => "MS", "Mathematical Software",
+		NA => "NA", "Numerical Analysis",
+		NE => "NE", "Neural and Evolutionary Computing",
+		NI => "NI", "Networking and Internet Architecture",
+		OH => "OH", "Other Computer Science",
+		OS => "OS", "Operating Systems",
+		PF => "PF", "Performance",
+		PL => "PL", "Programming Languages",
+		RO => "RO", "Robotics",
+		SC => "SC", "Symbolic Computation",
+		SD => "SD", "Sound",
+		SE => "SE", "Software Engineering",
+		SI => "SI", "Social and Information Networks",
+		SY => "SY", "Systems and Control",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Economics (`econ`) archive
+	EconSubject {
+		EM => "EM", "Econometrics",
+		GN => "GN", "General Economics",
+		TH => "TH", "Theoretical Economics",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Electrical Engineering and Systems Science (`eess`) archive
+	EessSubject {
+		AS => "AS", "Audio and Speech Processing",
+		IV => "IV", "Image and Video Processing",
+		SP => "SP", "Signal Processing",
+		SY => "SY", "Systems and Control",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Mathematics (`math`) archive
+	MathSubject {
+		AC => "AC", "Commutative Algebra",
+		AG => "AG", "Algebraic Geometry",
+		AP => "AP", "Analysis of PDEs",
+		AT => "AT", "Algebraic Topology",
+		CA => "CA", "Classical Analysis and ODEs",
+		CO => "CO", "Combinatorics",
+		CT => "CT", "Category Theory",
+		CV => "CV", "Complex Variables",
+		DG => "DG", "Differential Geometry",
+		DS => "DS", "Dynamical Systems",
+		FA => "FA", "Functional Analysis",
+		GM => "GM", "General Mathematics",
+		GN => "GN", "General Topology",
+		GR => "GR", "Group Theory",
+		GT => "GT", "Geometric Topology",
+		HO => "HO", "History and Overview",
+		IT => "IT", "Information Theory",
+		KT => "KT", "K-Theory and Homology",
+		LO => "LO", "Logic",
+		MG => "MG", "Metric Geometry",
+		MP => "MP", "Mathematical Physics",
+		NA => "NA", "Numerical Analysis",
+		NT => "NT", "Number Theory",
+		OA => "OA", "Operator Algebras",
+		OC => "OC", "Optimization and Control",
+		PR => "PR", "Probability",
+		QA => "QA", "Quantum Algebra",
+		RA => "RA", "Rings and Algebras",
+		RT => "RT", "Representation Theory",
+		SG => "SG", "Symplectic Geometry",
+		SP => "SP", "Spectral Theory",
+		ST => "ST", "Statistics Theory",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Nonlinear Sciences (`nlin`) archive
+	NlinSubject {
+		AO => "AO", "Adaptation and Self-Organizing Systems",
+		CD => "CD", "Chaotic Dynamics",
+		CG => "CG", "Cellular Automata and Lattice Gases",
+		PS => "PS", "Pattern Formation and Solitons",
+		SI => "SI", "Exactly Solvable and Integrable Systems",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Physics (`physics`) archive
+	PhysicsSubject {
+		AccPh => "acc-ph", "Accelerator Physics",
+		AoPh => "ao-ph", "Atmospheric and Oceanic Physics",
+		AppPh => "app-ph", "Applied Physics",
+		AtmClus => "atm-clus", "Atomic and Molecular Clusters",
+		AtomPh => "atom-ph", "Atomic Physics",
+		BioPh => "bio-ph", "Biological Physics",
+		ChemPh => "chem-ph", "Chemical Physics",
+		ClassPh => "class-ph", "Classical Physics",
+		CompPh => "comp-ph", "Computational Physics",
+		DataAn => "data-an", "Data Analysis, Statistics and Probability",
+		EdPh => "ed-ph", "Physics Education",
+		FluDyn => "flu-dyn", "Fluid Dynamics",
+		GenPh => "gen-ph", "General Physics",
+		GeoPh => "geo-ph", "Geophysics",
+		HistPh => "hist-ph", "History and Philosophy of Physics",
+		InsDet => "ins-det", "Instrumentation and Detectors",
+		MedPh => "med-ph", "Medical Physics",
+		Optics => "optics", "Optics",
+		PlasmPh => "plasm-ph", "Plasma Physics",
+		PopPh => "pop-ph", "Popular Physics",
+		SocPh => "soc-ph", "Physics and Society",
+		SpacePh => "space-ph", "Space Physics",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Quantitative Biology (`q-bio`) archive
+	QBioSubject {
+		BM => "BM", "Biomolecules",
+		CB => "CB", "Cell Behavior",
+		GN => "GN", "Genomics",
+		MN => "MN", "Molecular Networks",
+		NC => "NC", "Neurons and Cognition",
+		OT => "OT", "Other Quantitative Biology",
+		PE => "PE", "Populations and Evolution",
+		QM => "QM", "Quantitative Methods",
+		SC => "SC", "Subcellular Processes",
+		TO => "TO", "Tissues and Organs",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Quantitative Finance (`q-fin`) archive
+	QFinSubject {
+		CP => "CP", "Computational Finance",
+		EC => "EC", "Economics",
+		GN => "GN", "General Finance",
+		MF => "MF", "Mathematical Finance",
+		PM => "PM", "Portfolio Management",
+		PR => "PR", "Pricing of Securities",
+		RM => "RM", "Risk Management",
+		ST => "ST", "Statistical Finance",
+		TR => "TR", "Trading and Market Microstructure",
+	}
+}
+
+subject_enum! {
+	/// The subject classes of the Statistics (`stat`) archive
+	StatSubject {
+		AP => "AP", "Applications",
+		CO => "CO", "Computation",
+		ME => "ME", "Methodology",
+		ML => "ML", "Machine Learning",
+		OT => "OT", "Other Statistics",
+		TH => "TH", "Statistics Theory",
+	}
+}
+
+/// The subject class of an arXiv category, typed by the archive it belongs to
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivCategoryId, ArxivSubject, CsSubject};
+///
+/// let category = ArxivCategoryId::from_str("cs.LG").unwrap();
+/// assert_eq!(category.typed_subject(), Some(ArxivSubject::Cs(CsSubject::LG)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArxivSubject {
+	AstroPh(AstroPhSubject),
+	CondMat(CondMatSubject),
+	Cs(CsSubject),
+	Econ(EconSubject),
+	Eess(EessSubject),
+	Math(MathSubject),
+	Nlin(NlinSubject),
+	Physics(PhysicsSubject),
+	QBio(QBioSubject),
+	QFin(QFinSubject),
+	Stat(StatSubject),
+}
+
+impl ArxivSubject {
+	/// Parses the subject class of the given archive, if the archive has any subject classes.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArxivArchive, ArxivSubject, MathSubject};
+	///
+	/// assert_eq!(
+	///     ArxivSubject::parse(ArxivArchive::Math, "AG"),
+	///     Some(ArxivSubject::Math(MathSubject::AG))
+	/// );
+	/// assert_eq!(ArxivSubject::parse(ArxivArchive::HepTh, "AG"), None);
+	/// ```
+	pub fn parse(archive: ArxivArchive, s: &str) -> Option<Self> {
+		match archive {
+			ArxivArchive::AstroPh => s.parse().ok().map(Self::AstroPh),
+			ArxivArchive::CondMat => s.parse().ok().map(Self::CondMat),
+			ArxivArchive::Cs => s.parse().ok().map(Self::Cs),
+			ArxivArchive::Econ => s.parse().ok().map(Self::Econ),
+			ArxivArchive::Eess => s.parse().ok().map(Self::Eess),
+			ArxivArchive::Math => s.parse().ok().map(Self::Math),
+			ArxivArchive::Nlin => s.parse().ok().map(Self::Nlin),
+			ArxivArchive::Physics => s.parse().ok().map(Self::Physics),
+			ArxivArchive::QBio => s.parse().ok().map(Self::QBio),
+			ArxivArchive::QFin => s.parse().ok().map(Self::QFin),
+			ArxivArchive::Stat => s.parse().ok().map(Self::Stat),
+			ArxivArchive::GrQc
+			| ArxivArchive::HepEx
+			| ArxivArchive::HepLat
+			| ArxivArchive::HepPh
+			| ArxivArchive::HepTh
+			| ArxivArchive::MathPh
+			| ArxivArchive::NuclEx
+			| ArxivArchive::NuclTh
+			| ArxivArchive::QuantPh => None,
+		}
+	}
+
+	/// The identifiers of every subject class of the given archive, in canonical order,
+	/// which is empty if the archive has no subject classes.
+	pub(crate) const fn ids_of(archive: ArxivArchive) -> &'static [&'static str] {
+		match archive {
+			ArxivArchive::AstroPh => AstroPhSubject::IDS,
+			ArxivArchive::CondMat => CondMatSubject::IDS,
+			ArxivArchive::Cs => CsSubject::IDS,
+			ArxivArchive::Econ => EconSubject::IDS,
+			ArxivArchive::Eess => EessSubject::IDS,
+			ArxivArchive::Math => MathSubject::IDS,
+			ArxivArchive::Nlin => NlinSubject::IDS,
+			ArxivArchive::Physics => PhysicsSubject::IDS,
+			ArxivArchive::QBio => QBioSubject::IDS,
+			ArxivArchive::QFin => QFinSubject::IDS,
+			ArxivArchive::Stat => StatSubject::IDS,
+			ArxivArchive::GrQc
+			| ArxivArchive::HepEx
+			| ArxivArchive::HepLat
+			| ArxivArchive::HepPh
+			| ArxivArchive::HepTh
+			| ArxivArchive::MathPh
+			| ArxivArchive::NuclEx
+			| ArxivArchive::NuclTh
+			| ArxivArchive::QuantPh => &[],
+		}
+	}
+
+	/// The archive that the subject class belongs to
+	#[must_use]
+	pub const fn archive(self) -> ArxivArchive {
+		match self {
+			Self::AstroPh(_) => ArxivArchive::AstroPh,
+			Self::CondMat(_) => ArxivArchive::CondMat,
+			Self::Cs(_) => ArxivArchive::Cs,
+			Self::Econ(_) => ArxivArchive::Econ,
+			Self::Eess(_) => ArxivArchive::Eess,
+			Self::Math(_) => ArxivArchive::Math,
+			Self::Nlin(_) => ArxivArchive::Nlin,
+			Self::Physics(_) => ArxivArchive::Physics,
+			Self::QBio(_) => ArxivArchive::QBio,
+			Self::QFin(_) => ArxivArchive::QFin,
+			Self::Stat(_) => ArxivArchive::Stat,
+		}
+	}
+
+	/// The canonical identifier of the subject class, as used in category identifiers
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::AstroPh(s) => s.as_str(),
+			Self::CondMat(s) => s.as_str(),
+			Self::Cs(s) => s.as_str(),
+			Self::Econ(s) => s.as_str(),
+			Self::Eess(s) => s.as_str(),
+			Self::Math(s) => s.as_str(),
+			Self::Nlin(s) => s.as_str(),
+			Self::Physics(s) => s.as_str(),
+			Self::QBio(s) => s.as_str(),
+			Self::QFin(s) => s.as_str(),
+			Self::Stat(s) => s.as_str(),
+		}
+	}
+
+	/// The human-readable name of the subject class
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArxivSubject, CsSubject};
+	///
+	/// assert_eq!(ArxivSubject::Cs(CsSubject::LG).name(), "Machine Learning");
+	/// ```
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::AstroPh(s) => s.name(),
+			Self::CondMat(s) => s.name(),
+			Self::Cs(s) => s.name(),
+			Self::Econ(s) => s.name(),
+			Self::Eess(s) => s.name(),
+			Self::Math(s) => s.name(),
+			Self::Nlin(s) => s.name(),
+			Self::Physics(s) => s.name(),
+			Self::QBio(s) => s.name(),
+			Self::QFin(s) => s.name(),
+			Self::Stat(s) => s.name(),
+		}
+	}
+}
+
+impl Display for ArxivSubject {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn subject_ids_are_sorted() {
+		let tables = [
+			AstroPhSubject::IDS,
+			CondMatSubject::IDS,
+			CsSubject::IDS,
+			EconSubject::IDS,
+			EessSubject::IDS,
+			MathSubject::IDS,
+			NlinSubject::IDS,
+			PhysicsSubject::IDS,
+			QBioSubject::IDS,
+			QFinSubject::IDS,
+			StatSubject::IDS,
+		];
+		for table in tables {
+			assert!(table.windows(2).all(|w| w[0] < w[1]));
+		}
+	}
+
+	#[test]
+	fn parse_subject() {
+		assert_eq!(CsSubject::from_str("LG"), Ok(CsSubject::LG));
+		assert_eq!(PhysicsSubject::from_str("acc-ph"), Ok(PhysicsSubject::AccPh));
+		assert_eq!(CondMatSubject::from_str("LG"), Err(()));
+	}
+
+	#[test]
+	fn display_subject() {
+		assert_eq!(CondMatSubject::QuantGas.to_string(), "quant-gas");
+		assert_eq!(ArxivSubject::Stat(StatSubject::ML).to_string(), "ML");
+	}
+
+	#[test]
+	fn subject_archive() {
+		assert_eq!(ArxivSubject::Nlin(NlinSubject::CD).archive(), ArxivArchive::Nlin);
+	}
+}
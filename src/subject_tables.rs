@@ -1,18 +0,0 @@
-// TODO: Auto-generate the tables below from "https://arxiv.org/category_taxonomy" in a build.rs file
-
-pub(crate) const COMPSCI_TABLE: &[&str] = &[
-	"AI", "AR", "CC", "CE", "CG", "CL", "CR", "CV", "CY", "DB", "DC", "DL", "DM", "DS", "ET", "FL",
-	"GL", "GR", "GT", "HC", "IR", "IT", "LG", "LO", "MA", "MM", "MS", "NA", "NI", "OH", "OS", "PF",
-	"PL", "RO", "SC", "SD", "SE", "SI", "SY",
-];
-
-pub(crate) const MATH_TABLE: &[&str] = &[
-	"AC", "AG", "AP", "AT", "CA", "CO", "CT", "CV", "DG", "DS", "FA", "GM", "GN", "GR", "GT", "HO",
-	"IT", "KT", "LO", "MG", "MP", "NA", "NT", "OA", "OC", "PR", "QA", "RA", "RT", "SG", "SP", "ST",
-];
-
-pub(crate) const PHYSICS_TABLE: &[&str] = &[
-	"acc-ph", "ao-ph", "app-ph", "atm-clus", "atom-ph", "bio-ph", "chem-ph", "class-ph", "comp-ph",
-	"data-an", "ed-pn", "flu-dyn", "gen-ph", "geo-ph", "hist-ph", "ins-det", "med-ph", "optics",
-	"plasm-ph", "pop-ph", "soc-ph", "space-ph",
-];
@@ -0,0 +1,327 @@
+use crate::{ArxivArchive, ArxivCategoryId, ArxivCategoryIdError};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::sync::{PoisonError, RwLock};
+
+/// The taxonomy consulted by [`ArxivCategoryId::try_new`], if one is installed
+static INSTALLED: RwLock<Option<Taxonomy>> = RwLock::new(None);
+
+/// An error that can occur when loading or extending a [`Taxonomy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaxonomyError {
+	/// The document could not be parsed, along with the reason
+	Syntax(String),
+	/// The archive is not part of the category taxonomy
+	UnknownArchive(String),
+	/// The subject class is empty, longer than 15 bytes, or contains a dot or whitespace
+	InvalidSubject(String),
+}
+
+impl Error for TaxonomyError {}
+
+impl Display for TaxonomyError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Syntax(e) => write!(f, "The taxonomy document could not be parsed: {}", e),
+			Self::UnknownArchive(a) => {
+				write!(f, "The archive \"{}\" is not part of the taxonomy.", a)
+			}
+			Self::InvalidSubject(s) => write!(f, "The subject class \"{}\" is not valid.", s),
+		}
+	}
+}
+
+/// A category taxonomy that extends the built-in taxonomy with subject classes loaded at
+/// runtime, so that applications can accept newly introduced categories before they are
+/// part of a release of this crate.
+///
+/// Archives are fixed, but any archive can receive additional subject classes of up to 15
+/// bytes, which an [`ArxivCategoryId`] keeps inline.
+///
+/// A taxonomy recognizes its subject classes with [`Taxonomy::parse`] and
+/// [`Taxonomy::try_category`]. Once it is installed with [`Taxonomy::install`], they are
+/// recognized wherever categories are parsed, including [`ArxivCategoryId::from_str`], the
+/// [`ArxivCategorySet`](crate::ArxivCategorySet) of metadata records, stamps, and
+/// deserialization, so that the categories of the taxonomy round-trip through their string
+/// forms.
+///
+/// A taxonomy document maps archives to their additional subject classes and names, and
+/// can be loaded from JSON with the `taxonomy-json` feature, or from TOML with the
+/// `taxonomy-toml` feature:
+///
+/// ```json
+/// { "eess": { "XX": "Example Subject" } }
+/// ```
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use arxiv::{ArxivArchive, ArxivCategoryId, Taxonomy};
+///
+/// let mut taxonomy = Taxonomy::new();
+/// assert!(taxonomy.parse("econ.XX").is_err());
+///
+/// taxonomy.add_subject(ArxivArchive::Econ, "XX", "Example Subject").unwrap();
+/// let category = taxonomy.parse("econ.XX").unwrap();
+/// assert_eq!(category.to_string(), "econ.XX");
+/// assert_eq!(taxonomy.name_of(&category), "Example Subject");
+///
+/// // the built-in categories are still valid
+/// assert_eq!(taxonomy.parse("cs.LG"), ArxivCategoryId::from_str("cs.LG"));
+///
+/// // while the added subject classes are only valid elsewhere once the taxonomy is installed
+/// assert!(ArxivCategoryId::from_str("econ.XX").is_err());
+/// taxonomy.install();
+/// assert_eq!(ArxivCategoryId::from_str("econ.XX"), Ok(category));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Taxonomy {
+	subjects: BTreeMap<ArxivArchive, BTreeMap<String, String>>,
+}
+
+impl Taxonomy {
+	/// Creates a taxonomy without any additional subject classes.
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a subject class to an archive, along with its human-readable name.
+	pub fn add_subject(
+		&mut self,
+		archive: ArxivArchive,
+		subject: &str,
+		name: &str,
+	) -> Result<ArxivCategoryId, TaxonomyError> {
+		if let Some(category) = self.try_category(archive, subject) {
+			return Ok(category);
+		}
+
+		let category = ArxivCategoryId::added(archive, subject)
+			.filter(|_| {
+				!subject.is_empty()
+					&& !subject.contains(ArxivCategoryId::TOKEN_DELIM)
+					&& !subject.contains(char::is_whitespace)
+			})
+			.ok_or_else(|| TaxonomyError::InvalidSubject(String::from(subject)))?;
+		self.subjects
+			.entry(archive)
+			.or_default()
+			.insert(String::from(subject), String::from(name));
+
+		Ok(category)
+	}
+
+	/// The subject classes added to the built-in taxonomy, in order of their archives and
+	/// subject classes
+	pub fn added(&self) -> impl Iterator<Item = ArxivCategoryId> + '_ {
+		self.subjects.iter().flat_map(|(archive, subjects)| {
+			subjects
+				.keys()
+				.filter_map(move |subject| ArxivCategoryId::added(*archive, subject))
+		})
+	}
+
+	/// Checks if the subject class is valid for the archive, either in the built-in taxonomy
+	/// or as an added subject class. This is the equivalent of [`ArxivCategoryId::try_new`].
+	pub fn try_category(&self, archive: ArxivArchive, subject: &str) -> Option<ArxivCategoryId> {
+		ArxivCategoryId::try_builtin(archive, subject).or_else(|| {
+			self.subjects.get(&archive)?.get(subject)?;
+			ArxivCategoryId::added(archive, subject)
+		})
+	}
+
+	/// Parses a category identifier, accepting both built-in and added subject classes.
+	/// This is the equivalent of [`ArxivCategoryId::from_str`].
+	pub fn parse(&self, s: &str) -> Result<ArxivCategoryId, ArxivCategoryIdError> {
+		let (archive, subject) = ArxivCategoryId::split(s)?;
+
+		ArxivArchive::from_str(archive)
+			.ok()
+			.and_then(|archive| self.try_category(archive, subject))
+			.ok_or_else(|| ArxivCategoryIdError::Unknown(ArxivCategoryId::suggest(s)))
+	}
+
+	/// The human-readable name of a category, including added subject classes.
+	/// This is the equivalent of [`ArxivCategoryId::name`].
+	pub fn name_of(&self, category: &ArxivCategoryId) -> &str {
+		self.subjects
+			.get(&category.archive())
+			.and_then(|subjects| subjects.get(category.subject()))
+			.map_or_else(|| category.name(), String::as_str)
+	}
+
+	/// Installs the taxonomy, so that its added subject classes are recognized wherever
+	/// categories are parsed, replacing the taxonomy installed before, if any
+	pub fn install(self) {
+		*INSTALLED.write().unwrap_or_else(PoisonError::into_inner) = Some(self);
+	}
+
+	/// Uninstalls the installed taxonomy, if any, so that only the built-in taxonomy is
+	/// recognized again
+	pub fn uninstall() -> Option<Self> {
+		INSTALLED
+			.write()
+			.unwrap_or_else(PoisonError::into_inner)
+			.take()
+	}
+
+	/// Checks if the subject class was added to the archive by the installed taxonomy
+	pub(crate) fn try_installed(archive: ArxivArchive, subject: &str) -> Option<ArxivCategoryId> {
+		INSTALLED
+			.read()
+			.unwrap_or_else(PoisonError::into_inner)
+			.as_ref()?
+			.try_category(archive, subject)
+	}
+
+	/// Adds every subject class of a taxonomy document, which maps archives
+	/// to their subject classes and names.
+	#[cfg(any(feature = "taxonomy-json", feature = "taxonomy-toml"))]
+	fn extend_from_document(
+		&mut self,
+		document: BTreeMap<String, BTreeMap<String, String>>,
+	) -> Result<(), TaxonomyError> {
+		for (archive, subjects) in document {
+			let archive = ArxivArchive::from_str(&archive)
+				.map_err(|_| TaxonomyError::UnknownArchive(archive))?;
+			for (subject, name) in subjects {
+				self.add_subject(archive, &subject, &name)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Loads a taxonomy from a JSON document.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let taxonomy = Taxonomy::from_json(r#"{ "eess": { "XX": "Example Subject" } }"#).unwrap();
+	/// assert!(taxonomy.parse("eess.XX").is_ok());
+	/// ```
+	#[cfg(feature = "taxonomy-json")]
+	pub fn from_json(s: &str) -> Result<Self, TaxonomyError> {
+		let document = serde_json::from_str(s).map_err(|e| TaxonomyError::Syntax(e.to_string()))?;
+		let mut taxonomy = Self::new();
+		taxonomy.extend_from_document(document)?;
+		Ok(taxonomy)
+	}
+
+	/// Loads a taxonomy from a TOML document, where each archive is a table.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let taxonomy = Taxonomy::from_toml("[eess]\nXX = \"Example Subject\"").unwrap();
+	/// assert!(taxonomy.parse("eess.XX").is_ok());
+	/// ```
+	#[cfg(feature = "taxonomy-toml")]
+	pub fn from_toml(s: &str) -> Result<Self, TaxonomyError> {
+		let document = toml::from_str(s).map_err(|e| TaxonomyError::Syntax(e.to_string()))?;
+		let mut taxonomy = Self::new();
+		taxonomy.extend_from_document(document)?;
+		Ok(taxonomy)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn add_subject() {
+		let mut taxonomy = Taxonomy::new();
+		let category = taxonomy
+			.add_subject(ArxivArchive::Cs, "QC", "Quantum Computing")
+			.unwrap();
+		assert_eq!(taxonomy.parse("cs.QC"), Ok(category));
+		assert_eq!(taxonomy.added().collect::<Vec<_>>(), vec![category]);
+
+		for archive in [
+			ArxivArchive::QBio,
+			ArxivArchive::Econ,
+			ArxivArchive::AstroPh,
+		] {
+			taxonomy
+				.add_subject(archive, "XX", "Example Subject")
+				.unwrap();
+		}
+		taxonomy
+			.add_subject(ArxivArchive::Cs, "AA", "Example Subject")
+			.unwrap();
+		assert_eq!(
+			taxonomy
+				.added()
+				.map(|category| category.to_string())
+				.collect::<Vec<_>>(),
+			["astro-ph.XX", "cs.AA", "cs.QC", "econ.XX", "q-bio.XX"]
+		);
+		assert!(ArxivCategoryId::from_str("cs.QC").is_err());
+	}
+
+	#[test]
+	fn add_builtin_subject() {
+		let mut taxonomy = Taxonomy::new();
+		let category = taxonomy
+			.add_subject(ArxivArchive::Cs, "LG", "Learning")
+			.unwrap();
+		assert_eq!(taxonomy.name_of(&category), "Machine Learning");
+		assert_eq!(taxonomy.added().count(), 0);
+	}
+
+	#[test]
+	fn installed_subjects_round_trip() {
+		let mut taxonomy = Taxonomy::new();
+		let category = taxonomy
+			.add_subject(ArxivArchive::QFin, "ZZ", "Example Subject")
+			.unwrap();
+		assert!(ArxivCategoryId::from_str("q-fin.ZZ").is_err());
+
+		taxonomy.install();
+		assert_eq!(ArxivCategoryId::from_str(&category.to_string()), Ok(category));
+		let set = crate::ArxivCategorySet::from_str("q-fin.ZZ q-fin.TR").unwrap();
+		assert_eq!(set.primary(), category);
+		assert_eq!(set.to_string(), "q-fin.ZZ q-fin.TR");
+		let stamp =
+			crate::ArxivStamp::from_str("arXiv:2101.00001v1 [q-fin.ZZ] 1 Jan 2021").unwrap();
+		assert_eq!(stamp.category(), Some(&category));
+		#[cfg(feature = "serde")]
+		{
+			let json = serde_json::to_string(&category).unwrap();
+			assert_eq!(serde_json::from_str::<ArxivCategoryId>(&json).unwrap(), category);
+		}
+
+		let taxonomy = Taxonomy::uninstall().unwrap();
+		assert_eq!(taxonomy.name_of(&category), "Example Subject");
+		assert!(ArxivCategoryId::from_str("q-fin.ZZ").is_err());
+	}
+
+	#[test]
+	fn add_invalid_subject() {
+		let mut taxonomy = Taxonomy::new();
+		assert_eq!(
+			taxonomy.add_subject(ArxivArchive::Cs, "Q.C", ""),
+			Err(TaxonomyError::InvalidSubject(String::from("Q.C")))
+		);
+		assert!(taxonomy.add_subject(ArxivArchive::Cs, "", "").is_err());
+		assert!(taxonomy
+			.add_subject(ArxivArchive::Cs, "ABCDEFGHIJKLMNOP", "")
+			.is_err());
+	}
+
+	#[cfg(feature = "taxonomy-json")]
+	#[test]
+	fn json_unknown_archive() {
+		assert_eq!(
+			Taxonomy::from_json(r#"{ "foo": { "XX": "Example" } }"#),
+			Err(TaxonomyError::UnknownArchive(String::from("foo")))
+		);
+		assert!(matches!(Taxonomy::from_json("{"), Err(TaxonomyError::Syntax(_))));
+	}
+}
@@ -0,0 +1,256 @@
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+
+/// An error of an HTTP transport, such as a connection failure
+pub type TransportError = Box<dyn Error + Send + Sync>;
+
+/// The future of the response to a request sent by an [`HttpTransport`]
+pub type TransportFuture<'a> =
+	Pin<Box<dyn Future<Output = Result<HttpResponse, TransportError>> + Send + 'a>>;
+
+/// A response to an HTTP request sent by an [`HttpTransport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+	/// The status code of the response
+	pub status: u16,
+	/// The headers of the response, in order
+	pub headers: Vec<(String, String)>,
+	/// The body of the response
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	/// Creates a response without headers
+	#[must_use]
+	pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+		Self {
+			status,
+			headers: Vec::new(),
+			body: body.into(),
+		}
+	}
+
+	/// Adds a header to the response
+	#[must_use]
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.push((name.into(), value.into()));
+		self
+	}
+
+	/// The value of the first header with the name, ignoring ASCII case
+	#[must_use]
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// Whether the status code of the response is successful (2xx)
+	#[must_use]
+	#[inline]
+	pub fn is_success(&self) -> bool {
+		(200..300).contains(&self.status)
+	}
+}
+
+/// A transport sending the HTTP requests of an [`ArxivClient`](crate::ArxivClient), which
+/// can be implemented to use an HTTP client other than [`reqwest`], or a custom network stack
+/// such as a corporate proxy
+///
+/// Requests return boxed futures, so that the trait can be used as a trait object.
+///
+/// # Examples
+/// ```
+/// use arxiv::{HttpTransport, TransportError, TransportFuture};
+///
+/// struct Offline;
+///
+/// impl HttpTransport for Offline {
+///     fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+///         Box::pin(async move {
+///             Err(TransportError::from(format!("cannot request {} while offline", url)))
+///         })
+///     }
+/// }
+/// ```
+///
+/// [`reqwest`]: https://docs.rs/reqwest
+pub trait HttpTransport: Send + Sync {
+	/// Sends a `GET` request to the URL, which includes the query string, returning the
+	/// response whatever its status code.
+	fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a>;
+
+	/// Sends a `GET` request to the URL for the bytes of the resource from the offset, with a
+	/// `Range: bytes=<offset>-` header, returning the response whatever its status code.
+	///
+	/// The default implementation ignores the offset and requests the whole resource, which
+	/// downloads are then restarted with.
+	fn get_from<'a>(&'a self, url: &'a str, offset: u64) -> TransportFuture<'a> {
+		let _ = offset;
+		self.get(url)
+	}
+}
+
+/// A transport sending the HTTP requests of an
+/// [`ArxivBlockingClient`](crate::ArxivBlockingClient), blocking until their responses are
+/// received
+///
+/// # Examples
+/// ```
+/// use arxiv::{BlockingHttpTransport, HttpResponse, TransportError};
+///
+/// struct Offline;
+///
+/// impl BlockingHttpTransport for Offline {
+///     fn get(&self, url: &str) -> Result<HttpResponse, TransportError> {
+///         Err(format!("cannot request {} while offline", url).into())
+///     }
+/// }
+/// ```
+pub trait BlockingHttpTransport: Send + Sync {
+	/// Sends a `GET` request to the URL, which includes the query string, returning the
+	/// response whatever its status code.
+	fn get(&self, url: &str) -> Result<HttpResponse, TransportError>;
+
+	/// Sends a `GET` request to the URL for the bytes of the resource from the offset, with a
+	/// `Range: bytes=<offset>-` header, returning the response whatever its status code.
+	///
+	/// The default implementation ignores the offset and requests the whole resource, which
+	/// downloads are then restarted with.
+	fn get_from(&self, url: &str, offset: u64) -> Result<HttpResponse, TransportError> {
+		let _ = offset;
+		self.get(url)
+	}
+}
+
+/// The default transport of [`ArxivClient`](crate::ArxivClient), built on
+/// [`reqwest`](::reqwest)
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+	http: ::reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+	/// Creates a transport with a default HTTP client
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(feature = "reqwest")]
+impl From<::reqwest::Client> for ReqwestTransport {
+	fn from(http: ::reqwest::Client) -> Self {
+		Self { http }
+	}
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpTransport for ReqwestTransport {
+	fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+		Box::pin(async move { into_response(self.http.get(url).send().await?).await })
+	}
+
+	fn get_from<'a>(&'a self, url: &'a str, offset: u64) -> TransportFuture<'a> {
+		let request = self
+			.http
+			.get(url)
+			.header("Range", format!("bytes={}-", offset));
+		Box::pin(async move { into_response(request.send().await?).await })
+	}
+}
+
+/// The default transport of [`ArxivBlockingClient`](crate::ArxivBlockingClient), built on
+/// the blocking client of [`reqwest`](::reqwest)
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestBlockingTransport {
+	http: ::reqwest::blocking::Client,
+}
+
+#[cfg(feature = "blocking")]
+impl ReqwestBlockingTransport {
+	/// Creates a transport with a default HTTP client
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl From<::reqwest::blocking::Client> for ReqwestBlockingTransport {
+	fn from(http: ::reqwest::blocking::Client) -> Self {
+		Self { http }
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingHttpTransport for ReqwestBlockingTransport {
+	fn get(&self, url: &str) -> Result<HttpResponse, TransportError> {
+		into_blocking_response(self.http.get(url).send()?)
+	}
+
+	fn get_from(&self, url: &str, offset: u64) -> Result<HttpResponse, TransportError> {
+		let request = self
+			.http
+			.get(url)
+			.header("Range", format!("bytes={}-", offset));
+		into_blocking_response(request.send()?)
+	}
+}
+
+#[cfg(feature = "blocking")]
+fn into_blocking_response(
+	response: ::reqwest::blocking::Response,
+) -> Result<HttpResponse, TransportError> {
+	let status = response.status().as_u16();
+	let headers = response
+		.headers()
+		.iter()
+		.filter_map(|(name, value)| {
+			Some((String::from(name.as_str()), String::from(value.to_str().ok()?)))
+		})
+		.collect();
+
+	Ok(HttpResponse {
+		status,
+		headers,
+		body: response.bytes()?.to_vec(),
+	})
+}
+
+#[cfg(feature = "reqwest")]
+async fn into_response(response: ::reqwest::Response) -> Result<HttpResponse, TransportError> {
+	let status = response.status().as_u16();
+	let headers = response
+		.headers()
+		.iter()
+		.filter_map(|(name, value)| {
+			Some((String::from(name.as_str()), String::from(value.to_str().ok()?)))
+		})
+		.collect();
+
+	Ok(HttpResponse {
+		status,
+		headers,
+		body: response.bytes().await?.to_vec(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn response_headers() {
+		let response = HttpResponse::new(429, "").with_header("Retry-After", "5");
+		assert_eq!(response.header("retry-after"), Some("5"));
+		assert_eq!(response.header("Content-Type"), None);
+		assert!(!response.is_success());
+		assert!(HttpResponse::new(200, "<feed/>").is_success());
+	}
+}
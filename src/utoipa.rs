@@ -0,0 +1,129 @@
+//! Support for documenting identifiers and categories in OpenAPI descriptions with
+//! [`utoipa`](::utoipa), as strings with a format, a pattern and an example.
+
+use crate::pattern::{ARXIV_CATEGORY_PATTERN, ARXIV_ID_OLD_PATTERN, ARXIV_ID_PATTERN};
+use crate::{ArxivCategoryId, ArxivId, ArxivIdOld};
+use ::utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use ::utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+use ::utoipa::openapi::{RefOr, Required};
+use ::utoipa::{IntoParams, PartialSchema, ToSchema};
+use std::borrow::Cow;
+
+/// A string schema with a custom format, a pattern and an example
+fn string_schema(description: &str, format: &str, pattern: &str, example: &str) -> Schema {
+	ObjectBuilder::new()
+		.schema_type(Type::String)
+		.description(Some(description))
+		.format(Some(SchemaFormat::Custom(String::from(format))))
+		.pattern(Some(pattern))
+		.examples([example])
+		.into()
+}
+
+/// A required parameter, which is a path parameter unless specified otherwise
+fn parameter(
+	name: &str,
+	description: &str,
+	schema: RefOr<Schema>,
+	parameter_in: Option<ParameterIn>,
+) -> Parameter {
+	ParameterBuilder::new()
+		.name(name)
+		.parameter_in(parameter_in.unwrap_or(ParameterIn::Path))
+		.required(Required::True)
+		.description(Some(description))
+		.schema(Some(schema))
+		.build()
+}
+
+macro_rules! impl_to_schema {
+	($ty:ty, $name:literal, $description:literal, $format:literal, $pattern:expr, $example:literal) => {
+		impl PartialSchema for $ty {
+			fn schema() -> RefOr<Schema> {
+				string_schema($description, $format, $pattern, $example).into()
+			}
+		}
+
+		impl ToSchema for $ty {
+			fn name() -> Cow<'static, str> {
+				Cow::Borrowed($name)
+			}
+		}
+	};
+}
+
+impl_to_schema!(
+	ArxivId,
+	"ArxivId",
+	"An arXiv identifier",
+	"arxiv-id",
+	ARXIV_ID_PATTERN,
+	"arXiv:2101.00001v1"
+);
+impl_to_schema!(
+	ArxivIdOld,
+	"ArxivIdOld",
+	"An arXiv identifier of the scheme used up to March 2007",
+	"arxiv-id-old",
+	ARXIV_ID_OLD_PATTERN,
+	"hep-th/9901001"
+);
+impl_to_schema!(
+	ArxivCategoryId,
+	"ArxivCategoryId",
+	"An arXiv category",
+	"arxiv-category",
+	ARXIV_CATEGORY_PATTERN,
+	"cs.LG"
+);
+
+/// A path parameter named `id`, such as in `/papers/{id}`
+impl IntoParams for ArxivId {
+	fn into_params(parameter_in_provider: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+		vec![parameter(
+			"id",
+			"An arXiv identifier",
+			Self::schema(),
+			parameter_in_provider(),
+		)]
+	}
+}
+
+/// A path parameter named `category`, such as in `/categories/{category}`
+impl IntoParams for ArxivCategoryId {
+	fn into_params(parameter_in_provider: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+		vec![parameter(
+			"category",
+			"An arXiv category",
+			Self::schema(),
+			parameter_in_provider(),
+		)]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn identifier_schema() {
+		let schema = match ArxivId::schema() {
+			RefOr::T(Schema::Object(object)) => object,
+			_ => unreachable!(),
+		};
+		assert!(schema.format == Some(SchemaFormat::Custom(String::from("arxiv-id"))));
+		assert_eq!(schema.pattern.as_deref(), Some(ARXIV_ID_PATTERN));
+		assert_eq!(ArxivId::name(), "ArxivId");
+	}
+
+	#[test]
+	fn identifier_params() {
+		let params = ArxivId::into_params(|| None);
+		assert_eq!(params.len(), 1);
+		assert_eq!(params[0].name, "id");
+		assert!(params[0].parameter_in == ParameterIn::Path);
+
+		let params = ArxivCategoryId::into_params(|| Some(ParameterIn::Query));
+		assert!(params[0].parameter_in == ParameterIn::Query);
+	}
+}